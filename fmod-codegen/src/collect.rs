@@ -0,0 +1,169 @@
+// Copyright (c) 2024 Lily Lyons
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::{collections::BTreeMap, path::PathBuf};
+
+use fmod::studio::{LoadBankFlags, ParameterDescription, ParameterFlags};
+use fmod::{Guid, Utf8CString};
+
+use crate::{Error, Result};
+
+/// Everything we know about the loaded banks, keyed by their FMOD path.
+// BTreeMaps so the generated code is always in the same order
+#[derive(Debug, Default)]
+pub(crate) struct Project {
+    pub(crate) events: BTreeMap<String, Event>,
+    pub(crate) snapshots: BTreeMap<String, Event>,
+    pub(crate) buses: BTreeMap<String, Guid>,
+    pub(crate) vcas: BTreeMap<String, Guid>,
+    pub(crate) banks: BTreeMap<String, Guid>,
+    pub(crate) parameters: Vec<Parameter>,
+}
+
+#[derive(Debug)]
+pub(crate) struct Event {
+    pub(crate) guid: Guid,
+    pub(crate) parameters: Vec<Parameter>,
+}
+
+#[derive(Debug)]
+pub(crate) struct Parameter {
+    pub(crate) name: String,
+    pub(crate) id: fmod::studio::ParameterID,
+    pub(crate) minimum: f32,
+    pub(crate) maximum: f32,
+    pub(crate) labels: Vec<String>,
+}
+
+pub(crate) fn collect(banks: &[PathBuf]) -> Result<Project> {
+    let mut builder = unsafe {
+        // Safety: fmod-codegen is intended to be run from a build script, where nothing else is using FMOD.
+        fmod::studio::SystemBuilder::new()?
+    };
+    builder.core_builder().output(fmod::OutputType::NoSound)?;
+    let system = builder.build(0, fmod::studio::InitFlags::NORMAL, fmod::InitFlags::NORMAL)?;
+
+    let project = collect_from_system(system, banks);
+
+    unsafe {
+        // Safety: the system is not used after this point, and no other system exists.
+        system.release()?;
+    }
+
+    project
+}
+
+fn collect_from_system(system: fmod::studio::System, paths: &[PathBuf]) -> Result<Project> {
+    let mut banks = Vec::with_capacity(paths.len());
+    for path in paths {
+        let path_str = path
+            .to_str()
+            .ok_or_else(|| Error::NonUtf8Path(path.clone()))?;
+        let path_str = Utf8CString::new(path_str).map_err(fmod::Error::from)?;
+        banks.push(system.load_bank_file(&path_str, LoadBankFlags::NORMAL)?);
+    }
+
+    let mut paths = BTreeMap::new();
+    // the strings table has the path of everything, including things from banks we haven't loaded
+    for bank in &banks {
+        for index in 0..bank.string_count()? {
+            let (guid, path) = bank.get_string_info(index)?;
+            paths.insert(path.as_str().to_string(), guid);
+        }
+    }
+    // the lists only contain what is actually in a bank, but if we somehow missed something in the strings table we want it anyway
+    for bank in &banks {
+        paths.insert(bank.get_path()?.as_str().to_string(), bank.get_id()?);
+        for event in bank.get_event_list()? {
+            paths.insert(event.get_path()?.as_str().to_string(), event.get_id()?);
+        }
+        for bus in bank.get_bus_list()? {
+            paths.insert(bus.get_path()?.as_str().to_string(), bus.get_id()?);
+        }
+        for vca in bank.get_vca_list()? {
+            paths.insert(vca.get_path()?.as_str().to_string(), vca.get_id()?);
+        }
+    }
+
+    let mut project = Project::default();
+    for (path, guid) in paths {
+        if let Some(path) = path.strip_prefix("event:") {
+            let event = collect_event(system, guid)?;
+            project.events.insert(path.to_string(), event);
+        } else if let Some(path) = path.strip_prefix("snapshot:") {
+            let event = collect_event(system, guid)?;
+            project.snapshots.insert(path.to_string(), event);
+        } else if let Some(path) = path.strip_prefix("bus:") {
+            project.buses.insert(path.to_string(), guid);
+        } else if let Some(path) = path.strip_prefix("vca:") {
+            project.vcas.insert(path.to_string(), guid);
+        } else if let Some(path) = path.strip_prefix("bank:") {
+            project.banks.insert(path.to_string(), guid);
+        }
+        // parameter:/ paths are covered by the global parameter list below
+    }
+
+    for description in system.get_parameter_description_list()? {
+        let labels = collect_labels(&description, |index| {
+            system.get_parameter_label_by_id(description.id, index)
+        })?;
+        project.parameters.push(parameter(&description, labels));
+    }
+    project.parameters.sort_by(|a, b| a.name.cmp(&b.name));
+
+    Ok(project)
+}
+
+fn collect_event(system: fmod::studio::System, guid: Guid) -> Result<Event> {
+    // events referenced by the strings table may live in a bank that isn't loaded
+    let description = match system.get_event_by_id(guid) {
+        Ok(description) => description,
+        Err(e) if e == fmod::ffi::FMOD_RESULT::FMOD_ERR_EVENT_NOTFOUND => {
+            return Ok(Event {
+                guid,
+                parameters: vec![],
+            })
+        }
+        Err(e) => return Err(e.into()),
+    };
+
+    let mut parameters = vec![];
+    for index in 0..description.parameter_description_count()? {
+        let parameter_description = description.get_parameter_description_by_index(index)?;
+        // global parameters are emitted separately
+        if parameter_description.flags.contains(ParameterFlags::GLOBAL) {
+            continue;
+        }
+        let labels = collect_labels(&parameter_description, |label_index| {
+            description.get_parameter_label_by_index(index, label_index)
+        })?;
+        parameters.push(parameter(&parameter_description, labels));
+    }
+    parameters.sort_by(|a, b| a.name.cmp(&b.name));
+
+    Ok(Event { guid, parameters })
+}
+
+fn collect_labels(
+    description: &ParameterDescription,
+    mut get_label: impl FnMut(i32) -> fmod::Result<Utf8CString>,
+) -> Result<Vec<String>> {
+    let mut labels = vec![];
+    for index in 0..description.label_count() {
+        labels.push(get_label(index)?.as_str().to_string());
+    }
+    Ok(labels)
+}
+
+fn parameter(description: &ParameterDescription, labels: Vec<String>) -> Parameter {
+    Parameter {
+        name: description.name.as_str().to_string(),
+        id: description.id,
+        minimum: description.minimum,
+        maximum: description.maximum,
+        labels,
+    }
+}
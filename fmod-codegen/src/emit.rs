@@ -0,0 +1,314 @@
+// Copyright (c) 2024 Lily Lyons
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+
+use fmod::Guid;
+
+use crate::collect::{Event, Parameter, Project};
+use crate::ident::{pascal_case, screaming_snake_case, snake_case};
+use crate::{Error, Result};
+
+#[derive(Default)]
+struct Module {
+    items: Vec<String>,
+    // rust has separate namespaces for values and types, so a `CLICK` const and a `click` module can coexist
+    values: BTreeSet<String>,
+    types: BTreeSet<String>,
+    children: BTreeMap<String, Module>,
+}
+
+impl Module {
+    fn child(&mut self, ident: String) -> &mut Module {
+        self.types.insert(ident.clone());
+        self.children.entry(ident).or_default()
+    }
+
+    fn add_value(&mut self, module_path: &str, ident: String, item: String) -> Result<()> {
+        if !self.values.insert(ident.clone()) {
+            return Err(Error::NameCollision {
+                module: module_path.to_string(),
+                ident,
+            });
+        }
+        self.items.push(item);
+        Ok(())
+    }
+
+    fn add_type(&mut self, module_path: &str, ident: String, item: String) -> Result<()> {
+        if !self.types.insert(ident.clone()) {
+            return Err(Error::NameCollision {
+                module: module_path.to_string(),
+                ident,
+            });
+        }
+        self.items.push(item);
+        Ok(())
+    }
+
+    fn write(&self, out: &mut String, depth: usize) {
+        let indent = "    ".repeat(depth);
+        for item in &self.items {
+            for line in item.lines() {
+                if line.is_empty() {
+                    out.push('\n');
+                } else {
+                    let _ = writeln!(out, "{indent}{line}");
+                }
+            }
+        }
+        for (ident, child) in &self.children {
+            let _ = writeln!(out, "{indent}pub mod {ident} {{");
+            child.write(out, depth + 1);
+            let _ = writeln!(out, "{indent}}}");
+        }
+    }
+}
+
+pub(crate) fn emit(project: &Project) -> Result<String> {
+    let mut root = Module::default();
+
+    let events = root.child("events".to_string());
+    for (path, event) in &project.events {
+        add_event(events, "events", "event:", path, event)?;
+    }
+
+    let snapshots = root.child("snapshots".to_string());
+    for (path, event) in &project.snapshots {
+        add_event(snapshots, "snapshots", "snapshot:", path, event)?;
+    }
+
+    for (module_name, prefix, items) in [
+        ("buses", "bus:", &project.buses),
+        ("vcas", "vca:", &project.vcas),
+        ("banks", "bank:", &project.banks),
+    ] {
+        let module = root.child(module_name.to_string());
+        for (path, guid) in items {
+            add_guid(module, module_name, prefix, path, *guid)?;
+        }
+    }
+
+    let parameters = root.child("parameters".to_string());
+    for parameter in &project.parameters {
+        add_parameter(parameters, "parameters", "GlobalParameterID", parameter)?;
+    }
+
+    let mut out = String::new();
+    out.push_str("// This file was generated by fmod-codegen. Do not edit it by hand!\n\n");
+    for (ident, child) in &root.children {
+        let _ = writeln!(out, "#[allow(dead_code)]\npub mod {ident} {{");
+        child.write(&mut out, 1);
+        out.push_str("}\n\n");
+    }
+    Ok(out)
+}
+
+// navigates to the module for `path`, returning it with the module path and the last path segment
+fn module_for_path<'m>(
+    module: &'m mut Module,
+    module_path: &str,
+    path: &str,
+) -> (&'m mut Module, String, String) {
+    let mut segments: Vec<&str> = path.trim_start_matches('/').split('/').collect();
+    let name = segments.pop().unwrap_or_default().to_string();
+
+    let mut module = module;
+    let mut module_path = module_path.to_string();
+    for segment in segments {
+        let ident = snake_case(segment);
+        let _ = write!(module_path, "::{ident}");
+        module = module.child(ident);
+    }
+
+    (module, module_path, name)
+}
+
+fn add_guid(
+    module: &mut Module,
+    module_path: &str,
+    prefix: &str,
+    path: &str,
+    guid: Guid,
+) -> Result<()> {
+    let (module, module_path, name) = module_for_path(module, module_path, path);
+    // `bus:/` is the master bus
+    let ident = if name.is_empty() {
+        "MASTER".to_string()
+    } else {
+        screaming_snake_case(&name)
+    };
+
+    let item = format!(
+        "/// `{prefix}{path}`\npub const {ident}: ::fmod::Guid = {};\n",
+        guid_literal(guid)
+    );
+    module.add_value(&module_path, ident, item)
+}
+
+fn add_event(
+    module: &mut Module,
+    module_path: &str,
+    prefix: &str,
+    path: &str,
+    event: &Event,
+) -> Result<()> {
+    add_guid(module, module_path, prefix, path, event.guid)?;
+    if event.parameters.is_empty() {
+        return Ok(());
+    }
+
+    let (module, module_path, name) = module_for_path(module, module_path, path);
+    let ident = snake_case(&name);
+    let module_path = format!("{module_path}::{ident}");
+    let module = module.child(ident);
+    for parameter in &event.parameters {
+        add_parameter(module, &module_path, "EventParameterID", parameter)?;
+    }
+    Ok(())
+}
+
+// `id_type` is the typed ID in fmod::studio, EventParameterID or GlobalParameterID
+fn add_parameter(
+    module: &mut Module,
+    module_path: &str,
+    id_type: &str,
+    parameter: &Parameter,
+) -> Result<()> {
+    // labeled parameters take the enum of their labels, everything else takes a plain value
+    let value_type = if parameter.labels.is_empty() {
+        "f32".to_string()
+    } else {
+        pascal_case(&parameter.name)
+    };
+    let ident = screaming_snake_case(&parameter.name);
+    let item = format!(
+        "/// The `{}` parameter.\npub const {ident}: ::fmod::studio::{id_type}<{value_type}> = ::fmod::studio::{id_type}::new(::fmod::studio::ParameterID {{ data_1: {:#010x}, data_2: {:#010x} }});\n",
+        parameter.name, parameter.id.data_1, parameter.id.data_2,
+    );
+    module.add_value(module_path, ident, item)?;
+
+    if parameter.labels.is_empty() {
+        return Ok(());
+    }
+
+    let ident = value_type;
+    let enum_path = format!("{module_path}::{ident}");
+
+    // FMOD has one label per integer value of a labeled parameter, and label `i` is the value `minimum + i`.
+    // collect fetches labels by index over that range, so numbering variants from `minimum` gives every label its value.
+    #[allow(clippy::cast_precision_loss, clippy::float_cmp)]
+    // label counts are small integers, which f32 represents exactly
+    let covers_range = parameter.labels.len() as f32 == parameter.maximum - parameter.minimum + 1.0;
+    assert!(
+        covers_range,
+        "labels of `{enum_path}` don't cover its range"
+    );
+
+    let mut variants = BTreeSet::new();
+    let mut declaration = String::new();
+    let mut values = String::new();
+    let mut labels = String::new();
+    let mut value = parameter.minimum;
+    for label in &parameter.labels {
+        let variant = pascal_case(label);
+        if !variants.insert(variant.clone()) {
+            return Err(Error::NameCollision {
+                module: enum_path,
+                ident: variant,
+            });
+        }
+        let _ = writeln!(declaration, "    {variant},");
+        let _ = writeln!(values, "            Self::{variant} => {value:?},");
+        let _ = writeln!(labels, "            Self::{variant} => {label:?},");
+        value += 1.0;
+    }
+
+    let item = format!(
+        "/// The labels of the `{name}` parameter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum {ident} {{
+{declaration}}}
+
+impl {ident} {{
+    /// The parameter value this label corresponds to.
+    pub const fn value(self) -> f32 {{
+        match self {{
+{values}        }}
+    }}
+
+    /// The name of this label, as authored in FMOD Studio.
+    pub const fn label(self) -> &'static str {{
+        match self {{
+{labels}        }}
+    }}
+}}
+
+impl From<{ident}> for f32 {{
+    fn from(value: {ident}) -> f32 {{
+        value.value()
+    }}
+}}
+
+impl ::fmod::studio::ParameterValue for {ident} {{
+    fn into_value(self) -> f32 {{
+        self.value()
+    }}
+}}
+",
+        name = parameter.name,
+    );
+    module.add_type(module_path, ident, item)
+}
+
+fn guid_literal(guid: Guid) -> String {
+    let data_4 = guid
+        .data_4
+        .iter()
+        .map(|b| format!("{b:#04x}"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!(
+        "::fmod::Guid {{ data_1: {:#010x}, data_2: {:#06x}, data_3: {:#06x}, data_4: [{data_4}] }}",
+        guid.data_1, guid.data_2, guid.data_3,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn labeled(minimum: f32, maximum: f32, labels: &[&str]) -> Project {
+        let mut project = Project::default();
+        project.parameters.push(Parameter {
+            name: "Surface".to_string(),
+            id: fmod::studio::ParameterID {
+                data_1: 1,
+                data_2: 2,
+            },
+            minimum,
+            maximum,
+            labels: labels.iter().map(ToString::to_string).collect(),
+        });
+        project
+    }
+
+    #[test]
+    fn labels_count_up_from_minimum() {
+        let source = emit(&labeled(1.0, 3.0, &["Grass", "Wood", "Stone"])).unwrap();
+        assert!(source.contains("Self::Grass => 1.0,"));
+        assert!(source.contains("Self::Wood => 2.0,"));
+        assert!(source.contains("Self::Stone => 3.0,"));
+        assert!(source.contains("Self::Stone => \"Stone\","));
+    }
+
+    #[test]
+    #[should_panic = "don't cover its range"]
+    fn labels_must_cover_range() {
+        let _ = emit(&labeled(0.0, 3.0, &["Grass", "Wood"]));
+    }
+}
@@ -0,0 +1,113 @@
+// Copyright (c) 2024 Lily Lyons
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+// FMOD names can contain pretty much anything, so we split them into words and rebuild them as rust identifiers.
+// Anything that isn't ascii alphanumeric is treated as a word separator.
+
+const KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern",
+    "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub",
+    "ref", "return", "self", "static", "struct", "super", "trait", "true", "type", "unsafe", "use",
+    "where", "while", "abstract", "become", "box", "do", "final", "gen", "macro", "override",
+    "priv", "try", "typeof", "unsized", "virtual", "yield",
+];
+
+fn words(name: &str) -> Vec<String> {
+    let mut words = vec![];
+    let mut current = String::new();
+
+    let chars: Vec<char> = name.chars().collect();
+    for (i, &c) in chars.iter().enumerate() {
+        if !c.is_ascii_alphanumeric() {
+            if !current.is_empty() {
+                words.push(std::mem::take(&mut current));
+            }
+            continue;
+        }
+
+        // split camel case: "FootstepGrass" -> "Footstep" "Grass", "UIClick" -> "UI" "Click"
+        if c.is_ascii_uppercase() && !current.is_empty() {
+            let prev = chars[i - 1];
+            let next_is_lower = chars.get(i + 1).is_some_and(char::is_ascii_lowercase);
+            if prev.is_ascii_lowercase() || (prev.is_ascii_uppercase() && next_is_lower) {
+                words.push(std::mem::take(&mut current));
+            }
+        }
+        current.push(c);
+    }
+    if !current.is_empty() {
+        words.push(current);
+    }
+
+    words
+}
+
+fn finish(mut ident: String, fallback: &str) -> String {
+    if ident.is_empty() {
+        ident.push_str(fallback);
+    }
+    if ident.starts_with(|c: char| c.is_ascii_digit()) {
+        ident.insert(0, '_');
+    }
+    if KEYWORDS.contains(&ident.as_str()) || ident == "Self" {
+        ident.push('_');
+    }
+    ident
+}
+
+/// `Click Sound` -> `click_sound`
+pub(crate) fn snake_case(name: &str) -> String {
+    let words: Vec<String> = words(name).iter().map(|w| w.to_ascii_lowercase()).collect();
+    finish(words.join("_"), "unnamed")
+}
+
+/// `Click Sound` -> `CLICK_SOUND`
+pub(crate) fn screaming_snake_case(name: &str) -> String {
+    let words: Vec<String> = words(name).iter().map(|w| w.to_ascii_uppercase()).collect();
+    finish(words.join("_"), "UNNAMED")
+}
+
+/// `click sound` -> `ClickSound`
+pub(crate) fn pascal_case(name: &str) -> String {
+    let mut ident = String::new();
+    for word in words(name) {
+        let mut chars = word.chars();
+        if let Some(first) = chars.next() {
+            ident.push(first.to_ascii_uppercase());
+            ident.extend(chars.map(|c| c.to_ascii_lowercase()));
+        }
+    }
+    finish(ident, "Unnamed")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn snake() {
+        assert_eq!(snake_case("UI"), "ui");
+        assert_eq!(snake_case("Footsteps Grass"), "footsteps_grass");
+        assert_eq!(snake_case("UIClick"), "ui_click");
+        assert_eq!(snake_case("type"), "type_");
+        assert_eq!(snake_case("2D"), "_2d");
+    }
+
+    #[test]
+    fn screaming() {
+        assert_eq!(screaming_snake_case("Click"), "CLICK");
+        assert_eq!(screaming_snake_case("Master.strings"), "MASTER_STRINGS");
+        assert_eq!(screaming_snake_case("RPM (engine)"), "RPM_ENGINE");
+        assert_eq!(screaming_snake_case("???"), "UNNAMED");
+    }
+
+    #[test]
+    fn pascal() {
+        assert_eq!(pascal_case("surface type"), "SurfaceType");
+        assert_eq!(pascal_case("Self"), "Self_");
+        assert_eq!(pascal_case("10m"), "_10m");
+    }
+}
@@ -0,0 +1,197 @@
+// Copyright (c) 2024 Lily Lyons
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! fmod-codegen
+//!
+//! Generates a Rust module with constants for every event, snapshot, bus, VCA, bank and parameter in a set of FMOD Studio banks.
+//! This is intended to be used from a `build.rs`, so that a typo in an event path fails the build instead of the game.
+//!
+//! The banks are loaded by a Studio system using [`fmod::OutputType::NoSound`], so no sound card is required.
+//!
+//! # Generated code
+//!
+//! Paths are split on `/` into modules, and each item becomes a [`fmod::Guid`] constant.
+//! For example, `event:/UI/Click` becomes `events::ui::CLICK`, and `bus:/SFX/Footsteps` becomes `buses::sfx::FOOTSTEPS`.
+//! The master bus is emitted as `buses::MASTER`.
+//!
+//! Event parameters are emitted as [`fmod::studio::EventParameterID`] constants in a module named after the event (`events::ui::click::INTENSITY`).
+//! Labeled parameters also get an enum of their labels (`events::ui::click::Surface`), which can be converted into the parameter value.
+//! These enums implement [`fmod::studio::ParameterValue`], so they can be used with [`fmod::studio::EventDescription::parameter`].
+//! Global parameters are emitted into the `parameters` module, as [`fmod::studio::GlobalParameterID`] constants.
+//! The IDs are typed by their value, so `events::ui::click::SURFACE.set(instance, events::ui::click::Surface::Wood, false)` only accepts a label.
+//!
+//! # Example
+//! ```ignore
+//! // build.rs
+//! let out_dir = std::path::PathBuf::from(std::env::var_os("OUT_DIR").unwrap());
+//! fmod_codegen::Builder::new("assets/audio/Master.strings.bank")
+//!     .bank_directory("assets/audio")
+//!     .cargo_rerun_if_changed(true)
+//!     .write_to_file(out_dir.join("fmod_ids.rs"))?;
+//!
+//! // src/main.rs
+//! mod ids {
+//!     include!(concat!(env!("OUT_DIR"), "/fmod_ids.rs"));
+//! }
+//! let click = system.get_event_by_id(ids::events::ui::CLICK)?;
+//! ```
+
+#![warn(rust_2018_idioms, clippy::pedantic)]
+#![allow(clippy::missing_errors_doc, clippy::must_use_candidate)]
+
+use std::path::{Path, PathBuf};
+
+mod collect;
+mod emit;
+mod ident;
+
+/// Errors that can occur while generating code.
+#[derive(Debug)]
+pub enum Error {
+    /// An FMOD function failed.
+    Fmod(fmod::Error),
+    /// Reading a bank directory or writing the output file failed.
+    Io(std::io::Error),
+    /// A bank path was not valid UTF-8.
+    NonUtf8Path(PathBuf),
+    /// Two FMOD paths map to the same Rust identifier.
+    NameCollision {
+        /// The Rust module both items would be emitted into.
+        module: String,
+        /// The conflicting identifier.
+        ident: String,
+    },
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Fmod(error) => error.fmt(f),
+            Error::Io(error) => error.fmt(f),
+            Error::NonUtf8Path(path) => {
+                f.write_fmt(format_args!("path `{}` is not valid UTF-8", path.display()))
+            }
+            Error::NameCollision { module, ident } => f.write_fmt(format_args!(
+                "more than one FMOD path maps to `{module}::{ident}`"
+            )),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<fmod::Error> for Error {
+    fn from(value: fmod::Error) -> Self {
+        Error::Fmod(value)
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(value: std::io::Error) -> Self {
+        Error::Io(value)
+    }
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// A builder for generating Rust constants from FMOD Studio banks.
+#[must_use]
+#[derive(Debug, Clone)]
+pub struct Builder {
+    strings_bank: PathBuf,
+    banks: Vec<PathBuf>,
+    bank_directories: Vec<PathBuf>,
+    cargo_rerun_if_changed: bool,
+}
+
+impl Builder {
+    /// Creates a new [`Builder`] from the path to the strings bank (usually `Master.strings.bank`).
+    ///
+    /// The strings bank is required, as without it FMOD can't look up the path of anything.
+    pub fn new(strings_bank: impl Into<PathBuf>) -> Self {
+        Builder {
+            strings_bank: strings_bank.into(),
+            banks: vec![],
+            bank_directories: vec![],
+            cargo_rerun_if_changed: false,
+        }
+    }
+
+    /// Adds a bank to be loaded.
+    pub fn bank(mut self, path: impl Into<PathBuf>) -> Self {
+        self.banks.push(path.into());
+        self
+    }
+
+    /// Adds every `.bank` file in a directory to be loaded.
+    ///
+    /// The directory is not searched recursively.
+    pub fn bank_directory(mut self, path: impl Into<PathBuf>) -> Self {
+        self.bank_directories.push(path.into());
+        self
+    }
+
+    /// Print `cargo:rerun-if-changed` for every loaded bank and bank directory.
+    ///
+    /// Only enable this when calling from a build script!
+    pub fn cargo_rerun_if_changed(mut self, enabled: bool) -> Self {
+        self.cargo_rerun_if_changed = enabled;
+        self
+    }
+
+    /// Loads the banks and generates Rust source code for them.
+    ///
+    /// This creates and releases an FMOD Studio system, so it must not be called while any other FMOD system exists in this process.
+    /// Build scripts run in their own process, so this is not a concern there.
+    pub fn generate(&self) -> Result<String> {
+        let banks = self.bank_paths()?;
+
+        if self.cargo_rerun_if_changed {
+            for path in self.bank_directories.iter().chain(&banks) {
+                println!("cargo:rerun-if-changed={}", path.display());
+            }
+        }
+
+        let project = collect::collect(&banks)?;
+        emit::emit(&project)
+    }
+
+    /// Generates Rust source code and writes it to `path`.
+    ///
+    /// See [`Builder::generate`].
+    pub fn write_to_file(&self, path: impl AsRef<Path>) -> Result<()> {
+        let source = self.generate()?;
+        std::fs::write(path, source)?;
+        Ok(())
+    }
+
+    // the strings bank is always loaded first, so paths can be looked up while walking the other banks
+    fn bank_paths(&self) -> Result<Vec<PathBuf>> {
+        let mut paths = vec![self.strings_bank.clone()];
+        paths.extend(self.banks.iter().cloned());
+
+        for directory in &self.bank_directories {
+            let mut entries = vec![];
+            for entry in std::fs::read_dir(directory)? {
+                let path = entry?.path();
+                if path.is_file() && path.extension().is_some_and(|e| e == "bank") {
+                    entries.push(path);
+                }
+            }
+            // read_dir order is platform specific, sort so the output is stable
+            entries.sort();
+            paths.extend(entries);
+        }
+
+        // loading the same bank twice is an error, so remove any duplicates (the strings bank is usually in a bank directory!)
+        let mut seen = std::collections::HashSet::new();
+        paths.retain(|path| {
+            seen.insert(std::fs::canonicalize(path).unwrap_or_else(|_| path.clone()))
+        });
+
+        Ok(paths)
+    }
+}
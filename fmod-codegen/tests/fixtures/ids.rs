@@ -0,0 +1,78 @@
+// This file was generated by fmod-codegen. Do not edit it by hand!
+
+#[allow(dead_code)]
+pub mod banks {
+    /// `bank:/Master`
+    pub const MASTER: ::fmod::Guid = ::fmod::Guid { data_1: 0x31fa69de, data_2: 0x11d8, data_3: 0xd0b3, data_4: [0xde, 0x69, 0xfa, 0x31, 0xd8, 0x11, 0xb3, 0xd0] };
+    /// `bank:/Master.strings`
+    pub const MASTER_STRINGS: ::fmod::Guid = ::fmod::Guid { data_1: 0xda52b2be, data_2: 0x0fa9, data_3: 0xd787, data_4: [0xbe, 0xb2, 0x52, 0xda, 0xa9, 0x0f, 0x87, 0xd7] };
+}
+
+#[allow(dead_code)]
+pub mod buses {
+}
+
+#[allow(dead_code)]
+pub mod events {
+    /// `event:/Footstep`
+    pub const FOOTSTEP: ::fmod::Guid = ::fmod::Guid { data_1: 0x376ac6c6, data_2: 0xbb7d, data_3: 0xc5d3, data_4: [0xc6, 0xc6, 0x6a, 0x37, 0x7d, 0xbb, 0xd3, 0xc5] };
+    pub mod footstep {
+        /// The `Speed` parameter.
+        pub const SPEED: ::fmod::studio::EventParameterID<f32> = ::fmod::studio::EventParameterID::new(::fmod::studio::ParameterID { data_1: 0x060ddaf6, data_2: 0x70cca006 });
+        /// The `Surface` parameter.
+        pub const SURFACE: ::fmod::studio::EventParameterID<Surface> = ::fmod::studio::EventParameterID::new(::fmod::studio::ParameterID { data_1: 0x6a3cb286, data_2: 0x400c31d1 });
+        /// The labels of the `Surface` parameter.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum Surface {
+            Grass,
+            WoodenFloor,
+        }
+
+        impl Surface {
+            /// The parameter value this label corresponds to.
+            pub const fn value(self) -> f32 {
+                match self {
+                    Self::Grass => 0.0,
+                    Self::WoodenFloor => 1.0,
+                }
+            }
+
+            /// The name of this label, as authored in FMOD Studio.
+            pub const fn label(self) -> &'static str {
+                match self {
+                    Self::Grass => "Grass",
+                    Self::WoodenFloor => "Wooden Floor",
+                }
+            }
+        }
+
+        impl From<Surface> for f32 {
+            fn from(value: Surface) -> f32 {
+                value.value()
+            }
+        }
+
+        impl ::fmod::studio::ParameterValue for Surface {
+            fn into_value(self) -> f32 {
+                self.value()
+            }
+        }
+    }
+    pub mod ui {
+        /// `event:/UI/Click`
+        pub const CLICK: ::fmod::Guid = ::fmod::Guid { data_1: 0x03f0ded9, data_2: 0x5f64, data_3: 0x6862, data_4: [0xd9, 0xde, 0xf0, 0x03, 0x64, 0x5f, 0x62, 0x68] };
+    }
+}
+
+#[allow(dead_code)]
+pub mod parameters {
+}
+
+#[allow(dead_code)]
+pub mod snapshots {
+}
+
+#[allow(dead_code)]
+pub mod vcas {
+}
+
@@ -0,0 +1,109 @@
+// Copyright (c) 2024 Lily Lyons
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+// run with `cargo test -p fmod-codegen --features mock`
+#![cfg(feature = "mock")]
+
+use std::path::Path;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+use fmod::ffi::mock::{self, BankFixture, EventFixture, Fixture};
+use fmod::studio::LoadBankFlags;
+
+// what the generator emits for `setup`'s fixture, checked in so that it's compiled as part of this test
+mod ids {
+    include!("fixtures/ids.rs");
+}
+
+// the mock's state is global, so tests have to take turns
+static LOCK: Mutex<()> = Mutex::new(());
+
+fn setup() -> MutexGuard<'static, ()> {
+    let guard = LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+    mock::reset();
+    mock::set_fixture(
+        Fixture::new()
+            .bank(BankFixture::new("bank:/Master.strings").strings(true))
+            .bank(
+                BankFixture::new("bank:/Master")
+                    .event(EventFixture::new("event:/UI/Click"))
+                    .event(
+                        EventFixture::new("event:/Footstep")
+                            .parameter("Speed", 0.0, 10.0, 1.0)
+                            .labeled_parameter("Surface", ["Grass", "Wooden Floor"]),
+                    ),
+            ),
+    );
+    guard
+}
+
+#[test]
+fn generated_code_is_up_to_date() {
+    let _guard = setup();
+
+    let source = fmod_codegen::Builder::new("Master.strings.bank")
+        .bank("Master.bank")
+        .generate()
+        .unwrap();
+
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/ids.rs");
+    if std::env::var_os("FMOD_CODEGEN_BLESS").is_some() {
+        std::fs::write(&path, &source).unwrap();
+    }
+    assert_eq!(
+        source,
+        std::fs::read_to_string(&path).unwrap(),
+        "tests/fixtures/ids.rs is out of date, rerun with FMOD_CODEGEN_BLESS=1 to update it"
+    );
+}
+
+#[test]
+fn generated_ids_match_the_banks() {
+    let _guard = setup();
+
+    let system = unsafe { fmod::studio::SystemBuilder::new() }
+        .unwrap()
+        .build(32, fmod::studio::InitFlags::NORMAL, fmod::InitFlags::NORMAL)
+        .unwrap();
+    let bank = system
+        .load_bank_file("Master.bank", LoadBankFlags::NORMAL)
+        .unwrap();
+    assert_eq!(bank.get_id().unwrap(), ids::banks::MASTER);
+
+    let click = system.get_event_by_id(ids::events::ui::CLICK).unwrap();
+    assert_eq!(click.get_path().unwrap(), "event:/UI/Click");
+
+    let footstep = system.get_event_by_id(ids::events::FOOTSTEP).unwrap();
+    let instance = footstep.create_instance().unwrap();
+    // labels are numbered the same way FMOD numbers them
+    for (index, surface) in [
+        ids::events::footstep::Surface::Grass,
+        ids::events::footstep::Surface::WoodenFloor,
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        assert_eq!(
+            footstep
+                .get_parameter_label_by_index(1, index as i32)
+                .unwrap(),
+            surface.label()
+        );
+        ids::events::footstep::SURFACE
+            .set(instance, surface, false)
+            .unwrap();
+        assert_eq!(
+            ids::events::footstep::SURFACE.get(instance).unwrap().0,
+            index as f32
+        );
+    }
+    ids::events::footstep::SPEED
+        .set(instance, 2.5, false)
+        .unwrap();
+    assert_eq!(ids::events::footstep::SPEED.get(instance).unwrap().0, 2.5);
+
+    unsafe { system.release() }.unwrap();
+}
@@ -0,0 +1,78 @@
+// Copyright (c) 2024 Lily Lyons
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+// run with `cargo bench -p fmod-oxide --features mock --bench userdata`
+//
+// Compares `TypedUserdata::with_userdata` against fetching the raw userdata pointer on its own,
+// to show what the storage lookup costs on top of the FMOD call.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use fmod::ffi::mock::{self, BankFixture, EventFixture, Fixture};
+use fmod::studio::LoadBankFlags;
+use fmod::TypedUserdata;
+
+const ITERATIONS: u32 = 1_000_000;
+
+fn time(name: &str, mut f: impl FnMut()) -> Duration {
+    // warm up caches and the lock
+    for _ in 0..ITERATIONS / 10 {
+        f();
+    }
+
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        f();
+    }
+    let elapsed = start.elapsed();
+    println!(
+        "{name:<24} {:>8.1} ns/call",
+        elapsed.as_secs_f64() * 1e9 / f64::from(ITERATIONS)
+    );
+    elapsed
+}
+
+fn main() {
+    mock::reset();
+    mock::set_fixture(
+        Fixture::new()
+            .bank(BankFixture::new("bank:/Master").event(EventFixture::new("event:/Footstep"))),
+    );
+
+    let system = unsafe { fmod::studio::SystemBuilder::new() }
+        .unwrap()
+        .build(32, fmod::studio::InitFlags::NORMAL, fmod::InitFlags::NORMAL)
+        .unwrap();
+    system
+        .load_bank_file("Master.bank", LoadBankFlags::NORMAL)
+        .unwrap();
+    let instance = system
+        .get_event("event:/Footstep")
+        .unwrap()
+        .create_instance()
+        .unwrap();
+    instance.set_typed_userdata(1_u32).unwrap();
+
+    let raw = time("get_raw_userdata", || {
+        black_box(instance.get_raw_userdata().unwrap());
+    });
+    let borrowed = time("with_userdata", || {
+        black_box(instance.with_userdata(|value: &u32| *value).unwrap());
+    });
+    let cloned = time("get_typed_userdata", || {
+        black_box(instance.get_typed_userdata::<u32>().unwrap());
+    });
+
+    let overhead = borrowed.saturating_sub(raw);
+    println!(
+        "storage lookup overhead  {:>8.1} ns/call ({:.1} ns/call when cloning the Arc out)",
+        overhead.as_secs_f64() * 1e9 / f64::from(ITERATIONS),
+        cloned.saturating_sub(raw).as_secs_f64() * 1e9 / f64::from(ITERATIONS),
+    );
+
+    unsafe { system.release() }.unwrap();
+}
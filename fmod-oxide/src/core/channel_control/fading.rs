@@ -0,0 +1,262 @@
+// Copyright (c) 2024 Lily Lyons
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::{
+    ffi::{c_float, c_ulonglong},
+    time::Duration,
+};
+
+use fmod_sys::*;
+
+use crate::{ChannelControl, Curve};
+
+// how many segments non-linear curves are split into
+const CURVE_SEGMENTS: usize = 16;
+
+/// A fade point, as added by [`ChannelControl::add_fade_point`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FadePoint {
+    /// The time of this fade point, relative to the parent DSP clock.
+    pub dsp_clock: c_ulonglong,
+    pub volume: c_float,
+}
+
+/// The fade points of a [`ChannelControl`], sorted by time.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct FadeTimeline {
+    pub points: Vec<FadePoint>,
+}
+
+impl FadeTimeline {
+    /// The fade volume at `dsp_clock`.
+    ///
+    /// FMOD ramps linearly between fade points, and holds the volume of the first and last fade point before and after them.
+    /// If there are no fade points, the fade volume is `1.0`.
+    pub fn volume_at(&self, dsp_clock: c_ulonglong) -> c_float {
+        let index = self.points.partition_point(|p| p.dsp_clock <= dsp_clock);
+        match (
+            index.checked_sub(1).map(|i| self.points[i]),
+            self.points.get(index).copied(),
+        ) {
+            (None, None) => 1.0,
+            (Some(point), None) | (None, Some(point)) => point.volume,
+            (Some(before), Some(after)) => {
+                let progress = (dsp_clock - before.dsp_clock) as f64
+                    / (after.dsp_clock - before.dsp_clock) as f64;
+                before.volume + (after.volume - before.volume) * progress as c_float
+            }
+        }
+    }
+
+    /// The time of the last fade point, if there are any.
+    pub fn end(&self) -> Option<c_ulonglong> {
+        self.points.last().map(|p| p.dsp_clock)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+}
+
+// custom curves can return anything, but a fade shouldn't overshoot its start or end volume
+fn fade_shape(curve: Curve, progress: c_float) -> c_float {
+    curve.apply(progress).clamp(0.0, 1.0)
+}
+
+// FMOD ramps linearly between fade points, so anything but a linear curve is approximated with several fade points
+fn curve_points(
+    start: c_ulonglong,
+    length: c_ulonglong,
+    curve: Curve,
+    volume: impl Fn(c_float) -> c_float,
+) -> Vec<FadePoint> {
+    let segments = if matches!(curve, Curve::Linear) || length == 0 {
+        1
+    } else {
+        CURVE_SEGMENTS
+    };
+
+    let mut points = Vec::with_capacity(segments + 1);
+    points.push(FadePoint {
+        dsp_clock: start,
+        volume: volume(0.0),
+    });
+    for segment in 1..=segments {
+        let progress = segment as f64 / segments as f64;
+        points.push(FadePoint {
+            dsp_clock: start + (length as f64 * progress).round() as c_ulonglong,
+            volume: volume(progress as c_float),
+        });
+    }
+    points
+}
+
+impl ChannelControl {
+    /// Retrieves the stored fade points as a [`FadeTimeline`].
+    pub fn get_fade_timeline(&self) -> Result<FadeTimeline> {
+        let (dsp_clocks, volumes) = self.get_fade_points()?;
+        let mut points: Vec<FadePoint> = dsp_clocks
+            .into_iter()
+            .zip(volumes)
+            .map(|(dsp_clock, volume)| FadePoint { dsp_clock, volume })
+            .collect();
+        points.sort_by_key(|p| p.dsp_clock);
+        Ok(FadeTimeline { points })
+    }
+
+    /// Linearly fades from the current fade volume to `volume` over `duration`, starting now.
+    ///
+    /// Any fade points after now are removed.
+    pub fn fade_to(&self, volume: c_float, duration: Duration) -> Result<()> {
+        self.fade_to_with_curve(volume, duration, Curve::Linear)
+    }
+
+    /// Fades from the current fade volume to `volume` over `duration` following `curve`, starting now.
+    ///
+    /// FMOD only ramps linearly between fade points, so anything other than [`Curve::Linear`] is approximated with several fade points.
+    /// Any fade points after now are removed.
+    pub fn fade_to_with_curve(
+        &self,
+        volume: c_float,
+        duration: Duration,
+        curve: Curve,
+    ) -> Result<()> {
+        let (_, now) = self.get_dsp_clock()?;
+        let start_volume = self.get_fade_timeline()?.volume_at(now);
+        let length = self.duration_to_samples(duration)?;
+
+        self.remove_fade_points(now, c_ulonglong::MAX)?;
+        self.add_curve(now, length, curve, |t| {
+            start_volume + (volume - start_volume) * fade_shape(curve, t)
+        })
+    }
+
+    /// Fades out over `duration` and then stops.
+    ///
+    /// A [`Channel`](crate::Channel) will be stopped, a [`ChannelGroup`](crate::ChannelGroup) will stop all of its channels.
+    pub fn fade_out_and_stop(&self, duration: Duration) -> Result<()> {
+        self.fade_to(0.0, duration)?;
+
+        let (_, now) = self.get_dsp_clock()?;
+        let end = now + self.duration_to_samples(duration)?;
+        let (start, _, _) = self.get_delay()?;
+        self.set_delay(start, end, true)
+    }
+
+    /// Fades `from` out and `to` in over `duration`, starting now.
+    ///
+    /// `from` fades out from its current fade volume, and `to` fades in to full volume.
+    /// The fade out is the fade in reversed, so with [`Curve::EqualPower`] the two channels sum to a constant power.
+    ///
+    /// `from` is not stopped after fading out, use [`ChannelControl::fade_out_and_stop`] if you want that instead.
+    pub fn crossfade(
+        from: ChannelControl,
+        to: ChannelControl,
+        duration: Duration,
+        curve: Curve,
+    ) -> Result<()> {
+        let (_, from_now) = from.get_dsp_clock()?;
+        let from_volume = from.get_fade_timeline()?.volume_at(from_now);
+        let from_length = from.duration_to_samples(duration)?;
+        from.remove_fade_points(from_now, c_ulonglong::MAX)?;
+        from.add_curve(from_now, from_length, curve, |t| {
+            from_volume * fade_shape(curve, 1.0 - t)
+        })?;
+
+        // the two may have different parents, so we schedule each against its own parent clock
+        let (_, to_now) = to.get_dsp_clock()?;
+        let to_length = to.duration_to_samples(duration)?;
+        to.remove_fade_points(to_now, c_ulonglong::MAX)?;
+        to.add_curve(to_now, to_length, curve, |t| fade_shape(curve, t))
+    }
+
+    // adds fade points from `start` to `start + length`, with `volume` mapping the linear progress of the fade to a volume
+    fn add_curve(
+        self,
+        start: c_ulonglong,
+        length: c_ulonglong,
+        curve: Curve,
+        volume: impl Fn(c_float) -> c_float,
+    ) -> Result<()> {
+        for point in curve_points(start, length, curve, volume) {
+            self.add_fade_point(point.dsp_clock, point.volume)?;
+        }
+        Ok(())
+    }
+
+    // the parent clock runs at the mixer sample rate
+    fn duration_to_samples(self, duration: Duration) -> Result<c_ulonglong> {
+        let (sample_rate, _, _) = self.get_system()?.get_software_format()?;
+        Ok((duration.as_secs_f64() * f64::from(sample_rate)).round() as c_ulonglong)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)] // the volumes compared are exact
+mod tests {
+    use super::*;
+
+    fn point(dsp_clock: c_ulonglong, volume: c_float) -> FadePoint {
+        FadePoint { dsp_clock, volume }
+    }
+
+    #[test]
+    fn volume_between_points() {
+        let timeline = FadeTimeline {
+            points: vec![point(100, 0.0), point(200, 1.0), point(400, 0.5)],
+        };
+        assert_eq!(timeline.volume_at(100), 0.0);
+        assert_eq!(timeline.volume_at(150), 0.5);
+        assert_eq!(timeline.volume_at(200), 1.0);
+        assert_eq!(timeline.volume_at(300), 0.75);
+        assert_eq!(timeline.end(), Some(400));
+    }
+
+    #[test]
+    fn volume_outside_points() {
+        let timeline = FadeTimeline {
+            points: vec![point(100, 0.25), point(200, 0.75)],
+        };
+        assert_eq!(timeline.volume_at(0), 0.25);
+        assert_eq!(timeline.volume_at(1000), 0.75);
+
+        let empty = FadeTimeline::default();
+        assert_eq!(empty.volume_at(0), 1.0);
+        assert_eq!(empty.end(), None);
+    }
+
+    #[test]
+    fn linear_curves_are_one_segment() {
+        let points = curve_points(1000, 480, Curve::Linear, |t| t);
+        assert_eq!(points, [point(1000, 0.0), point(1480, 1.0)]);
+
+        // nothing to approximate if the fade is instant
+        let points = curve_points(1000, 0, Curve::EqualPower, |t| t);
+        assert_eq!(points, [point(1000, 0.0), point(1000, 1.0)]);
+    }
+
+    #[test]
+    fn curves_are_sampled_evenly() {
+        let points = curve_points(1000, 480, Curve::SCurve, |t| fade_shape(Curve::SCurve, t));
+        assert_eq!(points.len(), CURVE_SEGMENTS + 1);
+        for (segment, point) in points.iter().enumerate() {
+            let progress = segment as c_float / CURVE_SEGMENTS as c_float;
+            assert_eq!(point.dsp_clock, 1000 + 30 * segment as c_ulonglong);
+            assert_eq!(point.volume, Curve::SCurve.apply(progress));
+        }
+        assert_eq!(points.last(), Some(&point(1480, 1.0)));
+    }
+
+    #[test]
+    fn custom_curves_are_clamped() {
+        let overshoot = Curve::Custom(|t| t * 2.0 - 0.5);
+        let points = curve_points(0, 160, overshoot, |t| fade_shape(overshoot, t));
+        assert!(points.iter().all(|p| (0.0..=1.0).contains(&p.volume)));
+        assert_eq!(points[0].volume, 0.0);
+        assert_eq!(points[CURVE_SEGMENTS / 2].volume, 0.5);
+        assert_eq!(points[CURVE_SEGMENTS].volume, 1.0);
+    }
+}
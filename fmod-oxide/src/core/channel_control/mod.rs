@@ -8,6 +8,7 @@ use fmod_sys::*;
 
 mod callback;
 mod dsp;
+mod fading;
 mod filtering;
 mod general;
 mod panning;
@@ -16,6 +17,7 @@ mod scheduling;
 mod spatialization;
 mod volume;
 pub use callback::{ChannelControlCallback, ChannelControlType};
+pub use fading::{FadePoint, FadeTimeline};
 
 // FMOD's C API provides two versions of functions for channels: one that takes a `*mut FMOD_CHANNEL` and one that takes a `*mut FMOD_CHANNELGROUP`.
 // The C++ API provides a base class `ChannelControl` that `Channel` and `ChannelGroup` inherits from.
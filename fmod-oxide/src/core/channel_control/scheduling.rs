@@ -95,6 +95,8 @@ impl ChannelControl {
     }
 
     /// Retrieves information about stored fade points.
+    ///
+    /// See [`ChannelControl::get_fade_timeline`] for a typed version of this.
     pub fn get_fade_points(&self) -> Result<(Vec<c_ulonglong>, Vec<f32>)> {
         let mut num_points = 0;
         unsafe {
@@ -5,10 +5,10 @@
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
 use fmod_sys::*;
-use lanyard::Utf8CString;
+use lanyard::{Utf8CStr, Utf8CString, Utf8CStringBuilder};
 use std::ffi::c_int;
 
-use crate::{get_string, ChannelGroup};
+use crate::{get_string, get_string_in, get_string_into, ChannelGroup};
 
 impl ChannelGroup {
     /// Retrieves the name set when the group was created.
@@ -20,6 +20,22 @@ impl ChannelGroup {
         }
     }
 
+    /// Like [`ChannelGroup::get_name`], but writes the name into `name`, reusing its allocation.
+    pub fn get_name_into(&self, name: &mut Utf8CStringBuilder) -> Result<()> {
+        get_string_into(name, |name| unsafe {
+            FMOD_ChannelGroup_GetName(self.inner, name.as_mut_ptr().cast(), name.len() as c_int)
+        })
+    }
+
+    /// Like [`ChannelGroup::get_name`], but writes the name into `buffer` without allocating.
+    ///
+    /// Returns [`FMOD_RESULT::FMOD_ERR_TRUNCATED`] if the name (including the null terminator) doesn't fit.
+    pub fn get_name_in<'b>(&self, buffer: &'b mut [u8]) -> Result<&'b Utf8CStr> {
+        get_string_in(buffer, |name| unsafe {
+            FMOD_ChannelGroup_GetName(self.inner, name.as_mut_ptr().cast(), name.len() as c_int)
+        })
+    }
+
     /// Frees the memory for the group.
     ///
     /// Any [`Channel`]s or [`ChannelGroup`]s feeding into this group are moved to the master [`ChannelGroup`].
@@ -0,0 +1,41 @@
+// Copyright (c) 2024 Lily Lyons
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::ffi::c_float;
+
+/// The shape of a fade or a parameter ramp.
+///
+/// Used by [`ChannelControl::fade_to_with_curve`](crate::ChannelControl::fade_to_with_curve) and [`ParameterAutomator`](crate::studio::ParameterAutomator).
+#[derive(Debug, Clone, Copy)]
+pub enum Curve {
+    /// Moves at a constant rate.
+    Linear,
+    /// Starts slowly and speeds up towards the end, like an exponential fade.
+    Exponential,
+    /// Starts and ends slowly, and is fastest in the middle.
+    SCurve,
+    /// Keeps the combined power of a crossfade constant, so there is no dip in loudness halfway through.
+    EqualPower,
+    /// A custom curve.
+    ///
+    /// The function is passed the progress from `0.0` to `1.0`, and should return how far between the start and end value the result should be.
+    /// It should return `0.0` at `0.0` and `1.0` at `1.0`, although the end value is always reached regardless.
+    Custom(fn(c_float) -> c_float),
+}
+
+impl Curve {
+    /// Evaluates this curve at `progress`, which is clamped to `0.0..=1.0`.
+    pub fn apply(self, progress: c_float) -> c_float {
+        let t = progress.clamp(0.0, 1.0);
+        match self {
+            Curve::Linear => t,
+            Curve::Exponential => (2.0_f32.powf(10.0 * t) - 1.0) / 1023.0,
+            Curve::SCurve => t * t * (3.0 - 2.0 * t),
+            Curve::EqualPower => (t * std::f32::consts::FRAC_PI_2).sin(),
+            Curve::Custom(curve) => curve(t),
+        }
+    }
+}
@@ -0,0 +1,303 @@
+// Copyright (c) 2024 Lily Lyons
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use fmod_sys::*;
+use lanyard::Utf8CString;
+use std::ffi::c_int;
+
+use crate::{DriverState, Guid, SpeakerMode, System};
+
+/// An output device, as returned by [`System::get_driver_info`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutputDevice {
+    /// The index of the driver right now.
+    ///
+    /// Indices change when devices are added or removed, so use the [`Guid`] to remember a device.
+    pub index: c_int,
+    pub name: Utf8CString,
+    pub guid: Guid,
+    pub system_rate: c_int,
+    pub speaker_mode: SpeakerMode,
+    pub speaker_mode_channels: c_int,
+}
+
+/// A recording device, as returned by [`System::get_record_driver_info`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputDevice {
+    /// The index of the driver right now.
+    ///
+    /// Indices change when devices are added or removed, so use the [`Guid`] to remember a device.
+    pub index: c_int,
+    pub name: Utf8CString,
+    pub guid: Guid,
+    pub system_rate: c_int,
+    pub speaker_mode: SpeakerMode,
+    pub speaker_mode_channels: c_int,
+    pub state: DriverState,
+}
+
+/// A change to the devices, returned by [`DeviceManager::update`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeviceEvent {
+    OutputAdded(OutputDevice),
+    OutputRemoved(OutputDevice),
+    /// The operating system's default output device changed.
+    DefaultOutputChanged(OutputDevice),
+    InputAdded(InputDevice),
+    InputRemoved(InputDevice),
+    /// The operating system's default recording device changed.
+    DefaultInputChanged(InputDevice),
+    /// The output driver of the system changed, either because the manager switched it or because FMOD lost the previous device.
+    OutputSwitched(OutputDevice),
+}
+
+/// Keeps track of the output and recording devices of a [`System`], identifying them by [`Guid`] rather than index.
+///
+/// [`DeviceManager::update`] should be called regularly (once per frame is fine, FMOD caches the device lists) to pick up devices being plugged in and out.
+/// This polls instead of using [`SystemCallbackMask::DEVICELISTCHANGED`](crate::SystemCallbackMask::DEVICELISTCHANGED),
+/// as a system can only have one callback and that is left for your own use.
+///
+/// The output device can be chosen with [`DeviceManager::set_preferred_output`], which is remembered by [`Guid`] so it can be saved and restored between sessions.
+/// If the preferred device isn't connected the manager can follow the operating system's default device instead, see [`DeviceManager::set_follow_default`].
+#[derive(Debug)]
+pub struct DeviceManager {
+    system: System,
+    outputs: Vec<OutputDevice>,
+    inputs: Vec<InputDevice>,
+    current_output: Option<Guid>,
+    preferred_output: Option<Guid>,
+    follow_default: bool,
+}
+
+impl DeviceManager {
+    /// Creates a device manager, which follows the default output device until a preferred device is set.
+    pub fn new(system: System) -> Result<Self> {
+        let mut manager = DeviceManager {
+            system,
+            outputs: vec![],
+            inputs: vec![],
+            current_output: None,
+            preferred_output: None,
+            follow_default: true,
+        };
+        manager.outputs = manager.enumerate_outputs()?;
+        manager.inputs = manager.enumerate_inputs()?;
+        manager.current_output = manager.driver_guid()?;
+        Ok(manager)
+    }
+
+    pub fn outputs(&self) -> &[OutputDevice] {
+        &self.outputs
+    }
+
+    /// The connected recording devices.
+    pub fn inputs(&self) -> &[InputDevice] {
+        &self.inputs
+    }
+
+    pub fn output(&self, guid: Guid) -> Option<&OutputDevice> {
+        self.outputs.iter().find(|device| device.guid == guid)
+    }
+
+    pub fn input(&self, guid: Guid) -> Option<&InputDevice> {
+        self.inputs.iter().find(|device| device.guid == guid)
+    }
+
+    /// The operating system's default output device, which FMOD always lists first.
+    pub fn default_output(&self) -> Option<&OutputDevice> {
+        self.outputs.first()
+    }
+
+    /// The operating system's default recording device.
+    pub fn default_input(&self) -> Option<&InputDevice> {
+        self.inputs
+            .iter()
+            .find(|device| device.state.contains(DriverState::DEFAULT))
+    }
+
+    /// The device the system is outputting to.
+    pub fn current_output(&self) -> Option<&OutputDevice> {
+        self.output(self.current_output?)
+    }
+
+    pub fn preferred_output(&self) -> Option<Guid> {
+        self.preferred_output
+    }
+
+    /// Sets the output device to use whenever it is connected, switching to it now if it is.
+    ///
+    /// The device is remembered by [`Guid`], so it will be switched back to when it is plugged back in,
+    /// and a [`Guid`] saved from a previous session can be passed here on startup.
+    /// `None` clears the preferred device, switching to the default device if [`DeviceManager::set_follow_default`] is set.
+    ///
+    /// Returns the [`DeviceEvent::OutputSwitched`] event if the device was switched.
+    pub fn set_preferred_output(&mut self, guid: Option<Guid>) -> Result<Option<DeviceEvent>> {
+        self.preferred_output = guid;
+        self.switch_output()
+    }
+
+    pub fn get_follow_default(&self) -> bool {
+        self.follow_default
+    }
+
+    /// Sets whether the manager switches to the operating system's default output device whenever it changes.
+    ///
+    /// This only applies while the preferred device (if any) isn't connected.
+    ///
+    /// Returns the [`DeviceEvent::OutputSwitched`] event if the device was switched.
+    pub fn set_follow_default(&mut self, follow_default: bool) -> Result<Option<DeviceEvent>> {
+        self.follow_default = follow_default;
+        self.switch_output()
+    }
+
+    /// Checks for devices being added and removed, and switches the output device if needed.
+    ///
+    /// Returns what changed since the last update.
+    pub fn update(&mut self) -> Result<Vec<DeviceEvent>> {
+        let mut events = vec![];
+
+        let outputs = self.enumerate_outputs()?;
+        diff_devices(
+            &self.outputs,
+            &outputs,
+            |device| device.guid,
+            DeviceEvent::OutputAdded,
+            DeviceEvent::OutputRemoved,
+            &mut events,
+        );
+        if let Some(default) = outputs.first() {
+            if self.default_output().map(|device| device.guid) != Some(default.guid) {
+                events.push(DeviceEvent::DefaultOutputChanged(default.clone()));
+            }
+        }
+        self.outputs = outputs;
+
+        let inputs = self.enumerate_inputs()?;
+        diff_devices(
+            &self.inputs,
+            &inputs,
+            |device| device.guid,
+            DeviceEvent::InputAdded,
+            DeviceEvent::InputRemoved,
+            &mut events,
+        );
+        let old_default = self.default_input().map(|device| device.guid);
+        self.inputs = inputs;
+        if let Some(default) = self.default_input() {
+            if old_default != Some(default.guid) {
+                events.push(DeviceEvent::DefaultInputChanged(default.clone()));
+            }
+        }
+
+        // FMOD switches to another device by itself if the current one is unplugged
+        let driver = self.driver_guid()?;
+        let fmod_switched = driver != self.current_output;
+        self.current_output = driver;
+
+        // only report where the output ended up, if it was switched twice
+        match self.switch_output()? {
+            Some(event) => events.push(event),
+            None if fmod_switched => {
+                events.extend(
+                    self.current_output()
+                        .cloned()
+                        .map(DeviceEvent::OutputSwitched),
+                );
+            }
+            None => {}
+        }
+        Ok(events)
+    }
+
+    // switches to the preferred device, or the default one, if not already on it
+    fn switch_output(&mut self) -> Result<Option<DeviceEvent>> {
+        let preferred = self.preferred_output.and_then(|guid| self.output(guid));
+        let target = match preferred {
+            Some(device) => device,
+            None if self.follow_default => match self.default_output() {
+                Some(device) => device,
+                None => return Ok(None),
+            },
+            None => return Ok(None),
+        };
+        if Some(target.guid) == self.current_output {
+            return Ok(None);
+        }
+
+        let target = target.clone();
+        self.system.set_driver(target.index)?;
+        self.current_output = Some(target.guid);
+        Ok(Some(DeviceEvent::OutputSwitched(target)))
+    }
+
+    fn driver_guid(&self) -> Result<Option<Guid>> {
+        let driver = self.system.get_driver()?;
+        Ok(self
+            .outputs
+            .iter()
+            .find(|device| device.index == driver)
+            .map(|device| device.guid))
+    }
+
+    fn enumerate_outputs(&self) -> Result<Vec<OutputDevice>> {
+        (0..self.system.get_driver_count()?)
+            .map(|index| {
+                let (name, guid, system_rate, speaker_mode, speaker_mode_channels) =
+                    self.system.get_driver_info(index)?;
+                Ok(OutputDevice {
+                    index,
+                    name,
+                    guid,
+                    system_rate,
+                    speaker_mode,
+                    speaker_mode_channels,
+                })
+            })
+            .collect()
+    }
+
+    fn enumerate_inputs(&self) -> Result<Vec<InputDevice>> {
+        let (count, _) = self.system.get_recording_driver_count()?;
+        let mut inputs = vec![];
+        for index in 0..count {
+            let (name, guid, system_rate, speaker_mode, speaker_mode_channels, state) =
+                self.system.get_record_driver_info(index)?;
+            // some platforms keep listing devices after they're unplugged
+            if state.contains(DriverState::CONNECTED) {
+                inputs.push(InputDevice {
+                    index,
+                    name,
+                    guid,
+                    system_rate,
+                    speaker_mode,
+                    speaker_mode_channels,
+                    state,
+                });
+            }
+        }
+        Ok(inputs)
+    }
+}
+
+fn diff_devices<T: Clone>(
+    old: &[T],
+    new: &[T],
+    guid: impl Fn(&T) -> Guid,
+    added: impl Fn(T) -> DeviceEvent,
+    removed: impl Fn(T) -> DeviceEvent,
+    events: &mut Vec<DeviceEvent>,
+) {
+    for device in old {
+        if !new.iter().any(|other| guid(other) == guid(device)) {
+            events.push(removed(device.clone()));
+        }
+    }
+    for device in new {
+        if !old.iter().any(|other| guid(other) == guid(device)) {
+            events.push(added(device.clone()));
+        }
+    }
+}
@@ -0,0 +1,509 @@
+// Copyright (c) 2024 Lily Lyons
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use fmod_sys::*;
+use std::{
+    collections::HashMap,
+    ffi::{c_float, c_int},
+};
+
+use crate::{Geometry, System, Vector};
+
+/// How a triangle mesh is turned into polygons by [`Geometry::from_mesh`] and [`Geometry::add_mesh`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MeshOptions {
+    /// Whether the polygons occlude sound coming from both sides, or only from the side the triangles face.
+    pub double_sided: bool,
+    /// Whether neighbouring triangles that lie in the same plane are merged into larger convex polygons.
+    ///
+    /// This means fewer polygons for FMOD to test rays against, which is a big win for level meshes with lots of flat walls and floors.
+    pub merge_coplanar: bool,
+}
+
+impl Default for MeshOptions {
+    fn default() -> Self {
+        MeshOptions {
+            double_sided: true,
+            merge_coplanar: true,
+        }
+    }
+}
+
+impl Geometry {
+    /// Creates a geometry object from an indexed triangle mesh, sized to fit exactly.
+    ///
+    /// Every 3 indices into `vertices` make a triangle, wound counter-clockwise when looking at its front.
+    /// Triangles with no area are skipped.
+    ///
+    /// Returns [`FMOD_RESULT::FMOD_ERR_INVALID_PARAM`] if the number of indices isn't a multiple of 3 or an index is out of bounds.
+    pub fn from_mesh(
+        system: System,
+        vertices: &[Vector],
+        indices: &[u32],
+        direct_occlusion: c_float,
+        reverb_occlusion: c_float,
+        options: MeshOptions,
+    ) -> Result<Geometry> {
+        let polygons = mesh_polygons(vertices, indices, options.merge_coplanar)?;
+        let (polygon_count, vertex_count) = polygon_counts(&polygons)?;
+
+        let geometry = system.create_geometry(polygon_count, vertex_count)?;
+        if let Err(e) =
+            geometry.add_polygons(&polygons, direct_occlusion, reverb_occlusion, options)
+        {
+            let _ = geometry.release();
+            return Err(e);
+        }
+        Ok(geometry)
+    }
+
+    /// Adds an indexed triangle mesh to this geometry object, like [`Geometry::from_mesh`].
+    ///
+    /// Space for the whole mesh is checked before anything is added,
+    /// so this returns [`FMOD_RESULT::FMOD_ERR_INVALID_PARAM`] instead of adding part of the mesh if it would go over [`Geometry::get_max_polygons`].
+    ///
+    /// Returns the index of the first polygon that was added.
+    pub fn add_mesh(
+        &self,
+        vertices: &[Vector],
+        indices: &[u32],
+        direct_occlusion: c_float,
+        reverb_occlusion: c_float,
+        options: MeshOptions,
+    ) -> Result<c_int> {
+        let polygons = mesh_polygons(vertices, indices, options.merge_coplanar)?;
+        let (polygon_count, vertex_count) = polygon_counts(&polygons)?;
+
+        let (max_polygons, max_vertices) = self.get_max_polygons()?;
+        let existing_polygons = self.get_polygon_count()?;
+        let mut existing_vertices = 0;
+        for index in 0..existing_polygons {
+            existing_vertices += self.get_polygon_vertex_count(index)?;
+        }
+        if existing_polygons + polygon_count > max_polygons
+            || existing_vertices + vertex_count > max_vertices
+        {
+            return Err(Error::Fmod(FMOD_RESULT::FMOD_ERR_INVALID_PARAM));
+        }
+
+        self.add_polygons(&polygons, direct_occlusion, reverb_occlusion, options)?;
+        Ok(existing_polygons)
+    }
+
+    fn add_polygons(
+        self,
+        polygons: &[Vec<Vector>],
+        direct_occlusion: c_float,
+        reverb_occlusion: c_float,
+        options: MeshOptions,
+    ) -> Result<()> {
+        for polygon in polygons {
+            self.add_polygon(
+                direct_occlusion,
+                reverb_occlusion,
+                options.double_sided,
+                polygon,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl System {
+    /// Calculates geometry occlusion between a listener and many sound sources.
+    ///
+    /// Returns the direct and reverb occlusion of each source, in the same order as `sources`.
+    /// See [`System::get_geometry_occlusion`].
+    pub fn geometry_occlusion_batch(
+        &self,
+        listener: Vector,
+        sources: &[Vector],
+    ) -> Result<Vec<(c_float, c_float)>> {
+        sources
+            .iter()
+            .map(|&source| self.get_geometry_occlusion(listener, source))
+            .collect()
+    }
+}
+
+// the sine of the largest angle between triangles that are still considered coplanar (about 0.3 degrees)
+const COPLANAR_EPSILON: c_float = 0.005;
+
+#[derive(Debug)]
+struct Polygon {
+    // indices into the welded vertices, counter-clockwise around the normal
+    vertices: Vec<u32>,
+    normal: Vector,
+}
+
+fn polygon_counts(polygons: &[Vec<Vector>]) -> Result<(c_int, c_int)> {
+    let vertex_count: usize = polygons.iter().map(Vec::len).sum();
+    let polygon_count = c_int::try_from(polygons.len())
+        .map_err(|_| Error::Fmod(FMOD_RESULT::FMOD_ERR_INVALID_PARAM))?;
+    let vertex_count = c_int::try_from(vertex_count)
+        .map_err(|_| Error::Fmod(FMOD_RESULT::FMOD_ERR_INVALID_PARAM))?;
+    Ok((polygon_count, vertex_count))
+}
+
+#[allow(clippy::manual_is_multiple_of)] // usize::is_multiple_of needs rust 1.87
+fn mesh_polygons(
+    vertices: &[Vector],
+    indices: &[u32],
+    merge_coplanar: bool,
+) -> Result<Vec<Vec<Vector>>> {
+    if indices.len() % 3 != 0
+        || indices
+            .iter()
+            .any(|&index| index as usize >= vertices.len())
+    {
+        return Err(Error::Fmod(FMOD_RESULT::FMOD_ERR_INVALID_PARAM));
+    }
+
+    // meshes often duplicate vertices (for normals or uvs), which would hide that triangles share an edge
+    let mut welded = HashMap::new();
+    let remap: Vec<u32> = vertices
+        .iter()
+        .enumerate()
+        .map(|(index, v)| {
+            *welded
+                .entry([v.x.to_bits(), v.y.to_bits(), v.z.to_bits()])
+                .or_insert(index as u32)
+        })
+        .collect();
+
+    let mut polygons: Vec<Polygon> = indices
+        .chunks_exact(3)
+        .filter_map(|triangle| {
+            let [a, b, c] = [0, 1, 2].map(|i| remap[triangle[i] as usize]);
+            let normal = (vertices[b as usize] - vertices[a as usize])
+                .cross(vertices[c as usize] - vertices[a as usize]);
+            let length = normal.length();
+            (length > 0.0).then(|| Polygon {
+                vertices: vec![a, b, c],
+                normal: normal * (1.0 / length),
+            })
+        })
+        .collect();
+
+    if merge_coplanar {
+        polygons = merge_polygons(vertices, polygons);
+    }
+
+    Ok(polygons
+        .into_iter()
+        .map(|polygon| {
+            polygon
+                .vertices
+                .iter()
+                .map(|&i| vertices[i as usize])
+                .collect()
+        })
+        .collect())
+}
+
+// greedily merges polygons across shared edges for as long as the result stays convex
+fn merge_polygons(vertices: &[Vector], polygons: Vec<Polygon>) -> Vec<Polygon> {
+    let mut edges = HashMap::new();
+    for (index, polygon) in polygons.iter().enumerate() {
+        for edge in polygon_edges(polygon) {
+            edges.insert(edge, index);
+        }
+    }
+    let mut polygons: Vec<Option<Polygon>> = polygons.into_iter().map(Some).collect();
+
+    let mut merged_any = true;
+    while merged_any {
+        merged_any = false;
+        for index in 0..polygons.len() {
+            let mut edge_index = 0;
+            while let Some(polygon) = &polygons[index] {
+                let Some((a, b)) = polygon_edges(polygon).nth(edge_index) else {
+                    break;
+                };
+                edge_index += 1;
+
+                // a neighbour facing the same way has the same edge going the other way
+                let Some(&other_index) = edges.get(&(b, a)) else {
+                    continue;
+                };
+                let Some(other) = polygons[other_index]
+                    .as_ref()
+                    .filter(|_| other_index != index)
+                else {
+                    continue;
+                };
+                if !polygon_edges(other).any(|edge| edge == (b, a))
+                    || !coplanar(polygon.normal, other.normal)
+                {
+                    continue;
+                }
+                let Some(merged) = merge_pair(vertices, polygon, other, a, b) else {
+                    continue;
+                };
+
+                for edge in polygon_edges(&merged) {
+                    edges.insert(edge, index);
+                }
+                polygons[index] = Some(merged);
+                polygons[other_index] = None;
+                merged_any = true;
+                edge_index = 0;
+            }
+        }
+    }
+
+    polygons.into_iter().flatten().collect()
+}
+
+// joins two polygons along the edge a -> b of `first` (b -> a of `second`), if the result is convex
+fn merge_pair(
+    vertices: &[Vector],
+    first: &Polygon,
+    second: &Polygon,
+    a: u32,
+    b: u32,
+) -> Option<Polygon> {
+    let start = first.vertices.iter().position(|&v| v == b)?;
+    let other_start = second.vertices.iter().position(|&v| v == a)?;
+
+    // all of the first polygon from b around to a, then the second polygon from after a to before b
+    let mut merged: Vec<u32> = first.vertices[start..]
+        .iter()
+        .chain(&first.vertices[..start])
+        .copied()
+        .collect();
+    merged.extend(
+        second.vertices[other_start..]
+            .iter()
+            .chain(&second.vertices[..other_start])
+            .skip(1)
+            .take(second.vertices.len() - 2),
+    );
+
+    let mut unique = merged.clone();
+    unique.sort_unstable();
+    unique.dedup();
+    if unique.len() != merged.len() {
+        return None;
+    }
+
+    // drop vertices in the middle of straight edges, and give up if any corner bends the wrong way
+    let count = merged.len();
+    let mut convex = Vec::with_capacity(count);
+    for i in 0..count {
+        let previous = vertices[merged[(i + count - 1) % count] as usize];
+        let current = vertices[merged[i] as usize];
+        let next = vertices[merged[(i + 1) % count] as usize];
+        let into = current - previous;
+        let out = next - current;
+        let turn = into.cross(out).dot(first.normal);
+        let tolerance = COPLANAR_EPSILON * into.length() * out.length();
+        if turn < -tolerance {
+            return None;
+        }
+        if turn > tolerance {
+            convex.push(merged[i]);
+        }
+    }
+
+    Some(Polygon {
+        vertices: convex,
+        normal: first.normal,
+    })
+}
+
+// whether two unit normals point the same way, within COPLANAR_EPSILON
+fn coplanar(a: Vector, b: Vector) -> bool {
+    let sine = a.cross(b);
+    a.dot(b) > 0.0 && sine.dot(sine) <= COPLANAR_EPSILON * COPLANAR_EPSILON
+}
+
+fn polygon_edges(polygon: &Polygon) -> impl Iterator<Item = (u32, u32)> + '_ {
+    let count = polygon.vertices.len();
+    (0..count).map(move |i| (polygon.vertices[i], polygon.vertices[(i + 1) % count]))
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)] // every vertex is on a whole number, so the areas are exact
+mod tests {
+    use super::*;
+
+    const UP: Vector = Vector {
+        x: 0.0,
+        y: 0.0,
+        z: 1.0,
+    };
+
+    fn v(x: c_float, y: c_float) -> Vector {
+        Vector { x, y, z: 0.0 }
+    }
+
+    fn square(vertices: &mut Vec<Vector>, x: c_float, y: c_float) -> Polygon {
+        let first = vertices.len() as u32;
+        vertices.extend([v(x, y), v(x + 1.0, y), v(x + 1.0, y + 1.0), v(x, y + 1.0)]);
+        Polygon {
+            vertices: vec![first, first + 1, first + 2, first + 3],
+            normal: UP,
+        }
+    }
+
+    // the polygons are all in the xy plane facing +z, so this is the shoelace formula
+    fn area(polygon: &[Vector]) -> c_float {
+        let count = polygon.len();
+        (0..count)
+            .map(|i| {
+                let (a, b) = (polygon[i], polygon[(i + 1) % count]);
+                a.x * b.y - b.x * a.y
+            })
+            .sum::<c_float>()
+            / 2.0
+    }
+
+    fn assert_convex(polygon: &[Vector]) {
+        let count = polygon.len();
+        for i in 0..count {
+            let into = polygon[i] - polygon[(i + count - 1) % count];
+            let out = polygon[(i + 1) % count] - polygon[i];
+            assert!(into.cross(out).z > 0.0, "{polygon:?} is not convex");
+        }
+    }
+
+    #[test]
+    fn rejects_bad_indices() {
+        let vertices = [v(0.0, 0.0), v(1.0, 0.0), v(0.0, 1.0)];
+        assert!(mesh_polygons(&vertices, &[0, 1], true).is_err());
+        assert!(mesh_polygons(&vertices, &[0, 1, 3], true).is_err());
+    }
+
+    #[test]
+    fn welds_duplicate_vertices() {
+        // a quad where both triangles have their own copies of the shared edge
+        let vertices = [
+            v(0.0, 0.0),
+            v(1.0, 0.0),
+            v(1.0, 1.0),
+            v(0.0, 0.0),
+            v(1.0, 1.0),
+            v(0.0, 1.0),
+        ];
+        let polygons = mesh_polygons(&vertices, &[0, 1, 2, 3, 4, 5], true).unwrap();
+        assert_eq!(polygons.len(), 1);
+        assert_eq!(polygons[0].len(), 4);
+        assert_convex(&polygons[0]);
+        assert_eq!(area(&polygons[0]), 1.0);
+
+        let polygons = mesh_polygons(&vertices, &[0, 1, 2, 3, 4, 5], false).unwrap();
+        assert_eq!(polygons.len(), 2);
+    }
+
+    #[test]
+    fn merges_quads() {
+        let mut vertices = vec![];
+        let first = square(&mut vertices, 0.0, 0.0);
+        let second = square(&mut vertices, 1.0, 0.0);
+
+        // the squares touch, but only welded vertices count as a shared edge
+        assert!(merge_pair(&vertices, &first, &second, 1, 2).is_none());
+
+        let second = Polygon {
+            vertices: vec![1, 5, 6, 2],
+            normal: UP,
+        };
+        let merged = merge_pair(&vertices, &first, &second, 1, 2).unwrap();
+        // the vertices in the middle of the long edges are dropped
+        let mut corners = merged.vertices.clone();
+        corners.sort_unstable();
+        assert_eq!(corners, [0, 3, 5, 6]);
+    }
+
+    #[test]
+    fn rejects_concave_l_shape() {
+        let mut vertices = vec![
+            v(0.0, 0.0),
+            v(2.0, 0.0),
+            v(2.0, 1.0),
+            v(1.0, 1.0),
+            v(0.0, 1.0),
+        ];
+        let bottom = Polygon {
+            vertices: vec![0, 1, 2, 3, 4],
+            normal: UP,
+        };
+        vertices.extend([v(1.0, 2.0), v(0.0, 2.0)]);
+        let top = Polygon {
+            vertices: vec![4, 3, 5, 6],
+            normal: bottom.normal,
+        };
+        assert!(merge_pair(&vertices, &bottom, &top, 3, 4).is_none());
+
+        // the whole mesh still covers the L, just with more than one polygon
+        let vertices = [
+            v(0.0, 0.0),
+            v(1.0, 0.0),
+            v(2.0, 0.0),
+            v(0.0, 1.0),
+            v(1.0, 1.0),
+            v(2.0, 1.0),
+            v(0.0, 2.0),
+            v(1.0, 2.0),
+        ];
+        let indices = [
+            0, 1, 4, 0, 4, 3, // bottom left
+            1, 2, 5, 1, 5, 4, // bottom right
+            3, 4, 7, 3, 7, 6, // top left
+        ];
+        let polygons = mesh_polygons(&vertices, &indices, true).unwrap();
+        assert!(polygons.len() > 1);
+        for polygon in &polygons {
+            assert_convex(polygon);
+        }
+        assert_eq!(polygons.iter().map(|p| area(p)).sum::<c_float>(), 3.0);
+    }
+
+    #[test]
+    fn t_junction() {
+        // a square on top of a rectangle, meeting it at (1, 1) which isn't a vertex of the rectangle
+        let vertices = [
+            v(0.0, 0.0),
+            v(2.0, 0.0),
+            v(2.0, 1.0),
+            v(0.0, 1.0),
+            v(1.0, 1.0),
+            v(1.0, 2.0),
+            v(0.0, 2.0),
+            v(2.0, 2.0),
+        ];
+        let rectangle = [0, 1, 2, 0, 2, 3];
+        let top_left = [3, 4, 5, 3, 5, 6];
+        let top_right = [4, 2, 7, 4, 7, 5];
+
+        // only part of the rectangle's top edge is shared, so they stay apart
+        let indices = [rectangle, top_left].concat();
+        let polygons = mesh_polygons(&vertices, &indices, true).unwrap();
+        assert_eq!(polygons.len(), 2);
+        for polygon in &polygons {
+            assert_eq!(polygon.len(), 4);
+            assert_convex(polygon);
+        }
+        assert_eq!(polygons.iter().map(|p| area(p)).sum::<c_float>(), 3.0);
+
+        // with a second square the junction is in the middle of a straight edge, so it goes away and the whole edge is shared
+        let indices = [rectangle, top_left, top_right].concat();
+        let polygons = mesh_polygons(&vertices, &indices, true).unwrap();
+        assert_eq!(polygons.len(), 1);
+        assert_eq!(polygons[0].len(), 4);
+        assert_convex(&polygons[0]);
+        assert_eq!(area(&polygons[0]), 4.0);
+    }
+
+    #[test]
+    fn keeps_opposite_faces_apart() {
+        let vertices = [v(0.0, 0.0), v(1.0, 0.0), v(1.0, 1.0), v(0.0, 1.0)];
+        // the second triangle is wound the other way, so it faces -z
+        let polygons = mesh_polygons(&vertices, &[0, 1, 2, 0, 3, 2], true).unwrap();
+        assert_eq!(polygons.len(), 2);
+    }
+}
@@ -7,9 +7,12 @@
 use fmod_sys::*;
 
 mod general;
+mod mesh;
 mod polygons;
 mod spatialization;
 
+pub use mesh::MeshOptions;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[repr(transparent)] // so we can transmute between types
 pub struct Geometry {
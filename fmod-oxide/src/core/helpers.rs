@@ -5,30 +5,93 @@
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
 use fmod_sys::*;
-use lanyard::Utf8CString;
+use lanyard::{Utf8CStr, Utf8CString, Utf8CStringBuilder};
+use std::ffi::c_int;
 
-pub(crate) fn get_string(
+pub(crate) fn get_string(string_fn: impl FnMut(&mut [u8]) -> FMOD_RESULT) -> Result<Utf8CString> {
+    let mut string = Utf8CStringBuilder::with_capacity(255);
+    get_string_into(&mut string, string_fn)?;
+    // the buffer is at least 256 bytes, which is a lot to keep around for a short string
+    string.shrink_to_fit();
+    Ok(string.into_cstring())
+}
+
+// For functions that don't say how long the string is, so we have to keep growing the buffer until it fits.
+pub(crate) fn get_string_into(
+    string: &mut Utf8CStringBuilder,
     mut string_fn: impl FnMut(&mut [u8]) -> FMOD_RESULT,
-) -> Result<Utf8CString> {
-    let mut buffer = vec![0u8; 256];
+) -> Result<()> {
+    // SAFETY: fill_string leaves behind a single nul terminator, and all public fmod apis return UTF-8 strings.
+    let buffer = unsafe { string.as_mut_vec() };
+    // Start with whatever the builder can already hold, so reusing it doesn't allocate.
+    buffer.clear();
+    buffer.resize(buffer.capacity().max(256), 0);
     // Initial call to get the string.
-    let mut result = string_fn(&mut buffer);
+    let mut result = string_fn(buffer);
     // If the buffer is too small, resize it and try again.
     while let FMOD_RESULT::FMOD_ERR_TRUNCATED = result {
         buffer.resize(buffer.len() * 2, 0);
-        result = string_fn(&mut buffer);
+        result = string_fn(buffer);
     }
+    fill_string(buffer, result)
+}
+
+// For Studio functions, which tell us how long the string is (including the null terminator) if the buffer is too small.
+pub(crate) fn get_sized_string_into(
+    string: &mut Utf8CStringBuilder,
+    mut string_fn: impl FnMut(&mut [u8], &mut c_int) -> FMOD_RESULT,
+) -> Result<()> {
+    // SAFETY: fill_string leaves behind a single nul terminator, and all public fmod apis return UTF-8 strings.
+    let buffer = unsafe { string.as_mut_vec() };
+    buffer.clear();
+    buffer.resize(buffer.capacity(), 0);
+    let mut string_len = 0;
+    let mut result = string_fn(buffer, &mut string_len);
+    while let FMOD_RESULT::FMOD_ERR_TRUNCATED = result {
+        // the length should always be enough, but make sure we can't get stuck
+        let len = (string_len as usize).max(buffer.len() * 2);
+        buffer.resize(len, 0);
+        result = string_fn(buffer, &mut string_len);
+    }
+    fill_string(buffer, result)
+}
 
-    result.to_result()?;
+fn fill_string(buffer: &mut Vec<u8>, result: FMOD_RESULT) -> Result<()> {
+    let len = match result.to_result() {
+        Ok(()) => buffer.iter().position(|&b| b == 0),
+        Err(error) => {
+            buffer.clear();
+            buffer.push(0);
+            return Err(error);
+        }
+    };
+    let Some(len) = len else {
+        // don't leave the builder without its null terminator, even though we're about to panic
+        buffer.clear();
+        buffer.push(0);
+        panic!("fmod-oxide expected a null-terminated string but did not get one! THIS IS A VERY BAD BUG!");
+    };
+    // We add 1 to include the null terminator.
+    buffer.truncate(len + 1);
+    Ok(())
+}
+
+// Writes into a caller-provided buffer, returning FMOD_ERR_TRUNCATED if the string doesn't fit.
+pub(crate) fn get_string_in(
+    buffer: &mut [u8],
+    string_fn: impl FnOnce(&mut [u8]) -> FMOD_RESULT,
+) -> Result<&Utf8CStr> {
+    // fmod might not treat an empty buffer as too small, but there's no room for the null terminator
+    if buffer.is_empty() {
+        return Err(Error::Fmod(FMOD_RESULT::FMOD_ERR_TRUNCATED));
+    }
+    string_fn(buffer).to_result()?;
 
     let len = buffer.iter().position(|&b| b == 0).expect(
         "fmod-oxide expected a null-terminated string but did not get one! THIS IS A VERY BAD BUG!",
     );
-    // Resize to make sure we don't waste memory.
-    // We add 1 to include the null terminator.
-    buffer.truncate(len + 1);
-    buffer.shrink_to_fit();
-    let string = unsafe { Utf8CString::from_utf8_with_nul_unchecked(buffer) };
+    // all public fmod apis return UTF-8 strings. this should be safe.
+    let string = unsafe { Utf8CStr::from_utf8_with_nul_unchecked(&buffer[..=len]) };
     Ok(string)
 }
 
@@ -0,0 +1,57 @@
+// Copyright (c) 2024 Lily Lyons
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{Quaternion, Vector};
+
+impl From<::glam::Vec3> for Vector {
+    fn from(value: ::glam::Vec3) -> Self {
+        Vector {
+            x: value.x,
+            y: value.y,
+            z: value.z,
+        }
+    }
+}
+
+impl From<Vector> for ::glam::Vec3 {
+    fn from(value: Vector) -> Self {
+        ::glam::Vec3::new(value.x, value.y, value.z)
+    }
+}
+
+impl From<::glam::Vec3A> for Vector {
+    fn from(value: ::glam::Vec3A) -> Self {
+        Vector {
+            x: value.x,
+            y: value.y,
+            z: value.z,
+        }
+    }
+}
+
+impl From<Vector> for ::glam::Vec3A {
+    fn from(value: Vector) -> Self {
+        ::glam::Vec3A::new(value.x, value.y, value.z)
+    }
+}
+
+impl From<::glam::Quat> for Quaternion {
+    fn from(value: ::glam::Quat) -> Self {
+        Quaternion {
+            x: value.x,
+            y: value.y,
+            z: value.z,
+            w: value.w,
+        }
+    }
+}
+
+impl From<Quaternion> for ::glam::Quat {
+    /// The quaternion is normalized, as glam expects.
+    fn from(value: Quaternion) -> Self {
+        ::glam::Quat::from_xyzw(value.x, value.y, value.z, value.w).normalize()
+    }
+}
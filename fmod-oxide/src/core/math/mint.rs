@@ -0,0 +1,73 @@
+// Copyright (c) 2024 Lily Lyons
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::ffi::c_float;
+
+use crate::{Quaternion, Vector};
+
+impl From<::mint::Vector3<c_float>> for Vector {
+    fn from(value: ::mint::Vector3<c_float>) -> Self {
+        Vector {
+            x: value.x,
+            y: value.y,
+            z: value.z,
+        }
+    }
+}
+
+impl From<Vector> for ::mint::Vector3<c_float> {
+    fn from(value: Vector) -> Self {
+        ::mint::Vector3 {
+            x: value.x,
+            y: value.y,
+            z: value.z,
+        }
+    }
+}
+
+impl From<::mint::Point3<c_float>> for Vector {
+    fn from(value: ::mint::Point3<c_float>) -> Self {
+        Vector {
+            x: value.x,
+            y: value.y,
+            z: value.z,
+        }
+    }
+}
+
+impl From<Vector> for ::mint::Point3<c_float> {
+    fn from(value: Vector) -> Self {
+        ::mint::Point3 {
+            x: value.x,
+            y: value.y,
+            z: value.z,
+        }
+    }
+}
+
+impl From<::mint::Quaternion<c_float>> for Quaternion {
+    fn from(value: ::mint::Quaternion<c_float>) -> Self {
+        Quaternion {
+            x: value.v.x,
+            y: value.v.y,
+            z: value.v.z,
+            w: value.s,
+        }
+    }
+}
+
+impl From<Quaternion> for ::mint::Quaternion<c_float> {
+    fn from(value: Quaternion) -> Self {
+        ::mint::Quaternion {
+            v: ::mint::Vector3 {
+                x: value.x,
+                y: value.y,
+                z: value.z,
+            },
+            s: value.w,
+        }
+    }
+}
@@ -0,0 +1,414 @@
+// Copyright (c) 2024 Lily Lyons
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::{
+    ffi::c_float,
+    ops::{Add, Mul, Sub},
+};
+
+use crate::{Attributes3D, InitFlags, Vector};
+
+#[cfg(feature = "glam")]
+mod glam;
+#[cfg(feature = "mint")]
+mod mint;
+#[cfg(feature = "nalgebra")]
+mod nalgebra;
+
+/// A rotation, used to orient [`Attributes3D`] with [`Attributes3D::from_transform`].
+///
+/// FMOD itself has no use for quaternions, this is only here so rotations from different math libraries can be passed in.
+/// With the `mint`, `glam` or `nalgebra` features enabled, their quaternions convert into this.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct Quaternion {
+    pub x: c_float,
+    pub y: c_float,
+    pub z: c_float,
+    pub w: c_float,
+}
+
+impl Quaternion {
+    pub const IDENTITY: Quaternion = Quaternion {
+        x: 0.0,
+        y: 0.0,
+        z: 0.0,
+        w: 1.0,
+    };
+
+    /// Rotates `vector` by this quaternion, which is normalized first.
+    pub fn rotate(self, vector: Vector) -> Vector {
+        let length = (self.x * self.x + self.y * self.y + self.z * self.z + self.w * self.w).sqrt();
+        if length == 0.0 || !length.is_finite() {
+            return vector;
+        }
+        let (x, y, z, w) = (
+            self.x / length,
+            self.y / length,
+            self.z / length,
+            self.w / length,
+        );
+
+        // v + 2w(q × v) + 2q × (q × v), where q is the vector part
+        let tx = 2.0 * (y * vector.z - z * vector.y);
+        let ty = 2.0 * (z * vector.x - x * vector.z);
+        let tz = 2.0 * (x * vector.y - y * vector.x);
+        Vector {
+            x: vector.x + w * tx + (y * tz - z * ty),
+            y: vector.y + w * ty + (z * tx - x * tz),
+            z: vector.z + w * tz + (x * ty - y * tx),
+        }
+    }
+}
+
+impl Default for Quaternion {
+    fn default() -> Self {
+        Quaternion::IDENTITY
+    }
+}
+
+impl From<[c_float; 4]> for Quaternion {
+    fn from([x, y, z, w]: [c_float; 4]) -> Self {
+        Quaternion { x, y, z, w }
+    }
+}
+
+impl From<Quaternion> for [c_float; 4] {
+    fn from(value: Quaternion) -> Self {
+        [value.x, value.y, value.z, value.w]
+    }
+}
+
+impl Vector {
+    pub fn dot(self, other: Vector) -> c_float {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    #[must_use]
+    pub fn cross(self, other: Vector) -> Vector {
+        Vector {
+            x: self.y * other.z - self.z * other.y,
+            y: self.z * other.x - self.x * other.z,
+            z: self.x * other.y - self.y * other.x,
+        }
+    }
+
+    pub fn length(self) -> c_float {
+        self.dot(self).sqrt()
+    }
+
+    pub fn distance(self, other: Vector) -> c_float {
+        (other - self).length()
+    }
+
+    /// Cheaper than [`Vector::distance`], for when distances only need to be compared.
+    pub fn distance_squared(self, other: Vector) -> c_float {
+        let difference = other - self;
+        difference.dot(difference)
+    }
+}
+
+impl Add for Vector {
+    type Output = Vector;
+
+    fn add(self, other: Vector) -> Vector {
+        Vector {
+            x: self.x + other.x,
+            y: self.y + other.y,
+            z: self.z + other.z,
+        }
+    }
+}
+
+impl Sub for Vector {
+    type Output = Vector;
+
+    fn sub(self, other: Vector) -> Vector {
+        Vector {
+            x: self.x - other.x,
+            y: self.y - other.y,
+            z: self.z - other.z,
+        }
+    }
+}
+
+impl Mul<c_float> for Vector {
+    type Output = Vector;
+
+    fn mul(self, factor: c_float) -> Vector {
+        Vector {
+            x: self.x * factor,
+            y: self.y * factor,
+            z: self.z * factor,
+        }
+    }
+}
+
+impl From<[c_float; 3]> for Vector {
+    fn from([x, y, z]: [c_float; 3]) -> Self {
+        Vector { x, y, z }
+    }
+}
+
+impl From<Vector> for [c_float; 3] {
+    fn from(value: Vector) -> Self {
+        [value.x, value.y, value.z]
+    }
+}
+
+/// Which coordinate system 3D positions and orientations are in.
+///
+/// FMOD is left-handed by default, where +X is right, +Y is up and +Z is forward.
+/// If [`InitFlags::RIGHTHANDED_3D`] is used it is right-handed instead, where +Z points backwards and forward is -Z.
+/// This has to match the flags the [`crate::System`] was initialized with, FMOD has no way to check it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub enum Handedness {
+    #[default]
+    Left,
+    Right,
+}
+
+impl Handedness {
+    /// The handedness of a system initialized with `flags`.
+    pub fn from_init_flags(flags: InitFlags) -> Self {
+        if flags.contains(InitFlags::RIGHTHANDED_3D) {
+            Handedness::Right
+        } else {
+            Handedness::Left
+        }
+    }
+
+    /// The direction an unrotated object faces.
+    pub fn forward(self) -> Vector {
+        match self {
+            Handedness::Left => Vector {
+                x: 0.0,
+                y: 0.0,
+                z: 1.0,
+            },
+            Handedness::Right => Vector {
+                x: 0.0,
+                y: 0.0,
+                z: -1.0,
+            },
+        }
+    }
+}
+
+impl Attributes3D {
+    /// Creates attributes from a position, rotation and velocity, as they are usually stored in a game's transforms.
+    ///
+    /// The forward and up vectors are found by rotating [`Handedness::forward`] and +Y by `rotation`,
+    /// so `handedness` must match how the [`crate::System`] was initialized (see [`Handedness::from_init_flags`]).
+    ///
+    /// Positions and rotations from `glam`, `mint` and `nalgebra` can be passed in directly with the features of the same names.
+    pub fn from_transform(
+        position: impl Into<Vector>,
+        rotation: impl Into<Quaternion>,
+        velocity: impl Into<Vector>,
+        handedness: Handedness,
+    ) -> Self {
+        let rotation = rotation.into();
+        Attributes3D {
+            position: position.into(),
+            velocity: velocity.into(),
+            forward: rotation.rotate(handedness.forward()),
+            up: rotation.rotate(Vector {
+                x: 0.0,
+                y: 1.0,
+                z: 0.0,
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)] // the values compared are exact
+mod tests {
+    use super::*;
+
+    const X: Vector = Vector {
+        x: 1.0,
+        y: 0.0,
+        z: 0.0,
+    };
+    const Y: Vector = Vector {
+        x: 0.0,
+        y: 1.0,
+        z: 0.0,
+    };
+    const Z: Vector = Vector {
+        x: 0.0,
+        y: 0.0,
+        z: 1.0,
+    };
+
+    #[track_caller]
+    fn assert_close(actual: Vector, expected: Vector) {
+        assert!(
+            actual.distance(expected) < 1e-6,
+            "{actual:?} is not close to {expected:?}"
+        );
+    }
+
+    #[cfg(any(feature = "glam", feature = "nalgebra"))]
+    #[track_caller]
+    fn assert_quaternion_close(actual: Quaternion, expected: Quaternion) {
+        let actual: [c_float; 4] = actual.into();
+        let expected: [c_float; 4] = expected.into();
+        assert!(
+            actual
+                .iter()
+                .zip(expected)
+                .all(|(a, e)| (a - e).abs() < 1e-6),
+            "{actual:?} is not close to {expected:?}"
+        );
+    }
+
+    // a quarter turn counterclockwise around `axis`, looking down it
+    fn quarter_turn(axis: Vector) -> Quaternion {
+        let s = std::f32::consts::FRAC_1_SQRT_2;
+        Quaternion {
+            x: axis.x * s,
+            y: axis.y * s,
+            z: axis.z * s,
+            w: s,
+        }
+    }
+
+    #[test]
+    fn rotate_quarter_turns() {
+        assert_close(quarter_turn(Y).rotate(Z), X);
+        assert_close(quarter_turn(X).rotate(Y), Z);
+        assert_close(quarter_turn(Z).rotate(X), Y);
+        // rotating around an axis leaves it unchanged
+        assert_close(quarter_turn(Y).rotate(Y), Y);
+    }
+
+    #[test]
+    fn rotate_normalizes() {
+        let vector = Vector {
+            x: 1.0,
+            y: 2.0,
+            z: 3.0,
+        };
+        assert_eq!(Quaternion::IDENTITY.rotate(vector), vector);
+
+        let [x, y, z, w]: [c_float; 4] = quarter_turn(Y).into();
+        let scaled = Quaternion::from([x * 3.0, y * 3.0, z * 3.0, w * 3.0]);
+        assert_close(scaled.rotate(Z), X);
+
+        // quaternions that can't be normalized don't rotate anything
+        let zero = Quaternion::from([0.0; 4]);
+        assert_eq!(zero.rotate(vector), vector);
+        let nan = Quaternion::from([c_float::NAN, 0.0, 0.0, 1.0]);
+        assert_eq!(nan.rotate(vector), vector);
+    }
+
+    #[test]
+    fn handedness_forward() {
+        assert_eq!(Handedness::default(), Handedness::Left);
+        assert_eq!(Handedness::Left.forward(), Z);
+        assert_eq!(Handedness::Right.forward(), Z * -1.0);
+        assert_eq!(
+            Handedness::from_init_flags(InitFlags::NORMAL),
+            Handedness::Left
+        );
+        assert_eq!(
+            Handedness::from_init_flags(InitFlags::RIGHTHANDED_3D),
+            Handedness::Right
+        );
+    }
+
+    #[test]
+    fn attributes_from_transform() {
+        let position = [1.0, 2.0, 3.0];
+        let velocity = [0.0, 0.0, 4.0];
+
+        let left =
+            Attributes3D::from_transform(position, quarter_turn(Y), velocity, Handedness::Left);
+        assert_eq!(left.position, Vector::from(position));
+        assert_eq!(left.velocity, Vector::from(velocity));
+        assert_close(left.forward, X);
+        assert_close(left.up, Y);
+
+        // the same rotation turns a right-handed forward vector the other way
+        let right =
+            Attributes3D::from_transform(position, quarter_turn(Y), velocity, Handedness::Right);
+        assert_close(right.forward, X * -1.0);
+        assert_close(right.up, Y);
+
+        let unrotated = Attributes3D::from_transform(
+            position,
+            Quaternion::IDENTITY,
+            velocity,
+            Handedness::Right,
+        );
+        assert_eq!(unrotated.forward, Handedness::Right.forward());
+    }
+
+    #[cfg(feature = "glam")]
+    #[test]
+    fn glam_round_trip() {
+        let vector = Vector {
+            x: 1.0,
+            y: 2.0,
+            z: 3.0,
+        };
+        assert_eq!(Vector::from(::glam::Vec3::from(vector)), vector);
+        assert_eq!(Vector::from(::glam::Vec3A::from(vector)), vector);
+
+        let rotation = quarter_turn(Y);
+        let quat = ::glam::Quat::from(rotation);
+        assert_close((quat * ::glam::Vec3::Z).into(), X);
+        assert_quaternion_close(Quaternion::from(quat), rotation);
+        // glam expects normalized quaternions
+        let scaled = Quaternion::from([0.0, 0.0, 0.0, 2.0]);
+        assert_eq!(
+            Quaternion::from(::glam::Quat::from(scaled)),
+            Quaternion::IDENTITY
+        );
+    }
+
+    #[cfg(feature = "mint")]
+    #[test]
+    fn mint_round_trip() {
+        let vector = Vector {
+            x: 1.0,
+            y: 2.0,
+            z: 3.0,
+        };
+        assert_eq!(Vector::from(::mint::Vector3::from(vector)), vector);
+        assert_eq!(Vector::from(::mint::Point3::from(vector)), vector);
+
+        let rotation = Quaternion::from([1.0, 2.0, 3.0, 4.0]);
+        let quat = ::mint::Quaternion::from(rotation);
+        assert_eq!(quat.s, 4.0);
+        assert_quaternion_close(Quaternion::from(quat), rotation);
+    }
+
+    #[cfg(feature = "nalgebra")]
+    #[test]
+    fn nalgebra_round_trip() {
+        let vector = Vector {
+            x: 1.0,
+            y: 2.0,
+            z: 3.0,
+        };
+        assert_eq!(Vector::from(::nalgebra::Vector3::from(vector)), vector);
+        assert_eq!(Vector::from(::nalgebra::Point3::from(vector)), vector);
+
+        let rotation = quarter_turn(Y);
+        let unit = ::nalgebra::UnitQuaternion::from(rotation);
+        assert_close((unit * ::nalgebra::Vector3::z()).into(), X);
+        assert_quaternion_close(Quaternion::from(unit), rotation);
+        // nalgebra stores w first
+        let quaternion = ::nalgebra::Quaternion::new(4.0, 1.0, 2.0, 3.0);
+        assert_eq!(
+            Quaternion::from(quaternion),
+            Quaternion::from([1.0, 2.0, 3.0, 4.0])
+        );
+    }
+}
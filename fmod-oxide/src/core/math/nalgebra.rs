@@ -0,0 +1,67 @@
+// Copyright (c) 2024 Lily Lyons
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::ffi::c_float;
+
+use crate::{Quaternion, Vector};
+
+impl From<::nalgebra::Vector3<c_float>> for Vector {
+    fn from(value: ::nalgebra::Vector3<c_float>) -> Self {
+        Vector {
+            x: value.x,
+            y: value.y,
+            z: value.z,
+        }
+    }
+}
+
+impl From<Vector> for ::nalgebra::Vector3<c_float> {
+    fn from(value: Vector) -> Self {
+        ::nalgebra::Vector3::new(value.x, value.y, value.z)
+    }
+}
+
+impl From<::nalgebra::Point3<c_float>> for Vector {
+    fn from(value: ::nalgebra::Point3<c_float>) -> Self {
+        Vector {
+            x: value.x,
+            y: value.y,
+            z: value.z,
+        }
+    }
+}
+
+impl From<Vector> for ::nalgebra::Point3<c_float> {
+    fn from(value: Vector) -> Self {
+        ::nalgebra::Point3::new(value.x, value.y, value.z)
+    }
+}
+
+impl From<::nalgebra::Quaternion<c_float>> for Quaternion {
+    fn from(value: ::nalgebra::Quaternion<c_float>) -> Self {
+        Quaternion {
+            x: value.i,
+            y: value.j,
+            z: value.k,
+            w: value.w,
+        }
+    }
+}
+
+impl From<::nalgebra::UnitQuaternion<c_float>> for Quaternion {
+    fn from(value: ::nalgebra::UnitQuaternion<c_float>) -> Self {
+        value.into_inner().into()
+    }
+}
+
+impl From<Quaternion> for ::nalgebra::UnitQuaternion<c_float> {
+    /// The quaternion is normalized, as nalgebra expects.
+    fn from(value: Quaternion) -> Self {
+        ::nalgebra::UnitQuaternion::from_quaternion(::nalgebra::Quaternion::new(
+            value.w, value.x, value.y, value.z,
+        ))
+    }
+}
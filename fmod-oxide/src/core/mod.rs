@@ -40,6 +40,27 @@ pub use flags::*;
 mod enums;
 pub use enums::*;
 
+mod sequencer;
+pub use sequencer::*;
+
+mod recorder;
+pub use recorder::*;
+
+mod device_manager;
+pub use device_manager::*;
+
+mod occlusion;
+pub use occlusion::*;
+
+mod math;
+pub use math::*;
+
+mod curve;
+pub use curve::*;
+
+mod velocity;
+pub use velocity::*;
+
 mod reverb_presets;
 mod structs;
 pub use structs::*;
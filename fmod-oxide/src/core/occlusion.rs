@@ -0,0 +1,232 @@
+// Copyright (c) 2024 Lily Lyons
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use fmod_sys::*;
+use std::{
+    collections::HashMap,
+    ffi::c_float,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use crate::{ChannelControlCallback, ChannelControlType, System, Vector};
+
+/// Calculates occlusion between a sound source and a listener, usually with the game's own physics raycasts.
+///
+/// Registered on a [`System`] with [`System::set_occlusion_provider`].
+/// It is used for channels with [`OcclusionCallback`] set, and for Studio event instances passed to [`crate::studio::System::apply_occlusion`].
+pub trait OcclusionProvider: Send + Sync {
+    /// Returns the direct and reverb occlusion between `source` and `listener`, from 0 (not occluded) to 1 (fully occluded).
+    fn occlusion(&self, source: Vector, listener: Vector) -> (c_float, c_float);
+}
+
+/// How often an [`OcclusionProvider`] is asked for new values, and how quickly they are faded to.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OcclusionSettings {
+    /// How long occlusion is cached for before the provider is asked again.
+    pub refresh_interval: Duration,
+    /// How far the source or listener can move before the cached occlusion is recalculated early.
+    pub refresh_distance: c_float,
+    /// Roughly how long it takes to fade to new occlusion values, so they don't jump when something moves in front of a sound.
+    ///
+    /// Zero disables smoothing.
+    pub smoothing: Duration,
+}
+
+impl Default for OcclusionSettings {
+    fn default() -> Self {
+        OcclusionSettings {
+            refresh_interval: Duration::from_millis(100),
+            refresh_distance: 0.5,
+            smoothing: Duration::from_millis(100),
+        }
+    }
+}
+
+/// A [`ChannelControlCallback`] that sets occlusion from the [`OcclusionProvider`] of the channel's [`System`].
+///
+/// Set it with `channel.set_callback::<OcclusionCallback>()`,
+/// or call [`OcclusionCallback::occlusion`] from your own callback to use it alongside other callbacks.
+///
+/// The provider's values replace the occlusion FMOD calculated from [`crate::Geometry`].
+/// If the system has more than one listener, the nearest one is used.
+#[derive(Debug, Clone, Copy)]
+pub struct OcclusionCallback;
+
+impl ChannelControlCallback for OcclusionCallback {
+    fn occlusion(
+        channel_control: ChannelControlType,
+        direct: &mut c_float,
+        reverb: &mut c_float,
+    ) -> Result<()> {
+        let system = channel_control.get_system()?;
+        let Some(occlusion) = occlusion_provider(system) else {
+            return Ok(());
+        };
+
+        let (source, _) = channel_control.get_3d_attributes()?;
+        // this runs in the mixer, so the listeners are compared as they are fetched instead of being collected
+        let listeners = (0..system.get_3d_listener_count()?)
+            .map(|listener| Ok(system.get_3d_listener_attributes(listener)?.0));
+        let Some(listener) = nearest_listener(source, listeners)? else {
+            return Ok(());
+        };
+
+        (*direct, *reverb) = occlusion.get(channel_control.inner as usize, source, listener);
+        Ok(())
+    }
+}
+
+impl System {
+    /// Registers an [`OcclusionProvider`] for this system, replacing any previous one.
+    ///
+    /// Results are cached per channel and smoothed across frames according to `settings`.
+    pub fn set_occlusion_provider<P: OcclusionProvider + 'static>(
+        &self,
+        provider: P,
+        settings: OcclusionSettings,
+    ) {
+        let occlusion = Arc::new(Occlusion {
+            provider: Box::new(provider),
+            settings,
+            state: Mutex::new(OcclusionState {
+                cache: HashMap::new(),
+                last_prune: Instant::now(),
+            }),
+        });
+
+        let mut providers = PROVIDERS.lock().unwrap();
+        match providers.iter_mut().find(|(system, _)| system == self) {
+            Some((_, existing)) => *existing = occlusion,
+            None => providers.push((*self, occlusion)),
+        }
+    }
+
+    /// Removes the [`OcclusionProvider`] of this system, if it has one.
+    ///
+    /// Occlusion that was already set on channels is left as it is.
+    pub fn remove_occlusion_provider(&self) {
+        PROVIDERS
+            .lock()
+            .unwrap()
+            .retain(|(system, _)| system != self);
+    }
+}
+
+// FMOD can't store anything for us on the system that isn't already used for userdata, so keep track of providers here
+static PROVIDERS: Mutex<Vec<(System, Arc<Occlusion>)>> = Mutex::new(Vec::new());
+
+// cached values that haven't been used for this long belong to a channel that has stopped
+const PRUNE_AFTER: Duration = Duration::from_secs(1);
+
+pub(crate) struct Occlusion {
+    provider: Box<dyn OcclusionProvider>,
+    settings: OcclusionSettings,
+    state: Mutex<OcclusionState>,
+}
+
+struct OcclusionState {
+    cache: HashMap<usize, CachedOcclusion>,
+    last_prune: Instant,
+}
+
+struct CachedOcclusion {
+    source: Vector,
+    listener: Vector,
+    target: (c_float, c_float),
+    current: (c_float, c_float),
+    // when the provider was last asked
+    computed: Instant,
+    // when the current value was last smoothed
+    updated: Instant,
+}
+
+// the provider is cloned out so the registry isn't locked while it runs
+pub(crate) fn occlusion_provider(system: System) -> Option<Arc<Occlusion>> {
+    PROVIDERS
+        .lock()
+        .unwrap()
+        .iter()
+        .find(|(other, _)| *other == system)
+        .map(|(_, occlusion)| Arc::clone(occlusion))
+}
+
+#[allow(clippy::unnecessary_map_or)] // Option::is_none_or needs rust 1.82
+pub(crate) fn nearest_listener(
+    source: Vector,
+    listeners: impl IntoIterator<Item = Result<Vector>>,
+) -> Result<Option<Vector>> {
+    let mut nearest: Option<(Vector, c_float)> = None;
+    for listener in listeners {
+        let listener = listener?;
+        let distance = source.distance(listener);
+        if nearest.map_or(true, |(_, nearest)| distance < nearest) {
+            nearest = Some((listener, distance));
+        }
+    }
+    Ok(nearest.map(|(listener, _)| listener))
+}
+
+impl Occlusion {
+    // returns the smoothed occlusion for whatever `key` identifies, asking the provider if the cached value is out of date
+    pub(crate) fn get(&self, key: usize, source: Vector, listener: Vector) -> (c_float, c_float) {
+        let settings = &self.settings;
+        let now = Instant::now();
+
+        #[allow(clippy::unnecessary_map_or)] // Option::is_none_or needs rust 1.82
+        let needs_refresh = {
+            let mut state = self.state.lock().unwrap();
+            if now.duration_since(state.last_prune) >= PRUNE_AFTER {
+                state
+                    .cache
+                    .retain(|_, cached| now.duration_since(cached.updated) < PRUNE_AFTER);
+                state.last_prune = now;
+            }
+
+            state.cache.get(&key).map_or(true, |cached| {
+                now.duration_since(cached.computed) >= settings.refresh_interval
+                    || cached.source.distance(source) > settings.refresh_distance
+                    || cached.listener.distance(listener) > settings.refresh_distance
+            })
+        };
+
+        // the provider usually raycasts, so it runs without the lock to not hold up other channels
+        let target = needs_refresh.then(|| self.provider.occlusion(source, listener));
+
+        let mut state = self.state.lock().unwrap();
+        let cached = state.cache.entry(key).or_insert_with(|| {
+            // nothing to fade from, so start at the real value.
+            // there is only no value if another thread pruned this entry in between locking, which is close enough to never
+            let value = target.unwrap_or_default();
+            CachedOcclusion {
+                source,
+                listener,
+                target: value,
+                current: value,
+                computed: now,
+                updated: now,
+            }
+        });
+        if let Some(target) = target {
+            cached.target = target;
+            cached.source = source;
+            cached.listener = listener;
+            cached.computed = now;
+        }
+
+        // exponential smoothing, so the result doesn't depend on how often this is called
+        let elapsed = now.duration_since(cached.updated);
+        let alpha = if settings.smoothing.is_zero() {
+            1.0
+        } else {
+            1.0 - (-elapsed.as_secs_f32() / settings.smoothing.as_secs_f32()).exp()
+        };
+        cached.current.0 += (cached.target.0 - cached.current.0) * alpha;
+        cached.current.1 += (cached.target.1 - cached.current.1) * alpha;
+        cached.updated = now;
+        cached.current
+    }
+}
@@ -0,0 +1,332 @@
+// Copyright (c) 2024 Lily Lyons
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use fmod_sys::*;
+use std::{
+    ffi::{c_float, c_int, c_uint},
+    time::{Duration, Instant},
+};
+
+use crate::{Channel, ChannelGroup, Mode, Sound, SoundBuilder, SoundFormat, System, TimeUnit};
+
+/// Records from a recording driver into a looping [`Sound`] that it owns, and reads the audio back as it is recorded.
+///
+/// FMOD records into a ring buffer and only reports where in that buffer it is recording.
+/// The recorder keeps track of how much has been recorded since it was last read, so [`Recorder::read_available`] returns every sample exactly once,
+/// and it notices when reads are so far apart that unread audio was overwritten.
+///
+/// The recorded audio can also be played back for monitoring with [`Recorder::start_monitoring`].
+#[derive(Debug)]
+pub struct Recorder {
+    system: System,
+    driver: c_int,
+    sound: Sound,
+    channels: c_int,
+    sample_rate: c_int,
+    // the length of the ring buffer in frames
+    length: c_uint,
+    record_position: c_uint,
+    read_position: c_uint,
+    unread: c_uint,
+    recorded: u64,
+    // drivers record in blocks, so the record position moves at least this much at a time
+    block_size: Option<c_uint>,
+    overflow_count: u64,
+    last_poll: Instant,
+    monitor: Option<Monitor>,
+}
+
+#[derive(Debug)]
+struct Monitor {
+    group: Option<ChannelGroup>,
+    channel: Option<Channel>,
+    latency: c_uint,
+    smoothed_latency: f64,
+}
+
+impl Recorder {
+    /// Starts recording from `driver` into a ring buffer `buffer_length` long.
+    ///
+    /// The buffer uses the driver's native sample rate and channel count, so FMOD doesn't need to resample,
+    /// and holds 32-bit float samples.
+    /// It should be longer than the time between reads, or audio will be lost (see [`Recorder::overflow_count`]).
+    ///
+    /// Will return [`FMOD_RESULT::FMOD_ERR_RECORD_DISCONNECTED`] if the driver is unplugged,
+    /// and [`FMOD_RESULT::FMOD_ERR_FORMAT`] if it reports having no channels.
+    pub fn new(system: System, driver: c_int, buffer_length: Duration) -> Result<Self> {
+        let (_, _, sample_rate, _, channels, _) = system.get_record_driver_info(driver)?;
+        // a driver that reports no channels has nothing to record, and every frame would be empty
+        if channels <= 0 {
+            return Err(Error::Fmod(FMOD_RESULT::FMOD_ERR_FORMAT));
+        }
+        let length = duration_to_frames(buffer_length, sample_rate).max(1);
+        let builder = SoundBuilder::open_user(
+            length * frame_size(channels),
+            channels,
+            sample_rate,
+            SoundFormat::PCMFloat,
+        )
+        .with_mode(Mode::LOOP_NORMAL);
+        let sound = system.create_sound(&builder)?;
+
+        if let Err(e) = system.record_start(driver, sound, true) {
+            let _ = sound.release();
+            return Err(e);
+        }
+
+        Ok(Recorder {
+            system,
+            driver,
+            sound,
+            channels,
+            sample_rate,
+            length,
+            record_position: 0,
+            read_position: 0,
+            unread: 0,
+            recorded: 0,
+            block_size: None,
+            overflow_count: 0,
+            last_poll: Instant::now(),
+            monitor: None,
+        })
+    }
+
+    /// The sound being recorded into.
+    pub fn sound(&self) -> Sound {
+        self.sound
+    }
+
+    pub fn driver(&self) -> c_int {
+        self.driver
+    }
+
+    /// The number of interleaved channels in each frame.
+    pub fn channels(&self) -> c_int {
+        self.channels
+    }
+
+    pub fn sample_rate(&self) -> c_int {
+        self.sample_rate
+    }
+
+    /// The length of the ring buffer, in frames.
+    pub fn buffer_length(&self) -> c_uint {
+        self.length
+    }
+
+    /// The number of frames that have been recorded but not read yet, as of the last poll.
+    pub fn available(&self) -> c_uint {
+        self.unread
+    }
+
+    /// How many times unread audio was overwritten because the recorder wasn't read often enough.
+    pub fn overflow_count(&self) -> u64 {
+        self.overflow_count
+    }
+
+    /// Reads as much recorded audio as is available and fits into `buffer`, as interleaved samples.
+    ///
+    /// Returns the number of samples written, which is always a whole number of frames.
+    /// Audio that didn't fit is kept for the next read.
+    ///
+    /// If the recording overflowed since the last read, the overwritten audio is skipped
+    /// and reading starts again from the most recently recorded audio.
+    pub fn read_available(&mut self, buffer: &mut [c_float]) -> Result<usize> {
+        self.poll()?;
+
+        let channels = self.channels as usize;
+        let frames = (buffer.len() / channels).min(self.unread as usize) as c_uint;
+        if frames == 0 {
+            return Ok(0);
+        }
+
+        let frame_size = frame_size(self.channels);
+        self.sound.lock(
+            self.read_position * frame_size,
+            frames * frame_size,
+            |first, second| {
+                // the second part is only non-empty if the read wraps around the end of the buffer
+                let bytes = first.chunks_exact(4).chain(second.chunks_exact(4));
+                for (sample, bytes) in buffer.iter_mut().zip(bytes) {
+                    *sample = c_float::from_ne_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+                }
+            },
+        )?;
+
+        self.read_position = (self.read_position + frames) % self.length;
+        self.unread -= frames;
+        Ok(frames as usize * channels)
+    }
+
+    /// Plays the recorded audio back on `group` (or the master channel group), `latency` behind the recording.
+    ///
+    /// Playback starts once enough has been recorded, in [`Recorder::update`], which must be called once per frame while monitoring.
+    /// The recording and output devices run on different clocks, so the playback rate is nudged up or down to stop them drifting apart.
+    /// This only affects the monitoring channel, [`Recorder::read_available`] always returns the audio at the rate it was recorded.
+    ///
+    /// The latency is raised to the driver's block size if that is larger, and is at most half the buffer.
+    pub fn start_monitoring(
+        &mut self,
+        group: Option<ChannelGroup>,
+        latency: Duration,
+    ) -> Result<()> {
+        self.stop_monitoring()?;
+        self.monitor = Some(Monitor {
+            group,
+            channel: None,
+            latency: duration_to_frames(latency, self.sample_rate).min(self.length / 2),
+            smoothed_latency: 0.0,
+        });
+        Ok(())
+    }
+
+    pub fn stop_monitoring(&mut self) -> Result<()> {
+        let Some(monitor) = self.monitor.take() else {
+            return Ok(());
+        };
+        match monitor.channel.map(|channel| channel.stop()) {
+            Some(Err(e)) if e != FMOD_RESULT::FMOD_ERR_INVALID_HANDLE => Err(e),
+            _ => Ok(()),
+        }
+    }
+
+    /// The channel the recorded audio is played back on, if monitoring has started.
+    pub fn monitor_channel(&self) -> Option<Channel> {
+        self.monitor.as_ref().and_then(|monitor| monitor.channel)
+    }
+
+    /// How far playback is behind the recording, averaged over the last few updates.
+    pub fn monitor_latency(&self) -> Option<Duration> {
+        let monitor = self.monitor.as_ref()?;
+        monitor.channel?;
+        Some(Duration::from_secs_f64(
+            monitor.smoothed_latency.max(0.0) / f64::from(self.sample_rate),
+        ))
+    }
+
+    /// Polls the record position and keeps monitoring in sync with the recording.
+    ///
+    /// This only needs to be called while monitoring, as [`Recorder::read_available`] polls by itself.
+    pub fn update(&mut self) -> Result<()> {
+        self.poll()?;
+        self.update_monitor()
+    }
+
+    /// Stops recording and monitoring, and releases the sound.
+    pub fn release(mut self) -> Result<()> {
+        self.stop_monitoring()?;
+        self.system.record_stop(self.driver)?;
+        self.sound.release()
+    }
+
+    fn poll(&mut self) -> Result<()> {
+        let position = self.system.get_record_position(self.driver)?;
+        let now = Instant::now();
+
+        // the record position alone can't tell if it went all the way around the buffer since the last poll,
+        // so compare against how much should have been recorded in that time
+        let moved = wrapping_distance(self.record_position, position, self.length);
+        let expected =
+            now.duration_since(self.last_poll).as_secs_f64() * f64::from(self.sample_rate);
+        let laps = ((expected - f64::from(moved)) / f64::from(self.length))
+            .round()
+            .max(0.0) as u64;
+        let recorded = u64::from(moved) + laps * u64::from(self.length);
+
+        if laps == 0 && moved > 0 {
+            self.block_size = Some(self.block_size.map_or(moved, |size| size.min(moved)));
+        }
+
+        if u64::from(self.unread) + recorded >= u64::from(self.length) {
+            // the record position caught up with the read position, so the unread audio was overwritten
+            self.overflow_count += 1;
+            self.read_position = position;
+            self.unread = 0;
+        } else {
+            self.unread += recorded as c_uint;
+        }
+
+        self.recorded += recorded;
+        self.record_position = position;
+        self.last_poll = now;
+        Ok(())
+    }
+
+    fn update_monitor(&mut self) -> Result<()> {
+        let Some(monitor) = &mut self.monitor else {
+            return Ok(());
+        };
+        let latency = self
+            .block_size
+            .map_or(monitor.latency, |size| monitor.latency.max(size))
+            .min(self.length / 2);
+
+        let Some(channel) = monitor.channel else {
+            if self.recorded < u64::from(latency) {
+                return Ok(());
+            }
+            let channel = self.system.play_sound(self.sound, monitor.group, true)?;
+            let start = (self.record_position + self.length - latency) % self.length;
+            channel.set_position(start, TimeUnit::PCM)?;
+            channel.set_paused(false)?;
+            monitor.channel = Some(channel);
+            monitor.smoothed_latency = f64::from(latency);
+            return Ok(());
+        };
+
+        let play_position = match channel.get_position(TimeUnit::PCM) {
+            Ok(position) => position,
+            // the channel was stolen, so start again next update
+            Err(e) if e == FMOD_RESULT::FMOD_ERR_INVALID_HANDLE => {
+                monitor.channel = None;
+                return Ok(());
+            }
+            Err(e) => return Err(e),
+        };
+
+        // playback can get ahead of the recording, which looks like being almost a whole buffer behind
+        let mut current = f64::from(wrapping_distance(
+            play_position,
+            self.record_position,
+            self.length,
+        ));
+        if current > f64::from(self.length / 2) {
+            current -= f64::from(self.length);
+        }
+        monitor.smoothed_latency = monitor.smoothed_latency * 0.97 + current * 0.03;
+
+        // the same thresholds FMOD's record example uses, 1ms of drift and a 2% rate change
+        let threshold = f64::from(self.sample_rate) / 1000.0;
+        let sample_rate = self.sample_rate as c_float;
+        let frequency = if monitor.smoothed_latency < f64::from(latency) - threshold {
+            sample_rate - sample_rate / 50.0
+        } else if monitor.smoothed_latency > f64::from(latency) + threshold {
+            sample_rate + sample_rate / 50.0
+        } else {
+            sample_rate
+        };
+        channel.set_frequency(frequency)
+    }
+}
+
+// the size of one frame of 32-bit float samples, in bytes
+fn frame_size(channels: c_int) -> c_uint {
+    channels as c_uint * std::mem::size_of::<c_float>() as c_uint
+}
+
+fn duration_to_frames(duration: Duration, sample_rate: c_int) -> c_uint {
+    (duration.as_secs_f64() * f64::from(sample_rate)).round() as c_uint
+}
+
+// how far `to` is ahead of `from` in a ring buffer of `length`
+fn wrapping_distance(from: c_uint, to: c_uint, length: c_uint) -> c_uint {
+    if to >= from {
+        to - from
+    } else {
+        length - from + to
+    }
+}
@@ -0,0 +1,539 @@
+// Copyright (c) 2024 Lily Lyons
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use fmod_sys::*;
+use std::{
+    ffi::{c_float, c_uint, c_ulonglong},
+    time::Duration,
+};
+
+use crate::{Channel, ChannelGroup, Mode, Sound, System, TimeUnit};
+
+/// A piece of music that can be scheduled by a [`Sequencer`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Clip {
+    pub sound: Sound,
+    /// The tempo of this clip, in beats per minute.
+    ///
+    /// Must be finite and greater than zero.
+    pub tempo: f64,
+    /// Must be greater than zero.
+    pub beats_per_bar: u32,
+    /// Where the first beat of the first bar is in the sound, in PCM samples.
+    ///
+    /// Anything before this is treated as a pickup, and is played before the bar the clip is scheduled on if there is time to.
+    pub offset: c_uint,
+    /// If set, the clip loops forever between these points (in PCM samples) until another clip is queued.
+    ///
+    /// The beat grid is not reset when the clip loops, so the loop should be a whole number of bars long.
+    /// The sound should be created with [`Mode::LOOP_NORMAL`] so FMOD can prepare it for seamless looping.
+    pub loop_points: Option<(c_uint, c_uint)>,
+}
+
+impl Clip {
+    pub fn new(sound: Sound, tempo: f64, beats_per_bar: u32) -> Self {
+        Clip {
+            sound,
+            tempo,
+            beats_per_bar,
+            offset: 0,
+            loop_points: None,
+        }
+    }
+
+    // a tempo or bar length of zero would put every beat in the same place, and break the grid math
+    fn validate(self) -> Result<()> {
+        if !self.tempo.is_finite() || self.tempo <= 0.0 || self.beats_per_bar == 0 {
+            return Err(Error::Fmod(FMOD_RESULT::FMOD_ERR_INVALID_PARAM));
+        }
+        Ok(())
+    }
+}
+
+/// Where on the beat grid of the current clip something should happen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Quantize {
+    /// As soon as possible, ignoring the beat grid.
+    Immediate,
+    /// On the next beat.
+    Beat,
+    /// On the first beat of the next bar.
+    Bar,
+}
+
+/// How a [`Sequencer`] moves from the current clip to a queued one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Transition {
+    pub quantize: Quantize,
+    /// How long the current and queued clip should overlap for.
+    ///
+    /// The queued clip fades in while the current one fades out, starting at the quantized point.
+    /// If zero, the current clip is cut off exactly when the queued one starts.
+    pub crossfade: Duration,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Track {
+    clip: Clip,
+    channel: Channel,
+    // the parent clock the first beat of the clip plays at, beats are counted from here
+    origin: c_ulonglong,
+    // how many samples the clip fades in over, starting at `origin`
+    fade_in: c_ulonglong,
+}
+
+impl Track {
+    // the volume the fade in has reached at `clock`
+    fn volume_at(&self, clock: c_ulonglong) -> c_float {
+        if clock >= self.origin + self.fade_in {
+            return 1.0;
+        }
+        clock.saturating_sub(self.origin) as c_float / self.fade_in as c_float
+    }
+}
+
+/// Schedules music [`Clip`]s on a [`ChannelGroup`] at exact DSP clock times.
+///
+/// Everything is scheduled ahead of time with [`ChannelControl::set_delay`](crate::ChannelControl::set_delay) and [`ChannelControl::add_fade_point`](crate::ChannelControl::add_fade_point),
+/// so transitions are sample accurate no matter how often [`Sequencer::update`] is called.
+///
+/// Tempo is measured against the mixer's sample rate, so changing the pitch of the clips (but not the group) will put them off the beat grid.
+#[derive(Debug)]
+pub struct Sequencer {
+    system: System,
+    group: ChannelGroup,
+    sample_rate: f64,
+    schedule_ahead: c_ulonglong,
+    current: Option<Track>,
+    queued: Option<Track>,
+}
+
+impl Sequencer {
+    /// Creates a new sequencer that plays clips on `group`.
+    ///
+    /// Clips are scheduled at least the size of the DSP buffer ahead (see [`System::get_dsp_buffer_size`]), as anything scheduled sooner may start late.
+    pub fn new(system: System, group: ChannelGroup) -> Result<Self> {
+        let (sample_rate, _, _) = system.get_software_format()?;
+        let (buffer_length, buffer_count) = system.get_dsp_buffer_size()?;
+        Ok(Sequencer {
+            system,
+            group,
+            sample_rate: f64::from(sample_rate),
+            schedule_ahead: c_ulonglong::from(buffer_length) * buffer_count as c_ulonglong,
+            current: None,
+            queued: None,
+        })
+    }
+
+    /// Sets how far ahead of the current DSP clock things are scheduled, in samples.
+    pub fn set_schedule_ahead(&mut self, samples: c_ulonglong) {
+        self.schedule_ahead = samples;
+    }
+
+    pub fn get_schedule_ahead(&self) -> c_ulonglong {
+        self.schedule_ahead
+    }
+
+    pub fn channel_group(&self) -> ChannelGroup {
+        self.group
+    }
+
+    /// The clip that is currently playing.
+    pub fn current_clip(&self) -> Option<Clip> {
+        self.current.map(|track| track.clip)
+    }
+
+    /// The clip that will start playing at the next transition.
+    pub fn queued_clip(&self) -> Option<Clip> {
+        self.queued.map(|track| track.clip)
+    }
+
+    /// Stops everything and starts playing `clip` as soon as possible.
+    ///
+    /// Returns [`FMOD_RESULT::FMOD_ERR_INVALID_PARAM`] if the tempo or bar length of `clip` is invalid.
+    pub fn play(&mut self, clip: Clip) -> Result<()> {
+        clip.validate()?;
+        self.stop_all();
+        let earliest = self.now()? + self.schedule_ahead;
+        let track = self.start_track(clip, earliest, earliest, 0)?;
+        self.current = Some(track);
+        Ok(())
+    }
+
+    /// Queues `clip` to start at the next point on the current clip's beat grid, as described by `transition`.
+    ///
+    /// If a clip was already queued, it is replaced.
+    /// If nothing is playing, this is the same as [`Sequencer::play`].
+    ///
+    /// Returns [`FMOD_RESULT::FMOD_ERR_INVALID_PARAM`] if the tempo or bar length of `clip` is invalid.
+    pub fn queue(&mut self, clip: Clip, transition: Transition) -> Result<()> {
+        clip.validate()?;
+        self.cancel_queued()?;
+        let Some(current) = self.playing_track() else {
+            return self.play(clip);
+        };
+
+        let earliest = self.now()? + self.schedule_ahead;
+        let boundary = next_boundary(
+            current.clip,
+            current.origin,
+            transition.quantize,
+            earliest,
+            self.sample_rate,
+        );
+        let crossfade = duration_to_samples(transition.crossfade, self.sample_rate);
+
+        // the current clip might still be fading in, in which case the fade out starts from wherever that has got to
+        let volume = current.volume_at(boundary);
+        current
+            .channel
+            .remove_fade_points(boundary, c_ulonglong::MAX)?;
+        if crossfade > 0 {
+            current.channel.add_fade_point(boundary, volume)?;
+            current.channel.add_fade_point(boundary + crossfade, 0.0)?;
+        }
+        let (start, _, _) = current.channel.get_delay()?;
+        current
+            .channel
+            .set_delay(start, boundary + crossfade, true)?;
+
+        self.queued = Some(self.start_track(clip, boundary, earliest, crossfade)?);
+        Ok(())
+    }
+
+    /// Cancels the queued clip, if any, and lets the current clip keep playing.
+    pub fn cancel_queued(&mut self) -> Result<()> {
+        let Some(queued) = self.queued.take() else {
+            return Ok(());
+        };
+        // the channel may have already been stolen, in which case there is nothing to stop
+        let _ = queued.channel.stop();
+        let Some(current) = self.playing_track() else {
+            return Ok(());
+        };
+
+        // only undo the fade out queue scheduled, which starts at the queued clip's origin
+        current
+            .channel
+            .remove_fade_points(queued.origin, c_ulonglong::MAX)?;
+        let fade_in_end = current.origin + current.fade_in;
+        if current.fade_in > 0 && fade_in_end >= queued.origin {
+            current.channel.add_fade_point(fade_in_end, 1.0)?;
+        }
+        let (start, _, _) = current.channel.get_delay()?;
+        current.channel.set_delay(start, 0, true)
+    }
+
+    /// Plays `sound` once on the next point on the current clip's beat grid, over the top of the current clip.
+    ///
+    /// If nothing is playing the stinger is played as soon as possible.
+    pub fn stinger(&mut self, sound: Sound, quantize: Quantize) -> Result<Channel> {
+        let earliest = self.now()? + self.schedule_ahead;
+        let start = match self.playing_track() {
+            Some(current) => next_boundary(
+                current.clip,
+                current.origin,
+                quantize,
+                earliest,
+                self.sample_rate,
+            ),
+            None => earliest,
+        };
+
+        let channel = self.system.play_sound(sound, Some(self.group), true)?;
+        channel.set_delay(start, 0, true)?;
+        channel.set_paused(false)?;
+        Ok(channel)
+    }
+
+    /// Fades out and stops the current clip, cancelling any queued clip.
+    pub fn stop(&mut self, fade_out: Duration) -> Result<()> {
+        self.cancel_queued()?;
+        let Some(current) = self.playing_track() else {
+            return Ok(());
+        };
+        self.current = None;
+
+        let start = self.now()? + self.schedule_ahead;
+        let end = start + duration_to_samples(fade_out, self.sample_rate);
+        current
+            .channel
+            .remove_fade_points(start, c_ulonglong::MAX)?;
+        current
+            .channel
+            .add_fade_point(start, current.volume_at(start))?;
+        current.channel.add_fade_point(end, 0.0)?;
+        let (delay_start, _, _) = current.channel.get_delay()?;
+        current.channel.set_delay(delay_start, end, true)
+    }
+
+    /// Promotes the queued clip to the current clip once it has started.
+    ///
+    /// This only updates what [`Sequencer::current_clip`] and the beat grid refer to, the audio itself is already scheduled.
+    pub fn update(&mut self) -> Result<()> {
+        if let Some(queued) = self.queued {
+            if self.now()? >= queued.origin {
+                self.current = self.queued.take();
+            }
+        }
+        Ok(())
+    }
+
+    /// The position of the current clip on its beat grid, in beats since its first beat.
+    ///
+    /// This is negative while a pickup is playing.
+    pub fn beat_position(&self) -> Result<Option<f64>> {
+        let Some(current) = self.current else {
+            return Ok(None);
+        };
+        let now = self.now()? as f64;
+        Ok(Some(
+            (now - current.origin as f64) / beat_length(current.clip, self.sample_rate),
+        ))
+    }
+
+    fn now(&self) -> Result<c_ulonglong> {
+        // channels schedule against their parent's clock, and their parent is our group
+        Ok(self.group.get_dsp_clock()?.0)
+    }
+
+    fn stop_all(&mut self) {
+        for track in [self.current.take(), self.queued.take()]
+            .into_iter()
+            .flatten()
+        {
+            let _ = track.channel.stop();
+        }
+    }
+
+    // the current track, unless it has finished playing (or its channel was stolen)
+    fn playing_track(&mut self) -> Option<Track> {
+        let current = self.current?;
+        if current.channel.is_playing().unwrap_or(false) {
+            Some(current)
+        } else {
+            self.current = None;
+            None
+        }
+    }
+
+    // starts a clip so its first beat lands on `origin`, fading in over `fade_in` samples
+    fn start_track(
+        &self,
+        clip: Clip,
+        origin: c_ulonglong,
+        earliest: c_ulonglong,
+        fade_in: c_ulonglong,
+    ) -> Result<Track> {
+        let channel = self.system.play_sound(clip.sound, Some(self.group), true)?;
+
+        if let Some((loop_start, loop_end)) = clip.loop_points {
+            let mode = channel.get_mode()? - Mode::LOOP_OFF - Mode::LOOP_BIDI;
+            channel.set_mode(mode | Mode::LOOP_NORMAL)?;
+            channel.set_loop_count(-1)?;
+            channel.set_loop_points(loop_start, TimeUnit::PCM, loop_end, TimeUnit::PCM)?;
+        }
+
+        // play the pickup if there's time, otherwise skip it and start right on the first beat
+        let pickup = self.pickup_length(clip)?;
+        let start = if let Some(start) = pickup_start(origin, earliest, pickup) {
+            start
+        } else {
+            channel.set_position(clip.offset, TimeUnit::PCM)?;
+            origin
+        };
+        channel.set_delay(start, 0, true)?;
+
+        if fade_in > 0 {
+            channel.add_fade_point(origin, 0.0)?;
+            channel.add_fade_point(origin + fade_in, 1.0)?;
+        }
+        channel.set_paused(false)?;
+
+        Ok(Track {
+            clip,
+            channel,
+            origin,
+            fade_in,
+        })
+    }
+
+    // the offset of a clip converted from the sound's sample rate to the mixer's
+    fn pickup_length(&self, clip: Clip) -> Result<c_ulonglong> {
+        if clip.offset == 0 {
+            return Ok(0);
+        }
+        let (frequency, _) = clip.sound.get_defaults()?;
+        if !frequency.is_finite() || frequency <= 0.0 {
+            return Err(Error::Fmod(FMOD_RESULT::FMOD_ERR_INVALID_PARAM));
+        }
+        Ok(
+            (f64::from(clip.offset) * self.sample_rate / f64::from(frequency)).round()
+                as c_ulonglong,
+        )
+    }
+}
+
+// the first point on the beat grid starting at `origin` that is at or after `earliest`
+fn next_boundary(
+    clip: Clip,
+    origin: c_ulonglong,
+    quantize: Quantize,
+    earliest: c_ulonglong,
+    sample_rate: f64,
+) -> c_ulonglong {
+    let unit = match quantize {
+        Quantize::Immediate => return earliest,
+        Quantize::Beat => beat_length(clip, sample_rate),
+        Quantize::Bar => beat_length(clip, sample_rate) * f64::from(clip.beats_per_bar),
+    };
+    if earliest <= origin {
+        return origin;
+    }
+
+    let units = ((earliest - origin) as f64 / unit).ceil();
+    origin + (units * unit).round() as c_ulonglong
+}
+
+// where a clip has to start for its pickup to end on `origin`, or None if that's before `earliest`
+fn pickup_start(
+    origin: c_ulonglong,
+    earliest: c_ulonglong,
+    pickup: c_ulonglong,
+) -> Option<c_ulonglong> {
+    origin
+        .checked_sub(pickup)
+        .filter(|&start| start >= earliest)
+}
+
+fn beat_length(clip: Clip, sample_rate: f64) -> f64 {
+    60.0 / clip.tempo * sample_rate
+}
+
+fn duration_to_samples(duration: Duration, sample_rate: f64) -> c_ulonglong {
+    (duration.as_secs_f64() * sample_rate).round() as c_ulonglong
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)] // the fade volumes are all exact fractions
+mod tests {
+    use super::*;
+
+    const SAMPLE_RATE: f64 = 48000.0;
+
+    // 120 bpm in 4/4 at 48khz is 24000 samples a beat, and 96000 a bar
+    fn clip() -> Clip {
+        Clip::new(Sound::from(std::ptr::null_mut()), 120.0, 4)
+    }
+
+    #[test]
+    fn validates_clips() {
+        assert!(clip().validate().is_ok());
+        for tempo in [0.0, -120.0, f64::NAN, f64::INFINITY] {
+            assert!(Clip { tempo, ..clip() }.validate().is_err());
+        }
+        assert!(Clip {
+            beats_per_bar: 0,
+            ..clip()
+        }
+        .validate()
+        .is_err());
+    }
+
+    #[test]
+    fn beat_boundaries() {
+        let boundary =
+            |earliest| next_boundary(clip(), 1000, Quantize::Beat, earliest, SAMPLE_RATE);
+        assert_eq!(boundary(1001), 25000);
+        assert_eq!(boundary(25000), 25000);
+        assert_eq!(boundary(25001), 49000);
+    }
+
+    #[test]
+    fn bar_boundaries() {
+        let boundary = |earliest| next_boundary(clip(), 1000, Quantize::Bar, earliest, SAMPLE_RATE);
+        assert_eq!(boundary(1001), 97000);
+        assert_eq!(boundary(97000), 97000);
+        assert_eq!(boundary(97001), 193_000);
+
+        let waltz = Clip {
+            beats_per_bar: 3,
+            ..clip()
+        };
+        assert_eq!(
+            next_boundary(waltz, 0, Quantize::Bar, 1, SAMPLE_RATE),
+            72000
+        );
+    }
+
+    #[test]
+    fn boundary_already_passed() {
+        // the clip hasn't reached its first beat yet, so that's the next boundary whatever the quantization
+        for quantize in [Quantize::Beat, Quantize::Bar] {
+            assert_eq!(
+                next_boundary(clip(), 50000, quantize, 20000, SAMPLE_RATE),
+                50000
+            );
+        }
+        assert_eq!(
+            next_boundary(clip(), 50000, Quantize::Immediate, 20000, SAMPLE_RATE),
+            20000
+        );
+    }
+
+    #[test]
+    fn fractional_beats() {
+        // the grid follows the mixer's sample rate, and 7 bpm doesn't divide evenly into samples at all
+        assert_eq!(next_boundary(clip(), 0, Quantize::Beat, 1, 44100.0), 22050);
+        let slow = Clip {
+            tempo: 7.0,
+            ..clip()
+        };
+        assert_eq!(
+            next_boundary(slow, 0, Quantize::Beat, 411_429, SAMPLE_RATE),
+            822_857
+        );
+    }
+
+    #[test]
+    fn pickups() {
+        // enough time to play the whole pickup
+        assert_eq!(pickup_start(10000, 4000, 6000), Some(4000));
+        assert_eq!(pickup_start(10000, 1000, 6000), Some(4000));
+        // not enough time, so the pickup is skipped
+        assert_eq!(pickup_start(10000, 4001, 6000), None);
+        // a pickup longer than the clock has been running
+        assert_eq!(pickup_start(1000, 0, 6000), None);
+        assert_eq!(pickup_start(10000, 10000, 0), Some(10000));
+    }
+
+    #[test]
+    fn crossfades() {
+        assert_eq!(
+            duration_to_samples(Duration::from_millis(500), SAMPLE_RATE),
+            24000
+        );
+        assert_eq!(duration_to_samples(Duration::ZERO, SAMPLE_RATE), 0);
+
+        let track = Track {
+            clip: clip(),
+            channel: Channel::from(std::ptr::null_mut()),
+            origin: 1000,
+            fade_in: 2000,
+        };
+        assert_eq!(track.volume_at(0), 0.0);
+        assert_eq!(track.volume_at(2000), 0.5);
+        assert_eq!(track.volume_at(3000), 1.0);
+        assert_eq!(
+            Track {
+                fade_in: 0,
+                ..track
+            }
+            .volume_at(1000),
+            1.0
+        );
+    }
+}
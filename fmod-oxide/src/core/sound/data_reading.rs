@@ -4,7 +4,7 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
-use std::ffi::c_uint;
+use std::ffi::{c_uint, c_void};
 
 use fmod_sys::*;
 
@@ -44,5 +44,46 @@ impl Sound {
         Ok((open_state, percent_buffered, starving, disk_busy))
     }
 
-    // TODO read, seek, lock, unlock
+    /// Gives access to `length` bytes of the sample data starting at byte `offset`, for reading or writing.
+    ///
+    /// The data is a ring buffer for looping sounds, so it is passed to `f` in two parts.
+    /// The second part is only non-empty if the range wraps around the end of the sound.
+    /// The sound is unlocked again once `f` returns.
+    ///
+    /// The sound must have been created with [`crate::Mode::CREATE_SAMPLE`] or be a user created sound, streams can't be locked.
+    pub fn lock<R>(
+        &self,
+        offset: c_uint,
+        length: c_uint,
+        f: impl FnOnce(&mut [u8], &mut [u8]) -> R,
+    ) -> Result<R> {
+        let mut ptr_1: *mut c_void = std::ptr::null_mut();
+        let mut ptr_2: *mut c_void = std::ptr::null_mut();
+        let mut len_1 = 0;
+        let mut len_2 = 0;
+        unsafe {
+            FMOD_Sound_Lock(
+                self.inner, offset, length, &mut ptr_1, &mut ptr_2, &mut len_1, &mut len_2,
+            )
+            .to_result()?;
+
+            // fmod hands out a null pointer for a part that is empty, which a slice can't be made from
+            let first = if ptr_1.is_null() {
+                &mut []
+            } else {
+                std::slice::from_raw_parts_mut(ptr_1.cast(), len_1 as usize)
+            };
+            let second = if ptr_2.is_null() {
+                &mut []
+            } else {
+                std::slice::from_raw_parts_mut(ptr_2.cast(), len_2 as usize)
+            };
+            let result = f(first, second);
+
+            FMOD_Sound_Unlock(self.inner, ptr_1, ptr_2, len_1, len_2).to_result()?;
+            Ok(result)
+        }
+    }
+
+    // TODO read, seek
 }
@@ -10,9 +10,11 @@ use std::{
 };
 
 use fmod_sys::*;
-use lanyard::{Utf8CStr, Utf8CString};
+use lanyard::{Utf8CStr, Utf8CString, Utf8CStringBuilder};
 
-use crate::{get_string, Sound, SoundFormat, SoundType, Tag, TimeUnit};
+use crate::{
+    get_string, get_string_in, get_string_into, Sound, SoundFormat, SoundType, Tag, TimeUnit,
+};
 
 impl Sound {
     /// Retrieves the name of a sound.
@@ -24,6 +26,22 @@ impl Sound {
         })
     }
 
+    /// Like [`Sound::get_name`], but writes the name into `name`, reusing its allocation.
+    pub fn get_name_into(&self, name: &mut Utf8CStringBuilder) -> Result<()> {
+        get_string_into(name, |name| unsafe {
+            FMOD_Sound_GetName(self.inner, name.as_mut_ptr().cast(), name.len() as c_int)
+        })
+    }
+
+    /// Like [`Sound::get_name`], but writes the name into `buffer` without allocating.
+    ///
+    /// Returns [`FMOD_RESULT::FMOD_ERR_TRUNCATED`] if the name (including the null terminator) doesn't fit.
+    pub fn get_name_in<'b>(&self, buffer: &'b mut [u8]) -> Result<&'b Utf8CStr> {
+        get_string_in(buffer, |name| unsafe {
+            FMOD_Sound_GetName(self.inner, name.as_mut_ptr().cast(), name.len() as c_int)
+        })
+    }
+
     /// Returns format information about the sound.
     pub fn get_format(&self) -> Result<(SoundType, SoundFormat, c_int, c_int)> {
         let mut kind = 0;
@@ -17,7 +17,7 @@ use crate::{
     SoundFormat, SoundGroup, SoundType, TagType, TimeUnit,
 };
 
-#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Default)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 // force this type to have the exact same layout as FMOD_STUDIO_PARAMETER_ID so we can safely transmute between them.
 #[repr(C)]
 pub struct Guid {
@@ -78,6 +78,61 @@ impl std::fmt::Display for Guid {
     }
 }
 
+// guids are serialized in the same format as Display, so they can be used as keys in formats like JSON
+#[cfg(feature = "serde")]
+impl serde::Serialize for Guid {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Guid {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        struct GuidVisitor;
+
+        impl serde::de::Visitor<'_> for GuidVisitor {
+            type Value = Guid;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("a guid like {xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx}")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, string: &str) -> std::result::Result<Guid, E> {
+                // unlike Guid::parse this can't use FMOD, as FMOD may not be initialized yet
+                let invalid = || E::invalid_value(serde::de::Unexpected::Str(string), &self);
+                let trimmed = string
+                    .strip_prefix('{')
+                    .and_then(|s| s.strip_suffix('}'))
+                    .unwrap_or(string);
+                let groups: Vec<&str> = trimmed.split('-').collect();
+                let lengths = groups.iter().map(|g| g.len()).collect::<Vec<_>>();
+                if lengths != [8, 4, 4, 4, 12]
+                    || !trimmed.chars().all(|c| c == '-' || c.is_ascii_hexdigit())
+                {
+                    return Err(invalid());
+                }
+
+                let data_4 = u64::from_str_radix(&format!("{}{}", groups[3], groups[4]), 16)
+                    .map_err(|_| invalid())?;
+                Ok(Guid {
+                    data_1: c_uint::from_str_radix(groups[0], 16).map_err(|_| invalid())?,
+                    data_2: c_ushort::from_str_radix(groups[1], 16).map_err(|_| invalid())?,
+                    data_3: c_ushort::from_str_radix(groups[2], 16).map_err(|_| invalid())?,
+                    data_4: data_4.to_be_bytes(),
+                })
+            }
+        }
+
+        deserializer.deserialize_str(GuidVisitor)
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Default)]
 #[repr(C)]
 pub struct Vector {
@@ -138,6 +193,7 @@ impl From<Attributes3D> for FMOD_3D_ATTRIBUTES {
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CpuUsage {
     pub dsp: c_float,
     pub stream: c_float,
@@ -469,7 +525,28 @@ impl<'a> SoundBuilder<'a> {
         }
     }
 
-    // TODO open_user
+    /// Creates an empty sound of `length` bytes, which is not backed by a file or memory.
+    ///
+    /// This is what [`System::record_start`](crate::System::record_start) records into.
+    pub const fn open_user(
+        length: c_uint,
+        channel_count: c_int,
+        default_frequency: c_int,
+        format: SoundFormat,
+    ) -> Self {
+        Self {
+            mode: FMOD_OPENUSER,
+            create_sound_ex_info: FMOD_CREATESOUNDEXINFO {
+                length,
+                numchannels: channel_count,
+                defaultfrequency: default_frequency,
+                format: format as _,
+                ..EMPTY_EXINFO
+            },
+            name_or_data: std::ptr::null(),
+            _phantom: PhantomData,
+        }
+    }
 
     /// # Safety
     ///
@@ -832,3 +909,60 @@ impl<'a> SoundBuilder<'a> {
         }
     }
 }
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+
+    const GUID: Guid = Guid {
+        data_1: 0x0123_abcd,
+        data_2: 0x4567,
+        data_3: 0x89ef,
+        data_4: [0xfe, 0xdc, 0xba, 0x98, 0x76, 0x54, 0x32, 0x10],
+    };
+    const STRING: &str = "\"{0123abcd-4567-89ef-fedc-ba9876543210}\"";
+
+    #[test]
+    fn guid_round_trip() {
+        assert_eq!(serde_json::to_string(&GUID).unwrap(), STRING);
+        assert_eq!(serde_json::from_str::<Guid>(STRING).unwrap(), GUID);
+        assert_eq!(
+            serde_json::from_str::<Guid>("\"0123ABCD-4567-89EF-FEDC-BA9876543210\"").unwrap(),
+            GUID
+        );
+
+        let map = std::collections::BTreeMap::from([(GUID, 1), (Guid::default(), 2)]);
+        let json = serde_json::to_string(&map).unwrap();
+        assert_eq!(
+            serde_json::from_str::<std::collections::BTreeMap<Guid, i32>>(&json).unwrap(),
+            map
+        );
+    }
+
+    #[test]
+    fn guid_rejects_malformed() {
+        for string in [
+            "\"\"",
+            "\"{}\"",
+            // unbalanced or doubled braces
+            "\"{0123abcd-4567-89ef-fedc-ba9876543210\"",
+            "\"{{0123abcd-4567-89ef-fedc-ba9876543210}}\"",
+            // wrong group lengths
+            "\"{0123abc-4567-89ef-fedc-ba9876543210}\"",
+            "\"{0123abcd-4567-89ef-fedcba9876543210}\"",
+            "\"{0123abcd-4567-89ef-fedc-ba98-76543210}\"",
+            // not hex, including the signs from_str_radix would accept
+            "\"{0123abcg-4567-89ef-fedc-ba9876543210}\"",
+            "\"{+123abcd-4567-89ef-fedc-ba9876543210}\"",
+            "\"{0123abcd-+567-89ef-fedc-ba9876543210}\"",
+            // not a string at all
+            "1234",
+            "null",
+        ] {
+            assert!(
+                serde_json::from_str::<Guid>(string).is_err(),
+                "{string} was accepted"
+            );
+        }
+    }
+}
@@ -46,6 +46,7 @@ impl System {
         unsafe {
             FMOD_System_Release(self.inner).to_result()?;
         }
+        self.remove_occlusion_provider();
 
         #[cfg(feature = "userdata-abstraction")]
         crate::userdata::cleanup_userdata();
@@ -5,10 +5,10 @@
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
 use fmod_sys::*;
-use lanyard::{Utf8CStr, Utf8CString};
+use lanyard::{Utf8CStr, Utf8CString, Utf8CStringBuilder};
 use std::ffi::{c_int, c_uint};
 
-use crate::{get_string, Dsp, PluginType, System};
+use crate::{get_string_in, get_string_into, Dsp, PluginType, System};
 
 impl System {
     /// Specify a base search path for plugins so they can be placed somewhere else than the directory of the main executable.
@@ -98,10 +98,47 @@ impl System {
 
     /// Retrieves information for the selected plugin.
     pub fn get_plugin_info(&self, handle: c_uint) -> Result<(PluginType, Utf8CString, c_uint)> {
+        let mut name = Utf8CStringBuilder::with_capacity(255);
+        let (plugin_type, version) = self.get_plugin_info_into(handle, &mut name)?;
+        Ok((plugin_type, name.into_cstring(), version))
+    }
+
+    /// Like [`System::get_plugin_info`], but writes the name into `name`, reusing its allocation.
+    pub fn get_plugin_info_into(
+        &self,
+        handle: c_uint,
+        name: &mut Utf8CStringBuilder,
+    ) -> Result<(PluginType, c_uint)> {
+        let mut plugin_type = 0;
+        let mut version = 0;
+
+        get_string_into(name, |name| unsafe {
+            FMOD_System_GetPluginInfo(
+                self.inner,
+                handle,
+                &mut plugin_type,
+                name.as_mut_ptr().cast(),
+                name.len() as c_int,
+                &mut version,
+            )
+        })?;
+
+        let plugin_type = plugin_type.try_into()?;
+        Ok((plugin_type, version))
+    }
+
+    /// Like [`System::get_plugin_info`], but writes the name into `buffer` without allocating.
+    ///
+    /// Returns [`FMOD_RESULT::FMOD_ERR_TRUNCATED`] if the name (including the null terminator) doesn't fit.
+    pub fn get_plugin_info_in<'b>(
+        &self,
+        handle: c_uint,
+        buffer: &'b mut [u8],
+    ) -> Result<(PluginType, &'b Utf8CStr, c_uint)> {
         let mut plugin_type = 0;
         let mut version = 0;
 
-        let name = get_string(|name| unsafe {
+        let name = get_string_in(buffer, |name| unsafe {
             FMOD_System_GetPluginInfo(
                 self.inner,
                 handle,
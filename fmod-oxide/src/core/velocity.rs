@@ -0,0 +1,381 @@
+// Copyright (c) 2024 Lily Lyons
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use fmod_sys::*;
+use std::{
+    ffi::{c_float, c_int},
+    time::Duration,
+};
+
+use crate::{studio, Attributes3D, ChannelControl, System, Vector};
+
+/// How [`VelocityTracker`] turns positions into velocities.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VelocitySettings {
+    /// Roughly how long it takes for the velocity to follow a change in speed.
+    ///
+    /// This evens out frames of different lengths and physics steps that don't line up with frames, which would otherwise make the doppler pitch wobble.
+    /// Zero disables smoothing.
+    pub smoothing: Duration,
+    /// The speed in meters per second above which a movement is treated as a teleport.
+    ///
+    /// This is scaled by the distance factor of the [`System`] (see [`System::get_3d_settings`]) to get the speed in game units.
+    pub teleport_speed: c_float,
+}
+
+impl Default for VelocitySettings {
+    fn default() -> Self {
+        VelocitySettings {
+            smoothing: Duration::from_millis(50),
+            teleport_speed: 100.0,
+        }
+    }
+}
+
+/// Works out the velocity of something from its position each frame, for doppler.
+///
+/// A movement faster than [`VelocitySettings::teleport_speed`] is treated as a teleport,
+/// which resets the velocity to zero instead of causing a huge doppler shift for one frame.
+/// Teleports the game knows about can be reported with [`VelocityTracker::teleport`].
+#[derive(Debug, Clone)]
+pub struct VelocityTracker {
+    settings: VelocitySettings,
+    // game units per meter
+    distance_factor: c_float,
+    last: Option<(Vector, Duration)>,
+    velocity: Vector,
+}
+
+impl VelocityTracker {
+    /// Creates a tracker, using the distance factor of `system`.
+    ///
+    /// The distance factor is only read here, so create a new tracker if [`System::set_3d_settings`] is called afterwards.
+    pub fn new(system: System, settings: VelocitySettings) -> Result<Self> {
+        let (_, distance_factor, _) = system.get_3d_settings()?;
+        Ok(Self::with_distance_factor(distance_factor, settings))
+    }
+
+    /// Creates a tracker for a world with `distance_factor` game units per meter, without asking a [`System`].
+    pub fn with_distance_factor(distance_factor: c_float, settings: VelocitySettings) -> Self {
+        VelocityTracker {
+            settings,
+            distance_factor,
+            last: None,
+            velocity: Vector::default(),
+        }
+    }
+
+    /// The smoothed velocity, in game units per second.
+    pub fn velocity(&self) -> Vector {
+        self.velocity
+    }
+
+    /// The last position passed to [`VelocityTracker::update`] or [`VelocityTracker::teleport`].
+    pub fn position(&self) -> Option<Vector> {
+        self.last.map(|(position, _)| position)
+    }
+
+    /// Records the position at `time`, and returns the new velocity.
+    ///
+    /// `time` can be measured from any point (the start of the game, for example), as long as it is the same for every update.
+    /// Using game time rather than wall clock time means pausing the game doesn't affect the velocity.
+    /// The first update has no previous position, so the velocity stays at zero.
+    pub fn update(&mut self, position: Vector, time: Duration) -> Vector {
+        let Some((last_position, last_time)) = self.last else {
+            self.teleport(position, time);
+            return self.velocity;
+        };
+        // time going backwards or standing still says nothing about how fast this is moving
+        let Some(elapsed) = time.checked_sub(last_time).filter(|t| !t.is_zero()) else {
+            self.last = Some((position, time));
+            return self.velocity;
+        };
+
+        let seconds = elapsed.as_secs_f32();
+        let velocity = (position - last_position) * (1.0 / seconds);
+        if velocity.length() > self.settings.teleport_speed * self.distance_factor {
+            self.teleport(position, time);
+            return self.velocity;
+        }
+
+        let alpha = if self.settings.smoothing.is_zero() {
+            1.0
+        } else {
+            1.0 - (-seconds / self.settings.smoothing.as_secs_f32()).exp()
+        };
+        self.velocity = self.velocity + (velocity - self.velocity) * alpha;
+        self.last = Some((position, time));
+        self.velocity
+    }
+
+    /// Moves straight to `position` without it counting as movement, and resets the velocity to zero.
+    pub fn teleport(&mut self, position: Vector, time: Duration) {
+        self.last = Some((position, time));
+        self.velocity = Vector::default();
+    }
+
+    /// Forgets the last position, so the next update starts from zero velocity.
+    pub fn reset(&mut self) {
+        self.last = None;
+        self.velocity = Vector::default();
+    }
+}
+
+/// A sound source that works out its own velocity, see [`VelocityTracker`].
+///
+/// Call [`Emitter::update`] each frame, and then pass the result on to a channel with [`Emitter::apply_to_channel`]
+/// or an event instance with [`Emitter::apply_to_event`].
+#[derive(Debug, Clone)]
+pub struct Emitter {
+    tracked: TrackedAttributes,
+}
+
+impl Emitter {
+    pub fn new(system: System, settings: VelocitySettings) -> Result<Self> {
+        Ok(Emitter {
+            tracked: TrackedAttributes::new(system, settings)?,
+        })
+    }
+
+    /// Records the position and orientation at `time`, see [`VelocityTracker::update`].
+    ///
+    /// Only the position, forward and up vectors of `attributes` are used, the velocity is replaced with the tracked one.
+    pub fn update(&mut self, attributes: Attributes3D, time: Duration) -> Attributes3D {
+        self.tracked.update(attributes, time)
+    }
+
+    /// Like [`Emitter::update`], but the move doesn't count towards the velocity, see [`VelocityTracker::teleport`].
+    pub fn teleport(&mut self, attributes: Attributes3D, time: Duration) -> Attributes3D {
+        self.tracked.teleport(attributes, time)
+    }
+
+    /// The attributes from the last update, including the tracked velocity.
+    pub fn attributes(&self) -> Attributes3D {
+        self.tracked.attributes
+    }
+
+    pub fn tracker(&self) -> &VelocityTracker {
+        &self.tracked.tracker
+    }
+
+    /// Sets the position and velocity of a channel or channel group.
+    pub fn apply_to_channel(&self, channel_control: ChannelControl) -> Result<()> {
+        let attributes = self.tracked.attributes;
+        channel_control.set_3d_attributes(Some(attributes.position), Some(attributes.velocity))
+    }
+
+    pub fn apply_to_event(&self, instance: studio::EventInstance) -> Result<()> {
+        instance.set_3d_attributes(self.tracked.attributes)
+    }
+}
+
+/// A listener that works out its own velocity, see [`VelocityTracker`].
+///
+/// Call [`Listener::update`] each frame, and then pass the result on to FMOD with [`Listener::apply_to_studio`],
+/// or [`Listener::apply_to_system`] if the Studio API isn't being used.
+#[derive(Debug, Clone)]
+pub struct Listener {
+    index: c_int,
+    tracked: TrackedAttributes,
+}
+
+impl Listener {
+    /// Creates a tracker for the listener at `index`.
+    pub fn new(system: System, index: c_int, settings: VelocitySettings) -> Result<Self> {
+        Ok(Listener {
+            index,
+            tracked: TrackedAttributes::new(system, settings)?,
+        })
+    }
+
+    pub fn index(&self) -> c_int {
+        self.index
+    }
+
+    /// Records the position and orientation at `time`, see [`VelocityTracker::update`].
+    ///
+    /// Only the position, forward and up vectors of `attributes` are used, the velocity is replaced with the tracked one.
+    pub fn update(&mut self, attributes: Attributes3D, time: Duration) -> Attributes3D {
+        self.tracked.update(attributes, time)
+    }
+
+    /// Like [`Listener::update`], but the move doesn't count towards the velocity, see [`VelocityTracker::teleport`].
+    pub fn teleport(&mut self, attributes: Attributes3D, time: Duration) -> Attributes3D {
+        self.tracked.teleport(attributes, time)
+    }
+
+    /// The attributes from the last update, including the tracked velocity.
+    pub fn attributes(&self) -> Attributes3D {
+        self.tracked.attributes
+    }
+
+    pub fn tracker(&self) -> &VelocityTracker {
+        &self.tracked.tracker
+    }
+
+    pub fn apply_to_system(&self, system: System) -> Result<()> {
+        let attributes = self.tracked.attributes;
+        system.set_3d_listener_attributes(
+            self.index,
+            Some(attributes.position),
+            Some(attributes.velocity),
+            Some(attributes.forward),
+            Some(attributes.up),
+        )
+    }
+
+    /// Sets the listener attributes of a Studio system, leaving the attenuation position unset.
+    pub fn apply_to_studio(&self, system: studio::System) -> Result<()> {
+        system.set_listener_attributes(self.index, self.tracked.attributes, None)
+    }
+}
+
+// the part of Emitter and Listener that keeps track of the attributes
+#[derive(Debug, Clone)]
+struct TrackedAttributes {
+    tracker: VelocityTracker,
+    attributes: Attributes3D,
+}
+
+impl TrackedAttributes {
+    fn new(system: System, settings: VelocitySettings) -> Result<Self> {
+        Ok(TrackedAttributes {
+            tracker: VelocityTracker::new(system, settings)?,
+            attributes: default_attributes(),
+        })
+    }
+
+    fn update(&mut self, attributes: Attributes3D, time: Duration) -> Attributes3D {
+        let velocity = self.tracker.update(attributes.position, time);
+        self.attributes = Attributes3D {
+            velocity,
+            ..attributes
+        };
+        self.attributes
+    }
+
+    fn teleport(&mut self, attributes: Attributes3D, time: Duration) -> Attributes3D {
+        self.tracker.teleport(attributes.position, time);
+        self.attributes = Attributes3D {
+            velocity: Vector::default(),
+            ..attributes
+        };
+        self.attributes
+    }
+}
+
+// FMOD rejects zero forward and up vectors
+fn default_attributes() -> Attributes3D {
+    Attributes3D {
+        forward: Vector {
+            x: 0.0,
+            y: 0.0,
+            z: 1.0,
+        },
+        up: Vector {
+            x: 0.0,
+            y: 1.0,
+            z: 0.0,
+        },
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)] // the values compared are exact
+mod tests {
+    use super::*;
+
+    fn at(x: c_float) -> Vector {
+        Vector { x, y: 0.0, z: 0.0 }
+    }
+
+    fn millis(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    fn unsmoothed(distance_factor: c_float) -> VelocityTracker {
+        let settings = VelocitySettings {
+            smoothing: Duration::ZERO,
+            teleport_speed: 100.0,
+        };
+        VelocityTracker::with_distance_factor(distance_factor, settings)
+    }
+
+    #[test]
+    fn first_update_is_still() {
+        let mut tracker = unsmoothed(1.0);
+        assert_eq!(tracker.position(), None);
+        assert_eq!(tracker.update(at(5.0), millis(1000)), at(0.0));
+        assert_eq!(tracker.position(), Some(at(5.0)));
+
+        assert_eq!(tracker.update(at(6.0), millis(1500)), at(2.0));
+        tracker.reset();
+        assert_eq!(tracker.velocity(), at(0.0));
+        assert_eq!(tracker.update(at(7.0), millis(2000)), at(0.0));
+    }
+
+    #[test]
+    fn smoothing_follows_speed_changes() {
+        let settings = VelocitySettings {
+            smoothing: millis(100),
+            teleport_speed: 100.0,
+        };
+        let mut tracker = VelocityTracker::with_distance_factor(1.0, settings);
+        tracker.update(at(0.0), Duration::ZERO);
+
+        // after one smoothing period, the velocity has covered 1 - 1/e of the change
+        let velocity = tracker.update(at(1.0), millis(100));
+        let expected = 10.0 * (1.0 - (-1.0_f32).exp());
+        assert!((velocity.x - expected).abs() < 1e-4);
+
+        // and keeps approaching the actual speed
+        let mut previous = velocity.x;
+        for step in 2..10 {
+            let velocity = tracker.update(at(step as c_float), millis(step * 100));
+            assert!(velocity.x > previous && velocity.x < 10.0);
+            previous = velocity.x;
+        }
+        assert!(previous > 9.99);
+    }
+
+    #[test]
+    fn fast_moves_are_teleports() {
+        let mut tracker = unsmoothed(1.0);
+        tracker.update(at(0.0), Duration::ZERO);
+        assert_eq!(tracker.update(at(5.0), millis(100)), at(50.0));
+        // 200 m/s
+        assert_eq!(tracker.update(at(25.0), millis(200)), at(0.0));
+        assert_eq!(tracker.position(), Some(at(25.0)));
+        // the next move is measured from where it teleported to
+        assert_eq!(tracker.update(at(26.0), millis(300)), at(10.0));
+
+        // with 10 units per meter, the same move is only 20 m/s
+        let mut tracker = unsmoothed(10.0);
+        tracker.update(at(0.0), Duration::ZERO);
+        assert_eq!(tracker.update(at(20.0), millis(100)), at(200.0));
+
+        tracker.teleport(at(-5.0), millis(150));
+        assert_eq!(tracker.velocity(), at(0.0));
+        assert_eq!(tracker.position(), Some(at(-5.0)));
+    }
+
+    #[test]
+    fn time_must_move_forwards() {
+        let mut tracker = unsmoothed(1.0);
+        tracker.update(at(0.0), millis(1000));
+        assert_eq!(tracker.update(at(1.0), millis(1500)), at(2.0));
+
+        // no time has passed, so the velocity is kept but the position is still recorded
+        assert_eq!(tracker.update(at(3.0), millis(1500)), at(2.0));
+        assert_eq!(tracker.position(), Some(at(3.0)));
+        // and the same for time going backwards
+        assert_eq!(tracker.update(at(4.0), millis(500)), at(2.0));
+        assert_eq!(tracker.position(), Some(at(4.0)));
+
+        assert_eq!(tracker.update(at(5.0), millis(1000)), at(2.0));
+    }
+}
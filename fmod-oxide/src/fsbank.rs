@@ -0,0 +1,457 @@
+// Copyright (c) 2024 Lily Lyons
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Building FSB files with fsbank. Requires the `fsbank` feature.
+//!
+//! A list of [`SubSound`]s is encoded into a single FSB file, either on disk with [`FsBank::build_to_file`] or in memory with [`FsBank::build`].
+//! The bytes returned by [`FsBank::build`] can be loaded directly with [`SoundBuilder::open_memory`](crate::SoundBuilder::open_memory).
+//!
+//! ```ignore
+//! let mut fsbank = unsafe { fmod::fsbank::FsBank::init(fmod::fsbank::InitFlags::NORMAL, 0, None) }?;
+//! let bytes = fsbank.build(
+//!     &[
+//!         fmod::fsbank::SubSound::file(c!("footstep_01.wav")),
+//!         fmod::fsbank::SubSound::file(c!("footstep_02.wav")),
+//!     ],
+//!     &fmod::fsbank::BuildOptions::new(fmod::fsbank::Format::Vorbis),
+//! )?;
+//! let builder = unsafe { fmod::SoundBuilder::open_memory(&bytes) };
+//! let sound = system.create_sound(&builder)?;
+//! ```
+//!
+//! Builds block until they finish, so progress has to be polled from another thread while building, through a [`BuildHandle`]:
+//!
+//! ```ignore
+//! let mut fsbank = unsafe { FsBank::init(InitFlags::GENERATE_PROGRESS_ITEMS, 0, None) }?;
+//! let handle = fsbank.build_handle();
+//! std::thread::scope(|scope| {
+//!     let build = scope.spawn(|| fsbank.build(&subsounds, &options));
+//!     while !build.is_finished() {
+//!         while let Some(item) = handle.next_progress_item()? {
+//!             println!("{item:?}");
+//!         }
+//!         std::thread::sleep(std::time::Duration::from_millis(10));
+//!     }
+//!     build.join().unwrap()
+//! })?;
+//! ```
+
+use fmod_sys::*;
+use lanyard::{Utf8CStr, Utf8CString};
+use std::ffi::{c_char, c_float, c_int, c_uint, c_void};
+
+use crate::{Error, Result};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(
+    num_enum::TryFromPrimitive,
+    num_enum::IntoPrimitive,
+    num_enum::UnsafeFromPrimitive
+)]
+// stupid enum repr hack
+#[cfg_attr(target_env = "msvc", repr(i32))]
+#[cfg_attr(not(target_env = "msvc"), repr(u32))]
+pub enum Format {
+    Pcm = FSBANK_FORMAT_PCM,
+    Xma = FSBANK_FORMAT_XMA,
+    At9 = FSBANK_FORMAT_AT9,
+    Vorbis = FSBANK_FORMAT_VORBIS,
+    FAdpcm = FSBANK_FORMAT_FADPCM,
+    Opus = FSBANK_FORMAT_OPUS,
+}
+
+bitflags::bitflags! {
+  #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+  pub struct InitFlags: FSBANK_INITFLAGS {
+    const NORMAL =                 FSBANK_INIT_NORMAL;
+    const IGNORE_ERRORS =          FSBANK_INIT_IGNOREERRORS;
+    const WARNINGS_AS_ERRORS =     FSBANK_INIT_WARNINGSASERRORS;
+    const CREATE_INCLUDE_HEADER =  FSBANK_INIT_CREATEINCLUDEHEADER;
+    const DONT_LOAD_CACHE_FILES =  FSBANK_INIT_DONTLOADCACHEFILES;
+    const GENERATE_PROGRESS_ITEMS = FSBANK_INIT_GENERATEPROGRESSITEMS;
+  }
+}
+
+impl From<FSBANK_INITFLAGS> for InitFlags {
+    fn from(value: FSBANK_INITFLAGS) -> Self {
+        InitFlags::from_bits_truncate(value)
+    }
+}
+
+impl From<InitFlags> for FSBANK_INITFLAGS {
+    fn from(value: InitFlags) -> Self {
+        value.bits()
+    }
+}
+
+bitflags::bitflags! {
+  #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+  pub struct BuildFlags: FSBANK_BUILDFLAGS {
+    const DEFAULT =              FSBANK_BUILD_DEFAULT;
+    const DISABLE_SYNC_POINTS =  FSBANK_BUILD_DISABLESYNCPOINTS;
+    const DONT_LOOP =            FSBANK_BUILD_DONTLOOP;
+    const FILTER_HIGH_FREQ =     FSBANK_BUILD_FILTERHIGHFREQ;
+    const DISABLE_SEEKING =      FSBANK_BUILD_DISABLESEEKING;
+    const OPTIMIZE_SAMPLE_RATE = FSBANK_BUILD_OPTIMIZESAMPLERATE;
+    const DONT_WRITE_NAMES =     FSBANK_BUILD_FSB5_DONTWRITENAMES;
+    const NO_GUID =              FSBANK_BUILD_NOGUID;
+    const WRITE_PEAK_VOLUME =    FSBANK_BUILD_WRITEPEAKVOLUME;
+    const ALIGN_4K =             FSBANK_BUILD_ALIGN4K;
+  }
+}
+
+impl From<FSBANK_BUILDFLAGS> for BuildFlags {
+    fn from(value: FSBANK_BUILDFLAGS) -> Self {
+        BuildFlags::from_bits_truncate(value)
+    }
+}
+
+impl From<BuildFlags> for FSBANK_BUILDFLAGS {
+    fn from(value: BuildFlags) -> Self {
+        value.bits()
+    }
+}
+
+/// Where the audio of a [`SubSound`] comes from.
+///
+/// If there is more than one file they are interleaved into a single multichannel subsound.
+/// Every file must have the same sample rate and length.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SubSoundSource<'a> {
+    Files(Vec<&'a Utf8CStr>),
+    /// Files that have already been loaded into memory.
+    ///
+    /// These are read the same way as files on disk, so they must still be in a format fsbank understands (like WAV).
+    Memory(Vec<&'a [u8]>),
+}
+
+/// A subsound in the FSB being built.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SubSound<'a> {
+    pub source: SubSoundSource<'a>,
+    /// Flags that are combined with [`BuildOptions::flags`] for this subsound.
+    pub override_flags: BuildFlags,
+    /// Overrides [`BuildOptions::quality`] for this subsound if it isn't 0.
+    pub override_quality: c_uint,
+    /// The sample rate to resample to, or 0 to keep the source sample rate.
+    pub desired_sample_rate: c_float,
+    /// How much [`BuildFlags::OPTIMIZE_SAMPLE_RATE`] may lower the sample rate, from 0 to 100.
+    pub percent_optimized_rate: c_float,
+}
+
+impl<'a> SubSound<'a> {
+    pub fn file(file_name: &'a Utf8CStr) -> Self {
+        Self::new(SubSoundSource::Files(vec![file_name]))
+    }
+
+    pub fn memory(data: &'a [u8]) -> Self {
+        Self::new(SubSoundSource::Memory(vec![data]))
+    }
+
+    pub fn new(source: SubSoundSource<'a>) -> Self {
+        Self {
+            source,
+            override_flags: BuildFlags::DEFAULT,
+            override_quality: 0,
+            desired_sample_rate: 0.0,
+            percent_optimized_rate: 0.0,
+        }
+    }
+}
+
+/// Options that apply to every subsound in a build.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BuildOptions<'a> {
+    pub format: Format,
+    pub flags: BuildFlags,
+    /// The encoding quality, from 1 (smallest) to 100 (best), or 0 for the default of the format.
+    ///
+    /// This has no effect on [`Format::Pcm`] and [`Format::FAdpcm`].
+    pub quality: c_uint,
+    /// A key to encrypt the FSB with.
+    ///
+    /// The same key must be set with [`AdvancedSettings`](crate::AdvancedSettings) to load it.
+    pub encryption_key: Option<&'a Utf8CStr>,
+}
+
+impl BuildOptions<'_> {
+    pub fn new(format: Format) -> Self {
+        Self {
+            format,
+            flags: BuildFlags::DEFAULT,
+            quality: 0,
+            encryption_key: None,
+        }
+    }
+}
+
+/// The progress of a build, generated when fsbank is initialized with [`InitFlags::GENERATE_PROGRESS_ITEMS`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProgressItem {
+    /// The index of the subsound this is about, or -1 if it is about the whole build.
+    pub subsound_index: c_int,
+    /// The index of the thread doing the work, or -1 if it is the thread that called [`FsBank::build`].
+    pub thread_index: c_int,
+    pub state: ProgressState,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProgressState {
+    Decoding,
+    Analysing,
+    Preprocessing,
+    Encoding,
+    Writing,
+    Finished,
+    Failed {
+        error: Error,
+        message: Utf8CString,
+    },
+    Warning {
+        warning: Error,
+        message: Utf8CString,
+    },
+}
+
+/// A handle to fsbank, which is initialized for as long as this exists.
+///
+/// The fsbank API is global, so only one of these can exist at a time.
+/// fsbank can only run one build at a time, so building takes `&mut self`.
+#[derive(Debug)]
+pub struct FsBank {
+    _private: (),
+}
+
+/// A handle for watching and cancelling a build from another thread, returned by [`FsBank::build_handle`].
+///
+/// This doesn't borrow the [`FsBank`], so it can be used while [`FsBank::build`] is running.
+/// Once fsbank has been released its functions return an error.
+#[derive(Debug, Clone, Copy)]
+pub struct BuildHandle {
+    _private: (),
+}
+
+fn to_result(result: FSBANK_RESULT) -> Result<()> {
+    if result == FSBANK_OK {
+        Ok(())
+    } else {
+        Err(Error::FsBank(i64::from(result)))
+    }
+}
+
+impl FsBank {
+    /// Initializes fsbank.
+    ///
+    /// `simultaneous_jobs` is how many threads are used to encode, or 0 to use one per CPU core.
+    /// Encoded subsounds are cached in `cache_directory` (`fsbcache` in the working directory by default) to speed up later builds.
+    ///
+    /// # Safety
+    ///
+    /// This function must not be called at the same time as any other fsbank function.
+    pub unsafe fn init(
+        flags: InitFlags,
+        simultaneous_jobs: c_uint,
+        cache_directory: Option<&Utf8CStr>,
+    ) -> Result<Self> {
+        to_result(unsafe {
+            FSBank_Init(
+                FSBANK_FSBVERSION_FSB5,
+                flags.into(),
+                simultaneous_jobs,
+                cache_directory.map_or(std::ptr::null(), Utf8CStr::as_ptr),
+            )
+        })?;
+        Ok(Self { _private: () })
+    }
+
+    /// Builds an FSB in memory, returning its bytes.
+    ///
+    /// The bytes can be loaded with [`SoundBuilder::open_memory`](crate::SoundBuilder::open_memory).
+    pub fn build(
+        &mut self,
+        subsounds: &[SubSound<'_>],
+        options: &BuildOptions<'_>,
+    ) -> Result<Vec<u8>> {
+        Self::build_inner(subsounds, options, None)?;
+
+        let mut data = std::ptr::null();
+        let mut length = 0;
+        to_result(unsafe { FSBank_FetchFSBMemory(&mut data, &mut length) })?;
+        // the memory belongs to fsbank and is freed by the next build
+        let bytes = if data.is_null() {
+            Vec::new()
+        } else {
+            unsafe { std::slice::from_raw_parts(data.cast::<u8>(), length as usize) }.to_vec()
+        };
+        Ok(bytes)
+    }
+
+    /// Builds an FSB and writes it to `file_name`.
+    pub fn build_to_file(
+        &mut self,
+        subsounds: &[SubSound<'_>],
+        options: &BuildOptions<'_>,
+        file_name: &Utf8CStr,
+    ) -> Result<()> {
+        Self::build_inner(subsounds, options, Some(file_name))
+    }
+
+    fn build_inner(
+        subsounds: &[SubSound<'_>],
+        options: &BuildOptions<'_>,
+        file_name: Option<&Utf8CStr>,
+    ) -> Result<()> {
+        // FSBANK_SUBSOUND points to arrays of pointers, so those need to be collected first
+        let sources: Vec<(Vec<*const c_char>, Vec<*const c_void>, Vec<c_uint>)> = subsounds
+            .iter()
+            .map(|subsound| match &subsound.source {
+                SubSoundSource::Files(files) => (
+                    files.iter().map(|f| f.as_ptr()).collect(),
+                    Vec::new(),
+                    Vec::new(),
+                ),
+                SubSoundSource::Memory(data) => (
+                    Vec::new(),
+                    data.iter().map(|d| d.as_ptr().cast()).collect(),
+                    data.iter().map(|d| d.len() as c_uint).collect(),
+                ),
+            })
+            .collect();
+        let subsounds: Vec<FSBANK_SUBSOUND> = subsounds
+            .iter()
+            .zip(&sources)
+            .map(|(subsound, (names, data, lengths))| FSBANK_SUBSOUND {
+                fileNames: if names.is_empty() {
+                    std::ptr::null()
+                } else {
+                    names.as_ptr()
+                },
+                fileData: if data.is_empty() {
+                    std::ptr::null()
+                } else {
+                    data.as_ptr()
+                },
+                fileDataLengths: if lengths.is_empty() {
+                    std::ptr::null()
+                } else {
+                    lengths.as_ptr()
+                },
+                numFiles: (names.len() + data.len()) as c_uint,
+                overrideFlags: subsound.override_flags.into(),
+                overrideQuality: subsound.override_quality,
+                desiredSampleRate: subsound.desired_sample_rate,
+                percentOptimizedRate: subsound.percent_optimized_rate,
+            })
+            .collect();
+
+        to_result(unsafe {
+            FSBank_Build(
+                subsounds.as_ptr(),
+                subsounds.len() as c_uint,
+                options.format.into(),
+                options.flags.into(),
+                options.quality,
+                options
+                    .encryption_key
+                    .map_or(std::ptr::null(), Utf8CStr::as_ptr),
+                file_name.map_or(std::ptr::null(), Utf8CStr::as_ptr),
+            )
+        })
+    }
+
+    /// Returns a handle that can poll the progress of a build, or cancel it, from another thread.
+    pub fn build_handle(&self) -> BuildHandle {
+        BuildHandle { _private: () }
+    }
+
+    /// Fetches the next [`ProgressItem`], or `None` if there aren't any right now.
+    ///
+    /// Use [`BuildHandle::next_progress_item`] to poll while a build is running.
+    pub fn next_progress_item(&self) -> Result<Option<ProgressItem>> {
+        self.build_handle().next_progress_item()
+    }
+
+    /// Shuts down fsbank.
+    ///
+    /// # Safety
+    ///
+    /// This function must not be called at the same time as any other fsbank function, including from a build on another thread.
+    pub unsafe fn release(self) -> Result<()> {
+        to_result(unsafe { FSBank_Release() })
+    }
+}
+
+impl BuildHandle {
+    /// Cancels the build that is in progress on another thread, which then fails.
+    pub fn cancel_build(&self) -> Result<()> {
+        to_result(unsafe { FSBank_BuildCancel() })
+    }
+
+    /// Fetches the next [`ProgressItem`], or `None` if there aren't any right now.
+    pub fn next_progress_item(&self) -> Result<Option<ProgressItem>> {
+        let mut item = std::ptr::null();
+        to_result(unsafe { FSBank_FetchNextProgressItem(&mut item) })?;
+        if item.is_null() {
+            return Ok(None);
+        }
+
+        let progress = unsafe { ProgressItem::from_ffi(&*item) };
+        to_result(unsafe { FSBank_ReleaseProgressItem(item) })?;
+        progress.map(Some)
+    }
+}
+
+// fsbank fills these in from file names and system messages, which aren't guaranteed to be UTF-8
+fn state_message(message: &[c_char]) -> Utf8CString {
+    let bytes = unsafe { std::slice::from_raw_parts(message.as_ptr().cast::<u8>(), message.len()) };
+    if let Ok(message) = Utf8CStr::from_utf8_until_nul(bytes) {
+        return message.to_cstring();
+    }
+    let end = bytes.iter().position(|&b| b == 0).unwrap_or(bytes.len());
+    Utf8CString::new(String::from_utf8_lossy(&bytes[..end]))
+        .expect("message should not contain a nul byte")
+}
+
+impl ProgressItem {
+    /// # Safety
+    ///
+    /// `stateData` must point to the data for `state`, as it does when returned by fsbank.
+    unsafe fn from_ffi(value: &FSBANK_PROGRESSITEM) -> Result<Self> {
+        let state = match value.state {
+            FSBANK_STATE_DECODING => ProgressState::Decoding,
+            FSBANK_STATE_ANALYSING => ProgressState::Analysing,
+            FSBANK_STATE_PREPROCESSING => ProgressState::Preprocessing,
+            FSBANK_STATE_ENCODING => ProgressState::Encoding,
+            FSBANK_STATE_WRITING => ProgressState::Writing,
+            FSBANK_STATE_FINISHED => ProgressState::Finished,
+            FSBANK_STATE_FAILED => {
+                let data = unsafe { &*value.stateData.cast::<FSBANK_STATEDATA_FAILED>() };
+                ProgressState::Failed {
+                    error: Error::FsBank(i64::from(data.errorCode)),
+                    message: state_message(&data.errorString),
+                }
+            }
+            FSBANK_STATE_WARNING => {
+                let data = unsafe { &*value.stateData.cast::<FSBANK_STATEDATA_WARNING>() };
+                ProgressState::Warning {
+                    warning: Error::FsBank(i64::from(data.warnCode)),
+                    message: state_message(&data.warningString),
+                }
+            }
+            state => {
+                return Err(Error::EnumFromPrivitive {
+                    name: "FSBANK_STATE",
+                    primitive: i64::from(state),
+                })
+            }
+        };
+        Ok(Self {
+            subsound_index: value.subSoundIndex,
+            thread_index: value.threadIndex,
+            state,
+        })
+    }
+}
@@ -40,7 +40,12 @@
 //! This means that all FMOD functions take a `&Utf8CStr` instead of a `&str` or `&CStr`.
 //! `&Utf8CStr` is pretty cheap to construct (and can even be done statically with the `c!` macro), so this should not be a problem.
 //!
+//! Lookups by path or name (like `get_event`, `get_bus` and `set_parameter_by_name`) take `impl AsUtf8CStr` instead, so a `&str` or `String` can be passed in too.
+//! Strings shorter than [`INLINE_CAPACITY`] are nul-terminated in a buffer on the stack, so this only allocates for long strings.
+//!
 //! When FMOD returns a string, it will always return a `Utf8CString` (the owned version of `Utf8CStr`) because it's difficult to encode lifetime requirements of FMOD strings.
+//! Getters that are likely to be called often (like `get_path` and `get_name`) also have `_into` variants that write into a reusable [`Utf8CStringBuilder`],
+//! and `_in` variants that write into a `&mut [u8]` buffer, so they can be called every frame without allocating.
 //!
 //! This applies to structs like `fmod::studio::AdvancedSettings` which store C strings.
 //! Converting structs like `AdvancedSettings` to their FFI equivalents is done by reference as to not pass ownership of the string to FMOD.
@@ -53,6 +58,8 @@
 //!
 //! It's difficult to associate userdata with an individual system in this system though- so we have to clear the slotmap whenever any system is released.
 //! Releasing a system is performed at the end of execution generally so this probably won't be an issue.
+//!
+//! [`TypedUserdata`] provides typed getters and setters for userdata, so you don't have to downcast [`Userdata`] yourself.
 
 #![warn(rust_2018_idioms, clippy::pedantic)]
 #![allow(
@@ -63,6 +70,7 @@
     clippy::cast_possible_truncation,
     clippy::cast_possible_wrap,
     clippy::cast_sign_loss,
+    clippy::cast_precision_loss,
     clippy::must_use_candidate,
     clippy::missing_panics_doc // TODO: disable later
 )]
@@ -82,11 +90,16 @@ pub use core::*;
 
 pub mod studio;
 
+pub mod render;
+
+#[cfg(feature = "fsbank")]
+pub mod fsbank;
+
 #[doc(hidden)]
 #[cfg(feature = "userdata-abstraction")]
 pub mod userdata;
 #[cfg(feature = "userdata-abstraction")]
-pub use userdata::Userdata;
+pub use userdata::{TypedUserdata, Userdata};
 
 pub const VERSION: u32 = fmod_sys::FMOD_VERSION;
 pub const MAX_CHANNEL_WIDTH: u32 = fmod_sys::FMOD_MAX_CHANNEL_WIDTH;
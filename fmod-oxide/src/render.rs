@@ -0,0 +1,455 @@
+// Copyright (c) 2024 Lily Lyons
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Offline (faster than realtime) rendering, without a sound card.
+//!
+//! Each render creates its own Studio system using [`OutputType::WavWriterNRT`] or [`OutputType::NoSoundNRT`],
+//! plays a [`Source`] on it, and calls [`studio::System::update`] until the source finishes or [`RenderSettings::max_length`] is reached.
+//! Every update mixes exactly one block of [`RenderSettings::block_length`] samples.
+//!
+//! This is useful for baking previews of events or loudness checking them in a build pipeline.
+//!
+//! ```ignore
+//! let rendered = unsafe {
+//!     fmod::render::offline(
+//!         &fmod::render::Source::Event {
+//!             banks: &[c!("Master.bank"), c!("Master.strings.bank"), c!("SFX.bank")],
+//!             event: c!("event:/Weapons/Explosion"),
+//!             automation: &[],
+//!         },
+//!         &fmod::render::RenderSettings::default(),
+//!     )
+//! }?;
+//! println!("{} seconds of audio", rendered.info.length.as_secs_f32());
+//! ```
+
+use fmod_sys::*;
+use lanyard::{Utf8CStr, Utf8CString};
+use std::{
+    ffi::{c_float, c_int, c_uint},
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
+};
+
+use crate::{studio, Channel, ChannelControl, DspType, OutputType, SoundBuilder, SpeakerMode};
+
+/// What to play during a render.
+#[derive(Debug, Clone, Copy)]
+pub enum Source<'a> {
+    /// A sound file, which is played once.
+    Sound(&'a Utf8CStr),
+    /// A chain of built in DSPs.
+    ///
+    /// The first DSP is played with [`System::play_dsp`](crate::System::play_dsp), and the rest are added after it in order as effects.
+    /// DSPs never finish by themselves, so the render always lasts [`RenderSettings::max_length`].
+    DspChain(&'a [DspType]),
+    /// A Studio event, which is played once.
+    Event {
+        /// Every bank required to play the event, including the strings bank if `event` is a path.
+        banks: &'a [&'a Utf8CStr],
+        /// The path or ID of the event.
+        event: &'a Utf8CStr,
+        automation: &'a [ParameterAutomation<'a>],
+    },
+}
+
+/// Keyframes for a parameter of an event being rendered.
+///
+/// The parameter is linearly interpolated between keyframes, and holds the value of the first and last keyframe before and after them.
+#[derive(Debug, Clone, Copy)]
+pub struct ParameterAutomation<'a> {
+    pub name: &'a Utf8CStr,
+    /// `(time, value)` pairs, which must be sorted by time.
+    pub keyframes: &'a [(Duration, c_float)],
+}
+
+impl ParameterAutomation<'_> {
+    fn value_at(&self, time: Duration) -> Option<c_float> {
+        let index = self.keyframes.partition_point(|(t, _)| *t <= time);
+        match (
+            index.checked_sub(1).map(|i| self.keyframes[i]),
+            self.keyframes.get(index).copied(),
+        ) {
+            (None, None) => None,
+            (Some((_, value)), None) | (None, Some((_, value))) => Some(value),
+            (Some((before_time, before)), Some((after_time, after))) => {
+                // saturating, as unsorted keyframes could put the time outside of the pair
+                let progress = time.saturating_sub(before_time).as_secs_f32()
+                    / after_time.saturating_sub(before_time).as_secs_f32();
+                Some(before + (after - before) * progress)
+            }
+        }
+    }
+}
+
+/// Settings for an offline render.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RenderSettings {
+    pub sample_rate: c_int,
+    pub speaker_mode: SpeakerMode,
+    /// The number of samples mixed by each update.
+    ///
+    /// Parameter automation is only applied once per block.
+    pub block_length: c_uint,
+    /// The render is stopped after this long, even if the source is still playing.
+    pub max_length: Duration,
+}
+
+impl Default for RenderSettings {
+    #[allow(clippy::duration_suboptimal_units)] // Duration::from_mins needs rust 1.91
+    fn default() -> Self {
+        RenderSettings {
+            sample_rate: 48000,
+            speaker_mode: SpeakerMode::Stereo,
+            block_length: 1024,
+            max_length: Duration::from_secs(60),
+        }
+    }
+}
+
+/// Information about a finished render.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RenderInfo {
+    pub sample_rate: c_int,
+    pub channels: c_int,
+    /// How much audio was rendered.
+    pub length: Duration,
+    /// `false` if the source was still playing when [`RenderSettings::max_length`] was reached.
+    pub completed: bool,
+}
+
+/// Audio rendered into memory by [`offline`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rendered {
+    /// Interleaved samples.
+    pub samples: Vec<c_float>,
+    pub info: RenderInfo,
+}
+
+/// Renders `source` and returns the rendered audio.
+///
+/// The audio is written to a temporary WAV file with [`OutputType::WavWriterNRT`], which is read back and deleted.
+///
+/// # Safety
+///
+/// This creates and releases a Studio system, so the safety requirements of [`studio::SystemBuilder::new`] and [`studio::System::release`] apply.
+#[cfg_attr(
+    feature = "userdata-abstraction",
+    doc = "\n#### Note: releasing the system will drop ALL userdata, even userdata associated with other systems! See [`studio::System::release`]."
+)]
+pub unsafe fn offline(source: &Source<'_>, settings: &RenderSettings) -> Result<Rendered> {
+    static RENDER_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+    let path = std::env::temp_dir().join(format!(
+        "fmod-render-{}-{}.wav",
+        std::process::id(),
+        RENDER_COUNT.fetch_add(1, Ordering::Relaxed)
+    ));
+    let path_str = path
+        .to_str()
+        .ok_or(Error::Fmod(FMOD_RESULT::FMOD_ERR_FILE_BAD))?;
+    let path_str = Utf8CString::new(path_str)?;
+
+    let result = unsafe { offline_to_file(source, settings, &path_str) }.and_then(|info| {
+        let data = std::fs::read(&path).map_err(|_| Error::Fmod(FMOD_RESULT::FMOD_ERR_FILE_BAD))?;
+        let samples = read_wav(&data)?;
+        Ok(Rendered { samples, info })
+    });
+    let _ = std::fs::remove_file(&path);
+    result
+}
+
+/// Renders `source` to a WAV file at `path`.
+///
+/// # Safety
+///
+/// See [`offline`].
+pub unsafe fn offline_to_file(
+    source: &Source<'_>,
+    settings: &RenderSettings,
+    path: &Utf8CStr,
+) -> Result<RenderInfo> {
+    unsafe { render(source, settings, OutputType::WavWriterNRT, Some(path)) }
+}
+
+/// Renders `source` without writing the audio anywhere, using [`OutputType::NoSoundNRT`].
+///
+/// This is useful for finding out how long an event plays for, or checking that it finishes at all.
+///
+/// # Safety
+///
+/// See [`offline`].
+pub unsafe fn offline_dry_run(
+    source: &Source<'_>,
+    settings: &RenderSettings,
+) -> Result<RenderInfo> {
+    unsafe { render(source, settings, OutputType::NoSoundNRT, None) }
+}
+
+enum Playing {
+    Channel(Channel),
+    Dsp,
+    Event(studio::EventInstance),
+}
+
+unsafe fn render(
+    source: &Source<'_>,
+    settings: &RenderSettings,
+    output: OutputType,
+    path: Option<&Utf8CStr>,
+) -> Result<RenderInfo> {
+    let mut builder = unsafe { studio::SystemBuilder::new() }?;
+    builder
+        .core_builder()
+        .output(output)?
+        .software_format(settings.sample_rate, settings.speaker_mode, 0)?
+        .dsp_buffer_size(settings.block_length, 4)?;
+    // the wav writer takes the filename as its driver data
+    let driver_data = path.map_or(std::ptr::null_mut(), |p| p.as_ptr().cast_mut().cast());
+    let system = unsafe {
+        builder.build_with_extra_driver_data(
+            64,
+            studio::InitFlags::SYNCHRONOUS_UPDATE,
+            crate::InitFlags::NORMAL,
+            driver_data,
+        )
+    }?;
+
+    let result = render_with_system(system, source, settings);
+
+    // the wav file is only finished once the system is released
+    let release = unsafe { system.release() };
+    let info = result?;
+    release?;
+    Ok(info)
+}
+
+fn render_with_system(
+    system: studio::System,
+    source: &Source<'_>,
+    settings: &RenderSettings,
+) -> Result<RenderInfo> {
+    let core = system.get_core_system()?;
+    let (sample_rate, _, _) = core.get_software_format()?;
+    let channels = core.get_speaker_mode_channels(settings.speaker_mode)?;
+
+    let playing = match *source {
+        Source::Sound(path) => {
+            let sound = core.create_sound(&SoundBuilder::open(path))?;
+            Playing::Channel(core.play_sound(sound, None, false)?)
+        }
+        Source::DspChain(dsps) => {
+            let Some((&first, effects)) = dsps.split_first() else {
+                return Err(Error::Fmod(FMOD_RESULT::FMOD_ERR_INVALID_PARAM));
+            };
+            let channel = core.play_dsp(core.create_dsp_by_type(first)?, None, true)?;
+            // adding to the tail puts the dsp closest to the input, so add them in reverse to keep them in order
+            for &effect in effects.iter().rev() {
+                let dsp = core.create_dsp_by_type(effect)?;
+                channel.add_dsp(ChannelControl::DSP_TAIL, dsp)?;
+            }
+            channel.set_paused(false)?;
+            Playing::Dsp
+        }
+        Source::Event { banks, event, .. } => {
+            for bank in banks {
+                system.load_bank_file(bank, studio::LoadBankFlags::NORMAL)?;
+            }
+            let instance = system.get_event(event)?.create_instance()?;
+            instance.start()?;
+            Playing::Event(instance)
+        }
+    };
+    let automation = match *source {
+        Source::Event { automation, .. } => automation,
+        _ => &[],
+    };
+
+    let block = Duration::from_secs_f64(f64::from(settings.block_length) / f64::from(sample_rate));
+    let mut length = Duration::ZERO;
+    let mut completed = false;
+    while length < settings.max_length {
+        if let Playing::Event(instance) = playing {
+            for parameter in automation {
+                if let Some(value) = parameter.value_at(length) {
+                    instance.set_parameter_by_name(parameter.name, value, true)?;
+                }
+            }
+        }
+
+        system.update()?;
+        length += block;
+
+        completed = match playing {
+            // the channel handle becomes invalid once the sound finishes
+            Playing::Channel(channel) => !channel.is_playing().unwrap_or(false),
+            Playing::Dsp => false,
+            Playing::Event(instance) => {
+                instance.get_playback_state()? == studio::PlaybackState::Stopped
+            }
+        };
+        if completed {
+            break;
+        }
+    }
+
+    Ok(RenderInfo {
+        sample_rate,
+        channels,
+        length,
+        completed,
+    })
+}
+
+// reads the samples of a wav file as written by the wav writer, converting them to f32
+fn read_wav(data: &[u8]) -> Result<Vec<c_float>> {
+    const BAD: Error = Error::Fmod(FMOD_RESULT::FMOD_ERR_FILE_BAD);
+
+    if data.len() < 12 || &data[0..4] != b"RIFF" || &data[8..12] != b"WAVE" {
+        return Err(BAD);
+    }
+
+    let mut format = None;
+    let mut chunks = &data[12..];
+    while chunks.len() >= 8 {
+        let id = &chunks[0..4];
+        let size = u32::from_le_bytes(chunks[4..8].try_into().unwrap()) as usize;
+        // slicing twice so a huge size can't overflow
+        let body = chunks[8..].get(..size).ok_or(BAD)?;
+
+        match id {
+            b"fmt " if body.len() >= 16 => {
+                let mut tag = u16::from_le_bytes([body[0], body[1]]);
+                let bits = u16::from_le_bytes([body[14], body[15]]);
+                // WAVE_FORMAT_EXTENSIBLE stores the real format tag at the start of the subformat guid
+                if tag == 0xFFFE && body.len() >= 26 {
+                    tag = u16::from_le_bytes([body[24], body[25]]);
+                }
+                format = Some((tag, bits));
+            }
+            b"data" => {
+                let (tag, bits) = format.ok_or(BAD)?;
+                return decode_samples(body, tag, bits).ok_or(BAD);
+            }
+            _ => {}
+        }
+
+        // chunks are padded to an even length
+        let next = (8 + size + (size & 1)).min(chunks.len());
+        chunks = &chunks[next..];
+    }
+
+    Err(BAD)
+}
+
+fn decode_samples(data: &[u8], tag: u16, bits: u16) -> Option<Vec<c_float>> {
+    const PCM: u16 = 1;
+    const IEEE_FLOAT: u16 = 3;
+
+    let samples = match (tag, bits) {
+        (PCM, 8) => data
+            .iter()
+            .map(|&b| (f32::from(b) - 128.0) / 128.0)
+            .collect(),
+        (PCM, 16) => data
+            .chunks_exact(2)
+            .map(|b| f32::from(i16::from_le_bytes([b[0], b[1]])) / 32768.0)
+            .collect(),
+        (PCM, 24) => data
+            .chunks_exact(3)
+            .map(|b| (i32::from_le_bytes([0, b[0], b[1], b[2]]) >> 8) as f32 / 8_388_608.0)
+            .collect(),
+        (PCM, 32) => data
+            .chunks_exact(4)
+            .map(|b| i32::from_le_bytes([b[0], b[1], b[2], b[3]]) as f32 / 2_147_483_648.0)
+            .collect(),
+        (IEEE_FLOAT, 32) => data
+            .chunks_exact(4)
+            .map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+            .collect(),
+        _ => return None,
+    };
+    Some(samples)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wav(chunks: &[(&[u8; 4], &[u8])]) -> Vec<u8> {
+        let mut data = b"RIFF\0\0\0\0WAVE".to_vec();
+        for (id, body) in chunks {
+            data.extend_from_slice(*id);
+            data.extend_from_slice(&(body.len() as u32).to_le_bytes());
+            data.extend_from_slice(body);
+            if body.len() % 2 != 0 {
+                data.push(0);
+            }
+        }
+        data
+    }
+
+    fn fmt(tag: u16, bits: u16) -> Vec<u8> {
+        let mut body = vec![0; 16];
+        body[0..2].copy_from_slice(&tag.to_le_bytes());
+        body[14..16].copy_from_slice(&bits.to_le_bytes());
+        body
+    }
+
+    fn assert_bad(data: &[u8]) {
+        assert_eq!(read_wav(data).unwrap_err(), FMOD_RESULT::FMOD_ERR_FILE_BAD);
+    }
+
+    #[test]
+    fn reads_samples() {
+        let samples = [0i16, 16384, -32768];
+        let data: Vec<u8> = samples.iter().flat_map(|s| s.to_le_bytes()).collect();
+        // an odd sized chunk before the data checks the padding is skipped
+        let file = wav(&[(b"fmt ", &fmt(1, 16)), (b"junk", b"odd"), (b"data", &data)]);
+        assert_eq!(read_wav(&file).unwrap(), [0.0, 0.5, -1.0]);
+
+        let data: Vec<u8> = [0.25f32, -0.75]
+            .iter()
+            .flat_map(|s| s.to_le_bytes())
+            .collect();
+        let mut extensible = fmt(0xFFFE, 32);
+        extensible.resize(40, 0);
+        extensible[24..26].copy_from_slice(&3u16.to_le_bytes());
+        let file = wav(&[(b"fmt ", &extensible), (b"data", &data)]);
+        assert_eq!(read_wav(&file).unwrap(), [0.25, -0.75]);
+    }
+
+    #[test]
+    fn rejects_malformed_files() {
+        assert_bad(b"");
+        assert_bad(b"RIFF\0\0\0\0AVI ");
+        assert_bad(&wav(&[]));
+        // no format before the data
+        assert_bad(&wav(&[(b"data", &[0; 4])]));
+        // a format chunk too short to have the bit depth
+        assert_bad(&wav(&[(b"fmt ", &[1, 0, 1, 0]), (b"data", &[0; 4])]));
+        // mp3 isn't supported
+        assert_bad(&wav(&[(b"fmt ", &fmt(0x55, 0)), (b"data", &[0; 4])]));
+        assert_bad(&wav(&[(b"fmt ", &fmt(1, 12)), (b"data", &[0; 4])]));
+    }
+
+    #[test]
+    fn rejects_truncated_chunks() {
+        let file = wav(&[(b"fmt ", &fmt(1, 16)), (b"data", &[0; 4])]);
+
+        // cut off in the middle of the data, so the size is bigger than what's left
+        assert_bad(&file[..file.len() - 1]);
+        // cut off in the middle of the data chunk's header
+        assert_bad(&file[..file.len() - 6]);
+        // cut off in the middle of the format chunk
+        assert_bad(&file[..20]);
+
+        let mut huge = wav(&[(b"fmt ", &fmt(1, 16))]);
+        huge.extend_from_slice(b"data");
+        huge.extend_from_slice(&u32::MAX.to_le_bytes());
+        assert_bad(&huge);
+    }
+}
@@ -5,12 +5,12 @@
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
 use fmod_sys::*;
-use lanyard::Utf8CString;
-use std::ffi::c_void;
+use lanyard::{Utf8CStr, Utf8CString, Utf8CStringBuilder};
+use std::ffi::{c_int, c_void};
 use std::mem::MaybeUninit;
 
 use crate::studio::Bank;
-use crate::Guid;
+use crate::{get_sized_string_into, get_string_in, Guid};
 
 impl Bank {
     /// Retrieves the GUID.
@@ -27,44 +27,35 @@ impl Bank {
 
     /// Retrieves the path.
     pub fn get_path(&self) -> Result<Utf8CString> {
-        let mut string_len = 0;
-
-        // retrieve the length of the string.
-        // this includes the null terminator, so we don't need to account for that.
-        unsafe {
-            let error =
-                FMOD_Studio_Bank_GetPath(self.inner, std::ptr::null_mut(), 0, &mut string_len)
-                    .to_error();
-
-            // we expect the error to be fmod_err_truncated.
-            // if it isn't, we return the error.
-            match error {
-                Some(error) if error != FMOD_RESULT::FMOD_ERR_TRUNCATED => return Err(error),
-                _ => {}
-            }
-        };
-
-        let mut path = vec![0u8; string_len as usize];
-        let mut expected_string_len = 0;
+        let mut path = Utf8CStringBuilder::new();
+        self.get_path_into(&mut path)?;
+        Ok(path.into_cstring())
+    }
 
-        unsafe {
+    /// Like [`Bank::get_path`], but writes the path into `path`, reusing its allocation.
+    pub fn get_path_into(&self, path: &mut Utf8CStringBuilder) -> Result<()> {
+        get_sized_string_into(path, |buffer, string_len| unsafe {
             FMOD_Studio_Bank_GetPath(
                 self.inner,
-                // u8 and i8 have the same layout, so this is ok
-                path.as_mut_ptr().cast(),
+                buffer.as_mut_ptr().cast(),
+                buffer.len() as c_int,
                 string_len,
-                &mut expected_string_len,
             )
-            .to_result()?;
-
-            debug_assert_eq!(string_len, expected_string_len);
-
-            // all public fmod apis return UTF-8 strings. this should be safe.
-            // if i turn out to be wrong, perhaps we should add extra error types?
-            let path = Utf8CString::from_utf8_with_nul_unchecked(path);
+        })
+    }
 
-            Ok(path)
-        }
+    /// Like [`Bank::get_path`], but writes the path into `buffer` without allocating.
+    ///
+    /// Returns [`FMOD_RESULT::FMOD_ERR_TRUNCATED`] if the path (including the null terminator) doesn't fit.
+    pub fn get_path_in<'b>(&self, buffer: &'b mut [u8]) -> Result<&'b Utf8CStr> {
+        get_string_in(buffer, |buffer| unsafe {
+            FMOD_Studio_Bank_GetPath(
+                self.inner,
+                buffer.as_mut_ptr().cast(),
+                buffer.len() as c_int,
+                std::ptr::null_mut(),
+            )
+        })
     }
 
     /// Checks that the Bank reference is valid.
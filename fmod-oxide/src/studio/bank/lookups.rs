@@ -6,7 +6,7 @@
 
 use std::{ffi::c_int, mem::MaybeUninit};
 
-use crate::studio::{Bank, Bus, EventDescription, Vca};
+use crate::studio::{Bank, Bus, EventDescription, ListIter, Vca};
 use crate::Guid;
 use fmod_sys::*;
 use lanyard::Utf8CString;
@@ -23,14 +23,17 @@ impl Bank {
 
     /// Retrieves a list of the buses in the bank.
     pub fn get_bus_list(&self) -> Result<Vec<Bus>> {
+        let mut list = Vec::new();
+        self.get_bus_list_into(&mut list)?;
+        Ok(list)
+    }
+
+    /// Like [`Bank::get_bus_list`], but writes into `list`, reusing its allocation.
+    pub fn get_bus_list_into(&self, list: &mut Vec<Bus>) -> Result<()> {
         let expected_count = self.bus_count()?;
         let mut count = 0;
-        let mut list = vec![
-            Bus {
-                inner: std::ptr::null_mut()
-            };
-            expected_count as usize
-        ];
+        list.clear();
+        list.reserve(expected_count as usize);
 
         unsafe {
             FMOD_Studio_Bank_GetBusList(
@@ -44,8 +47,15 @@ impl Bank {
 
             debug_assert_eq!(count, expected_count);
 
-            Ok(list)
+            // fmod wrote `count` handles into the spare capacity
+            list.set_len(count as usize);
         }
+        Ok(())
+    }
+
+    /// Iterates over the buses in the bank, see [`ListIter`].
+    pub fn buses(&self) -> ListIter<Self, Bus> {
+        ListIter::new(*self, Self::get_bus_list_into)
     }
 
     /// Retrives the number of event descriptions in the bank.
@@ -65,15 +75,24 @@ impl Bank {
     /// This function counts the events which were added to the bank by the sound designer.
     /// The bank may contain additional events which are referenced by event instruments but were not added to the bank, and those referenced events are not counted.
     pub fn get_event_list(&self) -> Result<Vec<EventDescription>> {
+        let mut list = Vec::new();
+        self.get_event_list_into(&mut list)?;
+        Ok(list)
+    }
+
+    /// Like [`Bank::get_event_list`], but writes into `list`, reusing its allocation.
+    pub fn get_event_list_into(&self, list: &mut Vec<EventDescription>) -> Result<()> {
         let expected_count = self.event_count()?;
         let mut count = 0;
-        let mut list = vec![std::ptr::null_mut(); expected_count as usize];
+        list.clear();
+        list.reserve(expected_count as usize);
 
         unsafe {
             FMOD_Studio_Bank_GetEventList(
                 self.inner,
-                // bus is repr transparent and has the same layout as *mut FMOD_STUDIO_BUS, so this cast is ok
-                list.as_mut_ptr(),
+                // event description is repr transparent and has the same layout as *mut FMOD_STUDIO_EVENTDESCRIPTION, so this cast is ok
+                list.as_mut_ptr()
+                    .cast::<*mut FMOD_STUDIO_EVENTDESCRIPTION>(),
                 list.capacity() as c_int,
                 &mut count,
             )
@@ -81,11 +100,15 @@ impl Bank {
 
             debug_assert_eq!(count, expected_count);
 
-            Ok(std::mem::transmute::<
-                Vec<*mut fmod_sys::FMOD_STUDIO_EVENTDESCRIPTION>,
-                Vec<EventDescription>,
-            >(list))
+            // fmod wrote `count` handles into the spare capacity
+            list.set_len(count as usize);
         }
+        Ok(())
+    }
+
+    /// Iterates over the event descriptions in the bank, see [`ListIter`].
+    pub fn events(&self) -> ListIter<Self, EventDescription> {
+        ListIter::new(*self, Self::get_event_list_into)
     }
 
     /// Retrieves the number of string table entries in the bank.
@@ -163,19 +186,22 @@ impl Bank {
 
     /// Retrieves a list of the VCAs in the bank.
     pub fn get_vca_list(&self) -> Result<Vec<Vca>> {
-        let expected_count = self.event_count()?;
+        let mut list = Vec::new();
+        self.get_vca_list_into(&mut list)?;
+        Ok(list)
+    }
+
+    /// Like [`Bank::get_vca_list`], but writes into `list`, reusing its allocation.
+    pub fn get_vca_list_into(&self, list: &mut Vec<Vca>) -> Result<()> {
+        let expected_count = self.vca_count()?;
         let mut count = 0;
-        let mut list = vec![
-            Vca {
-                inner: std::ptr::null_mut()
-            };
-            expected_count as usize
-        ];
+        list.clear();
+        list.reserve(expected_count as usize);
 
         unsafe {
             FMOD_Studio_Bank_GetVCAList(
                 self.inner,
-                // bus is repr transparent and has the same layout as *mut FMOD_STUDIO_BUS, so this cast is ok
+                // vca is repr transparent and has the same layout as *mut FMOD_STUDIO_VCA, so this cast is ok
                 list.as_mut_ptr().cast::<*mut FMOD_STUDIO_VCA>(),
                 list.capacity() as c_int,
                 &mut count,
@@ -184,7 +210,14 @@ impl Bank {
 
             debug_assert_eq!(count, expected_count);
 
-            Ok(list)
+            // fmod wrote `count` handles into the spare capacity
+            list.set_len(count as usize);
         }
+        Ok(())
+    }
+
+    /// Iterates over the VCAs in the bank, see [`ListIter`].
+    pub fn vcas(&self) -> ListIter<Self, Vca> {
+        ListIter::new(*self, Self::get_vca_list_into)
     }
 }
@@ -0,0 +1,225 @@
+// Copyright (c) 2024 Lily Lyons
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use fmod_sys::*;
+use lanyard::Utf8CString;
+use std::ffi::{c_float, c_int};
+
+use crate::studio::{
+    Bank, EventDescription, ParameterFlags, ParameterID, ParameterKind, UserPropertyKind,
+};
+use crate::Guid;
+
+/// Everything about an event, gathered by [`EventDescription::describe`].
+///
+/// This is serializable with the `serde` feature, for tooling like audio browsers and bank validation.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[allow(clippy::struct_excessive_bools)] // these mirror the getters on EventDescription
+pub struct EventInfo {
+    /// The path of the event, or `None` if the strings bank isn't loaded.
+    pub path: Option<String>,
+    pub id: Guid,
+    /// The length of the timeline in milliseconds.
+    pub length: c_int,
+    pub is_3d: bool,
+    pub is_oneshot: bool,
+    pub is_snapshot: bool,
+    pub is_stream: bool,
+    pub has_sustain_point: bool,
+    pub is_doppler_enabled: bool,
+    pub min_distance: c_float,
+    pub max_distance: c_float,
+    pub sound_size: c_float,
+    pub parameters: Vec<ParameterDescriptionInfo>,
+    pub user_properties: Vec<UserPropertyInfo>,
+    pub instance_count: c_int,
+}
+
+/// A parameter of an event, including its labels.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ParameterDescriptionInfo {
+    pub name: String,
+    pub id: ParameterID,
+    pub guid: Guid,
+    pub minimum: c_float,
+    pub maximum: c_float,
+    pub default_value: c_float,
+    pub kind: ParameterKind,
+    pub flags: ParameterFlags,
+    /// The label of every value from `minimum` to `maximum`, if the parameter is labeled.
+    pub labels: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UserPropertyInfo {
+    pub name: String,
+    pub value: UserPropertyValue,
+}
+
+/// The value of a user property.
+///
+/// This is [`UserPropertyKind`] with a [`String`], so it can be serialized.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum UserPropertyValue {
+    Int(c_int),
+    Bool(bool),
+    Float(c_float),
+    String(String),
+}
+
+/// Everything in a bank, gathered by [`Bank::describe`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BankInfo {
+    /// The path of the bank, or `None` if the strings bank isn't loaded.
+    pub path: Option<String>,
+    pub id: Guid,
+    pub events: Vec<EventInfo>,
+    pub buses: Vec<MixerObjectInfo>,
+    pub vcas: Vec<MixerObjectInfo>,
+    /// The string table, which is only present in strings banks.
+    pub strings: Vec<StringInfo>,
+}
+
+/// A bus or VCA in a bank.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MixerObjectInfo {
+    /// The path of the bus or VCA, or `None` if the strings bank isn't loaded.
+    pub path: Option<String>,
+    pub id: Guid,
+}
+
+/// An entry in the string table of a strings bank.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StringInfo {
+    pub id: Guid,
+    pub path: String,
+}
+
+// paths are only available when the strings bank is loaded
+fn optional_path(path: Result<Utf8CString>) -> Result<Option<String>> {
+    match path {
+        Ok(path) => Ok(Some(path.into_string())),
+        Err(e) if e == FMOD_RESULT::FMOD_ERR_EVENT_NOTFOUND => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+impl EventDescription {
+    /// Gathers everything about this event with one call.
+    pub fn describe(&self) -> Result<EventInfo> {
+        let (min_distance, max_distance) = self.get_min_max_distance()?;
+
+        let mut parameters = vec![];
+        for index in 0..self.parameter_description_count()? {
+            let description = self.get_parameter_description_by_index(index)?;
+            let mut labels = vec![];
+            for label_index in 0..description.label_count() {
+                labels.push(
+                    self.get_parameter_label_by_index(index, label_index)?
+                        .into_string(),
+                );
+            }
+            parameters.push(ParameterDescriptionInfo {
+                name: description.name.into_string(),
+                id: description.id,
+                guid: description.guid,
+                minimum: description.minimum,
+                maximum: description.maximum,
+                default_value: description.default_value,
+                kind: description.kind,
+                flags: description.flags,
+                labels,
+            });
+        }
+
+        let mut user_properties = vec![];
+        for index in 0..self.user_property_count()? {
+            let property = self.get_user_property_by_index(index)?;
+            let value = match property.kind {
+                UserPropertyKind::Int(v) => UserPropertyValue::Int(v),
+                UserPropertyKind::Bool(v) => UserPropertyValue::Bool(v),
+                UserPropertyKind::Float(v) => UserPropertyValue::Float(v),
+                UserPropertyKind::String(v) => UserPropertyValue::String(v.into_string()),
+            };
+            user_properties.push(UserPropertyInfo {
+                name: property.name.into_string(),
+                value,
+            });
+        }
+
+        Ok(EventInfo {
+            path: optional_path(self.get_path())?,
+            id: self.get_id()?,
+            length: self.get_length()?,
+            is_3d: self.is_3d()?,
+            is_oneshot: self.is_oneshot()?,
+            is_snapshot: self.is_snapshot()?,
+            is_stream: self.is_stream()?,
+            has_sustain_point: self.has_sustain_point()?,
+            is_doppler_enabled: self.is_doppler_enabled()?,
+            min_distance,
+            max_distance,
+            sound_size: self.get_sound_size()?,
+            parameters,
+            user_properties,
+            instance_count: self.instance_count()?,
+        })
+    }
+}
+
+impl Bank {
+    /// Gathers every event, bus, VCA and string in this bank with one call.
+    ///
+    /// Events are described with [`EventDescription::describe`].
+    pub fn describe(&self) -> Result<BankInfo> {
+        let events = self
+            .get_event_list()?
+            .iter()
+            .map(EventDescription::describe)
+            .collect::<Result<_>>()?;
+
+        let mut buses = vec![];
+        for bus in self.get_bus_list()? {
+            buses.push(MixerObjectInfo {
+                path: optional_path(bus.get_path())?,
+                id: bus.get_id()?,
+            });
+        }
+
+        let mut vcas = vec![];
+        for vca in self.get_vca_list()? {
+            vcas.push(MixerObjectInfo {
+                path: optional_path(vca.get_path())?,
+                id: vca.get_id()?,
+            });
+        }
+
+        let mut strings = vec![];
+        for index in 0..self.string_count()? {
+            let (id, path) = self.get_string_info(index)?;
+            strings.push(StringInfo {
+                id,
+                path: path.into_string(),
+            });
+        }
+
+        Ok(BankInfo {
+            path: optional_path(self.get_path())?,
+            id: self.get_id()?,
+            events,
+            buses,
+            vcas,
+            strings,
+        })
+    }
+}
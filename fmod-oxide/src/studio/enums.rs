@@ -61,6 +61,7 @@ pub enum StopMode {
 // stupid enum repr hack
 #[cfg_attr(target_env = "msvc", repr(i32))]
 #[cfg_attr(not(target_env = "msvc"), repr(u32))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ParameterKind {
     GameControlled = FMOD_STUDIO_PARAMETER_GAME_CONTROLLED,
     AutomaticDistance = FMOD_STUDIO_PARAMETER_AUTOMATIC_DISTANCE,
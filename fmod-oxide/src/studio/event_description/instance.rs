@@ -7,7 +7,7 @@
 use fmod_sys::*;
 use std::ffi::c_int;
 
-use crate::studio::{EventDescription, EventInstance};
+use crate::studio::{EventDescription, EventInstance, ListIter};
 
 impl EventDescription {
     /// Creates a playable instance.
@@ -35,15 +35,23 @@ impl EventDescription {
     }
 
     pub fn get_instance_list(&self) -> Result<Vec<EventInstance>> {
+        let mut list = Vec::new();
+        self.get_instance_list_into(&mut list)?;
+        Ok(list)
+    }
+
+    /// Like [`EventDescription::get_instance_list`], but writes into `list`, reusing its allocation.
+    pub fn get_instance_list_into(&self, list: &mut Vec<EventInstance>) -> Result<()> {
         let expected_count = self.instance_count()?;
         let mut count = 0;
-        let mut list = vec![std::ptr::null_mut(); expected_count as usize];
+        list.clear();
+        list.reserve(expected_count as usize);
 
         unsafe {
             FMOD_Studio_EventDescription_GetInstanceList(
                 self.inner,
                 // eventinstance is repr transparent and has the same layout as *mut FMOD_STUDIO_EVENTINSTANCE, so this cast is ok
-                list.as_mut_ptr(),
+                list.as_mut_ptr().cast::<*mut FMOD_STUDIO_EVENTINSTANCE>(),
                 list.capacity() as c_int,
                 &mut count,
             )
@@ -51,12 +59,15 @@ impl EventDescription {
 
             debug_assert_eq!(count, expected_count);
 
-            // *mut FMOD_STUDIO_EVENTINSTANCE is transmutable to EventInstance
-            Ok(std::mem::transmute::<
-                Vec<*mut fmod_sys::FMOD_STUDIO_EVENTINSTANCE>,
-                Vec<EventInstance>,
-            >(list))
+            // fmod wrote `count` handles into the spare capacity
+            list.set_len(count as usize);
         }
+        Ok(())
+    }
+
+    /// Iterates over the instances of the event, see [`ListIter`].
+    pub fn instances(&self) -> ListIter<Self, EventInstance> {
+        ListIter::new(*self, Self::get_instance_list_into)
     }
 
     /// Releases all instances.
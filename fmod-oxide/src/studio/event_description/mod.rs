@@ -13,6 +13,10 @@ mod instance;
 mod parameter;
 mod sample_data;
 mod user_property;
+#[cfg(feature = "serde")]
+mod user_properties;
+#[cfg(feature = "serde")]
+pub use user_properties::UserPropertiesError;
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
 #[repr(transparent)] // so we can transmute between types
@@ -5,17 +5,33 @@
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
 use fmod_sys::*;
-use lanyard::{Utf8CStr, Utf8CString};
+use lanyard::{AsUtf8CStr, Utf8CStr, Utf8CString, Utf8CStringBuilder};
 use std::{ffi::c_int, mem::MaybeUninit};
 
-use crate::studio::{EventDescription, ParameterDescription, ParameterID};
+use crate::studio::{
+    EventDescription, EventParameter, ParameterDescription, ParameterID, ParameterValue,
+};
+use crate::{get_sized_string_into, get_string_in};
 
 impl EventDescription {
+    /// Creates a handle to an event parameter, which caches its ID, range, flags and labels.
+    ///
+    /// `K` is the type of value the parameter is set with, see [`ParameterValue`].
+    /// If the parameter can't hold values of `K`, or if it is a global parameter, [`FMOD_RESULT::FMOD_ERR_INVALID_PARAM`] is returned.
+    pub fn parameter<K: ParameterValue>(&self, name: impl AsUtf8CStr) -> Result<EventParameter<K>> {
+        let description = self.get_parameter_description_by_name(name)?;
+        let id = description.id;
+        EventParameter::new(description, |index| {
+            self.get_parameter_label_by_id(id, index)
+        })
+    }
+
     /// Retrieves an event parameter description by name.
     pub fn get_parameter_description_by_name(
         &self,
-        name: &Utf8CStr,
+        name: impl AsUtf8CStr,
     ) -> Result<ParameterDescription> {
+        let name = name.to_utf8_cstr()?;
         let mut description = MaybeUninit::zeroed();
         unsafe {
             FMOD_Studio_EventDescription_GetParameterDescriptionByName(
@@ -84,55 +100,54 @@ impl EventDescription {
     /// Path lookups will only succeed if the strings bank has been loaded.
     pub fn get_parameter_label_by_name(
         &self,
-        name: &Utf8CStr,
+        name: impl AsUtf8CStr,
         label_index: c_int,
     ) -> Result<Utf8CString> {
-        let mut string_len = 0;
+        let mut label = Utf8CStringBuilder::new();
+        self.get_parameter_label_by_name_into(name, label_index, &mut label)?;
+        Ok(label.into_cstring())
+    }
 
-        // retrieve the length of the string.
-        // this includes the null terminator, so we don't need to account for that.
-        unsafe {
-            let error = FMOD_Studio_EventDescription_GetParameterLabelByName(
+    /// Like [`EventDescription::get_parameter_label_by_name`], but writes the label into `label`, reusing its allocation.
+    pub fn get_parameter_label_by_name_into(
+        &self,
+        name: impl AsUtf8CStr,
+        label_index: c_int,
+        label: &mut Utf8CStringBuilder,
+    ) -> Result<()> {
+        let name = name.to_utf8_cstr()?;
+        get_sized_string_into(label, |buffer, string_len| unsafe {
+            FMOD_Studio_EventDescription_GetParameterLabelByName(
                 self.inner,
                 name.as_ptr(),
                 label_index,
-                std::ptr::null_mut(),
-                0,
-                &mut string_len,
+                buffer.as_mut_ptr().cast(),
+                buffer.len() as c_int,
+                string_len,
             )
-            .to_error();
-
-            // we expect the error to be fmod_err_truncated.
-            // if it isn't, we return the error.
-            match error {
-                Some(error) if error != FMOD_RESULT::FMOD_ERR_TRUNCATED => return Err(error),
-                _ => {}
-            }
-        };
-
-        let mut path = vec![0u8; string_len as usize];
-        let mut expected_string_len = 0;
+        })
+    }
 
-        unsafe {
+    /// Like [`EventDescription::get_parameter_label_by_name`], but writes the label into `buffer` without allocating.
+    ///
+    /// Returns [`FMOD_RESULT::FMOD_ERR_TRUNCATED`] if the label (including the null terminator) doesn't fit.
+    pub fn get_parameter_label_by_name_in<'b>(
+        &self,
+        name: impl AsUtf8CStr,
+        label_index: c_int,
+        buffer: &'b mut [u8],
+    ) -> Result<&'b Utf8CStr> {
+        let name = name.to_utf8_cstr()?;
+        get_string_in(buffer, |buffer| unsafe {
             FMOD_Studio_EventDescription_GetParameterLabelByName(
                 self.inner,
                 name.as_ptr(),
                 label_index,
-                // u8 and i8 have the same layout, so this is ok
-                path.as_mut_ptr().cast(),
-                string_len,
-                &mut expected_string_len,
+                buffer.as_mut_ptr().cast(),
+                buffer.len() as c_int,
+                std::ptr::null_mut(),
             )
-            .to_result()?;
-
-            debug_assert_eq!(string_len, expected_string_len);
-
-            // all public fmod apis return UTF-8 strings. this should be safe.
-            // if i turn out to be wrong, perhaps we should add extra error types?
-            let path = Utf8CString::from_utf8_with_nul_unchecked(path);
-
-            Ok(path)
-        }
+        })
     }
 
     /// Retrieves an event parameter label by ID.
@@ -141,52 +156,49 @@ impl EventDescription {
         id: ParameterID,
         label_index: c_int,
     ) -> Result<Utf8CString> {
-        let mut string_len = 0;
+        let mut label = Utf8CStringBuilder::new();
+        self.get_parameter_label_by_id_into(id, label_index, &mut label)?;
+        Ok(label.into_cstring())
+    }
 
-        // retrieve the length of the string.
-        // this includes the null terminator, so we don't need to account for that.
-        unsafe {
-            let error = FMOD_Studio_EventDescription_GetParameterLabelByID(
+    /// Like [`EventDescription::get_parameter_label_by_id`], but writes the label into `label`, reusing its allocation.
+    pub fn get_parameter_label_by_id_into(
+        &self,
+        id: ParameterID,
+        label_index: c_int,
+        label: &mut Utf8CStringBuilder,
+    ) -> Result<()> {
+        get_sized_string_into(label, |buffer, string_len| unsafe {
+            FMOD_Studio_EventDescription_GetParameterLabelByID(
                 self.inner,
                 id.into(),
                 label_index,
-                std::ptr::null_mut(),
-                0,
-                &mut string_len,
+                buffer.as_mut_ptr().cast(),
+                buffer.len() as c_int,
+                string_len,
             )
-            .to_error();
-
-            // we expect the error to be fmod_err_truncated.
-            // if it isn't, we return the error.
-            match error {
-                Some(error) if error != FMOD_RESULT::FMOD_ERR_TRUNCATED => return Err(error),
-                _ => {}
-            }
-        };
-
-        let mut path = vec![0u8; string_len as usize];
-        let mut expected_string_len = 0;
+        })
+    }
 
-        unsafe {
+    /// Like [`EventDescription::get_parameter_label_by_id`], but writes the label into `buffer` without allocating.
+    ///
+    /// Returns [`FMOD_RESULT::FMOD_ERR_TRUNCATED`] if the label (including the null terminator) doesn't fit.
+    pub fn get_parameter_label_by_id_in<'b>(
+        &self,
+        id: ParameterID,
+        label_index: c_int,
+        buffer: &'b mut [u8],
+    ) -> Result<&'b Utf8CStr> {
+        get_string_in(buffer, |buffer| unsafe {
             FMOD_Studio_EventDescription_GetParameterLabelByID(
                 self.inner,
                 id.into(),
                 label_index,
-                // u8 and i8 have the same layout, so this is ok
-                path.as_mut_ptr().cast(),
-                string_len,
-                &mut expected_string_len,
+                buffer.as_mut_ptr().cast(),
+                buffer.len() as c_int,
+                std::ptr::null_mut(),
             )
-            .to_result()?;
-
-            debug_assert_eq!(string_len, expected_string_len);
-
-            // all public fmod apis return UTF-8 strings. this should be safe.
-            // if i turn out to be wrong, perhaps we should add extra error types?
-            let path = Utf8CString::from_utf8_with_nul_unchecked(path);
-
-            Ok(path)
-        }
+        })
     }
 
     /// Retrieves an event parameter label by index.
@@ -197,51 +209,48 @@ impl EventDescription {
         index: c_int,
         label_index: c_int,
     ) -> Result<Utf8CString> {
-        let mut string_len = 0;
+        let mut label = Utf8CStringBuilder::new();
+        self.get_parameter_label_by_index_into(index, label_index, &mut label)?;
+        Ok(label.into_cstring())
+    }
 
-        // retrieve the length of the string.
-        // this includes the null terminator, so we don't need to account for that.
-        unsafe {
-            let error = FMOD_Studio_EventDescription_GetParameterLabelByIndex(
+    /// Like [`EventDescription::get_parameter_label_by_index`], but writes the label into `label`, reusing its allocation.
+    pub fn get_parameter_label_by_index_into(
+        &self,
+        index: c_int,
+        label_index: c_int,
+        label: &mut Utf8CStringBuilder,
+    ) -> Result<()> {
+        get_sized_string_into(label, |buffer, string_len| unsafe {
+            FMOD_Studio_EventDescription_GetParameterLabelByIndex(
                 self.inner,
                 index,
                 label_index,
-                std::ptr::null_mut(),
-                0,
-                &mut string_len,
+                buffer.as_mut_ptr().cast(),
+                buffer.len() as c_int,
+                string_len,
             )
-            .to_error();
-
-            // we expect the error to be fmod_err_truncated.
-            // if it isn't, we return the error.
-            match error {
-                Some(error) if error != FMOD_RESULT::FMOD_ERR_TRUNCATED => return Err(error),
-                _ => {}
-            }
-        };
-
-        let mut path = vec![0u8; string_len as usize];
-        let mut expected_string_len = 0;
+        })
+    }
 
-        unsafe {
+    /// Like [`EventDescription::get_parameter_label_by_index`], but writes the label into `buffer` without allocating.
+    ///
+    /// Returns [`FMOD_RESULT::FMOD_ERR_TRUNCATED`] if the label (including the null terminator) doesn't fit.
+    pub fn get_parameter_label_by_index_in<'b>(
+        &self,
+        index: c_int,
+        label_index: c_int,
+        buffer: &'b mut [u8],
+    ) -> Result<&'b Utf8CStr> {
+        get_string_in(buffer, |buffer| unsafe {
             FMOD_Studio_EventDescription_GetParameterLabelByIndex(
                 self.inner,
                 index,
                 label_index,
-                // u8 and i8 have the same layout, so this is ok
-                path.as_mut_ptr().cast(),
-                string_len,
-                &mut expected_string_len,
+                buffer.as_mut_ptr().cast(),
+                buffer.len() as c_int,
+                std::ptr::null_mut(),
             )
-            .to_result()?;
-
-            debug_assert_eq!(string_len, expected_string_len);
-
-            // all public fmod apis return UTF-8 strings. this should be safe.
-            // if i turn out to be wrong, perhaps we should add extra error types?
-            let path = Utf8CString::from_utf8_with_nul_unchecked(path);
-
-            Ok(path)
-        }
+        })
     }
 }
@@ -0,0 +1,565 @@
+// Copyright (c) 2024 Lily Lyons
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use fmod_sys::*;
+use serde::de::{
+    self, DeserializeOwned, DeserializeSeed, Expected, IntoDeserializer, MapAccess, Unexpected,
+    Visitor,
+};
+use std::{fmt::Display, str::FromStr};
+
+use crate::studio::{EventDescription, UserProperty, UserPropertyKind};
+
+impl EventDescription {
+    /// Deserializes every user property of this event into `T`, like a struct with a field per property.
+    ///
+    /// ```rust,no_run
+    /// # fn example(description: fmod::studio::EventDescription) -> Result<(), fmod::studio::UserPropertiesError> {
+    /// #[derive(serde::Deserialize)]
+    /// struct Config {
+    ///     cooldown: f32,
+    ///     subtitle: Option<String>,
+    /// }
+    /// let config: Config = description.user_properties()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Values are converted to the type `T` expects where possible:
+    /// numbers and bools are converted between each other (a float only becomes an integer if it is a whole number),
+    /// strings are parsed as numbers or as `true` and `false`, and anything can become a string.
+    /// Enums with unit variants can be deserialized from strings.
+    ///
+    /// If a property can't be deserialized, the returned [`UserPropertiesError::Deserialize`] has the name of that property.
+    pub fn user_properties<T: DeserializeOwned>(
+        &self,
+    ) -> std::result::Result<T, UserPropertiesError> {
+        let properties = (0..self.user_property_count()?)
+            .map(|index| self.get_user_property_by_index(index))
+            .collect::<Result<Vec<_>>>()?;
+
+        deserialize_properties(&properties)
+    }
+}
+
+fn deserialize_properties<T: DeserializeOwned>(
+    properties: &[UserProperty],
+) -> std::result::Result<T, UserPropertiesError> {
+    T::deserialize(PropertiesDeserializer(properties)).map_err(|e| {
+        UserPropertiesError::Deserialize {
+            property: e.property,
+            message: e.message,
+        }
+    })
+}
+
+/// The error returned by [`EventDescription::user_properties`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UserPropertiesError {
+    /// The user properties could not be retrieved.
+    Fmod(Error),
+    /// The user properties could not be deserialized.
+    ///
+    /// `property` is the name of the user property that failed, or `None` if the error isn't about a single property.
+    Deserialize {
+        property: Option<String>,
+        message: String,
+    },
+}
+
+impl Display for UserPropertiesError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Fmod(error) => error.fmt(f),
+            Self::Deserialize {
+                property: Some(property),
+                message,
+            } => f.write_fmt(format_args!(
+                "Failed to deserialize user property `{property}`: {message}"
+            )),
+            Self::Deserialize {
+                property: None,
+                message,
+            } => f.write_fmt(format_args!(
+                "Failed to deserialize user properties: {message}"
+            )),
+        }
+    }
+}
+
+impl std::error::Error for UserPropertiesError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Fmod(error) => Some(error),
+            Self::Deserialize { .. } => None,
+        }
+    }
+}
+
+impl From<Error> for UserPropertiesError {
+    fn from(value: Error) -> Self {
+        Self::Fmod(value)
+    }
+}
+
+// serde needs an error type that implements serde::de::Error, which fmod_sys::Error can't
+#[derive(Debug)]
+struct DeError {
+    property: Option<String>,
+    message: String,
+}
+
+impl Display for DeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for DeError {}
+
+impl de::Error for DeError {
+    fn custom<T: Display>(msg: T) -> Self {
+        Self {
+            property: None,
+            message: msg.to_string(),
+        }
+    }
+
+    fn missing_field(field: &'static str) -> Self {
+        Self {
+            property: Some(field.to_string()),
+            message: "the event has no user property with this name".to_string(),
+        }
+    }
+
+    fn unknown_field(field: &str, expected: &'static [&'static str]) -> Self {
+        Self {
+            property: Some(field.to_string()),
+            message: format!("unknown user property, expected one of {expected:?}"),
+        }
+    }
+}
+
+// the set of properties, which deserializes as a map from property names to values
+struct PropertiesDeserializer<'a>(&'a [UserProperty]);
+
+impl<'de> de::Deserializer<'de> for PropertiesDeserializer<'_> {
+    type Error = DeError;
+
+    fn deserialize_any<V: Visitor<'de>>(
+        self,
+        visitor: V,
+    ) -> std::result::Result<V::Value, DeError> {
+        visitor.visit_map(PropertiesAccess {
+            properties: self.0.iter(),
+            value: None,
+        })
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+struct PropertiesAccess<'a> {
+    properties: std::slice::Iter<'a, UserProperty>,
+    value: Option<&'a UserProperty>,
+}
+
+impl<'de> MapAccess<'de> for PropertiesAccess<'_> {
+    type Error = DeError;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> std::result::Result<Option<K::Value>, DeError> {
+        let Some(property) = self.properties.next() else {
+            return Ok(None);
+        };
+        self.value = Some(property);
+        let key: de::value::StrDeserializer<'_, DeError> =
+            property.name.as_str().into_deserializer();
+        seed.deserialize(key)
+            .map(Some)
+            .map_err(|e| e.with_property(property))
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> std::result::Result<V::Value, DeError> {
+        let property = self
+            .value
+            .take()
+            .expect("next_value_seed called before next_key_seed");
+        seed.deserialize(PropertyDeserializer(&property.kind))
+            .map_err(|e| e.with_property(property))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.properties.len())
+    }
+}
+
+impl DeError {
+    // errors from nested values don't know which property they came from
+    fn with_property(mut self, property: &UserProperty) -> Self {
+        self.property
+            .get_or_insert_with(|| property.name.as_str().to_string());
+        self
+    }
+}
+
+// a single property value, which converts itself to whatever the visitor asks for
+struct PropertyDeserializer<'a>(&'a UserPropertyKind);
+
+impl PropertyDeserializer<'_> {
+    fn unexpected(&self) -> Unexpected<'_> {
+        match self.0 {
+            UserPropertyKind::Int(v) => Unexpected::Signed(i64::from(*v)),
+            UserPropertyKind::Bool(v) => Unexpected::Bool(*v),
+            UserPropertyKind::Float(v) => Unexpected::Float(f64::from(*v)),
+            UserPropertyKind::String(v) => Unexpected::Str(v.as_str()),
+        }
+    }
+
+    fn integer<T>(&self, expected: &dyn Expected) -> std::result::Result<T, DeError>
+    where
+        T: TryFrom<i64> + FromStr,
+    {
+        let value = match self.0 {
+            UserPropertyKind::Int(v) => i64::from(*v),
+            UserPropertyKind::Bool(v) => i64::from(*v),
+            // only whole numbers, and i64::MIN as f32 is exact so this range is too
+            UserPropertyKind::Float(v)
+                if v.fract() == 0.0 && (i64::MIN as f32..-(i64::MIN as f32)).contains(v) =>
+            {
+                *v as i64
+            }
+            UserPropertyKind::Float(_) => {
+                return Err(de::Error::invalid_value(self.unexpected(), expected))
+            }
+            UserPropertyKind::String(v) => {
+                return v
+                    .trim()
+                    .parse()
+                    .map_err(|_| de::Error::invalid_value(self.unexpected(), expected))
+            }
+        };
+        T::try_from(value).map_err(|_| de::Error::invalid_value(self.unexpected(), expected))
+    }
+
+    fn float(&self, expected: &dyn Expected) -> std::result::Result<f64, DeError> {
+        match self.0 {
+            UserPropertyKind::Int(v) => Ok(f64::from(*v)),
+            UserPropertyKind::Bool(v) => Ok(f64::from(u8::from(*v))),
+            UserPropertyKind::Float(v) => Ok(f64::from(*v)),
+            UserPropertyKind::String(v) => v
+                .trim()
+                .parse()
+                .map_err(|_| de::Error::invalid_value(self.unexpected(), expected)),
+        }
+    }
+}
+
+macro_rules! deserialize_integers {
+    ($($method:ident => $visit:ident),* $(,)?) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> std::result::Result<V::Value, DeError> {
+                let value = self.integer(&visitor)?;
+                visitor.$visit(value)
+            }
+        )*
+    };
+}
+
+impl<'de> de::Deserializer<'de> for PropertyDeserializer<'_> {
+    type Error = DeError;
+
+    fn deserialize_any<V: Visitor<'de>>(
+        self,
+        visitor: V,
+    ) -> std::result::Result<V::Value, DeError> {
+        match self.0 {
+            UserPropertyKind::Int(v) => visitor.visit_i32(*v),
+            UserPropertyKind::Bool(v) => visitor.visit_bool(*v),
+            UserPropertyKind::Float(v) => visitor.visit_f32(*v),
+            UserPropertyKind::String(v) => visitor.visit_str(v.as_str()),
+        }
+    }
+
+    fn deserialize_bool<V: Visitor<'de>>(
+        self,
+        visitor: V,
+    ) -> std::result::Result<V::Value, DeError> {
+        let value = match self.0 {
+            UserPropertyKind::Int(v) => *v != 0,
+            UserPropertyKind::Bool(v) => *v,
+            UserPropertyKind::Float(v) => *v != 0.0,
+            UserPropertyKind::String(v) => match v.trim() {
+                s if s.eq_ignore_ascii_case("true") || s == "1" => true,
+                s if s.eq_ignore_ascii_case("false") || s == "0" => false,
+                _ => return Err(de::Error::invalid_value(self.unexpected(), &visitor)),
+            },
+        };
+        visitor.visit_bool(value)
+    }
+
+    deserialize_integers! {
+        deserialize_i8 => visit_i8,
+        deserialize_i16 => visit_i16,
+        deserialize_i32 => visit_i32,
+        deserialize_i64 => visit_i64,
+        deserialize_u8 => visit_u8,
+        deserialize_u16 => visit_u16,
+        deserialize_u32 => visit_u32,
+        deserialize_u64 => visit_u64,
+    }
+
+    fn deserialize_f32<V: Visitor<'de>>(
+        self,
+        visitor: V,
+    ) -> std::result::Result<V::Value, DeError> {
+        let value = self.float(&visitor)?;
+        visitor.visit_f32(value as f32)
+    }
+
+    fn deserialize_f64<V: Visitor<'de>>(
+        self,
+        visitor: V,
+    ) -> std::result::Result<V::Value, DeError> {
+        let value = self.float(&visitor)?;
+        visitor.visit_f64(value)
+    }
+
+    fn deserialize_char<V: Visitor<'de>>(
+        self,
+        visitor: V,
+    ) -> std::result::Result<V::Value, DeError> {
+        let mut chars = match self.0 {
+            UserPropertyKind::String(v) => v.as_str().chars(),
+            _ => return Err(de::Error::invalid_type(self.unexpected(), &visitor)),
+        };
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => visitor.visit_char(c),
+            _ => Err(de::Error::invalid_value(self.unexpected(), &visitor)),
+        }
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(
+        self,
+        visitor: V,
+    ) -> std::result::Result<V::Value, DeError> {
+        match self.0 {
+            UserPropertyKind::Int(v) => visitor.visit_string(v.to_string()),
+            UserPropertyKind::Bool(v) => visitor.visit_string(v.to_string()),
+            UserPropertyKind::Float(v) => visitor.visit_string(v.to_string()),
+            UserPropertyKind::String(v) => visitor.visit_str(v.as_str()),
+        }
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(
+        self,
+        visitor: V,
+    ) -> std::result::Result<V::Value, DeError> {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(
+        self,
+        visitor: V,
+    ) -> std::result::Result<V::Value, DeError> {
+        // missing properties are None, so a property that exists is always Some
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        visitor: V,
+    ) -> std::result::Result<V::Value, DeError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        _: &'static [&'static str],
+        visitor: V,
+    ) -> std::result::Result<V::Value, DeError> {
+        match self.0 {
+            UserPropertyKind::String(v) => visitor.visit_enum(v.as_str().into_deserializer()),
+            _ => Err(de::Error::invalid_type(self.unexpected(), &visitor)),
+        }
+    }
+
+    serde::forward_to_deserialize_any! {
+        i128 u128 bytes byte_buf unit unit_struct seq tuple
+        tuple_struct map struct identifier ignored_any
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)] // the values compared are exact
+mod tests {
+    use super::*;
+    use crate::Utf8CString;
+    use serde::Deserialize;
+
+    fn property(name: &str, kind: UserPropertyKind) -> UserProperty {
+        UserProperty {
+            name: Utf8CString::new(name).unwrap(),
+            kind,
+        }
+    }
+
+    fn string(value: &str) -> UserPropertyKind {
+        UserPropertyKind::String(Utf8CString::new(value).unwrap())
+    }
+
+    fn failed_property<T: DeserializeOwned>(properties: &[UserProperty]) -> Option<String> {
+        match deserialize_properties::<T>(properties) {
+            Err(UserPropertiesError::Deserialize { property, .. }) => property,
+            _ => panic!("expected a deserialize error"),
+        }
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    #[serde(rename_all = "lowercase")]
+    enum Surface {
+        Grass,
+        Wood,
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Config {
+        count: u8,
+        enabled: bool,
+        volume: f32,
+        name: String,
+        surface: Surface,
+        subtitle: Option<String>,
+    }
+
+    #[test]
+    fn properties_keep_their_kind() {
+        let properties = [
+            property("count", UserPropertyKind::Int(3)),
+            property("enabled", UserPropertyKind::Bool(true)),
+            property("volume", UserPropertyKind::Float(0.5)),
+            property("name", string("Footstep")),
+            property("surface", string("wood")),
+        ];
+        let config: Config = deserialize_properties(&properties).unwrap();
+        assert_eq!(
+            config,
+            Config {
+                count: 3,
+                enabled: true,
+                volume: 0.5,
+                name: "Footstep".to_string(),
+                surface: Surface::Wood,
+                subtitle: None,
+            }
+        );
+    }
+
+    #[test]
+    fn properties_are_coerced() {
+        let properties = [
+            property("count", UserPropertyKind::Float(4.0)),
+            property("enabled", string(" TRUE ")),
+            property("volume", UserPropertyKind::Int(2)),
+            property("name", UserPropertyKind::Float(1.5)),
+            property("surface", string("grass")),
+            property("subtitle", UserPropertyKind::Bool(false)),
+        ];
+        let config: Config = deserialize_properties(&properties).unwrap();
+        assert_eq!(config.count, 4);
+        assert!(config.enabled);
+        assert_eq!(config.volume, 2.0);
+        assert_eq!(config.name, "1.5");
+        assert_eq!(config.subtitle.as_deref(), Some("false"));
+
+        let properties = [
+            property("count", string("7")),
+            property("enabled", UserPropertyKind::Int(0)),
+            property("volume", string("0.25")),
+            property("name", UserPropertyKind::Int(-1)),
+            property("surface", string("grass")),
+        ];
+        let config: Config = deserialize_properties(&properties).unwrap();
+        assert_eq!(config.count, 7);
+        assert!(!config.enabled);
+        assert_eq!(config.volume, 0.25);
+        assert_eq!(config.name, "-1");
+    }
+
+    #[test]
+    fn errors_name_the_property() {
+        let valid = |count| {
+            [
+                property("count", count),
+                property("enabled", UserPropertyKind::Bool(true)),
+                property("volume", UserPropertyKind::Float(0.5)),
+                property("name", string("Footstep")),
+                property("surface", string("grass")),
+            ]
+        };
+        assert!(deserialize_properties::<Config>(&valid(UserPropertyKind::Int(1))).is_ok());
+
+        // fractional, out of range, and unparseable
+        for count in [
+            UserPropertyKind::Float(1.5),
+            UserPropertyKind::Int(256),
+            UserPropertyKind::Int(-1),
+            string("many"),
+        ] {
+            assert_eq!(
+                failed_property::<Config>(&valid(count)),
+                Some("count".to_string())
+            );
+        }
+
+        let mut properties = valid(UserPropertyKind::Int(1));
+        properties[1] = property("enabled", string("yes"));
+        assert_eq!(
+            failed_property::<Config>(&properties),
+            Some("enabled".to_string())
+        );
+
+        let mut properties = valid(UserPropertyKind::Int(1));
+        properties[4] = property("surface", string("metal"));
+        assert_eq!(
+            failed_property::<Config>(&properties),
+            Some("surface".to_string())
+        );
+
+        // missing
+        assert_eq!(
+            failed_property::<Config>(&properties[..4]),
+            Some("surface".to_string())
+        );
+
+        // not about any one property
+        assert_eq!(
+            deserialize_properties::<bool>(&properties).unwrap_err(),
+            UserPropertiesError::Deserialize {
+                property: None,
+                message: "invalid type: map, expected a boolean".to_string(),
+            }
+        );
+
+        let error =
+            deserialize_properties::<Config>(&[property("count", string("many"))]).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Failed to deserialize user property `count`: invalid value: string \"many\", expected u8"
+        );
+    }
+}
@@ -5,14 +5,15 @@
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
 use fmod_sys::*;
-use lanyard::Utf8CStr;
+use lanyard::AsUtf8CStr;
 use std::{ffi::c_int, mem::MaybeUninit};
 
 use crate::studio::{EventDescription, UserProperty};
 
 impl EventDescription {
     /// Retrieves a user property by name.
-    pub fn get_user_property(&self, name: &Utf8CStr) -> Result<UserProperty> {
+    pub fn get_user_property(&self, name: impl AsUtf8CStr) -> Result<UserProperty> {
+        let name = name.to_utf8_cstr()?;
         let mut property = MaybeUninit::uninit();
         unsafe {
             FMOD_Studio_EventDescription_GetUserProperty(
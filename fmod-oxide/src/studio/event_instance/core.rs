@@ -4,15 +4,11 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
-use std::{
-    ffi::{c_float, c_int, c_uint},
-    mem::MaybeUninit,
-};
+use std::ffi::{c_float, c_int};
 
 use fmod_sys::*;
-use lanyard::Utf8CStr;
 
-use crate::studio::{EventInstance, MemoryUsage, ParameterID};
+use crate::studio::EventInstance;
 use crate::ChannelGroup;
 
 impl EventInstance {
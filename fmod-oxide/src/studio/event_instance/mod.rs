@@ -8,10 +8,12 @@ use fmod_sys::*;
 
 mod attributes_3d;
 mod callback;
+mod core;
 mod general;
 mod parameters;
 mod playback;
 mod playback_properties;
+mod profiling;
 
 pub(crate) use callback::event_callback_impl;
 pub use callback::EventInstanceCallback;
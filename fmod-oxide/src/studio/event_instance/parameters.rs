@@ -7,7 +7,7 @@
 use std::ffi::{c_float, c_int};
 
 use fmod_sys::*;
-use lanyard::Utf8CStr;
+use lanyard::AsUtf8CStr;
 
 use crate::studio::{EventInstance, ParameterID};
 
@@ -21,10 +21,11 @@ impl EventInstance {
     /// If the event has no parameter matching name then [`FMOD_RESULT::FMOD_ERR_EVENT_NOTFOUND`] is returned.
     pub fn set_parameter_by_name(
         &self,
-        name: &Utf8CStr,
+        name: impl AsUtf8CStr,
         value: c_float,
         ignore_seek_speed: bool,
     ) -> Result<()> {
+        let name = name.to_utf8_cstr()?;
         unsafe {
             FMOD_Studio_EventInstance_SetParameterByName(
                 self.inner,
@@ -47,10 +48,12 @@ impl EventInstance {
     /// If the specified label is not found, [`FMOD_RESULT::FMOD_ERR_EVENT_NOTFOUND`] is returned. This lookup is case sensitive.
     pub fn set_parameter_by_name_with_label(
         &self,
-        name: &Utf8CStr,
-        label: &Utf8CStr,
+        name: impl AsUtf8CStr,
+        label: impl AsUtf8CStr,
         ignore_seek_speed: bool,
     ) -> Result<()> {
+        let name = name.to_utf8_cstr()?;
+        let label = label.to_utf8_cstr()?;
         unsafe {
             FMOD_Studio_EventInstance_SetParameterByNameWithLabel(
                 self.inner,
@@ -68,7 +71,8 @@ impl EventInstance {
     ///
     /// The second returned tuple field is the final value of the parameter after applying adjustments due to automation, modulation, seek speed, and parameter velocity to value.
     /// This is calculated asynchronously when the Studio system updates.
-    pub fn get_parameter_by_name(&self, name: &Utf8CStr) -> Result<(c_float, c_float)> {
+    pub fn get_parameter_by_name(&self, name: impl AsUtf8CStr) -> Result<(c_float, c_float)> {
+        let name = name.to_utf8_cstr()?;
         let mut value = 0.0;
         let mut final_value = 0.0;
         unsafe {
@@ -115,9 +119,10 @@ impl EventInstance {
     pub fn set_parameter_by_id_with_label(
         &self,
         id: ParameterID,
-        label: &Utf8CStr,
+        label: impl AsUtf8CStr,
         ignore_seek_speed: bool,
     ) -> Result<()> {
+        let label = label.to_utf8_cstr()?;
         unsafe {
             FMOD_Studio_EventInstance_SetParameterByIDWithLabel(
                 self.inner,
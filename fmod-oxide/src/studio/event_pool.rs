@@ -0,0 +1,295 @@
+// Copyright (c) 2024 Lily Lyons
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use fmod_sys::*;
+use std::ffi::c_float;
+
+use crate::studio::{EventDescription, EventInstance, PlaybackState, StopMode, System};
+
+/// What an [`EventPool`] does when every instance is playing and another one is requested.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StealPolicy {
+    /// Don't steal anything, [`EventPool::play`] returns `None`.
+    None,
+    /// Steal the instance that was started the longest time ago.
+    Oldest,
+    /// Steal the instance with the lowest final volume. Virtual instances are always considered the quietest.
+    Quietest,
+    /// Steal the instance furthest away from its closest listener.
+    /// Instances of 2D events are treated as if they were at the listener.
+    Farthest,
+}
+
+/// Statistics about an [`EventPool`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct EventPoolStats {
+    /// The number of instances in the pool.
+    pub capacity: usize,
+    /// The number of instances that are currently playing.
+    pub active: usize,
+    /// The most instances that have been playing at once.
+    pub peak_active: usize,
+    /// The number of times an instance was started.
+    pub plays: u64,
+    /// The number of times a playing instance was stolen.
+    pub steals: u64,
+    /// The number of times [`EventPool::play`] returned `None` because the pool was exhausted.
+    pub rejected: u64,
+    /// The number of instances that stopped on their own and were returned to the pool.
+    pub recycled: u64,
+}
+
+#[derive(Debug)]
+struct PooledInstance {
+    instance: EventInstance,
+    // which play this was, used to find the oldest instance
+    play: u64,
+    // the update this instance was started in, so we don't recycle it before FMOD has had a chance to start it
+    started_update: u64,
+    in_use: bool,
+}
+
+/// A fixed size pool of [`EventInstance`]s for a single [`EventDescription`].
+///
+/// Creating and releasing instances for every one-shot is wasteful, and nothing stops a busy scene from creating hundreds of instances of the same event.
+/// An [`EventPool`] creates all of its instances up front and reuses them once they have stopped.
+/// When every instance is playing, the [`StealPolicy`] decides which one is restarted.
+///
+/// [`EventPool::update`] should be called once per frame, after [`System::update`], to return stopped instances to the pool.
+#[derive(Debug)]
+pub struct EventPool {
+    system: System,
+    description: EventDescription,
+    instances: Vec<PooledInstance>,
+    policy: StealPolicy,
+    updates: u64,
+    stats: EventPoolStats,
+}
+
+impl EventPool {
+    /// Creates a pool of `capacity` instances of `description`.
+    ///
+    /// `system` is used to find listener positions for [`StealPolicy::Farthest`].
+    pub fn new(
+        system: System,
+        description: EventDescription,
+        capacity: usize,
+        policy: StealPolicy,
+    ) -> Result<Self> {
+        let mut instances: Vec<PooledInstance> = Vec::with_capacity(capacity);
+        for _ in 0..capacity {
+            let instance = match description.create_instance() {
+                Ok(instance) => instance,
+                Err(e) => {
+                    // don't leak the instances we did manage to create
+                    for pooled in &instances {
+                        let _ = pooled.instance.release();
+                    }
+                    return Err(e);
+                }
+            };
+            instances.push(PooledInstance {
+                instance,
+                play: 0,
+                started_update: 0,
+                in_use: false,
+            });
+        }
+
+        Ok(EventPool {
+            system,
+            description,
+            instances,
+            policy,
+            updates: 0,
+            stats: EventPoolStats {
+                capacity,
+                ..Default::default()
+            },
+        })
+    }
+
+    /// Starts an instance from the pool.
+    ///
+    /// `setup` is called before the instance is started, and should be used to set parameters and 3D attributes.
+    /// Instances are reused, so anything set on a previous play (like parameter values) is still set!
+    ///
+    /// If every instance is playing, an instance is stolen according to the pool's [`StealPolicy`].
+    /// Stolen instances are stopped immediately.
+    /// Returns `None` if the pool is exhausted and its policy is [`StealPolicy::None`].
+    pub fn play(
+        &mut self,
+        setup: impl FnOnce(EventInstance) -> Result<()>,
+    ) -> Result<Option<EventInstance>> {
+        let index = if let Some(index) = self.instances.iter().position(|p| !p.in_use) {
+            index
+        } else if let Some(index) = self.steal_candidate()? {
+            self.instances[index].instance.stop(StopMode::Immediate)?;
+            self.stats.steals += 1;
+            index
+        } else {
+            self.stats.rejected += 1;
+            return Ok(None);
+        };
+
+        let pooled = &mut self.instances[index];
+        // if setup fails the instance is left unused
+        pooled.in_use = false;
+        setup(pooled.instance)?;
+        pooled.instance.start()?;
+
+        self.stats.plays += 1;
+        pooled.play = self.stats.plays;
+        pooled.started_update = self.updates;
+        pooled.in_use = true;
+        let instance = pooled.instance;
+
+        let active = self.active_count();
+        self.stats.peak_active = self.stats.peak_active.max(active);
+
+        Ok(Some(instance))
+    }
+
+    /// Returns instances that have stopped to the pool.
+    ///
+    /// Instances that are no longer valid (for example because their bank was unloaded) are removed from the pool.
+    pub fn update(&mut self) -> Result<()> {
+        let mut result = Ok(());
+        self.instances.retain_mut(|pooled| {
+            if result.is_err() || !pooled.in_use || pooled.started_update == self.updates {
+                return true;
+            }
+            match pooled.instance.get_playback_state() {
+                Ok(PlaybackState::Stopped) => {
+                    pooled.in_use = false;
+                    self.stats.recycled += 1;
+                    true
+                }
+                Ok(_) => true,
+                Err(e) if e == FMOD_RESULT::FMOD_ERR_INVALID_HANDLE => false,
+                Err(e) => {
+                    result = Err(e);
+                    true
+                }
+            }
+        });
+        self.stats.capacity = self.instances.len();
+        self.updates += 1;
+        result
+    }
+
+    /// Stops every playing instance.
+    ///
+    /// With [`StopMode::Immediate`] the instances are returned to the pool right away, otherwise they are returned by [`EventPool::update`] once they finish fading out.
+    pub fn stop_all(&mut self, mode: StopMode) -> Result<()> {
+        for pooled in &mut self.instances {
+            if pooled.in_use {
+                pooled.instance.stop(mode)?;
+            }
+            // instances fading out can't be reused until they finish, so only immediately stopped instances can be reused right away
+            if mode == StopMode::Immediate {
+                pooled.in_use = false;
+            }
+        }
+        Ok(())
+    }
+
+    /// Releases every instance in the pool.
+    ///
+    /// Instances that are still playing will be released once they stop.
+    pub fn release(self) -> Result<()> {
+        for pooled in self.instances {
+            pooled.instance.release()?;
+        }
+        Ok(())
+    }
+
+    pub fn description(&self) -> EventDescription {
+        self.description
+    }
+
+    pub fn policy(&self) -> StealPolicy {
+        self.policy
+    }
+
+    pub fn set_policy(&mut self, policy: StealPolicy) {
+        self.policy = policy;
+    }
+
+    pub fn stats(&self) -> EventPoolStats {
+        EventPoolStats {
+            active: self.active_count(),
+            ..self.stats
+        }
+    }
+
+    /// Resets every counter in [`EventPoolStats`].
+    pub fn reset_stats(&mut self) {
+        self.stats = EventPoolStats {
+            capacity: self.instances.len(),
+            ..Default::default()
+        };
+    }
+
+    fn active_count(&self) -> usize {
+        self.instances.iter().filter(|p| p.in_use).count()
+    }
+
+    fn steal_candidate(&self) -> Result<Option<usize>> {
+        let index = match self.policy {
+            StealPolicy::None => None,
+            StealPolicy::Oldest => self
+                .instances
+                .iter()
+                .enumerate()
+                .min_by_key(|(_, p)| p.play)
+                .map(|(index, _)| index),
+            StealPolicy::Quietest => {
+                let mut volumes = Vec::with_capacity(self.instances.len());
+                for pooled in &self.instances {
+                    let volume = if pooled.instance.is_virtual()? {
+                        0.0
+                    } else {
+                        pooled.instance.get_volume()?.1
+                    };
+                    volumes.push(volume);
+                }
+                min_index(&volumes)
+            }
+            StealPolicy::Farthest => {
+                let mut listeners = vec![];
+                for listener in 0..self.system.get_listener_count()? {
+                    listeners.push(self.system.get_listener_attributes(listener)?.0.position);
+                }
+
+                let mut distances = Vec::with_capacity(self.instances.len());
+                for pooled in &self.instances {
+                    let distance = match pooled.instance.get_3d_attributes() {
+                        Ok(attributes) => listeners
+                            .iter()
+                            .map(|listener| attributes.position.distance_squared(*listener))
+                            .fold(c_float::INFINITY, c_float::min),
+                        // 2D events don't have a position, so count them as being at the listener
+                        Err(e) if e == FMOD_RESULT::FMOD_ERR_NEEDS3D => 0.0,
+                        Err(e) => return Err(e),
+                    };
+                    // we want the farthest, so flip the sign and pick the smallest
+                    distances.push(-distance);
+                }
+                min_index(&distances)
+            }
+        };
+        Ok(index)
+    }
+}
+
+fn min_index(values: &[c_float]) -> Option<usize> {
+    values
+        .iter()
+        .enumerate()
+        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(index, _)| index)
+}
@@ -56,6 +56,7 @@ impl From<LoadBankFlags> for FMOD_STUDIO_LOAD_BANK_FLAGS {
 
 bitflags::bitflags! {
   #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+  #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
   pub struct ParameterFlags: c_uint {
       const READONLY = FMOD_STUDIO_PARAMETER_READONLY;
       const AUTOMATIC = FMOD_STUDIO_PARAMETER_AUTOMATIC;
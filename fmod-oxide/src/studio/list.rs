@@ -0,0 +1,81 @@
+// Copyright (c) 2024 Lily Lyons
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use fmod_sys::*;
+use std::iter::FusedIterator;
+
+/// An iterator over a list that belongs to a Studio object, like the events in a [`super::Bank`] or the banks in a [`super::System`].
+///
+/// Nothing is fetched until the first call to [`Iterator::next`], and then the whole list is fetched at once (FMOD can't hand it out in pieces).
+/// That makes it cheap to chain, e.g. `system.banks().flat_map(|bank| bank.events())`.
+///
+/// If the list can't be fetched (for example because the bank was unloaded) the iterator is empty,
+/// and [`ListIter::error`] returns why.
+#[derive(Debug, Clone)]
+pub struct ListIter<O, T> {
+    owner: O,
+    fetch: fn(&O, &mut Vec<T>) -> Result<()>,
+    list: Option<std::vec::IntoIter<T>>,
+    error: Option<Error>,
+}
+
+impl<O, T> ListIter<O, T> {
+    pub(crate) fn new(owner: O, fetch: fn(&O, &mut Vec<T>) -> Result<()>) -> Self {
+        ListIter {
+            owner,
+            fetch,
+            list: None,
+            error: None,
+        }
+    }
+
+    /// The error fetching the list failed with, if it did.
+    ///
+    /// This is always `None` before the first call to [`Iterator::next`].
+    pub fn error(&self) -> Option<&Error> {
+        self.error.as_ref()
+    }
+
+    fn list(&mut self) -> &mut std::vec::IntoIter<T> {
+        let Self {
+            owner,
+            fetch,
+            list,
+            error,
+        } = self;
+        list.get_or_insert_with(|| {
+            let mut list = Vec::new();
+            if let Err(e) = fetch(owner, &mut list) {
+                *error = Some(e);
+                list.clear();
+            }
+            list.into_iter()
+        })
+    }
+}
+
+impl<O, T> Iterator for ListIter<O, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.list().next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match &self.list {
+            Some(list) => list.size_hint(),
+            None => (0, None),
+        }
+    }
+}
+
+impl<O, T> DoubleEndedIterator for ListIter<O, T> {
+    fn next_back(&mut self) -> Option<T> {
+        self.list().next_back()
+    }
+}
+
+impl<O, T> FusedIterator for ListIter<O, T> {}
@@ -0,0 +1,260 @@
+// Copyright (c) 2024 Lily Lyons
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use fmod_sys::*;
+use std::{
+    collections::BTreeMap,
+    ffi::c_float,
+    time::{Duration, Instant},
+};
+
+use crate::studio::{Bus, System, Vca};
+use crate::Guid;
+
+/// The state of a [`Bus`] that [`System::capture_mixer_state`] saves.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BusState {
+    /// The volume set with [`Bus::set_volume`], not the final volume.
+    pub volume: c_float,
+    pub mute: bool,
+    pub paused: bool,
+}
+
+/// The state of a [`Vca`] that [`System::capture_mixer_state`] saves.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VcaState {
+    /// The volume set with [`Vca::set_volume`], not the final volume.
+    pub volume: c_float,
+}
+
+/// A snapshot of every bus and VCA in the loaded banks, keyed by their [`Guid`].
+///
+/// This is what an options menu or photo mode would save and restore, and is serializable with the `serde` feature.
+/// Guids are serialized as strings, so this can be saved as JSON.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MixerState {
+    pub buses: BTreeMap<Guid, BusState>,
+    pub vcas: BTreeMap<Guid, VcaState>,
+}
+
+/// How a bus or VCA differs between two [`MixerState`]s.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MixerChange<T> {
+    /// Only in the newer state.
+    Added(T),
+    /// Only in the older state.
+    Removed(T),
+    Changed {
+        from: T,
+        to: T,
+    },
+}
+
+/// The differences between two [`MixerState`]s, created by [`MixerState::diff`].
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MixerDiff {
+    pub buses: BTreeMap<Guid, MixerChange<BusState>>,
+    pub vcas: BTreeMap<Guid, MixerChange<VcaState>>,
+}
+
+impl MixerState {
+    /// Compares this state against a newer state.
+    pub fn diff(&self, other: &MixerState) -> MixerDiff {
+        MixerDiff {
+            buses: diff_maps(&self.buses, &other.buses),
+            vcas: diff_maps(&self.vcas, &other.vcas),
+        }
+    }
+}
+
+impl MixerDiff {
+    /// Returns `true` if both states were the same.
+    pub fn is_empty(&self) -> bool {
+        self.buses.is_empty() && self.vcas.is_empty()
+    }
+}
+
+fn diff_maps<T: Copy + PartialEq>(
+    from: &BTreeMap<Guid, T>,
+    to: &BTreeMap<Guid, T>,
+) -> BTreeMap<Guid, MixerChange<T>> {
+    let mut changes = BTreeMap::new();
+    for (id, &old) in from {
+        match to.get(id) {
+            Some(&new) if new != old => {
+                changes.insert(*id, MixerChange::Changed { from: old, to: new });
+            }
+            Some(_) => {}
+            None => {
+                changes.insert(*id, MixerChange::Removed(old));
+            }
+        }
+    }
+    for (id, &new) in to {
+        if !from.contains_key(id) {
+            changes.insert(*id, MixerChange::Added(new));
+        }
+    }
+    changes
+}
+
+impl System {
+    /// Captures the volume, mute and pause state of every bus, and the volume of every VCA, in every loaded bank.
+    pub fn capture_mixer_state(&self) -> Result<MixerState> {
+        let mut state = MixerState::default();
+        for bank in self.get_bank_list()? {
+            // the same bus can be in more than one bank, like the master bus
+            for bus in bank.get_bus_list()? {
+                state.buses.insert(
+                    bus.get_id()?,
+                    BusState {
+                        volume: bus.get_volume()?.0,
+                        mute: bus.get_mute()?,
+                        paused: bus.get_paused()?,
+                    },
+                );
+            }
+            for vca in bank.get_vca_list()? {
+                state.vcas.insert(
+                    vca.get_id()?,
+                    VcaState {
+                        volume: vca.get_volume()?.0,
+                    },
+                );
+            }
+        }
+        Ok(state)
+    }
+
+    /// Restores a [`MixerState`], fading volumes to their saved value over `fade`.
+    ///
+    /// Mute and pause states are applied immediately.
+    /// Buses and VCAs that aren't in any loaded bank are skipped, so a state can be applied before every bank it was captured with is loaded.
+    ///
+    /// The returned [`MixerFade`] must be updated every frame until it finishes.
+    /// If `fade` is zero every volume is set immediately, and the returned [`MixerFade`] is already finished.
+    pub fn apply_mixer_state(&self, state: &MixerState, fade: Duration) -> Result<MixerFade> {
+        let mut targets = vec![];
+        for (&id, bus_state) in &state.buses {
+            let bus = match self.get_bus_by_id(id) {
+                Ok(bus) => bus,
+                Err(e) if e == FMOD_RESULT::FMOD_ERR_EVENT_NOTFOUND => continue,
+                Err(e) => return Err(e),
+            };
+            bus.set_mute(bus_state.mute)?;
+            bus.set_paused(bus_state.paused)?;
+            targets.push(VolumeTarget {
+                mixer: Mixer::Bus(bus),
+                start: bus.get_volume()?.0,
+                target: bus_state.volume,
+            });
+        }
+        for (&id, vca_state) in &state.vcas {
+            let vca = match self.get_vca_by_id(id) {
+                Ok(vca) => vca,
+                Err(e) if e == FMOD_RESULT::FMOD_ERR_EVENT_NOTFOUND => continue,
+                Err(e) => return Err(e),
+            };
+            targets.push(VolumeTarget {
+                mixer: Mixer::Vca(vca),
+                start: vca.get_volume()?.0,
+                target: vca_state.volume,
+            });
+        }
+
+        let mut fade = MixerFade {
+            targets,
+            duration: fade,
+            elapsed: Duration::ZERO,
+            last_update: None,
+        };
+        if fade.duration.is_zero() {
+            fade.advance(Duration::ZERO)?;
+        }
+        Ok(fade)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Mixer {
+    Bus(Bus),
+    Vca(Vca),
+}
+
+#[derive(Debug)]
+struct VolumeTarget {
+    mixer: Mixer,
+    start: c_float,
+    target: c_float,
+}
+
+/// A fade between the volumes of the mixer and a [`MixerState`], started by [`System::apply_mixer_state`].
+///
+/// [`MixerFade::update`] should be called once per frame, right before [`System::update`], until [`MixerFade::is_finished`] returns `true`.
+/// Dropping this stops the fade, leaving volumes wherever they were last set.
+#[derive(Debug)]
+pub struct MixerFade {
+    targets: Vec<VolumeTarget>,
+    duration: Duration,
+    elapsed: Duration,
+    last_update: Option<Instant>,
+}
+
+impl MixerFade {
+    pub fn is_finished(&self) -> bool {
+        self.targets.is_empty()
+    }
+
+    /// Advances the fade by the time since the last call to this function (or [`MixerFade::advance`]).
+    pub fn update(&mut self) -> Result<()> {
+        let now = Instant::now();
+        let delta = self
+            .last_update
+            .map_or(Duration::ZERO, |last| now.duration_since(last));
+        self.advance(delta)
+    }
+
+    /// Advances the fade by `delta`.
+    ///
+    /// Use this over [`MixerFade::update`] if you want the fade to follow your game's clock.
+    ///
+    /// Buses and VCAs that have been unloaded since the fade started are skipped.
+    /// If setting a volume fails for any other reason, the rest are still set and the first error is returned.
+    pub fn advance(&mut self, delta: Duration) -> Result<()> {
+        self.last_update = Some(Instant::now());
+        self.elapsed = (self.elapsed + delta).min(self.duration);
+        let progress = if self.duration.is_zero() {
+            1.0
+        } else {
+            self.elapsed.as_secs_f32() / self.duration.as_secs_f32()
+        };
+
+        let mut result = Ok(());
+        self.targets.retain(|target| {
+            let volume = target.start + (target.target - target.start) * progress;
+            let set = match target.mixer {
+                Mixer::Bus(bus) => bus.set_volume(volume),
+                Mixer::Vca(vca) => vca.set_volume(volume),
+            };
+            match set {
+                Ok(()) => progress < 1.0,
+                Err(e) if e == FMOD_RESULT::FMOD_ERR_INVALID_HANDLE => false,
+                Err(e) => {
+                    if result.is_ok() {
+                        result = Err(e);
+                    }
+                    progress < 1.0
+                }
+            }
+        });
+        result
+    }
+}
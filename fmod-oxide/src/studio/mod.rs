@@ -33,3 +33,24 @@ pub use event_instance::*;
 
 mod vca;
 pub use vca::*;
+
+mod parameter_handle;
+pub use parameter_handle::*;
+
+mod parameter_automator;
+pub use parameter_automator::*;
+
+mod event_pool;
+pub use event_pool::*;
+
+mod mixer_state;
+pub use mixer_state::*;
+
+mod profiler;
+pub use profiler::*;
+
+mod describe;
+pub use describe::*;
+
+mod list;
+pub use list::*;
@@ -0,0 +1,409 @@
+// Copyright (c) 2024 Lily Lyons
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use fmod_sys::*;
+use std::{
+    collections::VecDeque,
+    ffi::c_float,
+    time::{Duration, Instant},
+};
+
+use crate::studio::{EventInstance, ParameterID};
+use crate::Curve;
+
+// FMOD_Studio_EventInstance_SetParametersByIDs accepts at most 32 parameters per call
+const MAX_PARAMETERS_PER_CALL: usize = 32;
+
+// how many ended automations are kept for drain_finished, so they don't pile up if it is never called
+const MAX_FINISHED: usize = 256;
+
+/// Identifies an automation started by a [`ParameterAutomator`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct AutomationId(u64);
+
+/// Why an automation stopped running.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AutomationEnd {
+    /// The parameter reached its target value.
+    Completed,
+    /// Another automation was started on the same parameter of the same event instance.
+    Superseded,
+    /// The event instance was released or otherwise became invalid.
+    InstanceInvalid,
+}
+
+#[derive(Debug)]
+struct Automation {
+    id: AutomationId,
+    instance: EventInstance,
+    parameter: ParameterID,
+    start: c_float,
+    target: c_float,
+    duration: Duration,
+    elapsed: Duration,
+    curve: Curve,
+    invalid: bool,
+}
+
+impl Automation {
+    fn is_finished(&self) -> bool {
+        self.elapsed >= self.duration
+    }
+
+    fn value(&self) -> c_float {
+        if self.is_finished() {
+            return self.target;
+        }
+        let progress = self.elapsed.as_secs_f32() / self.duration.as_secs_f32();
+        self.start + (self.target - self.start) * self.curve.apply(progress)
+    }
+}
+
+/// Ramps event instance parameters towards target values over time.
+///
+/// The Studio API only sets parameters instantly (or at the seek speed authored in FMOD Studio).
+/// This advances every running ramp each time [`ParameterAutomator::update`] is called, and sets all the parameters of an event instance in one [`EventInstance::set_parameters_by_ids`] call.
+///
+/// [`ParameterAutomator::update`] should be called once per frame, right before [`System::update`](crate::studio::System::update), so the new values are submitted in that update.
+/// Parameters are always set with `ignore_seek_speed`, as the ramp already controls how fast the parameter changes.
+///
+/// Starting a ramp on a parameter that is already being automated replaces the old ramp, which ends with [`AutomationEnd::Superseded`].
+#[derive(Debug)]
+pub struct ParameterAutomator {
+    automations: Vec<Automation>,
+    finished: VecDeque<(AutomationId, AutomationEnd)>,
+    next_id: u64,
+    last_update: Option<Instant>,
+}
+
+impl Default for ParameterAutomator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ParameterAutomator {
+    pub fn new() -> Self {
+        ParameterAutomator {
+            automations: vec![],
+            finished: VecDeque::new(),
+            next_id: 0,
+            last_update: None,
+        }
+    }
+
+    /// Starts ramping a parameter from its current value to `target` over `duration`.
+    ///
+    /// The current value is retrieved with [`EventInstance::get_parameter_by_id`].
+    pub fn ramp(
+        &mut self,
+        instance: EventInstance,
+        parameter: ParameterID,
+        target: c_float,
+        duration: Duration,
+        curve: Curve,
+    ) -> Result<AutomationId> {
+        // if this parameter is already being automated, continue from where that automation left off rather than what FMOD has
+        let start = match self
+            .automations
+            .iter()
+            .find(|a| a.instance == instance && a.parameter == parameter)
+        {
+            Some(automation) => automation.value(),
+            None => instance.get_parameter_by_id(parameter)?.0,
+        };
+        Ok(self.ramp_from(instance, parameter, start, target, duration, curve))
+    }
+
+    /// Starts ramping a parameter from `start` to `target` over `duration`.
+    pub fn ramp_from(
+        &mut self,
+        instance: EventInstance,
+        parameter: ParameterID,
+        start: c_float,
+        target: c_float,
+        duration: Duration,
+        curve: Curve,
+    ) -> AutomationId {
+        let finished = &mut self.finished;
+        self.automations.retain(|a| {
+            let superseded = a.instance == instance && a.parameter == parameter;
+            if superseded {
+                push_finished(finished, a.id, AutomationEnd::Superseded);
+            }
+            !superseded
+        });
+
+        let id = AutomationId(self.next_id);
+        self.next_id += 1;
+        self.automations.push(Automation {
+            id,
+            instance,
+            parameter,
+            start,
+            target,
+            duration,
+            elapsed: Duration::ZERO,
+            curve,
+            invalid: false,
+        });
+        id
+    }
+
+    /// Stops an automation, leaving the parameter at whatever value it was last set to.
+    ///
+    /// Returns `false` if the automation had already ended.
+    /// Cancelled automations are not reported by [`ParameterAutomator::drain_finished`].
+    pub fn cancel(&mut self, id: AutomationId) -> bool {
+        let len = self.automations.len();
+        self.automations.retain(|a| a.id != id);
+        self.automations.len() != len
+    }
+
+    /// Stops every automation running on `instance`.
+    pub fn cancel_instance(&mut self, instance: EventInstance) {
+        self.automations.retain(|a| a.instance != instance);
+    }
+
+    /// Stops every automation.
+    pub fn clear(&mut self) {
+        self.automations.clear();
+    }
+
+    pub fn is_running(&self, id: AutomationId) -> bool {
+        self.automations.iter().any(|a| a.id == id)
+    }
+
+    /// The number of automations that are still running.
+    pub fn len(&self) -> usize {
+        self.automations.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.automations.is_empty()
+    }
+
+    /// Advances all automations by the time since the last call to this function (or [`ParameterAutomator::advance`]).
+    ///
+    /// The first call does not advance anything, it only sets the starting parameter values.
+    pub fn update(&mut self) -> Result<()> {
+        let now = Instant::now();
+        let delta = self
+            .last_update
+            .map_or(Duration::ZERO, |last| now.duration_since(last));
+        self.advance(delta)
+    }
+
+    /// Advances all automations by `delta`.
+    ///
+    /// Use this over [`ParameterAutomator::update`] if you want automations to follow your game's clock (e.g. so they stop when the game is paused).
+    ///
+    /// If setting the parameters of an instance fails, the other instances are still updated and the first error is returned.
+    /// Automations on instances that have become invalid are removed and end with [`AutomationEnd::InstanceInvalid`].
+    pub fn advance(&mut self, delta: Duration) -> Result<()> {
+        self.last_update = Some(Instant::now());
+
+        let mut batches: Vec<(EventInstance, Vec<ParameterID>, Vec<c_float>)> = vec![];
+        for automation in &mut self.automations {
+            automation.elapsed = (automation.elapsed + delta).min(automation.duration);
+            let value = automation.value();
+
+            match batches
+                .iter_mut()
+                .find(|(instance, ..)| *instance == automation.instance)
+            {
+                Some((_, ids, values)) => {
+                    ids.push(automation.parameter);
+                    values.push(value);
+                }
+                None => {
+                    batches.push((automation.instance, vec![automation.parameter], vec![value]));
+                }
+            }
+        }
+
+        let mut result = Ok(());
+        for (instance, ids, mut values) in batches {
+            let mut invalid = !instance.is_valid();
+            if !invalid {
+                for (ids, values) in ids
+                    .chunks(MAX_PARAMETERS_PER_CALL)
+                    .zip(values.chunks_mut(MAX_PARAMETERS_PER_CALL))
+                {
+                    match instance.set_parameters_by_ids(ids, values, true) {
+                        Ok(()) => {}
+                        Err(e) if e == FMOD_RESULT::FMOD_ERR_INVALID_HANDLE => invalid = true,
+                        Err(e) => {
+                            if result.is_ok() {
+                                result = Err(e);
+                            }
+                        }
+                    }
+                }
+            }
+
+            if invalid {
+                for automation in &mut self.automations {
+                    if automation.instance == instance {
+                        automation.invalid = true;
+                    }
+                }
+            }
+        }
+
+        let finished = &mut self.finished;
+        self.automations.retain(|a| {
+            let end = if a.invalid {
+                AutomationEnd::InstanceInvalid
+            } else if a.is_finished() {
+                AutomationEnd::Completed
+            } else {
+                return true;
+            };
+            push_finished(finished, a.id, end);
+            false
+        });
+
+        result
+    }
+
+    /// Removes and returns every automation that has ended since the last call to this function, in the order they ended.
+    ///
+    /// Only the last 256 are kept, so if this isn't called regularly the oldest ones are lost.
+    pub fn drain_finished(
+        &mut self,
+    ) -> std::collections::vec_deque::Drain<'_, (AutomationId, AutomationEnd)> {
+        self.finished.drain(..)
+    }
+}
+
+fn push_finished(
+    finished: &mut VecDeque<(AutomationId, AutomationEnd)>,
+    id: AutomationId,
+    end: AutomationEnd,
+) {
+    if finished.len() == MAX_FINISHED {
+        finished.pop_front();
+    }
+    finished.push_back((id, end));
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)] // the values compared are exact
+mod tests {
+    use super::*;
+
+    // these tests never call into FMOD, so the instances don't need to exist
+    fn instance(handle: usize) -> EventInstance {
+        EventInstance::from(handle as *mut FMOD_STUDIO_EVENTINSTANCE)
+    }
+
+    const VOLUME: ParameterID = ParameterID {
+        data_1: 1,
+        data_2: 0,
+    };
+
+    #[test]
+    fn ramps_reach_target() {
+        let mut automator = ParameterAutomator::new();
+        automator.ramp_from(
+            instance(1),
+            VOLUME,
+            0.0,
+            4.0,
+            Duration::from_secs(2),
+            Curve::Linear,
+        );
+        let automation = &mut automator.automations[0];
+        assert_eq!(automation.value(), 0.0);
+        automation.elapsed = Duration::from_millis(500);
+        assert_eq!(automation.value(), 1.0);
+        automation.elapsed = Duration::from_secs(2);
+        assert!(automation.is_finished());
+        assert_eq!(automation.value(), 4.0);
+
+        // the target is reached even if the curve doesn't end at 1
+        automator.ramp_from(
+            instance(1),
+            VOLUME,
+            0.0,
+            4.0,
+            Duration::ZERO,
+            Curve::Custom(|_| 0.5),
+        );
+        assert_eq!(automator.automations[0].value(), 4.0);
+    }
+
+    #[test]
+    fn retargeting_continues_from_current_value() {
+        let mut automator = ParameterAutomator::new();
+        let first = automator.ramp_from(
+            instance(1),
+            VOLUME,
+            0.0,
+            1.0,
+            Duration::from_secs(1),
+            Curve::Linear,
+        );
+        let other = automator.ramp_from(
+            instance(2),
+            VOLUME,
+            0.0,
+            1.0,
+            Duration::from_secs(1),
+            Curve::Linear,
+        );
+        automator.automations[0].elapsed = Duration::from_millis(250);
+
+        // the running ramp is used instead of asking FMOD for the current value
+        let second = automator
+            .ramp(
+                instance(1),
+                VOLUME,
+                0.0,
+                Duration::from_secs(1),
+                Curve::Linear,
+            )
+            .unwrap();
+        assert!(!automator.is_running(first));
+        assert!(automator.is_running(second));
+        assert!(automator.is_running(other));
+        assert_eq!(automator.len(), 2);
+
+        let automation = automator.automations.iter().find(|a| a.id == second);
+        assert_eq!(automation.unwrap().start, 0.25);
+        assert_eq!(
+            automator.drain_finished().collect::<Vec<_>>(),
+            [(first, AutomationEnd::Superseded)]
+        );
+    }
+
+    #[test]
+    fn finished_automations_are_capped() {
+        let mut automator = ParameterAutomator::new();
+        let count = MAX_FINISHED + 10;
+        for _ in 0..=count {
+            automator.ramp_from(
+                instance(1),
+                VOLUME,
+                0.0,
+                1.0,
+                Duration::from_secs(1),
+                Curve::Linear,
+            );
+        }
+
+        // every ramp but the last was superseded, and only the newest are kept
+        let finished: Vec<_> = automator.drain_finished().collect();
+        assert_eq!(finished.len(), MAX_FINISHED);
+        assert_eq!(finished[0].0, AutomationId(10));
+        assert_eq!(
+            finished.last(),
+            Some(&(AutomationId(count as u64 - 1), AutomationEnd::Superseded))
+        );
+        assert_eq!(automator.drain_finished().count(), 0);
+    }
+}
@@ -0,0 +1,527 @@
+// Copyright (c) 2024 Lily Lyons
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use fmod_sys::*;
+use lanyard::{Utf8CStr, Utf8CString};
+use std::{
+    ffi::{c_float, c_int},
+    marker::PhantomData,
+    ops::Deref,
+};
+
+use crate::studio::{EventInstance, ParameterDescription, ParameterFlags, ParameterID, System};
+
+/// A type that can be used as the value of an [`EventParameter`] or [`GlobalParameter`].
+pub trait ParameterValue: Copy {
+    /// Converts this into the raw value FMOD expects.
+    fn into_value(self) -> c_float;
+
+    /// Checks if a parameter can hold values of this type.
+    ///
+    /// This is called once when the parameter handle is created, not every time the parameter is set.
+    #[allow(unused_variables)]
+    fn check(description: &ParameterDescription) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl ParameterValue for c_float {
+    fn into_value(self) -> c_float {
+        self
+    }
+}
+
+/// Only valid for discrete and labeled parameters.
+impl ParameterValue for c_int {
+    fn into_value(self) -> c_float {
+        self as c_float
+    }
+
+    fn check(description: &ParameterDescription) -> Result<()> {
+        if description
+            .flags
+            .intersects(ParameterFlags::DISCRETE | ParameterFlags::LABELED)
+        {
+            Ok(())
+        } else {
+            Err(Error::Fmod(FMOD_RESULT::FMOD_ERR_INVALID_PARAM))
+        }
+    }
+}
+
+/// Only valid for discrete and labeled parameters with a range that includes 0 and 1.
+impl ParameterValue for bool {
+    fn into_value(self) -> c_float {
+        if self {
+            1.0
+        } else {
+            0.0
+        }
+    }
+
+    fn check(description: &ParameterDescription) -> Result<()> {
+        c_int::check(description)?;
+        if description.minimum <= 0.0 && description.maximum >= 1.0 {
+            Ok(())
+        } else {
+            Err(Error::Fmod(FMOD_RESULT::FMOD_ERR_INVALID_PARAM))
+        }
+    }
+}
+
+/// Everything about a parameter that [`EventParameter`] and [`GlobalParameter`] cache.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParameterInfo {
+    pub description: ParameterDescription,
+    /// The labels of this parameter, if it is labeled.
+    ///
+    /// The label at index `i` corresponds to the value `minimum + i`.
+    pub labels: Vec<Utf8CString>,
+}
+
+impl ParameterInfo {
+    fn new(
+        description: ParameterDescription,
+        mut get_label: impl FnMut(c_int) -> Result<Utf8CString>,
+    ) -> Result<Self> {
+        let labels = (0..description.label_count())
+            .map(&mut get_label)
+            .collect::<Result<_>>()?;
+        Ok(ParameterInfo {
+            description,
+            labels,
+        })
+    }
+
+    pub fn id(&self) -> ParameterID {
+        self.description.id
+    }
+
+    pub fn name(&self) -> &Utf8CStr {
+        &self.description.name
+    }
+
+    pub fn minimum(&self) -> c_float {
+        self.description.minimum
+    }
+
+    pub fn maximum(&self) -> c_float {
+        self.description.maximum
+    }
+
+    pub fn default_value(&self) -> c_float {
+        self.description.default_value
+    }
+
+    pub fn is_discrete(&self) -> bool {
+        self.description.flags.contains(ParameterFlags::DISCRETE)
+    }
+
+    pub fn is_labeled(&self) -> bool {
+        self.description.flags.contains(ParameterFlags::LABELED)
+    }
+
+    pub fn is_read_only(&self) -> bool {
+        self.description.flags.contains(ParameterFlags::READONLY)
+    }
+
+    /// Looks up the value of a label.
+    ///
+    /// This lookup is case sensitive, like FMOD's.
+    pub fn label_value(&self, label: &str) -> Option<c_float> {
+        self.labels
+            .iter()
+            .position(|l| l.as_str() == label)
+            .map(|index| self.description.minimum + index as c_float)
+    }
+
+    /// Looks up the label of a value.
+    pub fn value_label(&self, value: c_float) -> Option<&Utf8CStr> {
+        let index = value - self.description.minimum;
+        if index < 0.0 || index.fract() != 0.0 {
+            return None;
+        }
+        self.labels
+            .get(index as usize)
+            .map(Utf8CString::as_utf8_cstr)
+    }
+
+    fn validate(&self, value: c_float) -> Result<c_float> {
+        if value.is_finite()
+            && value >= self.description.minimum
+            && value <= self.description.maximum
+        {
+            Ok(value)
+        } else {
+            Err(Error::Fmod(FMOD_RESULT::FMOD_ERR_INVALID_PARAM))
+        }
+    }
+
+    fn validate_label(&self, label: &str) -> Result<c_float> {
+        self.label_value(label)
+            .ok_or(Error::Fmod(FMOD_RESULT::FMOD_ERR_EVENT_NOTFOUND))
+    }
+}
+
+/// A handle to an event parameter that caches its ID, range, flags and labels.
+///
+/// Setting parameters by name hashes the name every time, and setting them by ID requires looking up the ID first.
+/// This looks up everything once when it is created, so setting the parameter is as cheap as [`EventInstance::set_parameter_by_id`].
+///
+/// Created by [`EventDescription::parameter`](crate::studio::EventDescription::parameter).
+#[derive(Debug, Clone, PartialEq)]
+pub struct EventParameter<K = c_float> {
+    info: ParameterInfo,
+    kind: PhantomData<fn(K)>,
+}
+
+impl<K: ParameterValue> EventParameter<K> {
+    pub(crate) fn new(
+        description: ParameterDescription,
+        get_label: impl FnMut(c_int) -> Result<Utf8CString>,
+    ) -> Result<Self> {
+        // global parameters can't be set through an event instance
+        if description.flags.contains(ParameterFlags::GLOBAL) {
+            return Err(Error::Fmod(FMOD_RESULT::FMOD_ERR_INVALID_PARAM));
+        }
+        K::check(&description)?;
+
+        Ok(EventParameter {
+            info: ParameterInfo::new(description, get_label)?,
+            kind: PhantomData,
+        })
+    }
+
+    /// Sets the value of this parameter on `instance`.
+    ///
+    /// If the value is outside of the parameter's range, [`FMOD_RESULT::FMOD_ERR_INVALID_PARAM`] is returned.
+    pub fn set(&self, instance: EventInstance, value: K, ignore_seek_speed: bool) -> Result<()> {
+        let value = self.info.validate(value.into_value())?;
+        instance.set_parameter_by_id(self.info.id(), value, ignore_seek_speed)
+    }
+
+    /// Sets the value of this parameter on `instance` by label, without asking FMOD to look up the label.
+    ///
+    /// If the label is not found, [`FMOD_RESULT::FMOD_ERR_EVENT_NOTFOUND`] is returned.
+    /// This lookup is case sensitive.
+    pub fn set_label(
+        &self,
+        instance: EventInstance,
+        label: &str,
+        ignore_seek_speed: bool,
+    ) -> Result<()> {
+        let value = self.info.validate_label(label)?;
+        instance.set_parameter_by_id(self.info.id(), value, ignore_seek_speed)
+    }
+
+    /// Retrieves the value of this parameter on `instance`.
+    ///
+    /// See [`EventInstance::get_parameter_by_id`].
+    pub fn get(&self, instance: EventInstance) -> Result<(c_float, c_float)> {
+        instance.get_parameter_by_id(self.info.id())
+    }
+}
+
+impl<K> Deref for EventParameter<K> {
+    type Target = ParameterInfo;
+
+    fn deref(&self) -> &Self::Target {
+        &self.info
+    }
+}
+
+/// A handle to a global parameter that caches its ID, range, flags and labels.
+///
+/// Created by [`System::parameter`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct GlobalParameter<K = c_float> {
+    system: System,
+    info: ParameterInfo,
+    kind: PhantomData<fn(K)>,
+}
+
+impl<K: ParameterValue> GlobalParameter<K> {
+    pub(crate) fn new(
+        system: System,
+        description: ParameterDescription,
+        get_label: impl FnMut(c_int) -> Result<Utf8CString>,
+    ) -> Result<Self> {
+        K::check(&description)?;
+
+        Ok(GlobalParameter {
+            system,
+            info: ParameterInfo::new(description, get_label)?,
+            kind: PhantomData,
+        })
+    }
+
+    /// Sets the value of this parameter.
+    ///
+    /// If the value is outside of the parameter's range, [`FMOD_RESULT::FMOD_ERR_INVALID_PARAM`] is returned.
+    pub fn set(&self, value: K, ignore_seek_speed: bool) -> Result<()> {
+        let value = self.info.validate(value.into_value())?;
+        self.system
+            .set_parameter_by_id(self.info.id(), value, ignore_seek_speed)
+    }
+
+    /// Sets the value of this parameter by label, without asking FMOD to look up the label.
+    ///
+    /// If the label is not found, [`FMOD_RESULT::FMOD_ERR_EVENT_NOTFOUND`] is returned.
+    /// This lookup is case sensitive.
+    pub fn set_label(&self, label: &str, ignore_seek_speed: bool) -> Result<()> {
+        let value = self.info.validate_label(label)?;
+        self.system
+            .set_parameter_by_id(self.info.id(), value, ignore_seek_speed)
+    }
+
+    /// Retrieves the value of this parameter.
+    ///
+    /// See [`System::get_parameter_by_id`].
+    pub fn get(&self) -> Result<(c_float, c_float)> {
+        self.system.get_parameter_by_id(self.info.id())
+    }
+
+    pub fn system(&self) -> System {
+        self.system
+    }
+}
+
+impl<K> Deref for GlobalParameter<K> {
+    type Target = ParameterInfo;
+
+    fn deref(&self) -> &Self::Target {
+        &self.info
+    }
+}
+
+/// An [`EventParameter`] ID that knows the type of its values, without caching anything else.
+///
+/// Unlike [`EventParameter`] this isn't checked against the parameter's description, so it can be a constant.
+/// This is what fmod-codegen generates for event parameters.
+pub struct EventParameterID<K = c_float> {
+    id: ParameterID,
+    kind: PhantomData<fn(K)>,
+}
+
+impl<K> EventParameterID<K> {
+    pub const fn new(id: ParameterID) -> Self {
+        EventParameterID {
+            id,
+            kind: PhantomData,
+        }
+    }
+
+    pub const fn id(self) -> ParameterID {
+        self.id
+    }
+}
+
+impl<K: ParameterValue> EventParameterID<K> {
+    /// Sets the value of this parameter on `instance`.
+    ///
+    /// See [`EventInstance::set_parameter_by_id`].
+    pub fn set(self, instance: EventInstance, value: K, ignore_seek_speed: bool) -> Result<()> {
+        instance.set_parameter_by_id(self.id, value.into_value(), ignore_seek_speed)
+    }
+
+    /// Retrieves the value of this parameter on `instance`.
+    ///
+    /// See [`EventInstance::get_parameter_by_id`].
+    pub fn get(self, instance: EventInstance) -> Result<(c_float, c_float)> {
+        instance.get_parameter_by_id(self.id)
+    }
+}
+
+/// A [`GlobalParameter`] ID that knows the type of its values, without caching anything else.
+///
+/// Unlike [`GlobalParameter`] this isn't checked against the parameter's description, so it can be a constant.
+/// This is what fmod-codegen generates for global parameters.
+pub struct GlobalParameterID<K = c_float> {
+    id: ParameterID,
+    kind: PhantomData<fn(K)>,
+}
+
+impl<K> GlobalParameterID<K> {
+    pub const fn new(id: ParameterID) -> Self {
+        GlobalParameterID {
+            id,
+            kind: PhantomData,
+        }
+    }
+
+    pub const fn id(self) -> ParameterID {
+        self.id
+    }
+}
+
+impl<K: ParameterValue> GlobalParameterID<K> {
+    /// Sets the value of this parameter.
+    ///
+    /// See [`System::set_parameter_by_id`].
+    pub fn set(self, system: System, value: K, ignore_seek_speed: bool) -> Result<()> {
+        system.set_parameter_by_id(self.id, value.into_value(), ignore_seek_speed)
+    }
+
+    /// Retrieves the value of this parameter.
+    ///
+    /// See [`System::get_parameter_by_id`].
+    pub fn get(self, system: System) -> Result<(c_float, c_float)> {
+        system.get_parameter_by_id(self.id)
+    }
+}
+
+// derives would require K to implement these traits too, which f32 doesn't for Eq and Hash
+macro_rules! impl_parameter_id_traits {
+    ($($name:ident),*) => {
+        $(
+            impl<K> Clone for $name<K> {
+                fn clone(&self) -> Self {
+                    *self
+                }
+            }
+
+            impl<K> Copy for $name<K> {}
+
+            impl<K> PartialEq for $name<K> {
+                fn eq(&self, other: &Self) -> bool {
+                    self.id == other.id
+                }
+            }
+
+            impl<K> Eq for $name<K> {}
+
+            impl<K> std::hash::Hash for $name<K> {
+                fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+                    self.id.hash(state);
+                }
+            }
+
+            impl<K> std::fmt::Debug for $name<K> {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    f.debug_tuple(stringify!($name)).field(&self.id).finish()
+                }
+            }
+
+            impl<K> From<$name<K>> for ParameterID {
+                fn from(value: $name<K>) -> Self {
+                    value.id
+                }
+            }
+        )*
+    };
+}
+
+impl_parameter_id_traits!(EventParameterID, GlobalParameterID);
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)] // the values compared are exact
+mod tests {
+    use super::*;
+    use crate::Guid;
+
+    fn description(
+        minimum: c_float,
+        maximum: c_float,
+        flags: ParameterFlags,
+    ) -> ParameterDescription {
+        ParameterDescription {
+            name: Utf8CString::new("Surface").unwrap(),
+            id: ParameterID {
+                data_1: 1,
+                data_2: 2,
+            },
+            minimum,
+            maximum,
+            default_value: minimum,
+            kind: crate::studio::ParameterKind::GameControlled,
+            flags,
+            guid: Guid::default(),
+        }
+    }
+
+    fn labeled(minimum: c_float, labels: &[&str]) -> ParameterInfo {
+        let maximum = minimum + (labels.len() - 1) as c_float;
+        let description = description(
+            minimum,
+            maximum,
+            ParameterFlags::DISCRETE | ParameterFlags::LABELED,
+        );
+        ParameterInfo::new(description, |index| {
+            Ok(Utf8CString::new(labels[index as usize]).unwrap())
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn values_convert_to_floats() {
+        assert_eq!(0.5.into_value(), 0.5);
+        assert_eq!(3.into_value(), 3.0);
+        assert_eq!((-2).into_value(), -2.0);
+        assert_eq!(true.into_value(), 1.0);
+        assert_eq!(false.into_value(), 0.0);
+    }
+
+    #[test]
+    fn values_check_parameter_kind() {
+        let invalid = Err(Error::Fmod(FMOD_RESULT::FMOD_ERR_INVALID_PARAM));
+        let continuous = description(0.0, 1.0, ParameterFlags::empty());
+        let discrete = description(0.0, 4.0, ParameterFlags::DISCRETE);
+        let labeled = description(0.0, 1.0, ParameterFlags::DISCRETE | ParameterFlags::LABELED);
+        let positive = description(1.0, 4.0, ParameterFlags::DISCRETE);
+
+        assert_eq!(c_float::check(&continuous), Ok(()));
+        assert_eq!(c_float::check(&discrete), Ok(()));
+
+        assert_eq!(c_int::check(&continuous), invalid);
+        assert_eq!(c_int::check(&discrete), Ok(()));
+        assert_eq!(c_int::check(&labeled), Ok(()));
+
+        assert_eq!(bool::check(&continuous), invalid);
+        assert_eq!(bool::check(&discrete), Ok(()));
+        assert_eq!(bool::check(&labeled), Ok(()));
+        // the range has to include both 0 and 1
+        assert_eq!(bool::check(&positive), invalid);
+    }
+
+    #[test]
+    fn event_parameters_reject_global_parameters() {
+        let global = description(0.0, 1.0, ParameterFlags::GLOBAL);
+        let result = EventParameter::<c_float>::new(global, |_| unreachable!());
+        assert_eq!(
+            result.unwrap_err(),
+            Error::Fmod(FMOD_RESULT::FMOD_ERR_INVALID_PARAM)
+        );
+    }
+
+    #[test]
+    fn labels_map_to_values() {
+        let info = labeled(2.0, &["Grass", "Gravel", "Wood"]);
+        assert_eq!(info.labels.len(), 3);
+        assert_eq!(info.label_value("Gravel"), Some(3.0));
+        assert_eq!(info.label_value("gravel"), None);
+        assert_eq!(info.value_label(4.0).map(Utf8CStr::as_str), Some("Wood"));
+        assert_eq!(info.value_label(1.0), None);
+        assert_eq!(info.value_label(2.5), None);
+        assert_eq!(info.value_label(5.0), None);
+
+        assert_eq!(info.validate_label("Grass"), Ok(2.0));
+        assert_eq!(
+            info.validate_label("Carpet"),
+            Err(Error::Fmod(FMOD_RESULT::FMOD_ERR_EVENT_NOTFOUND))
+        );
+    }
+
+    #[test]
+    fn values_are_validated_against_range() {
+        let info = labeled(0.0, &["Off", "On"]);
+        let invalid = Err(Error::Fmod(FMOD_RESULT::FMOD_ERR_INVALID_PARAM));
+        assert_eq!(info.validate(0.0), Ok(0.0));
+        assert_eq!(info.validate(1.0), Ok(1.0));
+        assert_eq!(info.validate(1.5), invalid);
+        assert_eq!(info.validate(-0.5), invalid);
+        assert_eq!(info.validate(c_float::NAN), invalid);
+    }
+}
@@ -0,0 +1,665 @@
+// Copyright (c) 2024 Lily Lyons
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use fmod_sys::*;
+use std::{
+    collections::VecDeque,
+    ffi::{c_float, c_int, c_longlong},
+    io::Write,
+    time::{Duration, Instant},
+};
+
+use crate::studio::{
+    BufferInfo, BufferUsage, Bus, CpuUsage, EventDescription, MemoryUsage, System,
+};
+use crate::Guid;
+
+/// Limits that [`Profiler::sample`] checks every sample against.
+///
+/// Every limit is optional, and `None` by default.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Budget {
+    /// The most mixer (DSP) CPU usage allowed, in percent.
+    pub mixer_cpu: Option<c_float>,
+    /// The most Studio update CPU usage allowed, in percent.
+    pub update_cpu: Option<c_float>,
+    /// The most memory the Studio system may use, in bytes.
+    ///
+    /// Memory usage is only reported by the logging version of FMOD.
+    pub memory: Option<c_int>,
+    /// How full the Studio command queue may get at its peak, from `0.0` to `1.0` of its capacity.
+    pub command_queue_peak: Option<c_float>,
+    /// How full the Studio handle table may get at its peak, from `0.0` to `1.0` of its capacity.
+    pub handle_peak: Option<c_float>,
+    /// The most channels that may be playing at once, including virtual channels.
+    pub playing_channels: Option<c_int>,
+}
+
+/// A limit in a [`Budget`] that a sample went over.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BudgetOverrun {
+    MixerCpu { usage: c_float, budget: c_float },
+    UpdateCpu { usage: c_float, budget: c_float },
+    Memory { usage: c_int, budget: c_int },
+    CommandQueuePeak { usage: c_float, budget: c_float },
+    HandlePeak { usage: c_float, budget: c_float },
+    PlayingChannels { usage: c_int, budget: c_int },
+}
+
+impl BudgetOverrun {
+    /// A short name for the limit that was exceeded, as used by [`Profiler::write_csv`].
+    pub fn name(&self) -> &'static str {
+        match self {
+            BudgetOverrun::MixerCpu { .. } => "mixer_cpu",
+            BudgetOverrun::UpdateCpu { .. } => "update_cpu",
+            BudgetOverrun::Memory { .. } => "memory",
+            BudgetOverrun::CommandQueuePeak { .. } => "command_queue_peak",
+            BudgetOverrun::HandlePeak { .. } => "handle_peak",
+            BudgetOverrun::PlayingChannels { .. } => "playing_channels",
+        }
+    }
+}
+
+/// Everything [`Profiler::sample`] records about a single update.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProfileSample {
+    /// The time since the profiler was created.
+    pub time: Duration,
+    pub core_cpu: crate::CpuUsage,
+    pub studio_cpu: CpuUsage,
+    pub memory: MemoryUsage,
+    pub buffers: BufferUsage,
+    pub playing_channels: c_int,
+    pub real_channels: c_int,
+    /// Bytes read from disk for sample data, since the system was created.
+    pub sample_bytes_read: c_longlong,
+    /// Bytes read from disk for streams, since the system was created.
+    pub stream_bytes_read: c_longlong,
+    /// Bytes read from disk for everything else (like banks), since the system was created.
+    pub other_bytes_read: c_longlong,
+    pub overruns: Vec<BudgetOverrun>,
+}
+
+/// The minimum, maximum, mean and latest value of a statistic.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Stat {
+    pub last: f64,
+    pub min: f64,
+    pub max: f64,
+    pub mean: f64,
+}
+
+/// CPU and memory statistics of a [`Bus`] or [`EventDescription`] tracked by a [`Profiler`].
+///
+/// CPU times are in microseconds, and memory is in bytes.
+/// For an [`EventDescription`] these are the totals of every instance of it.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UsageStats {
+    /// How many samples these statistics were collected from.
+    pub samples: u64,
+    pub cpu_exclusive: Stat,
+    pub cpu_inclusive: Stat,
+    pub memory_exclusive: Stat,
+    pub memory_inclusive: Stat,
+    /// The number of instances. Always 1 for buses.
+    pub instances: Stat,
+}
+
+impl Stat {
+    fn record(&mut self, samples: u64, value: f64) {
+        if samples == 0 {
+            *self = Stat {
+                last: value,
+                min: value,
+                max: value,
+                mean: value,
+            };
+            return;
+        }
+        self.last = value;
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+        self.mean += (value - self.mean) / (samples + 1) as f64;
+    }
+}
+
+impl UsageStats {
+    fn record(&mut self, cpu: (u64, u64), memory: MemoryUsage, instances: usize) {
+        self.cpu_exclusive.record(self.samples, cpu.0 as f64);
+        self.cpu_inclusive.record(self.samples, cpu.1 as f64);
+        self.memory_exclusive
+            .record(self.samples, f64::from(memory.exclusive));
+        self.memory_inclusive
+            .record(self.samples, f64::from(memory.inclusive));
+        self.instances.record(self.samples, instances as f64);
+        self.samples += 1;
+    }
+}
+
+/// Samples CPU, memory and buffer usage of a Studio system over time.
+///
+/// The Studio profiling functions like [`System::get_cpu_usage`] only return the current value.
+/// Calling [`Profiler::sample`] once after every [`System::update`] records them into a fixed size history,
+/// and checks them against a [`Budget`].
+///
+/// Buses and event descriptions can also be tracked with [`Profiler::track_bus`] and [`Profiler::track_event`].
+/// Their history isn't kept, only [`UsageStats`] across every sample since they were tracked.
+/// Bus and event CPU usage is only measured if the core system was initialized with [`InitFlags::PROFILE_ENABLE`](crate::InitFlags::PROFILE_ENABLE).
+///
+/// The history can be exported with [`Profiler::write_csv`].
+/// With the `serde` feature the profiler can also be serialized, as its history and the statistics of every tracked bus and event (keyed by their ID).
+#[derive(Debug)]
+pub struct Profiler {
+    system: System,
+    budget: Budget,
+    history: VecDeque<ProfileSample>,
+    capacity: usize,
+    start: Instant,
+    buses: Vec<(Bus, Guid, UsageStats)>,
+    events: Vec<(EventDescription, Guid, UsageStats)>,
+    overrun_count: u64,
+}
+
+impl Profiler {
+    /// Creates a profiler that keeps the last `capacity` samples (at least 1).
+    pub fn new(system: System, capacity: usize, budget: Budget) -> Self {
+        let capacity = capacity.max(1);
+        Profiler {
+            system,
+            budget,
+            history: VecDeque::with_capacity(capacity),
+            capacity,
+            start: Instant::now(),
+            buses: vec![],
+            events: vec![],
+            overrun_count: 0,
+        }
+    }
+
+    pub fn budget(&self) -> Budget {
+        self.budget
+    }
+
+    pub fn set_budget(&mut self, budget: Budget) {
+        self.budget = budget;
+    }
+
+    /// Starts collecting [`UsageStats`] for a bus.
+    ///
+    /// Tracking a bus that is already tracked does nothing.
+    pub fn track_bus(&mut self, bus: Bus) -> Result<()> {
+        if !self.buses.iter().any(|(b, ..)| *b == bus) {
+            self.buses.push((bus, bus.get_id()?, UsageStats::default()));
+        }
+        Ok(())
+    }
+
+    /// Starts collecting [`UsageStats`] for every instance of an event.
+    ///
+    /// Tracking an event that is already tracked does nothing.
+    pub fn track_event(&mut self, description: EventDescription) -> Result<()> {
+        if !self.events.iter().any(|(d, ..)| *d == description) {
+            self.events
+                .push((description, description.get_id()?, UsageStats::default()));
+        }
+        Ok(())
+    }
+
+    pub fn untrack_bus(&mut self, bus: Bus) {
+        self.buses.retain(|(b, ..)| *b != bus);
+    }
+
+    pub fn untrack_event(&mut self, description: EventDescription) {
+        self.events.retain(|(d, ..)| *d != description);
+    }
+
+    /// Records a sample, returning it so overruns can be acted on immediately.
+    ///
+    /// Tracked buses and events that have been unloaded are no longer tracked.
+    pub fn sample(&mut self) -> Result<&ProfileSample> {
+        let core = self.system.get_core_system()?;
+        let (studio_cpu, core_cpu) = self.system.get_cpu_usage()?;
+        let memory = self.system.get_memory_usage()?;
+        let buffers = self.system.get_buffer_usage()?;
+        let (playing_channels, real_channels) = core.get_playing_channels()?;
+        let (sample_bytes_read, stream_bytes_read, other_bytes_read) = core.get_file_usage()?;
+
+        let mut sample = ProfileSample {
+            time: self.start.elapsed(),
+            core_cpu,
+            studio_cpu,
+            memory,
+            buffers,
+            playing_channels,
+            real_channels,
+            sample_bytes_read,
+            stream_bytes_read,
+            other_bytes_read,
+            overruns: vec![],
+        };
+        sample.overruns = self.budget.check(&sample);
+
+        // everything is queried before any statistics are recorded, so a failed sample doesn't count for some buses and events but not others
+        let mut result = Ok(());
+        let bus_usage: Vec<_> = self
+            .buses
+            .iter()
+            .map(|(bus, ..)| {
+                let usage = bus.get_cpu_usage().and_then(|(exclusive, inclusive)| {
+                    let cpu = (exclusive.into(), inclusive.into());
+                    Ok((cpu, bus.get_memory_usage()?, 1))
+                });
+                tracked_usage(usage, &mut result)
+            })
+            .collect();
+        let event_usage: Vec<_> = self
+            .events
+            .iter()
+            .map(|(description, ..)| {
+                let usage = description.get_instance_list().and_then(|instances| {
+                    let mut cpu = (0, 0);
+                    let mut memory = MemoryUsage {
+                        exclusive: 0,
+                        inclusive: 0,
+                        sample_data: 0,
+                    };
+                    for instance in &instances {
+                        let (exclusive, inclusive) = instance.get_cpu_usage()?;
+                        let instance_memory = instance.get_memory_usage()?;
+                        cpu.0 += u64::from(exclusive);
+                        cpu.1 += u64::from(inclusive);
+                        memory.exclusive += instance_memory.exclusive;
+                        memory.inclusive += instance_memory.inclusive;
+                        memory.sample_data += instance_memory.sample_data;
+                    }
+                    Ok((cpu, memory, instances.len()))
+                });
+                tracked_usage(usage, &mut result)
+            })
+            .collect();
+
+        // unloaded buses and events are dropped even if the sample failed
+        let record = result.is_ok();
+        record_usage(&mut self.buses, bus_usage, record);
+        record_usage(&mut self.events, event_usage, record);
+        result?;
+
+        self.overrun_count += sample.overruns.len() as u64;
+        if self.history.len() == self.capacity {
+            self.history.pop_front();
+        }
+        self.history.push_back(sample);
+        Ok(self.history.back().unwrap())
+    }
+
+    /// The recorded samples, from oldest to newest.
+    pub fn history(&self) -> &VecDeque<ProfileSample> {
+        &self.history
+    }
+
+    pub fn latest(&self) -> Option<&ProfileSample> {
+        self.history.back()
+    }
+
+    /// The total number of budget overruns, including ones in samples that are no longer in the history.
+    pub fn overrun_count(&self) -> u64 {
+        self.overrun_count
+    }
+
+    pub fn bus_stats(&self, bus: Bus) -> Option<&UsageStats> {
+        self.buses
+            .iter()
+            .find(|(b, ..)| *b == bus)
+            .map(|(.., stats)| stats)
+    }
+
+    pub fn event_stats(&self, description: EventDescription) -> Option<&UsageStats> {
+        self.events
+            .iter()
+            .find(|(d, ..)| *d == description)
+            .map(|(.., stats)| stats)
+    }
+
+    /// Every tracked bus, with its ID and statistics.
+    pub fn buses(&self) -> impl Iterator<Item = (Bus, Guid, &UsageStats)> {
+        self.buses.iter().map(|(bus, id, stats)| (*bus, *id, stats))
+    }
+
+    /// Every tracked event, with its ID and statistics.
+    pub fn events(&self) -> impl Iterator<Item = (EventDescription, Guid, &UsageStats)> {
+        self.events
+            .iter()
+            .map(|(description, id, stats)| (*description, *id, stats))
+    }
+
+    /// Forgets every sample and resets the statistics of tracked buses and events.
+    pub fn clear(&mut self) {
+        self.history.clear();
+        self.overrun_count = 0;
+        for (.., stats) in &mut self.buses {
+            *stats = UsageStats::default();
+        }
+        for (.., stats) in &mut self.events {
+            *stats = UsageStats::default();
+        }
+    }
+
+    /// Writes the history as CSV, with a header row and one row per sample.
+    ///
+    /// The last column lists the names of any budget overruns, separated by `;`.
+    pub fn write_csv(&self, mut writer: impl Write) -> std::io::Result<()> {
+        for (name, _) in COLUMNS {
+            write!(writer, "{name},")?;
+        }
+        writeln!(writer, "overruns")?;
+
+        for sample in &self.history {
+            for (_, value) in COLUMNS {
+                write!(writer, "{},", value(sample))?;
+            }
+            let overruns: Vec<_> = sample.overruns.iter().map(BudgetOverrun::name).collect();
+            writeln!(writer, "{}", overruns.join(";"))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Profiler {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        struct Tracked<'a, T>(&'a [(T, Guid, UsageStats)]);
+
+        impl<T> serde::Serialize for Tracked<'_, T> {
+            fn serialize<S: serde::Serializer>(
+                &self,
+                serializer: S,
+            ) -> std::result::Result<S::Ok, S::Error> {
+                serializer.collect_map(self.0.iter().map(|(_, id, stats)| (id, stats)))
+            }
+        }
+
+        let mut state = serializer.serialize_struct("Profiler", 3)?;
+        state.serialize_field("samples", &self.history)?;
+        state.serialize_field("buses", &Tracked(&self.buses))?;
+        state.serialize_field("events", &Tracked(&self.events))?;
+        state.end()
+    }
+}
+
+// the CPU usage, memory usage and instance count of a tracked bus or event in one sample
+type Usage = ((u64, u64), MemoryUsage, usize);
+
+// what a sample found out about a tracked bus or event
+enum SampledUsage {
+    Usage(Usage),
+    // still tracked, but there's nothing to record this sample
+    NoUsage,
+    // unloaded, so no longer tracked
+    Invalid,
+}
+
+fn tracked_usage(usage: Result<Usage>, result: &mut Result<()>) -> SampledUsage {
+    let error = match usage {
+        Ok(usage) => return SampledUsage::Usage(usage),
+        Err(error) => error,
+    };
+    if error == FMOD_RESULT::FMOD_ERR_INVALID_HANDLE {
+        return SampledUsage::Invalid;
+    }
+    // buses without a channel group (because nothing is playing through them) have no usage to report
+    if error != FMOD_RESULT::FMOD_ERR_STUDIO_NOT_LOADED && result.is_ok() {
+        *result = Err(error);
+    }
+    SampledUsage::NoUsage
+}
+
+fn record_usage<T>(
+    tracked: &mut Vec<(T, Guid, UsageStats)>,
+    usage: Vec<SampledUsage>,
+    record: bool,
+) {
+    let mut usage = usage.into_iter();
+    tracked.retain_mut(|(.., stats)| match usage.next() {
+        Some(SampledUsage::Usage((cpu, memory, instances))) if record => {
+            stats.record(cpu, memory, instances);
+            true
+        }
+        Some(SampledUsage::Usage(_) | SampledUsage::NoUsage) => true,
+        Some(SampledUsage::Invalid) | None => false,
+    });
+}
+
+impl Budget {
+    fn check(&self, sample: &ProfileSample) -> Vec<BudgetOverrun> {
+        fn peak(info: BufferInfo) -> c_float {
+            if info.capacity == 0 {
+                0.0
+            } else {
+                info.peak_usage as c_float / info.capacity as c_float
+            }
+        }
+
+        let mut overruns = vec![];
+        if let Some(budget) = self.mixer_cpu {
+            let usage = sample.core_cpu.dsp;
+            if usage > budget {
+                overruns.push(BudgetOverrun::MixerCpu { usage, budget });
+            }
+        }
+        if let Some(budget) = self.update_cpu {
+            let usage = sample.studio_cpu.update;
+            if usage > budget {
+                overruns.push(BudgetOverrun::UpdateCpu { usage, budget });
+            }
+        }
+        if let Some(budget) = self.memory {
+            let usage = sample.memory.inclusive;
+            if usage > budget {
+                overruns.push(BudgetOverrun::Memory { usage, budget });
+            }
+        }
+        if let Some(budget) = self.command_queue_peak {
+            let usage = peak(sample.buffers.studio_command_queue);
+            if usage > budget {
+                overruns.push(BudgetOverrun::CommandQueuePeak { usage, budget });
+            }
+        }
+        if let Some(budget) = self.handle_peak {
+            let usage = peak(sample.buffers.studio_handle);
+            if usage > budget {
+                overruns.push(BudgetOverrun::HandlePeak { usage, budget });
+            }
+        }
+        if let Some(budget) = self.playing_channels {
+            let usage = sample.playing_channels;
+            if usage > budget {
+                overruns.push(BudgetOverrun::PlayingChannels { usage, budget });
+            }
+        }
+        overruns
+    }
+}
+
+type Column = (&'static str, fn(&ProfileSample) -> f64);
+
+// the columns written by write_csv
+const COLUMNS: &[Column] = &[
+    ("time", |s| s.time.as_secs_f64()),
+    ("dsp_cpu", |s| f64::from(s.core_cpu.dsp)),
+    ("stream_cpu", |s| f64::from(s.core_cpu.stream)),
+    ("geometry_cpu", |s| f64::from(s.core_cpu.geometry)),
+    ("core_update_cpu", |s| f64::from(s.core_cpu.update)),
+    ("convolution_1_cpu", |s| f64::from(s.core_cpu.convolution_1)),
+    ("convolution_2_cpu", |s| f64::from(s.core_cpu.convolution_2)),
+    ("studio_update_cpu", |s| f64::from(s.studio_cpu.update)),
+    ("memory_exclusive", |s| f64::from(s.memory.exclusive)),
+    ("memory_inclusive", |s| f64::from(s.memory.inclusive)),
+    ("memory_sample_data", |s| f64::from(s.memory.sample_data)),
+    ("command_queue_usage", |s| {
+        f64::from(s.buffers.studio_command_queue.current_usage)
+    }),
+    ("command_queue_peak", |s| {
+        f64::from(s.buffers.studio_command_queue.peak_usage)
+    }),
+    ("command_queue_capacity", |s| {
+        f64::from(s.buffers.studio_command_queue.capacity)
+    }),
+    ("command_queue_stalls", |s| {
+        f64::from(s.buffers.studio_command_queue.stall_count)
+    }),
+    ("command_queue_stall_time", |s| {
+        f64::from(s.buffers.studio_command_queue.stall_time)
+    }),
+    ("handle_usage", |s| {
+        f64::from(s.buffers.studio_handle.current_usage)
+    }),
+    ("handle_peak", |s| {
+        f64::from(s.buffers.studio_handle.peak_usage)
+    }),
+    ("handle_capacity", |s| {
+        f64::from(s.buffers.studio_handle.capacity)
+    }),
+    ("playing_channels", |s| f64::from(s.playing_channels)),
+    ("real_channels", |s| f64::from(s.real_channels)),
+    ("sample_bytes_read", |s| s.sample_bytes_read as f64),
+    ("stream_bytes_read", |s| s.stream_bytes_read as f64),
+    ("other_bytes_read", |s| s.other_bytes_read as f64),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn buffer(peak_usage: c_int, capacity: c_int) -> BufferInfo {
+        BufferInfo {
+            current_usage: 0,
+            peak_usage,
+            capacity,
+            stall_count: 0,
+            stall_time: 0.0,
+        }
+    }
+
+    fn sample() -> ProfileSample {
+        ProfileSample {
+            time: Duration::from_millis(1500),
+            core_cpu: crate::CpuUsage {
+                dsp: 20.0,
+                ..Default::default()
+            },
+            studio_cpu: CpuUsage { update: 5.0 },
+            memory: MemoryUsage {
+                exclusive: 1000,
+                inclusive: 4000,
+                sample_data: 0,
+            },
+            buffers: BufferUsage {
+                studio_command_queue: buffer(512, 1024),
+                studio_handle: buffer(0, 0),
+            },
+            playing_channels: 48,
+            real_channels: 32,
+            sample_bytes_read: 100,
+            stream_bytes_read: 200,
+            other_bytes_read: 300,
+            overruns: vec![],
+        }
+    }
+
+    #[test]
+    fn empty_budget_never_overruns() {
+        assert!(Budget::default().check(&sample()).is_empty());
+    }
+
+    #[test]
+    fn budget_overruns() {
+        let budget = Budget {
+            mixer_cpu: Some(10.0),
+            update_cpu: Some(5.0),
+            memory: Some(2000),
+            command_queue_peak: Some(0.25),
+            handle_peak: Some(0.5),
+            playing_channels: Some(64),
+        };
+        assert_eq!(
+            budget.check(&sample()),
+            [
+                BudgetOverrun::MixerCpu {
+                    usage: 20.0,
+                    budget: 10.0
+                },
+                BudgetOverrun::Memory {
+                    usage: 4000,
+                    budget: 2000
+                },
+                BudgetOverrun::CommandQueuePeak {
+                    usage: 0.5,
+                    budget: 0.25
+                },
+            ]
+        );
+
+        // limits are inclusive, and an empty handle table is never full
+        let budget = Budget {
+            update_cpu: Some(5.0),
+            command_queue_peak: Some(0.5),
+            handle_peak: Some(0.0),
+            playing_channels: Some(48),
+            ..Default::default()
+        };
+        assert!(budget.check(&sample()).is_empty());
+    }
+
+    #[test]
+    fn csv_has_a_row_per_sample() {
+        let mut profiler = Profiler::new(System::from(std::ptr::null_mut()), 2, Budget::default());
+        let mut csv = vec![];
+        profiler.write_csv(&mut csv).unwrap();
+        let header = String::from_utf8(csv).unwrap();
+        assert_eq!(header.lines().count(), 1);
+        assert!(header.starts_with("time,dsp_cpu,"));
+        assert!(header.ends_with(",other_bytes_read,overruns\n"));
+
+        profiler.history.push_back(sample());
+        profiler.history.push_back(ProfileSample {
+            overruns: vec![
+                BudgetOverrun::UpdateCpu {
+                    usage: 5.0,
+                    budget: 1.0,
+                },
+                BudgetOverrun::PlayingChannels {
+                    usage: 48,
+                    budget: 16,
+                },
+            ],
+            ..sample()
+        });
+        let mut csv = vec![];
+        profiler.write_csv(&mut csv).unwrap();
+        let csv = String::from_utf8(csv).unwrap();
+        let rows: Vec<Vec<&str>> = csv.lines().map(|line| line.split(',').collect()).collect();
+
+        assert_eq!(rows.len(), 3);
+        assert!(rows.iter().all(|row| row.len() == COLUMNS.len() + 1));
+        let column = |name| rows[0].iter().position(|&n| n == name).unwrap();
+        assert_eq!(rows[1][column("time")], "1.5");
+        assert_eq!(rows[1][column("dsp_cpu")], "20");
+        assert_eq!(rows[1][column("command_queue_capacity")], "1024");
+        assert_eq!(rows[1][column("other_bytes_read")], "300");
+        assert_eq!(rows[1][column("overruns")], "");
+        assert_eq!(rows[2][column("overruns")], "update_cpu;playing_channels");
+    }
+}
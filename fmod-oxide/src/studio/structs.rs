@@ -16,6 +16,7 @@ use crate::{
 };
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MemoryUsage {
     pub exclusive: c_int,
     pub inclusive: c_int,
@@ -42,7 +43,8 @@ impl From<MemoryUsage> for FMOD_STUDIO_MEMORY_USAGE {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 // force this type to have the exact same layout as FMOD_STUDIO_PARAMETER_ID so we can safely transmute between them.
 #[repr(C)]
 pub struct ParameterID {
@@ -176,6 +178,17 @@ impl ParameterDescription {
             }
         }
     }
+
+    /// The number of labels this parameter has, which is 0 unless it is [`ParameterFlags::LABELED`].
+    ///
+    /// Labeled parameters have one label per integer value from `minimum` to `maximum`, and label indices start at 0.
+    pub fn label_count(&self) -> c_int {
+        if self.flags.contains(ParameterFlags::LABELED) {
+            (self.maximum - self.minimum) as c_int + 1
+        } else {
+            0
+        }
+    }
 }
 
 #[derive(Clone, PartialEq, Debug)]
@@ -253,6 +266,7 @@ impl From<&UserProperty> for FMOD_STUDIO_USER_PROPERTY {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BufferInfo {
     pub current_usage: c_int,
     pub peak_usage: c_int,
@@ -286,6 +300,7 @@ impl From<BufferInfo> for FMOD_STUDIO_BUFFER_INFO {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BufferUsage {
     pub studio_command_queue: BufferInfo,
     pub studio_handle: BufferInfo,
@@ -310,6 +325,7 @@ impl From<BufferUsage> for FMOD_STUDIO_BUFFER_USAGE {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CpuUsage {
     pub update: c_float,
 }
@@ -5,10 +5,10 @@
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
 use fmod_sys::*;
-use lanyard::Utf8CStr;
+use lanyard::AsUtf8CStr;
 use std::ffi::c_int;
 
-use crate::studio::{Bank, LoadBankFlags, System};
+use crate::studio::{Bank, ListIter, LoadBankFlags, System};
 use crate::Guid;
 
 impl System {
@@ -28,7 +28,12 @@ impl System {
     ///
     /// If a bank has been split, separating out assets and optionally streams from the metadata bank, all parts must be loaded before any APIs that use the data are called.
     /// It is recommended you load each part one after another (order is not important), then proceed with dependent API calls such as [`Bank::load_sample_data`] or [`System::get_event`].
-    pub fn load_bank_file(&self, filename: &Utf8CStr, load_flags: LoadBankFlags) -> Result<Bank> {
+    pub fn load_bank_file(
+        &self,
+        filename: impl AsUtf8CStr,
+        load_flags: LoadBankFlags,
+    ) -> Result<Bank> {
+        let filename = filename.to_utf8_cstr()?;
         let mut bank = std::ptr::null_mut();
         unsafe {
             FMOD_Studio_System_LoadBankFile(
@@ -106,7 +111,7 @@ impl System {
             FMOD_Studio_System_LoadBankMemory(
                 self.inner,
                 buffer.cast::<i8>(),
-                (*buffer).len() as c_int,
+                buffer.len() as c_int,
                 FMOD_STUDIO_LOAD_MEMORY_POINT,
                 flags.bits(),
                 &mut bank,
@@ -126,7 +131,8 @@ impl System {
     /// `path_or_id` may be a path, such as `bank:/Weapons` or an ID string such as `{793cddb6-7fa1-4e06-b805-4c74c0fd625b}`.
     ///
     /// Note that path lookups will only succeed if the strings bank has been loaded.
-    pub fn get_bank(&self, path_or_id: &Utf8CStr) -> Result<Bank> {
+    pub fn get_bank(&self, path_or_id: impl AsUtf8CStr) -> Result<Bank> {
+        let path_or_id = path_or_id.to_utf8_cstr()?;
         let mut bank = std::ptr::null_mut();
         unsafe {
             FMOD_Studio_System_GetBank(self.inner, path_or_id.as_ptr(), &mut bank).to_result()?;
@@ -153,15 +159,23 @@ impl System {
     }
 
     pub fn get_bank_list(&self) -> Result<Vec<Bank>> {
+        let mut list = Vec::new();
+        self.get_bank_list_into(&mut list)?;
+        Ok(list)
+    }
+
+    /// Like [`System::get_bank_list`], but writes into `list`, reusing its allocation.
+    pub fn get_bank_list_into(&self, list: &mut Vec<Bank>) -> Result<()> {
         let expected_count = self.bank_count()?;
         let mut count = 0;
-        let mut list = vec![std::ptr::null_mut(); expected_count as usize];
+        list.clear();
+        list.reserve(expected_count as usize);
 
         unsafe {
             FMOD_Studio_System_GetBankList(
                 self.inner,
                 // bank is repr transparent and has the same layout as *mut FMOD_STUDIO_BANK, so this cast is ok
-                list.as_mut_ptr(),
+                list.as_mut_ptr().cast::<*mut FMOD_STUDIO_BANK>(),
                 list.capacity() as c_int,
                 &mut count,
             )
@@ -169,10 +183,14 @@ impl System {
 
             debug_assert_eq!(count, expected_count);
 
-            Ok(std::mem::transmute::<
-                Vec<*mut fmod_sys::FMOD_STUDIO_BANK>,
-                Vec<Bank>,
-            >(list))
+            // fmod wrote `count` handles into the spare capacity
+            list.set_len(count as usize);
         }
+        Ok(())
+    }
+
+    /// Iterates over the loaded banks, see [`ListIter`].
+    pub fn banks(&self) -> ListIter<Self, Bank> {
+        ListIter::new(*self, Self::get_bank_list_into)
     }
 }
@@ -5,11 +5,11 @@
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
 use fmod_sys::*;
-use lanyard::{Utf8CStr, Utf8CString};
-use std::mem::MaybeUninit;
+use lanyard::{AsUtf8CStr, Utf8CStr, Utf8CString, Utf8CStringBuilder};
+use std::{ffi::c_int, mem::MaybeUninit};
 
 use crate::studio::System;
-use crate::Guid;
+use crate::{get_sized_string_into, get_string_in, Guid};
 
 impl System {
     /// Retrieves the Core System.
@@ -26,7 +26,8 @@ impl System {
     /// The strings bank must be loaded prior to calling this function, otherwise [`FMOD_RESULT::FMOD_ERR_EVENT_NOTFOUND`] is returned.
     ///
     /// The path can be copied to the system clipboard from FMOD Studio using the "Copy Path" context menu command.
-    pub fn lookup_id(&self, path: &Utf8CStr) -> Result<Guid> {
+    pub fn lookup_id(&self, path: impl AsUtf8CStr) -> Result<Guid> {
+        let path = path.to_utf8_cstr()?;
         let mut guid = MaybeUninit::zeroed();
         unsafe {
             FMOD_Studio_System_LookupID(self.inner, path.as_ptr(), guid.as_mut_ptr())
@@ -41,50 +42,37 @@ impl System {
     ///
     /// The strings bank must be loaded prior to calling this function, otherwise [`FMOD_RESULT::FMOD_ERR_EVENT_NOTFOUND`] is returned.
     pub fn lookup_path(&self, id: Guid) -> Result<Utf8CString> {
-        let mut string_len = 0;
+        let mut path = Utf8CStringBuilder::new();
+        self.lookup_path_into(id, &mut path)?;
+        Ok(path.into_cstring())
+    }
 
-        // retrieve the length of the string.
-        // this includes the null terminator, so we don't need to account for that.
-        unsafe {
-            let error = FMOD_Studio_System_LookupPath(
+    /// Like [`System::lookup_path`], but writes the path into `path`, reusing its allocation.
+    pub fn lookup_path_into(&self, id: Guid, path: &mut Utf8CStringBuilder) -> Result<()> {
+        get_sized_string_into(path, |buffer, string_len| unsafe {
+            FMOD_Studio_System_LookupPath(
                 self.inner,
                 &id.into(),
-                std::ptr::null_mut(),
-                0,
-                &mut string_len,
+                buffer.as_mut_ptr().cast(),
+                buffer.len() as c_int,
+                string_len,
             )
-            .to_error();
-
-            // we expect the error to be fmod_err_truncated.
-            // if it isn't, we return the error.
-            match error {
-                Some(error) if error != FMOD_RESULT::FMOD_ERR_TRUNCATED => return Err(error),
-                _ => {}
-            }
-        };
-
-        let mut path = vec![0u8; string_len as usize];
-        let mut expected_string_len = 0;
+        })
+    }
 
-        unsafe {
+    /// Like [`System::lookup_path`], but writes the path into `buffer` without allocating.
+    ///
+    /// Returns [`FMOD_RESULT::FMOD_ERR_TRUNCATED`] if the path (including the null terminator) doesn't fit.
+    pub fn lookup_path_in<'b>(&self, id: Guid, buffer: &'b mut [u8]) -> Result<&'b Utf8CStr> {
+        get_string_in(buffer, |buffer| unsafe {
             FMOD_Studio_System_LookupPath(
                 self.inner,
                 &id.into(),
-                // u8 and i8 have the same layout, so this is ok
-                path.as_mut_ptr().cast(),
-                string_len,
-                &mut expected_string_len,
+                buffer.as_mut_ptr().cast(),
+                buffer.len() as c_int,
+                std::ptr::null_mut(),
             )
-            .to_result()?;
-
-            debug_assert_eq!(string_len, expected_string_len);
-
-            // all public fmod apis return UTF-8 strings. this should be safe.
-            // if i turn out to be wrong, perhaps we should add extra error types?
-            let path = Utf8CString::from_utf8_with_nul_unchecked(path);
-
-            Ok(path)
-        }
+        })
     }
 
     /// Checks that the [`System`] reference is valid and has been initialized.
@@ -38,7 +38,11 @@ impl System {
         \nIf your userdata drop code relies on accessing potentially invalid owners, you should handle this manually."
     )]
     pub unsafe fn release(self) -> Result<()> {
+        let core_system = self.get_core_system();
         unsafe { FMOD_Studio_System_Release(self.inner).to_result()? };
+        if let Ok(core_system) = core_system {
+            core_system.remove_occlusion_provider();
+        }
 
         #[cfg(feature = "userdata-abstraction")]
         crate::userdata::clear_userdata();
@@ -5,7 +5,7 @@
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
 use fmod_sys::*;
-use lanyard::Utf8CStr;
+use lanyard::{AsUtf8CStr, Utf8CStr};
 use std::mem::MaybeUninit;
 
 use crate::Guid;
@@ -20,7 +20,8 @@ impl System {
     /// `path_or_id` may be a path, such as `bus:/SFX/Ambience`, or an ID string, such as `{d9982c58-a056-4e6c-b8e3-883854b4bffb}`.
     ///
     /// Note that path lookups will only succeed if the strings bank has been loaded.
-    pub fn get_bus(&self, path_or_id: &Utf8CStr) -> Result<Bus> {
+    pub fn get_bus(&self, path_or_id: impl AsUtf8CStr) -> Result<Bus> {
+        let path_or_id = path_or_id.to_utf8_cstr()?;
         let mut bus = std::ptr::null_mut();
         unsafe {
             FMOD_Studio_System_GetBus(self.inner, path_or_id.as_ptr(), &mut bus).to_result()?;
@@ -46,7 +47,8 @@ impl System {
     /// `path+or_id` may be a path, such as `event:/UI/Cancel` or `snapshot:/IngamePause`, or an ID string, such as `{2a3e48e6-94fc-4363-9468-33d2dd4d7b00}`.
     ///
     /// Note that path lookups will only succeed if the strings bank has been loaded.
-    pub fn get_event(&self, path_or_id: &Utf8CStr) -> Result<EventDescription> {
+    pub fn get_event(&self, path_or_id: impl AsUtf8CStr) -> Result<EventDescription> {
+        let path_or_id = path_or_id.to_utf8_cstr()?;
         let mut event = std::ptr::null_mut();
         unsafe {
             FMOD_Studio_System_GetEvent(self.inner, path_or_id.as_ptr(), &mut event).to_result()?;
@@ -72,7 +74,8 @@ impl System {
     /// `path_or_id` may be a path, such as `vca:/MyVCA`, or an ID string, such as `{d9982c58-a056-4e6c-b8e3-883854b4bffb`}.
     ///
     /// Note that path lookups will only succeed if the strings bank has been loaded.
-    pub fn get_vca(&self, path_or_id: &Utf8CStr) -> Result<Vca> {
+    pub fn get_vca(&self, path_or_id: impl AsUtf8CStr) -> Result<Vca> {
+        let path_or_id = path_or_id.to_utf8_cstr()?;
         let mut vca = std::ptr::null_mut();
         unsafe {
             FMOD_Studio_System_GetVCA(self.inner, path_or_id.as_ptr(), &mut vca).to_result()?;
@@ -14,6 +14,7 @@ mod general;
 mod lifecycle;
 mod listener;
 mod misc;
+mod occlusion;
 mod parameter;
 mod plugins;
 mod profiling; // things too small to really make their own module
@@ -0,0 +1,45 @@
+// Copyright (c) 2024 Lily Lyons
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use fmod_sys::*;
+
+use crate::studio::{EventInstance, System};
+use crate::{nearest_listener, occlusion_provider};
+
+impl System {
+    /// Sets the occlusion of event instances from the [`crate::OcclusionProvider`] of the core system.
+    ///
+    /// Occlusion is applied to the [`crate::ChannelGroup`] of each instance, so it affects everything the event plays.
+    /// This should be called once per frame with the instances that should be occluded, as the results are smoothed across calls.
+    /// Instances that aren't loaded yet are skipped.
+    ///
+    /// If there is more than one listener, the nearest one is used.
+    pub fn apply_occlusion(&self, instances: &[EventInstance]) -> Result<()> {
+        let Some(occlusion) = occlusion_provider(self.get_core_system()?) else {
+            return Ok(());
+        };
+        let listeners = (0..self.get_listener_count()?)
+            .map(|listener| Ok(self.get_listener_attributes(listener)?.0.position))
+            .collect::<Result<Vec<_>>>()?;
+
+        for instance in instances {
+            let channel_group = match instance.get_channel_group() {
+                Ok(channel_group) => channel_group,
+                Err(e) if e == FMOD_RESULT::FMOD_ERR_STUDIO_NOT_LOADED => continue,
+                Err(e) => return Err(e),
+            };
+            let source = instance.get_3d_attributes()?.position;
+            let Some(listener) = nearest_listener(source, listeners.iter().copied().map(Ok))?
+            else {
+                return Ok(());
+            };
+
+            let (direct, reverb) = occlusion.get(instance.inner as usize, source, listener);
+            channel_group.set_3d_occlusion(direct, reverb)?;
+        }
+        Ok(())
+    }
+}
@@ -5,15 +5,33 @@
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
 use fmod_sys::*;
-use lanyard::{Utf8CStr, Utf8CString};
+use lanyard::{AsUtf8CStr, Utf8CStr, Utf8CString, Utf8CStringBuilder};
 use std::{
     ffi::{c_float, c_int},
     mem::MaybeUninit,
 };
 
-use crate::studio::{ParameterDescription, ParameterID, System};
+use crate::studio::{
+    GlobalParameter, ListIter, ParameterDescription, ParameterID, ParameterValue, System,
+};
+use crate::{get_sized_string_into, get_string_in};
 
 impl System {
+    /// Creates a handle to a global parameter, which caches its ID, range, flags and labels.
+    ///
+    /// `K` is the type of value the parameter is set with, see [`ParameterValue`].
+    /// If the parameter can't hold values of `K`, [`FMOD_RESULT::FMOD_ERR_INVALID_PARAM`] is returned.
+    pub fn parameter<K: ParameterValue>(
+        &self,
+        name: impl AsUtf8CStr,
+    ) -> Result<GlobalParameter<K>> {
+        let description = self.get_parameter_description_by_name(name)?;
+        let id = description.id;
+        GlobalParameter::new(*self, description, |index| {
+            self.get_parameter_label_by_id(id, index)
+        })
+    }
+
     /// Retrieves a global parameter value by unique identifier.
     ///
     /// The second tuple field is the final value of the parameter after applying adjustments due to automation, modulation, seek speed, and parameter velocity to value.
@@ -60,9 +78,10 @@ impl System {
     pub fn set_parameter_by_id_with_label(
         &self,
         id: ParameterID,
-        label: &Utf8CStr,
+        label: impl AsUtf8CStr,
         ignore_seek_speed: bool,
     ) -> Result<()> {
+        let label = label.to_utf8_cstr()?;
         unsafe {
             FMOD_Studio_System_SetParameterByIDWithLabel(
                 self.inner,
@@ -103,7 +122,8 @@ impl System {
     ///
     /// The second tuple field is the final value of the parameter after applying adjustments due to automation, modulation, seek speed, and parameter velocity to value.
     /// This is calculated asynchronously when the Studio system updates.
-    pub fn get_parameter_by_name(&self, name: &Utf8CStr) -> Result<(c_float, c_float)> {
+    pub fn get_parameter_by_name(&self, name: impl AsUtf8CStr) -> Result<(c_float, c_float)> {
+        let name = name.to_utf8_cstr()?;
         let mut value = 0.0;
         let mut final_value = 0.0;
 
@@ -123,10 +143,11 @@ impl System {
     /// Sets a global parameter value by name.
     pub fn set_parameter_by_name(
         &self,
-        name: &Utf8CStr,
+        name: impl AsUtf8CStr,
         value: c_float,
         ignore_seek_speed: bool,
     ) -> Result<()> {
+        let name = name.to_utf8_cstr()?;
         unsafe {
             FMOD_Studio_System_SetParameterByName(
                 self.inner,
@@ -143,10 +164,12 @@ impl System {
     /// If the specified label is not found, [`FMOD_RESULT::FMOD_ERR_EVENT_NOTFOUND`] is returned. This lookup is case sensitive.
     pub fn set_parameter_by_name_with_label(
         &self,
-        name: &Utf8CStr,
-        label: &Utf8CStr,
+        name: impl AsUtf8CStr,
+        label: impl AsUtf8CStr,
         ignore_seek_speed: bool,
     ) -> Result<()> {
+        let name = name.to_utf8_cstr()?;
+        let label = label.to_utf8_cstr()?;
         unsafe {
             FMOD_Studio_System_SetParameterByNameWithLabel(
                 self.inner,
@@ -164,8 +187,9 @@ impl System {
     /// Path lookups will only succeed if the strings bank has been loaded.
     pub fn get_parameter_description_by_name(
         &self,
-        name: &Utf8CStr,
+        name: impl AsUtf8CStr,
     ) -> Result<ParameterDescription> {
+        let name = name.to_utf8_cstr()?;
         let mut description = MaybeUninit::zeroed();
         unsafe {
             FMOD_Studio_System_GetParameterDescriptionByName(
@@ -207,18 +231,39 @@ impl System {
 
     /// Retrieves a list of global parameters.
     pub fn get_parameter_description_list(&self) -> Result<Vec<ParameterDescription>> {
+        let mut list = Vec::new();
+        self.get_parameter_description_list_into(&mut list)?;
+        Ok(list)
+    }
+
+    /// Like [`System::get_parameter_description_list`], but writes into `list`, reusing its allocation.
+    ///
+    /// The names of the parameters are still allocated.
+    pub fn get_parameter_description_list_into(
+        &self,
+        list: &mut Vec<ParameterDescription>,
+    ) -> Result<()> {
+        // fmod writes into the spare capacity of `list`, and the descriptions are converted in place
+        const _: () = assert!(
+            std::mem::size_of::<FMOD_STUDIO_PARAMETER_DESCRIPTION>()
+                <= std::mem::size_of::<ParameterDescription>()
+                && std::mem::align_of::<FMOD_STUDIO_PARAMETER_DESCRIPTION>()
+                    <= std::mem::align_of::<ParameterDescription>()
+        );
+
         let expected_count = self.parameter_description_count()?;
         let mut count = 0;
-        // FIXME: is the use of MaybeUninit necessary?
-        // it does imply intention though, which is ok.
-        let mut list = vec![MaybeUninit::zeroed(); expected_count as usize];
+        list.clear();
+        list.reserve(expected_count as usize);
 
         unsafe {
+            let descriptions = list
+                .as_mut_ptr()
+                .cast::<FMOD_STUDIO_PARAMETER_DESCRIPTION>();
+            // a fmod description is no bigger than ours, so there is room for at least `capacity` of them
             FMOD_Studio_System_GetParameterDescriptionList(
                 self.inner,
-                // bank is repr transparent and has the same layout as *mut FMOD_STUDIO_BANK, so this cast is ok
-                list.as_mut_ptr()
-                    .cast::<FMOD_STUDIO_PARAMETER_DESCRIPTION>(),
+                descriptions,
                 list.capacity() as c_int,
                 &mut count,
             )
@@ -226,16 +271,21 @@ impl System {
 
             debug_assert_eq!(count, expected_count);
 
-            let list = list
-                .into_iter()
-                .map(|uninit| {
-                    let description = uninit.assume_init();
-                    ParameterDescription::from_ffi(description)
-                })
-                .collect();
-
-            Ok(list)
+            // converting from the back means a converted description only ever overwrites fmod descriptions that were already converted
+            for index in (0..count as usize).rev() {
+                let description = descriptions.add(index).read();
+                list.as_mut_ptr()
+                    .add(index)
+                    .write(ParameterDescription::from_ffi(description));
+            }
+            list.set_len(count as usize);
         }
+        Ok(())
+    }
+
+    /// Iterates over the global parameters, see [`ListIter`].
+    pub fn parameter_descriptions(&self) -> ListIter<Self, ParameterDescription> {
+        ListIter::new(*self, Self::get_parameter_description_list_into)
     }
 
     /// Retrieves a global parameter label by name or path.
@@ -244,55 +294,54 @@ impl System {
     /// Path lookups will only succeed if the strings bank has been loaded.
     pub fn get_parameter_label_by_name(
         &self,
-        name: &Utf8CStr,
+        name: impl AsUtf8CStr,
         label_index: c_int,
     ) -> Result<Utf8CString> {
-        let mut string_len = 0;
+        let mut label = Utf8CStringBuilder::new();
+        self.get_parameter_label_by_name_into(name, label_index, &mut label)?;
+        Ok(label.into_cstring())
+    }
 
-        // retrieve the length of the string.
-        // this includes the null terminator, so we don't need to account for that.
-        unsafe {
-            let error = FMOD_Studio_System_GetParameterLabelByName(
+    /// Like [`System::get_parameter_label_by_name`], but writes the label into `label`, reusing its allocation.
+    pub fn get_parameter_label_by_name_into(
+        &self,
+        name: impl AsUtf8CStr,
+        label_index: c_int,
+        label: &mut Utf8CStringBuilder,
+    ) -> Result<()> {
+        let name = name.to_utf8_cstr()?;
+        get_sized_string_into(label, |buffer, string_len| unsafe {
+            FMOD_Studio_System_GetParameterLabelByName(
                 self.inner,
                 name.as_ptr(),
                 label_index,
-                std::ptr::null_mut(),
-                0,
-                &mut string_len,
+                buffer.as_mut_ptr().cast(),
+                buffer.len() as c_int,
+                string_len,
             )
-            .to_error();
-
-            // we expect the error to be fmod_err_truncated.
-            // if it isn't, we return the error.
-            match error {
-                Some(error) if error != FMOD_RESULT::FMOD_ERR_TRUNCATED => return Err(error),
-                _ => {}
-            }
-        };
-
-        let mut path = vec![0u8; string_len as usize];
-        let mut expected_string_len = 0;
+        })
+    }
 
-        unsafe {
+    /// Like [`System::get_parameter_label_by_name`], but writes the label into `buffer` without allocating.
+    ///
+    /// Returns [`FMOD_RESULT::FMOD_ERR_TRUNCATED`] if the label (including the null terminator) doesn't fit.
+    pub fn get_parameter_label_by_name_in<'b>(
+        &self,
+        name: impl AsUtf8CStr,
+        label_index: c_int,
+        buffer: &'b mut [u8],
+    ) -> Result<&'b Utf8CStr> {
+        let name = name.to_utf8_cstr()?;
+        get_string_in(buffer, |buffer| unsafe {
             FMOD_Studio_System_GetParameterLabelByName(
                 self.inner,
                 name.as_ptr(),
                 label_index,
-                // u8 and i8 have the same layout, so this is ok
-                path.as_mut_ptr().cast(),
-                string_len,
-                &mut expected_string_len,
+                buffer.as_mut_ptr().cast(),
+                buffer.len() as c_int,
+                std::ptr::null_mut(),
             )
-            .to_result()?;
-
-            debug_assert_eq!(string_len, expected_string_len);
-
-            // all public fmod apis return UTF-8 strings. this should be safe.
-            // if i turn out to be wrong, perhaps we should add extra error types?
-            let path = Utf8CString::from_utf8_with_nul_unchecked(path);
-
-            Ok(path)
-        }
+        })
     }
 
     /// Retrieves a global parameter label by ID.
@@ -301,51 +350,48 @@ impl System {
         id: ParameterID,
         label_index: c_int,
     ) -> Result<Utf8CString> {
-        let mut string_len = 0;
+        let mut label = Utf8CStringBuilder::new();
+        self.get_parameter_label_by_id_into(id, label_index, &mut label)?;
+        Ok(label.into_cstring())
+    }
 
-        // retrieve the length of the string.
-        // this includes the null terminator, so we don't need to account for that.
-        unsafe {
-            let error = FMOD_Studio_System_GetParameterLabelByID(
+    /// Like [`System::get_parameter_label_by_id`], but writes the label into `label`, reusing its allocation.
+    pub fn get_parameter_label_by_id_into(
+        &self,
+        id: ParameterID,
+        label_index: c_int,
+        label: &mut Utf8CStringBuilder,
+    ) -> Result<()> {
+        get_sized_string_into(label, |buffer, string_len| unsafe {
+            FMOD_Studio_System_GetParameterLabelByID(
                 self.inner,
                 id.into(),
                 label_index,
-                std::ptr::null_mut(),
-                0,
-                &mut string_len,
+                buffer.as_mut_ptr().cast(),
+                buffer.len() as c_int,
+                string_len,
             )
-            .to_error();
-
-            // we expect the error to be fmod_err_truncated.
-            // if it isn't, we return the error.
-            match error {
-                Some(error) if error != FMOD_RESULT::FMOD_ERR_TRUNCATED => return Err(error),
-                _ => {}
-            }
-        };
-
-        let mut path = vec![0u8; string_len as usize];
-        let mut expected_string_len = 0;
+        })
+    }
 
-        unsafe {
+    /// Like [`System::get_parameter_label_by_id`], but writes the label into `buffer` without allocating.
+    ///
+    /// Returns [`FMOD_RESULT::FMOD_ERR_TRUNCATED`] if the label (including the null terminator) doesn't fit.
+    pub fn get_parameter_label_by_id_in<'b>(
+        &self,
+        id: ParameterID,
+        label_index: c_int,
+        buffer: &'b mut [u8],
+    ) -> Result<&'b Utf8CStr> {
+        get_string_in(buffer, |buffer| unsafe {
             FMOD_Studio_System_GetParameterLabelByID(
                 self.inner,
                 id.into(),
                 label_index,
-                // u8 and i8 have the same layout, so this is ok
-                path.as_mut_ptr().cast(),
-                string_len,
-                &mut expected_string_len,
+                buffer.as_mut_ptr().cast(),
+                buffer.len() as c_int,
+                std::ptr::null_mut(),
             )
-            .to_result()?;
-
-            debug_assert_eq!(string_len, expected_string_len);
-
-            // all public fmod apis return UTF-8 strings. this should be safe.
-            // if i turn out to be wrong, perhaps we should add extra error types?
-            let path = Utf8CString::from_utf8_with_nul_unchecked(path);
-
-            Ok(path)
-        }
+        })
     }
 }
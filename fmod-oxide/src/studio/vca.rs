@@ -4,12 +4,15 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
-use std::{ffi::c_float, mem::MaybeUninit};
+use std::{
+    ffi::{c_float, c_int},
+    mem::MaybeUninit,
+};
 
 use fmod_sys::*;
-use lanyard::Utf8CString;
+use lanyard::{Utf8CStr, Utf8CString, Utf8CStringBuilder};
 
-use crate::Guid;
+use crate::{get_sized_string_into, get_string_in, Guid};
 
 /// Represents a global mixer VCA.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -71,46 +74,36 @@ impl Vca {
     /// Retrieves the path.
     ///
     /// The strings bank must be loaded prior to calling this function, otherwise [`FMOD_RESULT::FMOD_ERR_EVENT_NOTFOUND`] is returned.
-    // TODO: convert into possible macro for the sake of reusing code
     pub fn get_path(&self) -> Result<Utf8CString> {
-        let mut string_len = 0;
-
-        // retrieve the length of the string.
-        // this includes the null terminator, so we don't need to account for that.
-        unsafe {
-            let error =
-                FMOD_Studio_VCA_GetPath(self.inner, std::ptr::null_mut(), 0, &mut string_len)
-                    .to_error();
-
-            // we expect the error to be fmod_err_truncated.
-            // if it isn't, we return the error.
-            match error {
-                Some(error) if error != FMOD_RESULT::FMOD_ERR_TRUNCATED => return Err(error),
-                _ => {}
-            }
-        };
-
-        let mut path = vec![0u8; string_len as usize];
-        let mut expected_string_len = 0;
+        let mut path = Utf8CStringBuilder::new();
+        self.get_path_into(&mut path)?;
+        Ok(path.into_cstring())
+    }
 
-        unsafe {
+    /// Like [`Vca::get_path`], but writes the path into `path`, reusing its allocation.
+    pub fn get_path_into(&self, path: &mut Utf8CStringBuilder) -> Result<()> {
+        get_sized_string_into(path, |buffer, string_len| unsafe {
             FMOD_Studio_VCA_GetPath(
                 self.inner,
-                // u8 and i8 have the same layout, so this is ok
-                path.as_mut_ptr().cast(),
+                buffer.as_mut_ptr().cast(),
+                buffer.len() as c_int,
                 string_len,
-                &mut expected_string_len,
             )
-            .to_result()?;
-
-            debug_assert_eq!(string_len, expected_string_len);
-
-            // all public fmod apis return UTF-8 strings. this should be safe.
-            // if i turn out to be wrong, perhaps we should add extra error types?
-            let path = Utf8CString::from_utf8_with_nul_unchecked(path);
+        })
+    }
 
-            Ok(path)
-        }
+    /// Like [`Vca::get_path`], but writes the path into `buffer` without allocating.
+    ///
+    /// Returns [`FMOD_RESULT::FMOD_ERR_TRUNCATED`] if the path (including the null terminator) doesn't fit.
+    pub fn get_path_in<'b>(&self, buffer: &'b mut [u8]) -> Result<&'b Utf8CStr> {
+        get_string_in(buffer, |buffer| unsafe {
+            FMOD_Studio_VCA_GetPath(
+                self.inner,
+                buffer.as_mut_ptr().cast(),
+                buffer.len() as c_int,
+                std::ptr::null_mut(),
+            )
+        })
     }
 
     /// Checks that the VCA reference is valid.
@@ -17,7 +17,7 @@ use slotmap::{HopSlotMap, Key, KeyData};
 
 use crate::{
     studio::{Bank, CommandReplay, EventDescription, EventInstance, System as StudioSystem},
-    ChannelControl, Dsp, DspConnection, Geometry, Reverb3D, Sound, SoundGroup, System,
+    ChannelControl, Dsp, DspConnection, Geometry, Reverb3D, Shareable, Sound, SoundGroup, System,
 };
 
 #[derive(Default)]
@@ -134,6 +134,63 @@ pub(crate) fn clear_userdata() {
     storage.slotmap.clear();
 }
 
+mod private {
+    use super::Userdata;
+
+    pub trait Sealed {
+        fn userdata_pointer(&self) -> fmod_sys::Result<*mut std::ffi::c_void>;
+
+        fn set_erased_userdata(&self, userdata: Userdata) -> fmod_sys::Result<()>;
+    }
+}
+
+/// Typed access to the [`Userdata`] of an FMOD object.
+///
+/// This is implemented for every FMOD object that supports userdata, and saves you from having to downcast [`Userdata`] yourself.
+/// It can't be implemented outside of this crate.
+pub trait TypedUserdata: private::Sealed {
+    /// Sets the userdata of this object to `userdata`, replacing any existing userdata.
+    fn set_typed_userdata<T: Shareable>(&self, userdata: T) -> fmod_sys::Result<()> {
+        self.set_erased_userdata(Arc::new(userdata))
+    }
+
+    /// Retrieves the userdata of this object.
+    ///
+    /// Returns `None` if there is no userdata, or if the userdata is not a `T`.
+    fn get_typed_userdata<T: Shareable>(&self) -> fmod_sys::Result<Option<Arc<T>>> {
+        let pointer = self.userdata_pointer()?;
+        let userdata = get_userdata(pointer.into());
+        Ok(userdata.and_then(|u| u.downcast::<T>().ok()))
+    }
+
+    /// Calls `f` with a reference to the userdata of this object.
+    ///
+    /// Returns `None` if there is no userdata, or if the userdata is not a `T`.
+    ///
+    /// The userdata storage isn't locked while `f` is running, so `f` is free to set or release userdata (including this object's).
+    ///
+    /// This still briefly takes a read lock on the userdata storage and clones the [`Userdata`] before calling `f`.
+    /// Both are needed to stay sound:
+    /// - the raw userdata pointer can be anything (it's settable through `set_raw_userdata`, and outlives released objects),
+    ///   so it has to be looked up in the storage rather than dereferenced.
+    /// - `f` may replace or release the userdata it's borrowing, so the borrow needs its own reference to keep it alive.
+    ///
+    /// Uncontended, that's a few atomics for the lock and the reference count.
+    /// `benches/userdata.rs` measures it at around 25ns per call, about the same as fetching the raw pointer from the mock.
+    fn with_userdata<T: Shareable, R>(
+        &self,
+        f: impl FnOnce(&T) -> R,
+    ) -> fmod_sys::Result<Option<R>> {
+        let pointer = self.userdata_pointer()?;
+        // keeps the userdata alive without holding the lock
+        let userdata = get_userdata(pointer.into());
+        Ok(userdata
+            .as_deref()
+            .and_then(|u| u.downcast_ref::<T>())
+            .map(f))
+    }
+}
+
 impl From<UserdataKey> for *mut std::ffi::c_void {
     fn from(key: UserdataKey) -> Self {
         key.data().as_ffi() as *mut std::ffi::c_void
@@ -223,3 +280,37 @@ impl From<System> for HasUserdata {
         HasUserdata::System(s)
     }
 }
+
+macro_rules! impl_typed_userdata {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl private::Sealed for $ty {
+                fn userdata_pointer(&self) -> fmod_sys::Result<*mut std::ffi::c_void> {
+                    self.get_raw_userdata()
+                }
+
+                fn set_erased_userdata(&self, userdata: Userdata) -> fmod_sys::Result<()> {
+                    self.set_userdata(userdata)
+                }
+            }
+
+            impl TypedUserdata for $ty {}
+        )*
+    };
+}
+
+impl_typed_userdata!(
+    StudioSystem,
+    Bank,
+    EventDescription,
+    EventInstance,
+    CommandReplay,
+    Reverb3D,
+    SoundGroup,
+    ChannelControl,
+    Dsp,
+    Sound,
+    Geometry,
+    DspConnection,
+    System,
+);
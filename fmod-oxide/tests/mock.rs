@@ -0,0 +1,422 @@
+// Copyright (c) 2024 Lily Lyons
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+// run with `cargo test -p fmod-oxide --features mock`
+#![cfg(feature = "mock")]
+
+use std::sync::{Mutex, MutexGuard, PoisonError};
+use std::time::Duration;
+
+use fmod::ffi::mock::{self, BankFixture, EventFixture, Fixture};
+use fmod::ffi::FMOD_RESULT;
+use fmod::studio::{
+    AutomationEnd, EventPool, LoadBankFlags, ParameterAutomator, PlaybackState, StealPolicy,
+    StopMode,
+};
+
+// the mock's state is global, so tests have to take turns
+static LOCK: Mutex<()> = Mutex::new(());
+
+fn setup() -> (MutexGuard<'static, ()>, fmod::studio::System) {
+    let guard = LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+    mock::reset();
+    mock::set_fixture(
+        Fixture::new().bank(
+            BankFixture::new("bank:/Master")
+                .event(EventFixture::new("event:/Music").parameter("Intensity", 0.0, 1.0, 0.25))
+                .event(EventFixture::new("event:/Footstep")),
+        ),
+    );
+
+    let system = unsafe { fmod::studio::SystemBuilder::new() }
+        .unwrap()
+        .build(32, fmod::studio::InitFlags::NORMAL, fmod::InitFlags::NORMAL)
+        .unwrap();
+    (guard, system)
+}
+
+#[test]
+fn banks_and_events() {
+    let (_guard, system) = setup();
+
+    let bank = system
+        .load_bank_file("assets/Master.bank", LoadBankFlags::NORMAL)
+        .unwrap();
+    assert_eq!(bank.get_path().unwrap(), "bank:/Master");
+    assert_eq!(bank.event_count().unwrap(), 2);
+
+    let event = system.get_event("event:/Music").unwrap();
+    assert_eq!(event.get_path().unwrap(), "event:/Music");
+    assert_eq!(
+        system.get_event("event:/Missing").unwrap_err(),
+        fmod::Error::Fmod(FMOD_RESULT::FMOD_ERR_EVENT_NOTFOUND)
+    );
+
+    bank.unload().unwrap();
+    assert!(!event.is_valid());
+
+    unsafe { system.release() }.unwrap();
+}
+
+#[test]
+fn event_instance_playback() {
+    let (_guard, system) = setup();
+    system
+        .load_bank_file("Master.bank", LoadBankFlags::NORMAL)
+        .unwrap();
+
+    let instance = system
+        .get_event("event:/Music")
+        .unwrap()
+        .create_instance()
+        .unwrap();
+    assert_eq!(
+        instance.get_playback_state().unwrap(),
+        PlaybackState::Stopped
+    );
+
+    instance.start().unwrap();
+    assert_eq!(
+        instance.get_playback_state().unwrap(),
+        PlaybackState::Starting
+    );
+    system.update().unwrap();
+    assert_eq!(
+        instance.get_playback_state().unwrap(),
+        PlaybackState::Playing
+    );
+
+    instance
+        .set_parameter_by_name("Intensity", 0.75, false)
+        .unwrap();
+    assert_eq!(instance.get_parameter_by_name("Intensity").unwrap().0, 0.75);
+    assert_eq!(
+        mock::instances()[0].parameters,
+        [("Intensity".to_string(), 0.75)]
+    );
+
+    instance.stop(StopMode::Immediate).unwrap();
+    assert_eq!(
+        instance.get_playback_state().unwrap(),
+        PlaybackState::Stopped
+    );
+    instance.release().unwrap();
+    system.update().unwrap();
+    assert!(mock::instances().is_empty());
+
+    unsafe { system.release() }.unwrap();
+}
+
+#[test]
+fn list_iterators_report_errors() {
+    let (_guard, system) = setup();
+    let bank = system
+        .load_bank_file("Master.bank", LoadBankFlags::NORMAL)
+        .unwrap();
+
+    let mut events = bank.events();
+    assert_eq!(events.error(), None);
+    assert_eq!(events.by_ref().count(), 2);
+    assert_eq!(events.error(), None);
+
+    bank.unload().unwrap();
+    let mut events = bank.events();
+    assert_eq!(events.next(), None);
+    assert_eq!(
+        events.error(),
+        Some(&fmod::Error::Fmod(FMOD_RESULT::FMOD_ERR_INVALID_HANDLE))
+    );
+
+    unsafe { system.release() }.unwrap();
+}
+
+#[test]
+fn unsupported_functions_are_recorded() {
+    let (_guard, system) = setup();
+
+    assert_eq!(
+        system.get_bus("bus:/").unwrap_err(),
+        fmod::Error::Fmod(FMOD_RESULT::FMOD_ERR_UNSUPPORTED)
+    );
+    assert!(mock::calls().contains(&"FMOD_Studio_System_GetBus"));
+
+    unsafe { system.release() }.unwrap();
+}
+
+#[cfg(feature = "userdata-abstraction")]
+#[test]
+fn userdata_can_be_replaced_while_borrowed() {
+    use fmod::TypedUserdata;
+
+    let (_guard, system) = setup();
+    system
+        .load_bank_file("Master.bank", LoadBankFlags::NORMAL)
+        .unwrap();
+    let instance = system
+        .get_event("event:/Footstep")
+        .unwrap()
+        .create_instance()
+        .unwrap();
+
+    instance.set_typed_userdata(1_u32).unwrap();
+    let old = instance
+        .with_userdata(|value: &u32| {
+            instance.set_typed_userdata(value + 1).unwrap();
+            *value
+        })
+        .unwrap();
+    assert_eq!(old, Some(1));
+    assert_eq!(*instance.get_typed_userdata::<u32>().unwrap().unwrap(), 2);
+
+    unsafe { system.release() }.unwrap();
+}
+
+#[test]
+fn event_pool_steals_farthest() {
+    let (_guard, system) = setup();
+    mock::set_fixture(
+        Fixture::new().bank(
+            BankFixture::new("bank:/Master")
+                .event(EventFixture::new("event:/Explosion").is_3d(true))
+                .event(EventFixture::new("event:/Footstep")),
+        ),
+    );
+    system
+        .load_bank_file("Master.bank", LoadBankFlags::NORMAL)
+        .unwrap();
+
+    let description = system.get_event("event:/Explosion").unwrap();
+    let mut pool = EventPool::new(system, description, 2, StealPolicy::Farthest).unwrap();
+    let at = |x| {
+        move |instance: fmod::studio::EventInstance| {
+            instance.set_3d_attributes(fmod::Attributes3D {
+                position: fmod::Vector { x, y: 0.0, z: 0.0 },
+                ..Default::default()
+            })
+        }
+    };
+    pool.play(at(1.0)).unwrap().unwrap();
+    let far = pool.play(at(10.0)).unwrap().unwrap();
+    let stolen = pool.play(at(2.0)).unwrap().unwrap();
+    assert_eq!(stolen, far);
+    assert_eq!(pool.stats().steals, 1);
+
+    // 2D events have no position, but can still be stolen
+    let description = system.get_event("event:/Footstep").unwrap();
+    let mut pool = EventPool::new(system, description, 1, StealPolicy::Farthest).unwrap();
+    let first = pool.play(|_| Ok(())).unwrap().unwrap();
+    assert_eq!(pool.play(|_| Ok(())).unwrap(), Some(first));
+    assert_eq!(pool.stats().steals, 1);
+
+    unsafe { system.release() }.unwrap();
+}
+
+#[test]
+fn event_pool_drops_invalid_instances() {
+    let (_guard, system) = setup();
+    system
+        .load_bank_file("Master.bank", LoadBankFlags::NORMAL)
+        .unwrap();
+
+    let description = system.get_event("event:/Footstep").unwrap();
+    let mut pool = EventPool::new(system, description, 2, StealPolicy::None).unwrap();
+    let released = pool.play(|_| Ok(())).unwrap().unwrap();
+    pool.play(|_| Ok(())).unwrap().unwrap();
+    system.update().unwrap();
+    pool.update().unwrap();
+
+    released.stop(StopMode::Immediate).unwrap();
+    released.release().unwrap();
+    system.update().unwrap();
+    assert!(!released.is_valid());
+
+    pool.update().unwrap();
+    let stats = pool.stats();
+    assert_eq!((stats.capacity, stats.active, stats.recycled), (1, 1, 0));
+    assert_eq!(pool.play(|_| Ok(())).unwrap(), None);
+
+    unsafe { system.release() }.unwrap();
+}
+
+#[test]
+fn event_parameter_handles() {
+    let (_guard, system) = setup();
+    mock::set_fixture(
+        Fixture::new().bank(
+            BankFixture::new("bank:/Master").event(
+                EventFixture::new("event:/Music")
+                    .parameter("Intensity", 0.0, 1.0, 0.25)
+                    .labeled_parameter("Section", ["Intro", "Verse", "Chorus"]),
+            ),
+        ),
+    );
+    system
+        .load_bank_file("Master.bank", LoadBankFlags::NORMAL)
+        .unwrap();
+    let event = system.get_event("event:/Music").unwrap();
+    let instance = event.create_instance().unwrap();
+
+    let intensity = event.parameter::<f32>("Intensity").unwrap();
+    assert_eq!((intensity.minimum(), intensity.maximum()), (0.0, 1.0));
+    assert_eq!(intensity.get(instance).unwrap().0, 0.25);
+    intensity.set(instance, 0.75, false).unwrap();
+    assert_eq!(intensity.get(instance).unwrap().0, 0.75);
+    // checked before FMOD would clamp it
+    assert_eq!(
+        intensity.set(instance, 1.5, false).unwrap_err(),
+        fmod::Error::Fmod(FMOD_RESULT::FMOD_ERR_INVALID_PARAM)
+    );
+    assert_eq!(intensity.get(instance).unwrap().0, 0.75);
+    // continuous parameters can't be set with integers
+    assert_eq!(
+        event.parameter::<i32>("Intensity").unwrap_err(),
+        fmod::Error::Fmod(FMOD_RESULT::FMOD_ERR_INVALID_PARAM)
+    );
+
+    let section = event.parameter::<i32>("Section").unwrap();
+    assert!(section.is_labeled());
+    assert_eq!(section.labels, ["Intro", "Verse", "Chorus"]);
+    section.set(instance, 2, false).unwrap();
+    assert_eq!(section.get(instance).unwrap().0, 2.0);
+    section.set_label(instance, "Verse", false).unwrap();
+    assert_eq!(section.get(instance).unwrap().0, 1.0);
+    assert_eq!(
+        section.set_label(instance, "Bridge", false).unwrap_err(),
+        fmod::Error::Fmod(FMOD_RESULT::FMOD_ERR_EVENT_NOTFOUND)
+    );
+    // labeled parameters starting at 0 can hold booleans
+    assert!(event.parameter::<bool>("Section").is_ok());
+
+    assert_eq!(
+        event.parameter::<f32>("Missing").unwrap_err(),
+        fmod::Error::Fmod(FMOD_RESULT::FMOD_ERR_EVENT_NOTFOUND)
+    );
+
+    unsafe { system.release() }.unwrap();
+}
+
+#[test]
+fn global_parameter_handles() {
+    let _guard = LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+    mock::reset();
+    // global parameters are read when the system is created
+    mock::set_fixture(
+        Fixture::new()
+            .parameter("Time", 0.0, 24.0, 12.0)
+            .labeled_parameter("Weather", ["Clear", "Rain"]),
+    );
+    let system = unsafe { fmod::studio::SystemBuilder::new() }
+        .unwrap()
+        .build(32, fmod::studio::InitFlags::NORMAL, fmod::InitFlags::NORMAL)
+        .unwrap();
+
+    let time = system.parameter::<f32>("Time").unwrap();
+    assert_eq!(time.get().unwrap().0, 12.0);
+    time.set(18.5, false).unwrap();
+    assert_eq!(time.get().unwrap().0, 18.5);
+    assert_eq!(
+        time.set(-1.0, false).unwrap_err(),
+        fmod::Error::Fmod(FMOD_RESULT::FMOD_ERR_INVALID_PARAM)
+    );
+
+    let weather = system.parameter::<bool>("Weather").unwrap();
+    assert_eq!(weather.labels, ["Clear", "Rain"]);
+    weather.set(true, false).unwrap();
+    assert_eq!(weather.get().unwrap().0, 1.0);
+    weather.set_label("Clear", false).unwrap();
+    assert_eq!(weather.get().unwrap().0, 0.0);
+
+    let description = system.get_parameter_description_by_name("Time").unwrap();
+    assert!(description
+        .flags
+        .contains(fmod::studio::ParameterFlags::GLOBAL));
+
+    unsafe { system.release() }.unwrap();
+}
+
+#[test]
+fn parameter_automator_batches_and_completes() {
+    let (_guard, system) = setup();
+    // more parameters than fit in one FMOD_Studio_EventInstance_SetParametersByIDs call
+    let event = (0..40).fold(EventFixture::new("event:/Ambience"), |event, i| {
+        event.parameter(format!("Layer {i}"), 0.0, 1.0, 0.0)
+    });
+    mock::set_fixture(Fixture::new().bank(BankFixture::new("bank:/Master").event(event)));
+    system
+        .load_bank_file("Master.bank", LoadBankFlags::NORMAL)
+        .unwrap();
+    let description = system.get_event("event:/Ambience").unwrap();
+    let instance = description.create_instance().unwrap();
+
+    let mut automator = ParameterAutomator::new();
+    let mut ids = vec![];
+    for index in 0..description.parameter_description_count().unwrap() {
+        let parameter = description
+            .get_parameter_description_by_index(index)
+            .unwrap();
+        let id = automator
+            .ramp(
+                instance,
+                parameter.id,
+                1.0,
+                Duration::from_secs(1),
+                fmod::Curve::Linear,
+            )
+            .unwrap();
+        ids.push(id);
+    }
+
+    mock::clear_calls();
+    automator.advance(Duration::from_millis(500)).unwrap();
+    let calls = mock::calls();
+    let batches = calls
+        .iter()
+        .filter(|&&call| call == "FMOD_Studio_EventInstance_SetParametersByIDs")
+        .count();
+    assert_eq!(batches, 2);
+    assert!(mock::instances()[0]
+        .parameters
+        .iter()
+        .all(|(_, value)| *value == 0.5));
+    assert_eq!(automator.drain_finished().count(), 0);
+
+    automator.advance(Duration::from_secs(1)).unwrap();
+    assert!(automator.is_empty());
+    assert!(mock::instances()[0]
+        .parameters
+        .iter()
+        .all(|(_, value)| *value == 1.0));
+    let finished: Vec<_> = automator.drain_finished().collect();
+    let completed: Vec<_> = ids
+        .into_iter()
+        .map(|id| (id, AutomationEnd::Completed))
+        .collect();
+    assert_eq!(finished, completed);
+
+    // automations on released instances end without an error
+    let parameter = description
+        .get_parameter_description_by_index(0)
+        .unwrap()
+        .id;
+    let id = automator
+        .ramp(
+            instance,
+            parameter,
+            0.0,
+            Duration::from_secs(1),
+            fmod::Curve::Linear,
+        )
+        .unwrap();
+    instance.release().unwrap();
+    system.update().unwrap();
+    automator.advance(Duration::from_millis(100)).unwrap();
+    assert_eq!(
+        automator.drain_finished().collect::<Vec<_>>(),
+        [(id, AutomationEnd::InstanceInvalid)]
+    );
+
+    unsafe { system.release() }.unwrap();
+}
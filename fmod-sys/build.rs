@@ -1,6 +1,6 @@
 use std::path::PathBuf;
 
-#[cfg(windows)]
+#[cfg(all(windows, not(any(feature = "mock", feature = "force-docs-bindings"))))]
 fn find_fmod_directory() -> PathBuf {
     for drive in ["C", "D"] {
         let test_path = PathBuf::from(format!(
@@ -26,7 +26,7 @@ fn find_fmod_directory() -> PathBuf {
         .unwrap_or_else(|| PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("fmod"))
 }
 
-#[cfg(not(windows))]
+#[cfg(all(not(windows), not(any(feature = "mock", feature = "force-docs-bindings"))))]
 fn find_fmod_directory() -> PathBuf {
     let out_dir = PathBuf::from(std::env::var_os("OUT_DIR").unwrap());
     let out_path = out_dir.join("fmod");
@@ -44,9 +44,19 @@ fn main() {
     if std::env::var("DOCS_RS").is_ok() {
         return;
     }
-    #[cfg(feature = "force-docs-bindings")]
-    return;
+    #[cfg(all(feature = "mock", feature = "dynamic-loading"))]
+    compile_error!("the mock and dynamic-loading features can't be enabled at the same time");
+    #[cfg(all(feature = "fsbank", feature = "dynamic-loading"))]
+    compile_error!("the fsbank and dynamic-loading features can't be enabled at the same time");
 
+    #[cfg(feature = "mock")]
+    generate_mock_fallbacks();
+    #[cfg(not(any(feature = "mock", feature = "force-docs-bindings")))]
+    generate_bindings();
+}
+
+#[cfg(not(any(feature = "mock", feature = "force-docs-bindings")))]
+fn generate_bindings() {
     let fmod_dir = find_fmod_directory();
     let api_dir = fmod_dir.join("api");
 
@@ -57,7 +67,7 @@ fn main() {
     println!("cargo:rerun-if-changed=\"{api_dir_display}/core/inc\"");
     println!("cargo:rerun-if-changed=\"{api_dir_display}/studio/inc\"");
 
-    let bindgen = bindgen::builder()
+    let mut bindgen = bindgen::builder()
         .parse_callbacks(Box::new(bindgen::CargoCallbacks::new()))
         .clang_arg(format!("-I{api_dir_display}/core/inc"))
         .clang_arg(format!("-I{api_dir_display}/studio/inc"))
@@ -72,6 +82,13 @@ fn main() {
         .prepend_enum_name(false) // fmod already does this
         .header("src/wrapper.h");
 
+    if cfg!(feature = "fsbank") {
+        println!("cargo:rerun-if-changed=\"{api_dir_display}/fsbank/inc\"");
+        bindgen = bindgen
+            .clang_arg(format!("-I{api_dir_display}/fsbank/inc"))
+            .clang_arg("-DFMOD_SYS_FSBANK");
+    }
+
     #[cfg(target_arch = "x86")]
     let target_arch = "x86";
     #[cfg(all(target_arch = "x86_64", not(windows)))]
@@ -82,26 +99,43 @@ fn main() {
     let include_debug = cfg!(any(debug_assertions, feature = "force-debug"));
     let debug_char = if include_debug { "L" } else { "" };
 
-    println!("cargo:rustc-link-search={api_dir_display}/core/lib/{target_arch}");
-    println!("cargo:rustc-link-search={api_dir_display}/studio/lib/{target_arch}");
+    // with dynamic loading, the libraries are found at runtime by `dynamic::load`
+    if !cfg!(feature = "dynamic-loading") {
+        println!("cargo:rustc-link-search={api_dir_display}/core/lib/{target_arch}");
+        println!("cargo:rustc-link-search={api_dir_display}/studio/lib/{target_arch}");
 
-    #[cfg(target_os = "linux")]
-    {
-        println!("cargo:rustc-link-lib=fmod{debug_char}");
-        println!("cargo:rustc-link-lib=fmodstudio{debug_char}");
+        #[cfg(target_os = "linux")]
+        {
+            println!("cargo:rustc-link-lib=fmod{debug_char}");
+            println!("cargo:rustc-link-lib=fmodstudio{debug_char}");
+        }
+        #[cfg(target_os = "windows")]
+        {
+            println!("cargo:rustc-link-lib=fmod{debug_char}_vc");
+            println!("cargo:rustc-link-lib=fmodstudio{debug_char}_vc");
+        }
     }
-    #[cfg(target_os = "windows")]
-    {
-        println!("cargo:rustc-link-lib=fmod{debug_char}_vc");
-        println!("cargo:rustc-link-lib=fmodstudio{debug_char}_vc");
+
+    // fsbank doesn't ship a logging version
+    if cfg!(feature = "fsbank") {
+        println!("cargo:rustc-link-search={api_dir_display}/fsbank/lib/{target_arch}");
+
+        #[cfg(target_os = "linux")]
+        println!("cargo:rustc-link-lib=fsbank");
+        #[cfg(target_os = "windows")]
+        println!("cargo:rustc-link-lib=fsbank_vc");
     }
 
     let bindings = bindgen.generate().expect("failed to generate bindings");
     let out_path = PathBuf::from(std::env::var_os("OUT_DIR").unwrap()).join("bindings.rs");
 
+    #[cfg(not(feature = "dynamic-loading"))]
     bindings
         .write_to_file(out_path)
         .expect("failed to write bindings");
+    #[cfg(feature = "dynamic-loading")]
+    std::fs::write(out_path, generate_dynamic_bindings(&bindings.to_string()))
+        .expect("failed to write bindings");
 
     let docs_path = PathBuf::from(std::env::var_os("CARGO_MANIFEST_DIR").unwrap())
         .join("docs/documentation.rs");
@@ -110,6 +144,17 @@ fn main() {
         .write_to_file(docs_path)
         .expect("failed to write bindings");
 
+    // the wrapper calls into FMOD's C++ API, which can't be loaded at runtime
+    #[cfg(not(feature = "dynamic-loading"))]
+    compile_channel_control_wrapper(&api_dir_display.to_string());
+}
+
+#[cfg(not(any(
+    feature = "mock",
+    feature = "force-docs-bindings",
+    feature = "dynamic-loading"
+)))]
+fn compile_channel_control_wrapper(api_dir_display: &str) {
     println!("cargo:rerun-if-changed=\"src/channel_control.cpp\"");
     println!("cargo:rerun-if-changed=\"src/channel_control.h\"");
 
@@ -134,3 +179,326 @@ fn main() {
 
     build.compile("channel_control_wrapper");
 }
+
+// the mock defines a subset of FMOD in src/mock, everything else gets a stub that returns FMOD_ERR_UNSUPPORTED.
+// we generate these from the mock's bindings so that every function the bindings declare is defined
+#[cfg(feature = "mock")]
+fn generate_mock_fallbacks() {
+    let manifest_dir = PathBuf::from(std::env::var_os("CARGO_MANIFEST_DIR").unwrap());
+    let mock_dir = manifest_dir.join("src/mock");
+    let bindings_dir = mock_dir.join("bindings");
+
+    println!("cargo:rerun-if-changed={}", mock_dir.display());
+    println!("cargo:rerun-if-changed={}", bindings_dir.display());
+
+    let mut bindings = std::fs::read_to_string(bindings_dir.join("fmod.rs"))
+        .expect("failed to read src/mock/bindings/fmod.rs");
+    if cfg!(feature = "fsbank") {
+        bindings.push_str(
+            &std::fs::read_to_string(bindings_dir.join("fsbank.rs"))
+                .expect("failed to read src/mock/bindings/fsbank.rs"),
+        );
+    }
+
+    let mut mocked = std::collections::HashSet::new();
+    for entry in std::fs::read_dir(&mock_dir).expect("failed to read src/mock") {
+        let path = entry.unwrap().path();
+        // skips the bindings directory
+        if !path.is_file() {
+            continue;
+        }
+        let source = std::fs::read_to_string(path).unwrap();
+        let mut rest = source.as_str();
+        while let Some(index) = rest.find("extern \"C\" fn ") {
+            rest = &rest[index + "extern \"C\" fn ".len()..];
+            let name_end = rest.find('(').unwrap();
+            mocked.insert(rest[..name_end].trim().to_string());
+        }
+    }
+
+    let mut fallbacks = String::new();
+    for function in extern_functions(&bindings) {
+        let ExternFunction {
+            name,
+            arguments,
+            return_type,
+        } = &function;
+        if mocked.contains(name) {
+            continue;
+        }
+
+        let body = match return_type.as_str() {
+            "FMOD_RESULT" => format!("crate::mock::unsupported(\"{name}\")"),
+            "FSBANK_RESULT" => {
+                format!("crate::mock::record(\"{name}\");\n    FSBANK_ERR_UNINITIALIZED")
+            }
+            "" => format!("crate::mock::record(\"{name}\")"),
+            _ => format!("crate::mock::record(\"{name}\");\n    unsafe {{ std::mem::zeroed() }}"),
+        };
+        fallbacks.push_str(&format!(
+            "#[no_mangle]\npub unsafe extern \"C\" fn {name}({arguments}){} {{\n    {body}\n}}\n\n",
+            function.return_suffix()
+        ));
+    }
+
+    let out_path = PathBuf::from(std::env::var_os("OUT_DIR").unwrap()).join("mock_fallbacks.rs");
+    std::fs::write(out_path, fallbacks).expect("failed to write mock fallbacks");
+}
+
+// with dynamic loading nothing can be linked, so every extern function is replaced by a function that calls through a pointer resolved by `dynamic::load`.
+// the channel control wrapper is written in C++ against FMOD, so it's replaced by src/dynamic/channel_control.rs instead
+#[cfg(feature = "dynamic-loading")]
+fn generate_dynamic_bindings(bindings: &str) -> String {
+    let functions: Vec<ExternFunction> = extern_functions(bindings)
+        .into_iter()
+        .filter(|function| {
+            !function.name.starts_with("FMOD_ChannelControl_")
+                && !function.name.ends_with("_CastToControl")
+        })
+        .collect();
+
+    let mut output = strip_extern_blocks(bindings);
+
+    output.push_str("\n#[allow(clippy::type_complexity)]\npub(crate) struct DynamicFunctions {\n");
+    for function in &functions {
+        output.push_str(&format!(
+            "    pub(crate) {}: Option<unsafe extern \"C\" fn({}){}>,\n",
+            function.name,
+            function.arguments,
+            function.return_suffix()
+        ));
+    }
+    output.push_str("}\n\nimpl DynamicFunctions {\n    pub(crate) unsafe fn load(core: &libloading::Library, studio: &libloading::Library) -> Self {\n        unsafe {\n            DynamicFunctions {\n");
+    for function in &functions {
+        let library = if function.name.starts_with("FMOD_Studio_") {
+            "studio"
+        } else {
+            "core"
+        };
+        output.push_str(&format!(
+            "                {0}: {library}.get(b\"{0}\\0\").ok().map(|symbol| *symbol),\n",
+            function.name
+        ));
+    }
+    output.push_str("            }\n        }\n    }\n}\n\n");
+
+    for function in &functions {
+        let ExternFunction {
+            name,
+            arguments,
+            return_type,
+        } = function;
+        let fallback = if return_type == "FMOD_RESULT" {
+            "result"
+        } else {
+            "{ let _ = result; unsafe { std::mem::zeroed() } }"
+        };
+
+        // src/dynamic/channel_control.rs needs to know which pointers are channel groups, so keep track of them as they come and go
+        let call = format!("function({})", function.argument_names().join(", "));
+        let first_argument = function
+            .argument_names()
+            .first()
+            .copied()
+            .unwrap_or_default();
+        let mut before_call = String::new();
+        let mut after_call = String::new();
+        for (argument, ty) in function.arguments() {
+            match ty.replace(' ', "").as_str() {
+                "*mut*mutFMOD_CHANNELGROUP" => after_call.push_str(&format!(
+                    " crate::dynamic::channel_control::remember_channel_group({argument});"
+                )),
+                "*mut*mutFMOD_CHANNEL" => after_call.push_str(&format!(
+                    " crate::dynamic::channel_control::forget_channel({argument});"
+                )),
+                _ => {}
+            }
+        }
+        match name.as_str() {
+            "FMOD_ChannelGroup_Release" => after_call.push_str(&format!(
+                " crate::dynamic::channel_control::forget_channel_group({first_argument});"
+            )),
+            "FMOD_System_Release" => after_call.push_str(&format!(
+                " crate::dynamic::channel_control::forget_system({first_argument});"
+            )),
+            "FMOD_Studio_System_Release" => {
+                before_call.push_str(&format!(
+                    "let core = crate::dynamic::channel_control::studio_core_system({first_argument}); "
+                ));
+                after_call.push_str(" crate::dynamic::channel_control::forget_system(core);");
+            }
+            _ => {}
+        }
+        let call = if after_call.is_empty() {
+            call
+        } else {
+            format!(
+                "{before_call}let result = {call}; if result == FMOD_RESULT::FMOD_OK {{{after_call} }} result"
+            )
+        };
+
+        output.push_str(&format!(
+            "#[allow(clippy::missing_safety_doc, clippy::too_many_arguments)]\npub unsafe fn {name}({arguments}){} {{\n    match crate::dynamic::function(|functions| functions.{name}) {{\n        Ok(function) => unsafe {{ {call} }},\n        Err(result) => {fallback},\n    }}\n}}\n\n",
+            function.return_suffix(),
+        ));
+    }
+
+    output
+}
+
+#[cfg(any(feature = "mock", feature = "dynamic-loading"))]
+struct ExternFunction {
+    name: String,
+    // everything between the parentheses, as written in the bindings
+    arguments: String,
+    // empty if the function doesn't return anything
+    return_type: String,
+}
+
+#[cfg(any(feature = "mock", feature = "dynamic-loading"))]
+impl ExternFunction {
+    fn return_suffix(&self) -> String {
+        if self.return_type.is_empty() {
+            String::new()
+        } else {
+            format!(" -> {}", self.return_type)
+        }
+    }
+
+    // the name and type of every argument
+    #[cfg(feature = "dynamic-loading")]
+    fn arguments(&self) -> Vec<(&str, &str)> {
+        split_top_level(&self.arguments, ',')
+            .into_iter()
+            .filter_map(|argument| argument.split_once(':'))
+            .map(|(name, ty)| (name.trim(), ty.trim()))
+            .collect()
+    }
+
+    #[cfg(feature = "dynamic-loading")]
+    fn argument_names(&self) -> Vec<&str> {
+        self.arguments().into_iter().map(|(name, _)| name).collect()
+    }
+}
+
+// finds every function declared in an `extern "C"` block.
+// this is only ever run on bindgen's output, so it doesn't need to handle everything rust allows
+#[cfg(any(feature = "mock", feature = "dynamic-loading"))]
+fn extern_functions(bindings: &str) -> Vec<ExternFunction> {
+    let mut functions = vec![];
+    for block in extern_blocks(bindings) {
+        for declaration in block.split(';') {
+            let Some(index) = declaration.find("pub fn ") else {
+                continue;
+            };
+            let signature = declaration[index + "pub fn ".len()..].trim();
+            let arguments_start = signature.find('(').unwrap();
+
+            // find the end of the argument list, so function pointer arguments aren't mistaken for the return type
+            let mut depth = 0;
+            let mut arguments_end = 0;
+            for (i, c) in signature.char_indices() {
+                match c {
+                    '(' => depth += 1,
+                    ')' => {
+                        depth -= 1;
+                        if depth == 0 {
+                            arguments_end = i;
+                            break;
+                        }
+                    }
+                    _ => {}
+                }
+            }
+
+            functions.push(ExternFunction {
+                name: signature[..arguments_start].trim().to_string(),
+                arguments: signature[arguments_start + 1..arguments_end]
+                    .trim()
+                    .to_string(),
+                return_type: signature[arguments_end + 1..]
+                    .trim()
+                    .trim_start_matches("->")
+                    .trim()
+                    .to_string(),
+            });
+        }
+    }
+    functions
+}
+
+// the contents of every `extern "C" { ... }` block
+#[cfg(any(feature = "mock", feature = "dynamic-loading"))]
+fn extern_blocks(bindings: &str) -> Vec<&str> {
+    extern_block_ranges(bindings)
+        .into_iter()
+        .map(|(_, contents, _)| &bindings[contents])
+        .collect()
+}
+
+#[cfg(feature = "dynamic-loading")]
+fn strip_extern_blocks(bindings: &str) -> String {
+    let mut output = String::with_capacity(bindings.len());
+    let mut last = 0;
+    for (start, _, end) in extern_block_ranges(bindings) {
+        output.push_str(&bindings[last..start]);
+        last = end;
+    }
+    output.push_str(&bindings[last..]);
+    output
+}
+
+// (start of the block, range of its contents, end of the block)
+#[cfg(any(feature = "mock", feature = "dynamic-loading"))]
+fn extern_block_ranges(bindings: &str) -> Vec<(usize, std::ops::Range<usize>, usize)> {
+    const BLOCK_START: &str = "extern \"C\" {";
+
+    let mut ranges = vec![];
+    let mut offset = 0;
+    while let Some(index) = bindings[offset..].find(BLOCK_START) {
+        let start = offset + index;
+        let contents_start = start + BLOCK_START.len();
+        let mut depth = 1;
+        let mut end = bindings.len();
+        for (i, c) in bindings[contents_start..].char_indices() {
+            match c {
+                '{' => depth += 1,
+                '}' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        end = contents_start + i;
+                        break;
+                    }
+                }
+                _ => {}
+            }
+        }
+        ranges.push((start, contents_start..end, end + 1));
+        offset = end + 1;
+    }
+    ranges
+}
+
+#[cfg(feature = "dynamic-loading")]
+fn split_top_level(string: &str, separator: char) -> Vec<&str> {
+    let mut parts = vec![];
+    let mut depth = 0;
+    let mut start = 0;
+    let mut previous = ' ';
+    for (i, c) in string.char_indices() {
+        match c {
+            '(' | '<' | '[' => depth += 1,
+            // the > in -> isn't a closing bracket
+            '>' if previous == '-' => {}
+            ')' | '>' | ']' => depth -= 1,
+            c if c == separator && depth == 0 => {
+                parts.push(&string[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+        previous = c;
+    }
+    parts.push(&string[start..]);
+    parts
+}
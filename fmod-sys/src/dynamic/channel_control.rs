@@ -0,0 +1,392 @@
+// Copyright (c) 2024 Lily Lyons
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+// stand-ins for src/channel_control.cpp, which calls FMOD's C++ API and so can't be used with dynamic loading.
+//
+// the C API has no ChannelControl functions, only separate FMOD_Channel_* and FMOD_ChannelGroup_* ones,
+// and nothing in FMOD tells a channel and a channel group apart.
+// so every channel group the generated bindings hand out is remembered, and everything else is assumed to be a channel.
+//
+// FMOD frees some channel groups on its own (like those of event instances and buses), and we aren't told when.
+// so that a freed address FMOD reuses for a channel isn't mistaken for a group, handing out a channel forgets its address,
+// and releasing a system forgets every group that belonged to it.
+// the wrapper uses bool instead of FMOD_BOOL too, so those functions need converting.
+#![allow(clippy::missing_safety_doc, clippy::too_many_arguments)]
+
+use std::{
+    collections::HashMap,
+    ffi::{c_float, c_int, c_ulonglong, c_void},
+    sync::{Mutex, MutexGuard, PoisonError},
+};
+
+use crate::*;
+
+// channel group address -> the address of the system it belongs to
+static CHANNEL_GROUPS: Mutex<Option<HashMap<usize, usize>>> = Mutex::new(None);
+
+fn channel_groups() -> MutexGuard<'static, Option<HashMap<usize, usize>>> {
+    CHANNEL_GROUPS
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+}
+
+// called by the generated bindings after a function successfully wrote a channel group to `group`
+pub(crate) unsafe fn remember_channel_group(group: *mut *mut FMOD_CHANNELGROUP) {
+    if group.is_null() {
+        return;
+    }
+    let group = unsafe { group.read() };
+    if group.is_null() {
+        return;
+    }
+    let mut system = std::ptr::null_mut();
+    // called without holding the lock, as this goes through the generated bindings too
+    let _ = unsafe { FMOD_ChannelGroup_GetSystemObject(group, &mut system) };
+    channel_groups()
+        .get_or_insert_with(HashMap::new)
+        .insert(group as usize, system as usize);
+}
+
+// called by the generated bindings after a function successfully wrote a channel to `channel`
+pub(crate) unsafe fn forget_channel(channel: *mut *mut FMOD_CHANNEL) {
+    if channel.is_null() {
+        return;
+    }
+    let channel = unsafe { channel.read() };
+    if let Some(groups) = channel_groups().as_mut() {
+        groups.remove(&(channel as usize));
+    }
+}
+
+// called by the generated bindings after a channel group was released
+pub(crate) fn forget_channel_group(group: *mut FMOD_CHANNELGROUP) {
+    if let Some(groups) = channel_groups().as_mut() {
+        groups.remove(&(group as usize));
+    }
+}
+
+// called by the generated bindings after a system was released, which frees all of its channel groups
+pub(crate) fn forget_system(system: *mut FMOD_SYSTEM) {
+    if let Some(groups) = channel_groups().as_mut() {
+        groups.retain(|_, owner| *owner != system as usize);
+    }
+}
+
+// called by the generated bindings before a studio system is released, as its core system can't be retrieved afterwards
+pub(crate) unsafe fn studio_core_system(system: *mut FMOD_STUDIO_SYSTEM) -> *mut FMOD_SYSTEM {
+    let mut core = std::ptr::null_mut();
+    let _ = unsafe { FMOD_Studio_System_GetCoreSystem(system, &mut core) };
+    core
+}
+
+fn is_channel_group(channelcontrol: *mut FMOD_CHANNELCONTROL) -> bool {
+    channel_groups()
+        .as_ref()
+        .is_some_and(|groups| groups.contains_key(&(channelcontrol as usize)))
+}
+
+// calls the channel or channel group version of a function, depending on what `channelcontrol` is
+macro_rules! dispatch {
+    ($channelcontrol:expr, $channel:ident, $group:ident($($argument:expr),*)) => {
+        if is_channel_group($channelcontrol) {
+            $group($channelcontrol.cast(), $($argument),*)
+        } else {
+            $channel($channelcontrol.cast(), $($argument),*)
+        }
+    };
+}
+
+macro_rules! forward {
+    ($($wrapper:ident => $channel:ident, $group:ident($($argument:ident: $ty:ty),*);)*) => {
+        $(
+            pub unsafe fn $wrapper(channelcontrol: *mut FMOD_CHANNELCONTROL, $($argument: $ty),*) -> FMOD_RESULT {
+                unsafe { dispatch!(channelcontrol, $channel, $group($($argument),*)) }
+            }
+        )*
+    };
+}
+
+pub unsafe fn FMOD_Channel_CastToControl(channel: *mut FMOD_CHANNEL) -> *mut FMOD_CHANNELCONTROL {
+    channel.cast()
+}
+
+pub unsafe fn FMOD_ChannelGroup_CastToControl(
+    group: *mut FMOD_CHANNELGROUP,
+) -> *mut FMOD_CHANNELCONTROL {
+    group.cast()
+}
+
+forward! {
+    FMOD_ChannelControl_GetSystemObject => FMOD_Channel_GetSystemObject, FMOD_ChannelGroup_GetSystemObject(system: *mut *mut FMOD_SYSTEM);
+    FMOD_ChannelControl_Stop => FMOD_Channel_Stop, FMOD_ChannelGroup_Stop();
+    FMOD_ChannelControl_SetVolume => FMOD_Channel_SetVolume, FMOD_ChannelGroup_SetVolume(volume: c_float);
+    FMOD_ChannelControl_GetVolume => FMOD_Channel_GetVolume, FMOD_ChannelGroup_GetVolume(volume: *mut c_float);
+    FMOD_ChannelControl_GetAudibility => FMOD_Channel_GetAudibility, FMOD_ChannelGroup_GetAudibility(audibility: *mut c_float);
+    FMOD_ChannelControl_SetPitch => FMOD_Channel_SetPitch, FMOD_ChannelGroup_SetPitch(pitch: c_float);
+    FMOD_ChannelControl_GetPitch => FMOD_Channel_GetPitch, FMOD_ChannelGroup_GetPitch(pitch: *mut c_float);
+    FMOD_ChannelControl_SetReverbProperties => FMOD_Channel_SetReverbProperties, FMOD_ChannelGroup_SetReverbProperties(instance: c_int, wet: c_float);
+    FMOD_ChannelControl_GetReverbProperties => FMOD_Channel_GetReverbProperties, FMOD_ChannelGroup_GetReverbProperties(instance: c_int, wet: *mut c_float);
+    FMOD_ChannelControl_SetLowPassGain => FMOD_Channel_SetLowPassGain, FMOD_ChannelGroup_SetLowPassGain(gain: c_float);
+    FMOD_ChannelControl_GetLowPassGain => FMOD_Channel_GetLowPassGain, FMOD_ChannelGroup_GetLowPassGain(gain: *mut c_float);
+    FMOD_ChannelControl_SetMode => FMOD_Channel_SetMode, FMOD_ChannelGroup_SetMode(mode: FMOD_MODE);
+    FMOD_ChannelControl_GetMode => FMOD_Channel_GetMode, FMOD_ChannelGroup_GetMode(mode: *mut FMOD_MODE);
+    FMOD_ChannelControl_SetCallback => FMOD_Channel_SetCallback, FMOD_ChannelGroup_SetCallback(callback: FMOD_CHANNELCONTROL_CALLBACK);
+    FMOD_ChannelControl_SetPan => FMOD_Channel_SetPan, FMOD_ChannelGroup_SetPan(pan: c_float);
+    FMOD_ChannelControl_SetMixLevelsOutput => FMOD_Channel_SetMixLevelsOutput, FMOD_ChannelGroup_SetMixLevelsOutput(
+        frontleft: c_float,
+        frontright: c_float,
+        center: c_float,
+        lfe: c_float,
+        surroundleft: c_float,
+        surroundright: c_float,
+        backleft: c_float,
+        backright: c_float
+    );
+    FMOD_ChannelControl_SetMixLevelsInput => FMOD_Channel_SetMixLevelsInput, FMOD_ChannelGroup_SetMixLevelsInput(levels: *mut c_float, numlevels: c_int);
+    FMOD_ChannelControl_SetMixMatrix => FMOD_Channel_SetMixMatrix, FMOD_ChannelGroup_SetMixMatrix(
+        matrix: *mut c_float,
+        outchannels: c_int,
+        inchannels: c_int,
+        inchannel_hop: c_int
+    );
+    FMOD_ChannelControl_GetMixMatrix => FMOD_Channel_GetMixMatrix, FMOD_ChannelGroup_GetMixMatrix(
+        matrix: *mut c_float,
+        outchannels: *mut c_int,
+        inchannels: *mut c_int,
+        inchannel_hop: c_int
+    );
+    FMOD_ChannelControl_GetDSPClock => FMOD_Channel_GetDSPClock, FMOD_ChannelGroup_GetDSPClock(dspclock: *mut c_ulonglong, parentclock: *mut c_ulonglong);
+    FMOD_ChannelControl_AddFadePoint => FMOD_Channel_AddFadePoint, FMOD_ChannelGroup_AddFadePoint(dspclock: c_ulonglong, volume: c_float);
+    FMOD_ChannelControl_SetFadePointRamp => FMOD_Channel_SetFadePointRamp, FMOD_ChannelGroup_SetFadePointRamp(dspclock: c_ulonglong, volume: c_float);
+    FMOD_ChannelControl_RemoveFadePoints => FMOD_Channel_RemoveFadePoints, FMOD_ChannelGroup_RemoveFadePoints(
+        dspclock_start: c_ulonglong,
+        dspclock_end: c_ulonglong
+    );
+    FMOD_ChannelControl_GetFadePoints => FMOD_Channel_GetFadePoints, FMOD_ChannelGroup_GetFadePoints(
+        numpoints: *mut std::ffi::c_uint,
+        point_dspclock: *mut c_ulonglong,
+        point_volume: *mut c_float
+    );
+    FMOD_ChannelControl_GetDSP => FMOD_Channel_GetDSP, FMOD_ChannelGroup_GetDSP(index: c_int, dsp: *mut *mut FMOD_DSP);
+    FMOD_ChannelControl_AddDSP => FMOD_Channel_AddDSP, FMOD_ChannelGroup_AddDSP(index: c_int, dsp: *mut FMOD_DSP);
+    FMOD_ChannelControl_RemoveDSP => FMOD_Channel_RemoveDSP, FMOD_ChannelGroup_RemoveDSP(dsp: *mut FMOD_DSP);
+    FMOD_ChannelControl_GetNumDSPs => FMOD_Channel_GetNumDSPs, FMOD_ChannelGroup_GetNumDSPs(numdsps: *mut c_int);
+    FMOD_ChannelControl_SetDSPIndex => FMOD_Channel_SetDSPIndex, FMOD_ChannelGroup_SetDSPIndex(dsp: *mut FMOD_DSP, index: c_int);
+    FMOD_ChannelControl_GetDSPIndex => FMOD_Channel_GetDSPIndex, FMOD_ChannelGroup_GetDSPIndex(dsp: *mut FMOD_DSP, index: *mut c_int);
+    FMOD_ChannelControl_Set3DAttributes => FMOD_Channel_Set3DAttributes, FMOD_ChannelGroup_Set3DAttributes(pos: *const FMOD_VECTOR, vel: *const FMOD_VECTOR);
+    FMOD_ChannelControl_Get3DAttributes => FMOD_Channel_Get3DAttributes, FMOD_ChannelGroup_Get3DAttributes(pos: *mut FMOD_VECTOR, vel: *mut FMOD_VECTOR);
+    FMOD_ChannelControl_Set3DMinMaxDistance => FMOD_Channel_Set3DMinMaxDistance, FMOD_ChannelGroup_Set3DMinMaxDistance(mindistance: c_float, maxdistance: c_float);
+    FMOD_ChannelControl_Get3DMinMaxDistance => FMOD_Channel_Get3DMinMaxDistance, FMOD_ChannelGroup_Get3DMinMaxDistance(
+        mindistance: *mut c_float,
+        maxdistance: *mut c_float
+    );
+    FMOD_ChannelControl_Set3DConeSettings => FMOD_Channel_Set3DConeSettings, FMOD_ChannelGroup_Set3DConeSettings(
+        insideconeangle: c_float,
+        outsideconeangle: c_float,
+        outsidevolume: c_float
+    );
+    FMOD_ChannelControl_Get3DConeSettings => FMOD_Channel_Get3DConeSettings, FMOD_ChannelGroup_Get3DConeSettings(
+        insideconeangle: *mut c_float,
+        outsideconeangle: *mut c_float,
+        outsidevolume: *mut c_float
+    );
+    FMOD_ChannelControl_Set3DConeOrientation => FMOD_Channel_Set3DConeOrientation, FMOD_ChannelGroup_Set3DConeOrientation(orientation: *mut FMOD_VECTOR);
+    FMOD_ChannelControl_Get3DConeOrientation => FMOD_Channel_Get3DConeOrientation, FMOD_ChannelGroup_Get3DConeOrientation(orientation: *mut FMOD_VECTOR);
+    FMOD_ChannelControl_Set3DCustomRolloff => FMOD_Channel_Set3DCustomRolloff, FMOD_ChannelGroup_Set3DCustomRolloff(points: *mut FMOD_VECTOR, numpoints: c_int);
+    FMOD_ChannelControl_Get3DCustomRolloff => FMOD_Channel_Get3DCustomRolloff, FMOD_ChannelGroup_Get3DCustomRolloff(
+        points: *mut *mut FMOD_VECTOR,
+        numpoints: *mut c_int
+    );
+    FMOD_ChannelControl_Set3DOcclusion => FMOD_Channel_Set3DOcclusion, FMOD_ChannelGroup_Set3DOcclusion(
+        directocclusion: c_float,
+        reverbocclusion: c_float
+    );
+    FMOD_ChannelControl_Get3DOcclusion => FMOD_Channel_Get3DOcclusion, FMOD_ChannelGroup_Get3DOcclusion(
+        directocclusion: *mut c_float,
+        reverbocclusion: *mut c_float
+    );
+    FMOD_ChannelControl_Set3DSpread => FMOD_Channel_Set3DSpread, FMOD_ChannelGroup_Set3DSpread(angle: c_float);
+    FMOD_ChannelControl_Get3DSpread => FMOD_Channel_Get3DSpread, FMOD_ChannelGroup_Get3DSpread(angle: *mut c_float);
+    FMOD_ChannelControl_Set3DLevel => FMOD_Channel_Set3DLevel, FMOD_ChannelGroup_Set3DLevel(level: c_float);
+    FMOD_ChannelControl_Get3DLevel => FMOD_Channel_Get3DLevel, FMOD_ChannelGroup_Get3DLevel(level: *mut c_float);
+    FMOD_ChannelControl_Set3DDopplerLevel => FMOD_Channel_Set3DDopplerLevel, FMOD_ChannelGroup_Set3DDopplerLevel(level: c_float);
+    FMOD_ChannelControl_Get3DDopplerLevel => FMOD_Channel_Get3DDopplerLevel, FMOD_ChannelGroup_Get3DDopplerLevel(level: *mut c_float);
+    FMOD_ChannelControl_SetUserData => FMOD_Channel_SetUserData, FMOD_ChannelGroup_SetUserData(userdata: *mut c_void);
+    FMOD_ChannelControl_GetUserData => FMOD_Channel_GetUserData, FMOD_ChannelGroup_GetUserData(userdata: *mut *mut c_void);
+}
+
+// reads an FMOD_BOOL out parameter into a bool one
+unsafe fn get_bool(out: *mut bool, get: impl FnOnce(*mut FMOD_BOOL) -> FMOD_RESULT) -> FMOD_RESULT {
+    let mut value = FMOD_BOOL::FALSE;
+    let result = get(&mut value);
+    if !out.is_null() {
+        unsafe { out.write(value.into()) };
+    }
+    result
+}
+
+pub unsafe fn FMOD_ChannelControl_SetPaused(
+    channelcontrol: *mut FMOD_CHANNELCONTROL,
+    paused: bool,
+) -> FMOD_RESULT {
+    unsafe {
+        dispatch!(
+            channelcontrol,
+            FMOD_Channel_SetPaused,
+            FMOD_ChannelGroup_SetPaused(paused.into())
+        )
+    }
+}
+
+pub unsafe fn FMOD_ChannelControl_GetPaused(
+    channelcontrol: *mut FMOD_CHANNELCONTROL,
+    paused: *mut bool,
+) -> FMOD_RESULT {
+    unsafe {
+        get_bool(paused, |value| {
+            dispatch!(
+                channelcontrol,
+                FMOD_Channel_GetPaused,
+                FMOD_ChannelGroup_GetPaused(value)
+            )
+        })
+    }
+}
+
+pub unsafe fn FMOD_ChannelControl_SetVolumeRamp(
+    channelcontrol: *mut FMOD_CHANNELCONTROL,
+    ramp: bool,
+) -> FMOD_RESULT {
+    unsafe {
+        dispatch!(
+            channelcontrol,
+            FMOD_Channel_SetVolumeRamp,
+            FMOD_ChannelGroup_SetVolumeRamp(ramp.into())
+        )
+    }
+}
+
+pub unsafe fn FMOD_ChannelControl_GetVolumeRamp(
+    channelcontrol: *mut FMOD_CHANNELCONTROL,
+    ramp: *mut bool,
+) -> FMOD_RESULT {
+    unsafe {
+        get_bool(ramp, |value| {
+            dispatch!(
+                channelcontrol,
+                FMOD_Channel_GetVolumeRamp,
+                FMOD_ChannelGroup_GetVolumeRamp(value)
+            )
+        })
+    }
+}
+
+pub unsafe fn FMOD_ChannelControl_SetMute(
+    channelcontrol: *mut FMOD_CHANNELCONTROL,
+    mute: bool,
+) -> FMOD_RESULT {
+    unsafe {
+        dispatch!(
+            channelcontrol,
+            FMOD_Channel_SetMute,
+            FMOD_ChannelGroup_SetMute(mute.into())
+        )
+    }
+}
+
+pub unsafe fn FMOD_ChannelControl_GetMute(
+    channelcontrol: *mut FMOD_CHANNELCONTROL,
+    mute: *mut bool,
+) -> FMOD_RESULT {
+    unsafe {
+        get_bool(mute, |value| {
+            dispatch!(
+                channelcontrol,
+                FMOD_Channel_GetMute,
+                FMOD_ChannelGroup_GetMute(value)
+            )
+        })
+    }
+}
+
+pub unsafe fn FMOD_ChannelControl_IsPlaying(
+    channelcontrol: *mut FMOD_CHANNELCONTROL,
+    isplaying: *mut bool,
+) -> FMOD_RESULT {
+    unsafe {
+        get_bool(isplaying, |value| {
+            dispatch!(
+                channelcontrol,
+                FMOD_Channel_IsPlaying,
+                FMOD_ChannelGroup_IsPlaying(value)
+            )
+        })
+    }
+}
+
+pub unsafe fn FMOD_ChannelControl_SetDelay(
+    channelcontrol: *mut FMOD_CHANNELCONTROL,
+    dspclock_start: c_ulonglong,
+    dspclock_end: c_ulonglong,
+    stopchannels: bool,
+) -> FMOD_RESULT {
+    unsafe {
+        dispatch!(
+            channelcontrol,
+            FMOD_Channel_SetDelay,
+            FMOD_ChannelGroup_SetDelay(dspclock_start, dspclock_end, stopchannels.into())
+        )
+    }
+}
+
+pub unsafe fn FMOD_ChannelControl_GetDelay(
+    channelcontrol: *mut FMOD_CHANNELCONTROL,
+    dspclock_start: *mut c_ulonglong,
+    dspclock_end: *mut c_ulonglong,
+    stopchannels: *mut bool,
+) -> FMOD_RESULT {
+    unsafe {
+        get_bool(stopchannels, |value| {
+            dispatch!(
+                channelcontrol,
+                FMOD_Channel_GetDelay,
+                FMOD_ChannelGroup_GetDelay(dspclock_start, dspclock_end, value)
+            )
+        })
+    }
+}
+
+pub unsafe fn FMOD_ChannelControl_Set3DDistanceFilter(
+    channelcontrol: *mut FMOD_CHANNELCONTROL,
+    custom: bool,
+    customLevel: c_float,
+    centerFreq: c_float,
+) -> FMOD_RESULT {
+    unsafe {
+        dispatch!(
+            channelcontrol,
+            FMOD_Channel_Set3DDistanceFilter,
+            FMOD_ChannelGroup_Set3DDistanceFilter(custom.into(), customLevel, centerFreq)
+        )
+    }
+}
+
+pub unsafe fn FMOD_ChannelControl_Get3DDistanceFilter(
+    channelcontrol: *mut FMOD_CHANNELCONTROL,
+    custom: *mut bool,
+    customLevel: *mut c_float,
+    centerFreq: *mut c_float,
+) -> FMOD_RESULT {
+    unsafe {
+        get_bool(custom, |value| {
+            dispatch!(
+                channelcontrol,
+                FMOD_Channel_Get3DDistanceFilter,
+                FMOD_ChannelGroup_Get3DDistanceFilter(value, customLevel, centerFreq)
+            )
+        })
+    }
+}
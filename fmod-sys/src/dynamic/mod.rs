@@ -0,0 +1,202 @@
+// Copyright (c) 2024 Lily Lyons
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Loading FMOD at runtime, enabled with the `dynamic-loading` feature.
+//!
+//! With this feature nothing is linked at build time (the FMOD headers are still needed to generate bindings).
+//! Instead, [`load`] must be called before any other FMOD function.
+//! Until FMOD is loaded, every function returns `FMOD_ERR_UNINITIALIZED` (or a zeroed value, for functions that don't return an `FMOD_RESULT`),
+//! so a game can keep running without audio if the libraries are missing.
+//!
+//! Functions that are not exported by the loaded libraries return `FMOD_ERR_UNSUPPORTED`.
+
+use std::{
+    ffi::{c_uint, OsString},
+    path::Path,
+    sync::OnceLock,
+};
+
+use crate::*;
+
+// re-exported from the crate root, where the linked bindings would declare them
+#[doc(hidden)]
+pub mod channel_control;
+
+/// Which build of the FMOD libraries to load.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LibraryKind {
+    /// The release libraries (`fmod` and `fmodstudio`).
+    Release,
+    /// The logging libraries (`fmodL` and `fmodstudioL`), which print debug output through `FMOD_Debug_Initialize`.
+    Logging,
+}
+
+impl LibraryKind {
+    /// The platform specific file name of the core library, like `libfmodL.so` or `fmod.dll`.
+    pub fn core_filename(self) -> OsString {
+        libloading::library_filename(format!("fmod{}", self.suffix()))
+    }
+
+    /// The platform specific file name of the studio library, like `libfmodstudioL.so` or `fmodstudio.dll`.
+    pub fn studio_filename(self) -> OsString {
+        libloading::library_filename(format!("fmodstudio{}", self.suffix()))
+    }
+
+    fn suffix(self) -> &'static str {
+        match self {
+            LibraryKind::Release => "",
+            LibraryKind::Logging => "L",
+        }
+    }
+}
+
+struct Loaded {
+    functions: DynamicFunctions,
+    // never unloaded, as the functions point into them
+    _core: libloading::Library,
+    _studio: libloading::Library,
+}
+
+static LOADED: OnceLock<Loaded> = OnceLock::new();
+
+/// Loads the core and studio libraries of `kind` from `directory`.
+///
+/// See [`load_from`].
+///
+/// # Safety
+///
+/// See [`load_from`].
+pub unsafe fn load(directory: impl AsRef<Path>, kind: LibraryKind) -> Result<()> {
+    let directory = directory.as_ref();
+    unsafe {
+        load_from(
+            directory.join(kind.core_filename()),
+            directory.join(kind.studio_filename()),
+        )
+    }
+}
+
+/// Loads the core and studio libraries from the given paths.
+///
+/// The studio library links against the core library, so both must be the same [`LibraryKind`].
+///
+/// The version of the core library is checked against `FMOD_VERSION`.
+/// FMOD is compatible across minor versions, so only the product and major version need to match.
+///
+/// FMOD can only be loaded once, calling this function after FMOD has been loaded does nothing.
+///
+/// # Safety
+///
+/// Loading a library runs its initialization code, and the libraries must actually be FMOD!
+/// This must not be called concurrently with any other FMOD function.
+pub unsafe fn load_from(core: impl AsRef<Path>, studio: impl AsRef<Path>) -> Result<()> {
+    if is_loaded() {
+        return Ok(());
+    }
+
+    let core_path = core.as_ref();
+    let core = unsafe { open(core_path) }?;
+    let studio = unsafe { open(studio.as_ref()) }?;
+    let functions = unsafe { DynamicFunctions::load(&core, &studio) };
+    unsafe { check_version(&functions, core_path) }?;
+
+    // if we lost a race to load FMOD, ours is dropped, which is fine as nothing has used it
+    let _ = LOADED.set(Loaded {
+        functions,
+        _core: core,
+        _studio: studio,
+    });
+    Ok(())
+}
+
+/// Whether [`load`] or [`load_from`] has succeeded.
+pub fn is_loaded() -> bool {
+    LOADED.get().is_some()
+}
+
+// used by the generated functions to look up the function they call
+pub(crate) fn function<T>(
+    get: impl FnOnce(&DynamicFunctions) -> Option<T>,
+) -> std::result::Result<T, FMOD_RESULT> {
+    let loaded = LOADED.get().ok_or(FMOD_RESULT::FMOD_ERR_UNINITIALIZED)?;
+    get(&loaded.functions).ok_or(FMOD_RESULT::FMOD_ERR_UNSUPPORTED)
+}
+
+unsafe fn open(path: &Path) -> Result<libloading::Library> {
+    unsafe { libloading::Library::new(path) }.map_err(|e| Error::LoadLibrary {
+        path: path.to_path_buf(),
+        message: e.to_string(),
+    })
+}
+
+// FMOD doesn't have a way to get its version without a system, so we create one just to check
+unsafe fn check_version(functions: &DynamicFunctions, core_path: &Path) -> Result<()> {
+    let (Some(create), Some(get_version), Some(release)) = (
+        functions.FMOD_System_Create,
+        functions.FMOD_System_GetVersion,
+        functions.FMOD_System_Release,
+    ) else {
+        return Err(Error::LoadLibrary {
+            path: core_path.to_path_buf(),
+            message: "missing FMOD_System_Create, FMOD_System_GetVersion or FMOD_System_Release"
+                .to_string(),
+        });
+    };
+
+    let mut system = std::ptr::null_mut();
+    let result = unsafe { create(&mut system, FMOD_VERSION) };
+    // FMOD checks the version itself, but doesn't tell us what its version is
+    if result == FMOD_RESULT::FMOD_ERR_HEADER_MISMATCH {
+        return Err(Error::VersionMismatch {
+            expected: FMOD_VERSION,
+            found: None,
+        });
+    }
+    result.to_result()?;
+
+    let mut version = 0;
+    let result = unsafe { get_version(system, &mut version) };
+    unsafe { release(system) }.to_result()?;
+    result.to_result()?;
+
+    compare_versions(FMOD_VERSION, version)
+}
+
+fn compare_versions(expected: c_uint, found: c_uint) -> Result<()> {
+    // the lowest byte is the minor version, which doesn't affect compatibility
+    if found >> 8 != expected >> 8 {
+        return Err(Error::VersionMismatch {
+            expected,
+            found: Some(found),
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn versions() {
+        assert!(compare_versions(0x0002_0220, 0x0002_0220).is_ok());
+        assert!(compare_versions(0x0002_0220, 0x0002_0209).is_ok());
+
+        let error = compare_versions(0x0002_0220, 0x0002_0300).unwrap_err();
+        assert_eq!(
+            error,
+            Error::VersionMismatch {
+                expected: 0x0002_0220,
+                found: Some(0x0002_0300),
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "FMOD version mismatch: these bindings are for FMOD 2.02.20 but the library is FMOD 2.03.00"
+        );
+        assert!(compare_versions(0x0002_0220, 0x0001_0220).is_err());
+    }
+}
@@ -3,13 +3,20 @@
 //! Due to licensing restrictions, the FMOD API cannot be distributed with this crate.
 //! docs.rs documention is provided for the FMOD API but the actual API is not available without the FMOD library.
 //!
-//! Currently, this crate provides both core and studio bindings (no fsbank bindings).
+//! Currently, this crate provides both core and studio bindings.
+//! FSBank bindings are available with the `fsbank` feature.
 //!
 //! # Configuration
 //! In debug builds this crate will link against the logging version of the FMOD library.
 //! In release builds this crate will link against the release version of the FMOD library.
 //! You can force the debug version of the library by enabling the `force-debug` feature.
 //!
+//! Enabling the `dynamic-loading` feature loads FMOD at runtime instead of linking against it, see the `dynamic` module.
+//! `force-debug` has no effect with `dynamic-loading`, as the library is picked when it is loaded.
+//!
+//! Enabling the `mock` feature replaces FMOD with a Rust stand-in for tests, which doesn't need FMOD to be installed.
+//! See the `mock` module for what it supports.
+//!
 //! By default, this crate will search for an FMOD installation directory in the following places:
 //!
 //! (On windows)
@@ -33,23 +40,54 @@
 //!    - studio:
 //!      - inc
 //!      - lib (contains architecture specific directories)
+//!    - fsbank (only required with the `fsbank` feature):
+//!      - inc
+//!      - lib (contains architecture specific directories)
 #![allow(non_upper_case_globals)]
 #![allow(non_camel_case_types)]
 #![allow(non_snake_case)]
 #![doc(html_favicon_url = "https://www.fmod.com/assets/fmod-logo.svg")]
 #![doc(html_logo_url = "https://www.fmod.com/assets/fmod-logo.svg")]
 
-#[cfg(any(docsrs, feature = "force-docs-bindings"))]
+// the mock can't rely on generated bindings, as it's meant to build without FMOD installed
+#[cfg(feature = "mock")]
+include!("mock/bindings/fmod.rs");
+#[cfg(all(feature = "mock", feature = "fsbank"))]
+include!("mock/bindings/fsbank.rs");
+
+#[cfg(all(any(docsrs, feature = "force-docs-bindings"), not(feature = "mock")))]
 include!("../docs/documentation.rs");
 
-#[cfg(not(any(docsrs, feature = "force-docs-bindings")))]
+#[cfg(not(any(docsrs, feature = "force-docs-bindings", feature = "mock")))]
 include!(concat!(env!("OUT_DIR"), "/bindings.rs"));
 
+#[cfg(feature = "mock")]
+pub mod mock;
+
+#[cfg(feature = "dynamic-loading")]
+pub mod dynamic;
+#[cfg(feature = "dynamic-loading")]
+pub use dynamic::channel_control::*;
+
 #[derive(Clone, PartialEq, Eq)]
 pub enum Error {
     Fmod(FMOD_RESULT), // FIXME make FMOD_RESULT be a NonZero
     NulError(std::ffi::NulError),
     EnumFromPrivitive { name: &'static str, primitive: i64 },
+    /// A library could not be loaded by `dynamic::load`.
+    LoadLibrary {
+        path: std::path::PathBuf,
+        message: String,
+    },
+    /// The loaded FMOD library is not compatible with the headers these bindings were generated from.
+    ///
+    /// `found` is `None` if FMOD refused to create a system and so the version of the library is unknown.
+    VersionMismatch {
+        expected: std::ffi::c_uint,
+        found: Option<std::ffi::c_uint>,
+    },
+    /// An error returned by the FSBank API, holding the raw `FSBANK_RESULT`.
+    FsBank(i64),
 }
 
 impl std::fmt::Debug for Error {
@@ -65,6 +103,18 @@ impl std::fmt::Debug for Error {
                 .field("name", name)
                 .field("primitive", primitive)
                 .finish(),
+            Self::LoadLibrary { path, message } => debug_struct
+                .field("path", path)
+                .field("message", message)
+                .finish(),
+            Self::VersionMismatch { expected, found } => debug_struct
+                .field("expected", expected)
+                .field("found", found)
+                .finish(),
+            Self::FsBank(code) => debug_struct
+                .field("fsbank code", code)
+                .field("message", &fsbank_error_code_to_str(*code))
+                .finish(),
         }
     }
 }
@@ -77,6 +127,26 @@ impl std::fmt::Display for Error {
             Self::EnumFromPrivitive { name, primitive } => f.write_fmt(format_args!(
                 "No discriminant in enum `{name}` matches the value `{primitive:?}"
             )),
+            Self::LoadLibrary { path, message } => f.write_fmt(format_args!(
+                "Failed to load `{}`: {message}",
+                path.display()
+            )),
+            Self::VersionMismatch {
+                expected,
+                found: Some(found),
+            } => f.write_fmt(format_args!(
+                "FMOD version mismatch: these bindings are for FMOD {} but the library is FMOD {}",
+                version_to_string(*expected),
+                version_to_string(*found)
+            )),
+            Self::VersionMismatch {
+                expected,
+                found: None,
+            } => f.write_fmt(format_args!(
+                "FMOD version mismatch: these bindings are for FMOD {} but the library is a different version",
+                version_to_string(*expected)
+            )),
+            Self::FsBank(code) => f.write_str(fsbank_error_code_to_str(*code)),
         }
     }
 }
@@ -144,6 +214,9 @@ impl From<Error> for FMOD_RESULT {
             Error::Fmod(code) => code,
             Error::NulError(_) => FMOD_RESULT::FMOD_ERR_INVALID_PARAM,
             Error::EnumFromPrivitive { .. } => FMOD_RESULT::FMOD_ERR_INVALID_PARAM,
+            Error::LoadLibrary { .. } => FMOD_RESULT::FMOD_ERR_FILE_NOTFOUND,
+            Error::VersionMismatch { .. } => FMOD_RESULT::FMOD_ERR_HEADER_MISMATCH,
+            Error::FsBank(code) => fsbank_error_to_fmod(code),
         }
     }
 }
@@ -175,6 +248,16 @@ impl FMOD_BOOL {
     pub const TRUE: Self = Self(1);
 }
 
+// FMOD versions are 0xaaaabbcc, for product version aaaa, major version bb and minor version cc
+fn version_to_string(version: std::ffi::c_uint) -> String {
+    format!(
+        "{:x}.{:02x}.{:02x}",
+        version >> 16,
+        (version >> 8) & 0xFF,
+        version & 0xFF
+    )
+}
+
 #[allow(non_snake_case)]
 pub const fn error_code_to_str(result: FMOD_RESULT) -> &'static str {
     match result
@@ -264,3 +347,43 @@ pub const fn error_code_to_str(result: FMOD_RESULT) -> &'static str {
         _ =>                                               "Unknown error.",
     }
 }
+
+// FSBANK_RESULT isn't part of the bindings without the fsbank feature, so this matches on the raw values (in the order fsbank.h declares them)
+#[rustfmt::skip]
+pub const fn fsbank_error_code_to_str(result: i64) -> &'static str {
+    match result
+    {
+        0 =>  "No errors.",
+        1 =>  "An expected chunk is missing from the cache, perhaps try deleting cache files.",
+        2 =>  "The build process was cancelled during compilation by the user.",
+        3 =>  "The build process cannot continue due to previously ignored errors.",
+        4 =>  "Encoder for chosen format has encountered an unexpected error.",
+        5 =>  "Encoder initialization failed.",
+        6 =>  "Encoder for chosen format is not supported on this platform.",
+        7 =>  "An operating system based file error was encountered.",
+        8 =>  "A specified file could not be found.",
+        9 =>  "Internal error from FMOD sub-system.",
+        10 => "Already initialized.",
+        11 => "The format of the source file is invalid.",
+        12 => "An invalid parameter has been passed to this function.",
+        13 => "Run out of memory.",
+        14 => "Not initialized yet.",
+        15 => "Chosen encode format is not supported by this FSB version.",
+        16 => "Source file is too short for seamless looping. Looping disabled.",
+        17 => "FSBANK_BUILD_FILTERHIGHFREQ flag ignored: feature only supported by XMA format.",
+        18 => "FSBANK_BUILD_DISABLESEEKING flag ignored: feature only supported by XMA format.",
+        19 => "FSBANK_BUILD_DONTLOOP flag forced: looping is not supported by this format.",
+        _ =>  "Unknown error.",
+    }
+}
+
+const fn fsbank_error_to_fmod(result: i64) -> FMOD_RESULT {
+    match result {
+        8 => FMOD_RESULT::FMOD_ERR_FILE_NOTFOUND,
+        11 => FMOD_RESULT::FMOD_ERR_FORMAT,
+        12 => FMOD_RESULT::FMOD_ERR_INVALID_PARAM,
+        13 => FMOD_RESULT::FMOD_ERR_MEMORY,
+        14 => FMOD_RESULT::FMOD_ERR_UNINITIALIZED,
+        _ => FMOD_RESULT::FMOD_ERR_INTERNAL,
+    }
+}
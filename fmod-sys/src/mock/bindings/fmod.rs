@@ -0,0 +1,3769 @@
+// Hand-written subset of the FMOD Core and Studio bindings, used by the `mock` feature.
+//
+// These follow the same layout bindgen produces from the FMOD headers, but only declare what this workspace uses,
+// so the mock can build without the FMOD SDK. Functions declared here but not defined in the mock
+// get a stub generated by build.rs. When wrapping a new FMOD function, add its declaration (and any new types) here.
+//
+// The signatures match FMOD 2.02.
+
+pub const FMOD_VERSION: u32 = 131616;
+pub const FMOD_MAX_CHANNEL_WIDTH: u32 = 32;
+pub const FMOD_MAX_SYSTEMS: u32 = 8;
+pub const FMOD_MAX_LISTENERS: u32 = 8;
+pub const FMOD_REVERB_MAXINSTANCES: u32 = 4;
+pub const FMOD_PORT_INDEX_NONE: i32 = -1;
+pub const FMOD_PORT_INDEX_FLAG_VR_CONTROLLER: u64 = 1152921504606846976;
+pub const FMOD_DEBUG_LEVEL_NONE: u32 = 0;
+pub const FMOD_DEBUG_LEVEL_ERROR: u32 = 1;
+pub const FMOD_DEBUG_LEVEL_WARNING: u32 = 2;
+pub const FMOD_DEBUG_LEVEL_LOG: u32 = 4;
+pub const FMOD_DEBUG_TYPE_MEMORY: u32 = 256;
+pub const FMOD_DEBUG_TYPE_FILE: u32 = 512;
+pub const FMOD_DEBUG_TYPE_CODEC: u32 = 1024;
+pub const FMOD_DEBUG_TYPE_TRACE: u32 = 2048;
+pub const FMOD_DEBUG_DISPLAY_TIMESTAMPS: u32 = 65536;
+pub const FMOD_DEBUG_DISPLAY_LINENUMBERS: u32 = 131072;
+pub const FMOD_DEBUG_DISPLAY_THREAD: u32 = 262144;
+pub const FMOD_MEMORY_NORMAL: u32 = 0;
+pub const FMOD_MEMORY_STREAM_FILE: u32 = 1;
+pub const FMOD_MEMORY_STREAM_DECODE: u32 = 2;
+pub const FMOD_MEMORY_SAMPLEDATA: u32 = 4;
+pub const FMOD_MEMORY_DSP_BUFFER: u32 = 8;
+pub const FMOD_MEMORY_PLUGIN: u32 = 16;
+pub const FMOD_MEMORY_PERSISTENT: u32 = 2097152;
+pub const FMOD_MEMORY_ALL: u32 = 4294967295;
+pub const FMOD_INIT_NORMAL: u32 = 0;
+pub const FMOD_INIT_STREAM_FROM_UPDATE: u32 = 1;
+pub const FMOD_INIT_MIX_FROM_UPDATE: u32 = 2;
+pub const FMOD_INIT_3D_RIGHTHANDED: u32 = 4;
+pub const FMOD_INIT_CLIP_OUTPUT: u32 = 8;
+pub const FMOD_INIT_CHANNEL_LOWPASS: u32 = 256;
+pub const FMOD_INIT_CHANNEL_DISTANCEFILTER: u32 = 512;
+pub const FMOD_INIT_PROFILE_ENABLE: u32 = 65536;
+pub const FMOD_INIT_VOL0_BECOMES_VIRTUAL: u32 = 131072;
+pub const FMOD_INIT_GEOMETRY_USECLOSEST: u32 = 262144;
+pub const FMOD_INIT_PREFER_DOLBY_DOWNMIX: u32 = 524288;
+pub const FMOD_INIT_THREAD_UNSAFE: u32 = 1048576;
+pub const FMOD_INIT_PROFILE_METER_ALL: u32 = 2097152;
+pub const FMOD_INIT_MEMORY_TRACKING: u32 = 4194304;
+pub const FMOD_DRIVER_STATE_CONNECTED: u32 = 1;
+pub const FMOD_DRIVER_STATE_DEFAULT: u32 = 2;
+pub const FMOD_TIMEUNIT_MS: u32 = 1;
+pub const FMOD_TIMEUNIT_PCM: u32 = 2;
+pub const FMOD_TIMEUNIT_PCMBYTES: u32 = 4;
+pub const FMOD_TIMEUNIT_RAWBYTES: u32 = 8;
+pub const FMOD_TIMEUNIT_PCMFRACTION: u32 = 16;
+pub const FMOD_TIMEUNIT_MODORDER: u32 = 256;
+pub const FMOD_TIMEUNIT_MODROW: u32 = 512;
+pub const FMOD_TIMEUNIT_MODPATTERN: u32 = 1024;
+pub const FMOD_SYSTEM_CALLBACK_DEVICELISTCHANGED: u32 = 1;
+pub const FMOD_SYSTEM_CALLBACK_DEVICELOST: u32 = 2;
+pub const FMOD_SYSTEM_CALLBACK_MEMORYALLOCATIONFAILED: u32 = 4;
+pub const FMOD_SYSTEM_CALLBACK_THREADCREATED: u32 = 8;
+pub const FMOD_SYSTEM_CALLBACK_BADDSPCONNECTION: u32 = 16;
+pub const FMOD_SYSTEM_CALLBACK_PREMIX: u32 = 32;
+pub const FMOD_SYSTEM_CALLBACK_POSTMIX: u32 = 64;
+pub const FMOD_SYSTEM_CALLBACK_ERROR: u32 = 128;
+pub const FMOD_SYSTEM_CALLBACK_MIDMIX: u32 = 256;
+pub const FMOD_SYSTEM_CALLBACK_THREADDESTROYED: u32 = 512;
+pub const FMOD_SYSTEM_CALLBACK_PREUPDATE: u32 = 1024;
+pub const FMOD_SYSTEM_CALLBACK_POSTUPDATE: u32 = 2048;
+pub const FMOD_SYSTEM_CALLBACK_RECORDLISTCHANGED: u32 = 4096;
+pub const FMOD_SYSTEM_CALLBACK_BUFFEREDNOMIX: u32 = 8192;
+pub const FMOD_SYSTEM_CALLBACK_DEVICEREINITIALIZE: u32 = 16384;
+pub const FMOD_SYSTEM_CALLBACK_OUTPUTUNDERRUN: u32 = 32768;
+pub const FMOD_SYSTEM_CALLBACK_RECORDPOSITIONCHANGED: u32 = 65536;
+pub const FMOD_SYSTEM_CALLBACK_ALL: u32 = 4294967295;
+pub const FMOD_DEFAULT: u32 = 0;
+pub const FMOD_LOOP_OFF: u32 = 1;
+pub const FMOD_LOOP_NORMAL: u32 = 2;
+pub const FMOD_LOOP_BIDI: u32 = 4;
+pub const FMOD_2D: u32 = 8;
+pub const FMOD_3D: u32 = 16;
+pub const FMOD_CREATESTREAM: u32 = 128;
+pub const FMOD_CREATESAMPLE: u32 = 256;
+pub const FMOD_CREATECOMPRESSEDSAMPLE: u32 = 512;
+pub const FMOD_OPENUSER: u32 = 1024;
+pub const FMOD_OPENMEMORY: u32 = 2048;
+pub const FMOD_OPENMEMORY_POINT: u32 = 268435456;
+pub const FMOD_OPENRAW: u32 = 4096;
+pub const FMOD_OPENONLY: u32 = 8192;
+pub const FMOD_ACCURATETIME: u32 = 16384;
+pub const FMOD_MPEGSEARCH: u32 = 32768;
+pub const FMOD_NONBLOCKING: u32 = 65536;
+pub const FMOD_UNIQUE: u32 = 131072;
+pub const FMOD_3D_HEADRELATIVE: u32 = 262144;
+pub const FMOD_3D_WORLDRELATIVE: u32 = 524288;
+pub const FMOD_3D_INVERSEROLLOFF: u32 = 1048576;
+pub const FMOD_3D_LINEARROLLOFF: u32 = 2097152;
+pub const FMOD_3D_LINEARSQUAREROLLOFF: u32 = 4194304;
+pub const FMOD_3D_INVERSETAPEREDROLLOFF: u32 = 8388608;
+pub const FMOD_3D_CUSTOMROLLOFF: u32 = 67108864;
+pub const FMOD_3D_IGNOREGEOMETRY: u32 = 1073741824;
+pub const FMOD_IGNORETAGS: u32 = 33554432;
+pub const FMOD_LOWMEM: u32 = 134217728;
+pub const FMOD_VIRTUAL_PLAYFROMSTART: u32 = 2147483648;
+pub const FMOD_CHANNELMASK_FRONT_LEFT: u32 = 1;
+pub const FMOD_CHANNELMASK_FRONT_RIGHT: u32 = 2;
+pub const FMOD_CHANNELMASK_FRONT_CENTER: u32 = 4;
+pub const FMOD_CHANNELMASK_LOW_FREQUENCY: u32 = 8;
+pub const FMOD_CHANNELMASK_SURROUND_LEFT: u32 = 16;
+pub const FMOD_CHANNELMASK_SURROUND_RIGHT: u32 = 32;
+pub const FMOD_CHANNELMASK_BACK_LEFT: u32 = 64;
+pub const FMOD_CHANNELMASK_BACK_RIGHT: u32 = 128;
+pub const FMOD_CHANNELMASK_BACK_CENTER: u32 = 256;
+pub const FMOD_CHANNELMASK_MONO: u32 = 1;
+pub const FMOD_CHANNELMASK_STEREO: u32 = 3;
+pub const FMOD_CHANNELMASK_LRC: u32 = 7;
+pub const FMOD_CHANNELMASK_QUAD: u32 = 51;
+pub const FMOD_CHANNELMASK_SURROUND: u32 = 55;
+pub const FMOD_CHANNELMASK_5POINT1: u32 = 63;
+pub const FMOD_CHANNELMASK_5POINT1_REARS: u32 = 207;
+pub const FMOD_CHANNELMASK_7POINT0: u32 = 247;
+pub const FMOD_CHANNELMASK_7POINT1: u32 = 255;
+pub const FMOD_THREAD_PRIORITY_PLATFORM_MIN: i32 = -32768;
+pub const FMOD_THREAD_PRIORITY_PLATFORM_MAX: u32 = 32768;
+pub const FMOD_THREAD_PRIORITY_DEFAULT: i32 = -32769;
+pub const FMOD_THREAD_PRIORITY_LOW: i32 = -32770;
+pub const FMOD_THREAD_PRIORITY_MEDIUM: i32 = -32771;
+pub const FMOD_THREAD_PRIORITY_HIGH: i32 = -32772;
+pub const FMOD_THREAD_PRIORITY_VERY_HIGH: i32 = -32773;
+pub const FMOD_THREAD_PRIORITY_EXTREME: i32 = -32774;
+pub const FMOD_THREAD_PRIORITY_CRITICAL: i32 = -32775;
+pub const FMOD_THREAD_PRIORITY_MIXER: i32 = -32774;
+pub const FMOD_THREAD_PRIORITY_FEEDER: i32 = -32775;
+pub const FMOD_THREAD_PRIORITY_STREAM: i32 = -32773;
+pub const FMOD_THREAD_PRIORITY_FILE: i32 = -32772;
+pub const FMOD_THREAD_PRIORITY_NONBLOCKING: i32 = -32772;
+pub const FMOD_THREAD_PRIORITY_RECORD: i32 = -32772;
+pub const FMOD_THREAD_PRIORITY_GEOMETRY: i32 = -32770;
+pub const FMOD_THREAD_PRIORITY_PROFILER: i32 = -32771;
+pub const FMOD_THREAD_PRIORITY_STUDIO_UPDATE: i32 = -32771;
+pub const FMOD_THREAD_PRIORITY_STUDIO_LOAD_BANK: i32 = -32771;
+pub const FMOD_THREAD_PRIORITY_STUDIO_LOAD_SAMPLE: i32 = -32771;
+pub const FMOD_THREAD_PRIORITY_CONVOLUTION1: i32 = -32773;
+pub const FMOD_THREAD_PRIORITY_CONVOLUTION2: i32 = -32773;
+pub const FMOD_THREAD_STACK_SIZE_DEFAULT: u32 = 0;
+pub const FMOD_THREAD_STACK_SIZE_MIXER: u32 = 81920;
+pub const FMOD_THREAD_STACK_SIZE_FEEDER: u32 = 16384;
+pub const FMOD_THREAD_STACK_SIZE_STREAM: u32 = 98304;
+pub const FMOD_THREAD_STACK_SIZE_FILE: u32 = 65536;
+pub const FMOD_THREAD_STACK_SIZE_NONBLOCKING: u32 = 114688;
+pub const FMOD_THREAD_STACK_SIZE_RECORD: u32 = 16384;
+pub const FMOD_THREAD_STACK_SIZE_GEOMETRY: u32 = 49152;
+pub const FMOD_THREAD_STACK_SIZE_PROFILER: u32 = 131072;
+pub const FMOD_THREAD_STACK_SIZE_STUDIO_UPDATE: u32 = 98304;
+pub const FMOD_THREAD_STACK_SIZE_STUDIO_LOAD_BANK: u32 = 98304;
+pub const FMOD_THREAD_STACK_SIZE_STUDIO_LOAD_SAMPLE: u32 = 98304;
+pub const FMOD_THREAD_STACK_SIZE_CONVOLUTION1: u32 = 16384;
+pub const FMOD_THREAD_STACK_SIZE_CONVOLUTION2: u32 = 16384;
+pub const FMOD_THREAD_AFFINITY_GROUP_DEFAULT: u64 = 4611686018427387904;
+pub const FMOD_THREAD_AFFINITY_GROUP_A: u64 = 4611686018427387905;
+pub const FMOD_THREAD_AFFINITY_GROUP_B: u64 = 4611686018427387906;
+pub const FMOD_THREAD_AFFINITY_GROUP_C: u64 = 4611686018427387907;
+pub const FMOD_THREAD_AFFINITY_MIXER: u64 = 4611686018427387905;
+pub const FMOD_THREAD_AFFINITY_FEEDER: u64 = 4611686018427387907;
+pub const FMOD_THREAD_AFFINITY_STREAM: u64 = 4611686018427387907;
+pub const FMOD_THREAD_AFFINITY_FILE: u64 = 4611686018427387907;
+pub const FMOD_THREAD_AFFINITY_NONBLOCKING: u64 = 4611686018427387907;
+pub const FMOD_THREAD_AFFINITY_RECORD: u64 = 4611686018427387907;
+pub const FMOD_THREAD_AFFINITY_GEOMETRY: u64 = 4611686018427387907;
+pub const FMOD_THREAD_AFFINITY_PROFILER: u64 = 4611686018427387907;
+pub const FMOD_THREAD_AFFINITY_STUDIO_UPDATE: u64 = 4611686018427387906;
+pub const FMOD_THREAD_AFFINITY_STUDIO_LOAD_BANK: u64 = 4611686018427387907;
+pub const FMOD_THREAD_AFFINITY_STUDIO_LOAD_SAMPLE: u64 = 4611686018427387907;
+pub const FMOD_THREAD_AFFINITY_CONVOLUTION1: u64 = 4611686018427387907;
+pub const FMOD_THREAD_AFFINITY_CONVOLUTION2: u64 = 4611686018427387907;
+pub const FMOD_THREAD_AFFINITY_CORE_ALL: u32 = 0;
+pub const FMOD_THREAD_AFFINITY_CORE_0: u32 = 1;
+pub const FMOD_THREAD_AFFINITY_CORE_1: u32 = 2;
+pub const FMOD_THREAD_AFFINITY_CORE_2: u32 = 4;
+pub const FMOD_THREAD_AFFINITY_CORE_3: u32 = 8;
+pub const FMOD_THREAD_AFFINITY_CORE_4: u32 = 16;
+pub const FMOD_THREAD_AFFINITY_CORE_5: u32 = 32;
+pub const FMOD_THREAD_AFFINITY_CORE_6: u32 = 64;
+pub const FMOD_THREAD_AFFINITY_CORE_7: u32 = 128;
+pub const FMOD_THREAD_AFFINITY_CORE_8: u32 = 256;
+pub const FMOD_THREAD_AFFINITY_CORE_9: u32 = 512;
+pub const FMOD_THREAD_AFFINITY_CORE_10: u32 = 1024;
+pub const FMOD_THREAD_AFFINITY_CORE_11: u32 = 2048;
+pub const FMOD_THREAD_AFFINITY_CORE_12: u32 = 4096;
+pub const FMOD_THREAD_AFFINITY_CORE_13: u32 = 8192;
+pub const FMOD_THREAD_AFFINITY_CORE_14: u32 = 16384;
+pub const FMOD_THREAD_AFFINITY_CORE_15: u32 = 32768;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct FMOD_SYSTEM {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct FMOD_SOUND {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct FMOD_CHANNELCONTROL {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct FMOD_CHANNEL {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct FMOD_CHANNELGROUP {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct FMOD_SOUNDGROUP {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct FMOD_REVERB3D {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct FMOD_DSP {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct FMOD_DSPCONNECTION {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct FMOD_POLYGON {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct FMOD_GEOMETRY {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct FMOD_SYNCPOINT {
+    _unused: [u8; 0],
+}
+// the plugin SDK structs are only ever passed around by pointer, so the mock leaves them opaque
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct FMOD_DSP_DESCRIPTION {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct FMOD_CODEC_DESCRIPTION {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct FMOD_OUTPUT_DESCRIPTION {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct FMOD_DSP_STATE {
+    _unused: [u8; 0],
+}
+
+#[repr(transparent)]
+#[derive(Debug, Default, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct FMOD_BOOL(pub ::std::os::raw::c_int);
+pub type FMOD_DEBUG_FLAGS = ::std::os::raw::c_uint;
+pub type FMOD_MEMORY_TYPE = ::std::os::raw::c_uint;
+pub type FMOD_INITFLAGS = ::std::os::raw::c_uint;
+pub type FMOD_DRIVER_STATE = ::std::os::raw::c_uint;
+pub type FMOD_TIMEUNIT = ::std::os::raw::c_uint;
+pub type FMOD_SYSTEM_CALLBACK_TYPE = ::std::os::raw::c_uint;
+pub type FMOD_MODE = ::std::os::raw::c_uint;
+pub type FMOD_CHANNELMASK = ::std::os::raw::c_uint;
+pub type FMOD_PORT_INDEX = ::std::os::raw::c_ulonglong;
+pub type FMOD_THREAD_PRIORITY = ::std::os::raw::c_int;
+pub type FMOD_THREAD_STACK_SIZE = ::std::os::raw::c_uint;
+pub type FMOD_THREAD_AFFINITY = ::std::os::raw::c_longlong;
+
+pub const FMOD_THREAD_TYPE_MIXER: FMOD_THREAD_TYPE = 0;
+pub const FMOD_THREAD_TYPE_FEEDER: FMOD_THREAD_TYPE = 1;
+pub const FMOD_THREAD_TYPE_STREAM: FMOD_THREAD_TYPE = 2;
+pub const FMOD_THREAD_TYPE_FILE: FMOD_THREAD_TYPE = 3;
+pub const FMOD_THREAD_TYPE_NONBLOCKING: FMOD_THREAD_TYPE = 4;
+pub const FMOD_THREAD_TYPE_RECORD: FMOD_THREAD_TYPE = 5;
+pub const FMOD_THREAD_TYPE_GEOMETRY: FMOD_THREAD_TYPE = 6;
+pub const FMOD_THREAD_TYPE_PROFILER: FMOD_THREAD_TYPE = 7;
+pub const FMOD_THREAD_TYPE_STUDIO_UPDATE: FMOD_THREAD_TYPE = 8;
+pub const FMOD_THREAD_TYPE_STUDIO_LOAD_BANK: FMOD_THREAD_TYPE = 9;
+pub const FMOD_THREAD_TYPE_STUDIO_LOAD_SAMPLE: FMOD_THREAD_TYPE = 10;
+pub const FMOD_THREAD_TYPE_CONVOLUTION1: FMOD_THREAD_TYPE = 11;
+pub const FMOD_THREAD_TYPE_CONVOLUTION2: FMOD_THREAD_TYPE = 12;
+pub const FMOD_THREAD_TYPE_MAX: FMOD_THREAD_TYPE = 13;
+pub const FMOD_THREAD_TYPE_FORCEINT: FMOD_THREAD_TYPE = 65536;
+pub type FMOD_THREAD_TYPE = ::std::os::raw::c_uint;
+pub const FMOD_CHANNELCONTROL_CHANNEL: FMOD_CHANNELCONTROL_TYPE = 0;
+pub const FMOD_CHANNELCONTROL_CHANNELGROUP: FMOD_CHANNELCONTROL_TYPE = 1;
+pub const FMOD_CHANNELCONTROL_MAX: FMOD_CHANNELCONTROL_TYPE = 2;
+pub const FMOD_CHANNELCONTROL_FORCEINT: FMOD_CHANNELCONTROL_TYPE = 65536;
+pub type FMOD_CHANNELCONTROL_TYPE = ::std::os::raw::c_uint;
+pub const FMOD_OUTPUTTYPE_AUTODETECT: FMOD_OUTPUTTYPE = 0;
+pub const FMOD_OUTPUTTYPE_UNKNOWN: FMOD_OUTPUTTYPE = 1;
+pub const FMOD_OUTPUTTYPE_NOSOUND: FMOD_OUTPUTTYPE = 2;
+pub const FMOD_OUTPUTTYPE_WAVWRITER: FMOD_OUTPUTTYPE = 3;
+pub const FMOD_OUTPUTTYPE_NOSOUND_NRT: FMOD_OUTPUTTYPE = 4;
+pub const FMOD_OUTPUTTYPE_WAVWRITER_NRT: FMOD_OUTPUTTYPE = 5;
+pub const FMOD_OUTPUTTYPE_WASAPI: FMOD_OUTPUTTYPE = 6;
+pub const FMOD_OUTPUTTYPE_ASIO: FMOD_OUTPUTTYPE = 7;
+pub const FMOD_OUTPUTTYPE_PULSEAUDIO: FMOD_OUTPUTTYPE = 8;
+pub const FMOD_OUTPUTTYPE_ALSA: FMOD_OUTPUTTYPE = 9;
+pub const FMOD_OUTPUTTYPE_COREAUDIO: FMOD_OUTPUTTYPE = 10;
+pub const FMOD_OUTPUTTYPE_AUDIOTRACK: FMOD_OUTPUTTYPE = 11;
+pub const FMOD_OUTPUTTYPE_OPENSL: FMOD_OUTPUTTYPE = 12;
+pub const FMOD_OUTPUTTYPE_AUDIOOUT: FMOD_OUTPUTTYPE = 13;
+pub const FMOD_OUTPUTTYPE_AUDIO3D: FMOD_OUTPUTTYPE = 14;
+pub const FMOD_OUTPUTTYPE_WEBAUDIO: FMOD_OUTPUTTYPE = 15;
+pub const FMOD_OUTPUTTYPE_NNAUDIO: FMOD_OUTPUTTYPE = 16;
+pub const FMOD_OUTPUTTYPE_WINSONIC: FMOD_OUTPUTTYPE = 17;
+pub const FMOD_OUTPUTTYPE_AAUDIO: FMOD_OUTPUTTYPE = 18;
+pub const FMOD_OUTPUTTYPE_AUDIOWORKLET: FMOD_OUTPUTTYPE = 19;
+pub const FMOD_OUTPUTTYPE_PHASE: FMOD_OUTPUTTYPE = 20;
+pub const FMOD_OUTPUTTYPE_OHAUDIO: FMOD_OUTPUTTYPE = 21;
+pub const FMOD_OUTPUTTYPE_MAX: FMOD_OUTPUTTYPE = 22;
+pub const FMOD_OUTPUTTYPE_FORCEINT: FMOD_OUTPUTTYPE = 65536;
+pub type FMOD_OUTPUTTYPE = ::std::os::raw::c_uint;
+pub const FMOD_DEBUG_MODE_TTY: FMOD_DEBUG_MODE = 0;
+pub const FMOD_DEBUG_MODE_FILE: FMOD_DEBUG_MODE = 1;
+pub const FMOD_DEBUG_MODE_CALLBACK: FMOD_DEBUG_MODE = 2;
+pub const FMOD_DEBUG_MODE_FORCEINT: FMOD_DEBUG_MODE = 65536;
+pub type FMOD_DEBUG_MODE = ::std::os::raw::c_uint;
+pub const FMOD_SPEAKERMODE_DEFAULT: FMOD_SPEAKERMODE = 0;
+pub const FMOD_SPEAKERMODE_RAW: FMOD_SPEAKERMODE = 1;
+pub const FMOD_SPEAKERMODE_MONO: FMOD_SPEAKERMODE = 2;
+pub const FMOD_SPEAKERMODE_STEREO: FMOD_SPEAKERMODE = 3;
+pub const FMOD_SPEAKERMODE_QUAD: FMOD_SPEAKERMODE = 4;
+pub const FMOD_SPEAKERMODE_SURROUND: FMOD_SPEAKERMODE = 5;
+pub const FMOD_SPEAKERMODE_5POINT1: FMOD_SPEAKERMODE = 6;
+pub const FMOD_SPEAKERMODE_7POINT1: FMOD_SPEAKERMODE = 7;
+pub const FMOD_SPEAKERMODE_7POINT1POINT4: FMOD_SPEAKERMODE = 8;
+pub const FMOD_SPEAKERMODE_MAX: FMOD_SPEAKERMODE = 9;
+pub const FMOD_SPEAKERMODE_FORCEINT: FMOD_SPEAKERMODE = 65536;
+pub type FMOD_SPEAKERMODE = ::std::os::raw::c_uint;
+pub const FMOD_SPEAKER_NONE: FMOD_SPEAKER = -1;
+pub const FMOD_SPEAKER_FRONT_LEFT: FMOD_SPEAKER = 0;
+pub const FMOD_SPEAKER_FRONT_RIGHT: FMOD_SPEAKER = 1;
+pub const FMOD_SPEAKER_FRONT_CENTER: FMOD_SPEAKER = 2;
+pub const FMOD_SPEAKER_LOW_FREQUENCY: FMOD_SPEAKER = 3;
+pub const FMOD_SPEAKER_SURROUND_LEFT: FMOD_SPEAKER = 4;
+pub const FMOD_SPEAKER_SURROUND_RIGHT: FMOD_SPEAKER = 5;
+pub const FMOD_SPEAKER_BACK_LEFT: FMOD_SPEAKER = 6;
+pub const FMOD_SPEAKER_BACK_RIGHT: FMOD_SPEAKER = 7;
+pub const FMOD_SPEAKER_TOP_FRONT_LEFT: FMOD_SPEAKER = 8;
+pub const FMOD_SPEAKER_TOP_FRONT_RIGHT: FMOD_SPEAKER = 9;
+pub const FMOD_SPEAKER_TOP_BACK_LEFT: FMOD_SPEAKER = 10;
+pub const FMOD_SPEAKER_TOP_BACK_RIGHT: FMOD_SPEAKER = 11;
+pub const FMOD_SPEAKER_MAX: FMOD_SPEAKER = 12;
+pub const FMOD_SPEAKER_FORCEINT: FMOD_SPEAKER = 65536;
+pub type FMOD_SPEAKER = ::std::os::raw::c_int;
+pub const FMOD_CHANNELORDER_DEFAULT: FMOD_CHANNELORDER = 0;
+pub const FMOD_CHANNELORDER_WAVEFORMAT: FMOD_CHANNELORDER = 1;
+pub const FMOD_CHANNELORDER_PROTOOLS: FMOD_CHANNELORDER = 2;
+pub const FMOD_CHANNELORDER_ALLMONO: FMOD_CHANNELORDER = 3;
+pub const FMOD_CHANNELORDER_ALLSTEREO: FMOD_CHANNELORDER = 4;
+pub const FMOD_CHANNELORDER_ALSA: FMOD_CHANNELORDER = 5;
+pub const FMOD_CHANNELORDER_MAX: FMOD_CHANNELORDER = 6;
+pub const FMOD_CHANNELORDER_FORCEINT: FMOD_CHANNELORDER = 65536;
+pub type FMOD_CHANNELORDER = ::std::os::raw::c_uint;
+pub const FMOD_PLUGINTYPE_OUTPUT: FMOD_PLUGINTYPE = 0;
+pub const FMOD_PLUGINTYPE_CODEC: FMOD_PLUGINTYPE = 1;
+pub const FMOD_PLUGINTYPE_DSP: FMOD_PLUGINTYPE = 2;
+pub const FMOD_PLUGINTYPE_MAX: FMOD_PLUGINTYPE = 3;
+pub const FMOD_PLUGINTYPE_FORCEINT: FMOD_PLUGINTYPE = 65536;
+pub type FMOD_PLUGINTYPE = ::std::os::raw::c_uint;
+pub const FMOD_SOUND_TYPE_UNKNOWN: FMOD_SOUND_TYPE = 0;
+pub const FMOD_SOUND_TYPE_AIFF: FMOD_SOUND_TYPE = 1;
+pub const FMOD_SOUND_TYPE_ASF: FMOD_SOUND_TYPE = 2;
+pub const FMOD_SOUND_TYPE_DLS: FMOD_SOUND_TYPE = 3;
+pub const FMOD_SOUND_TYPE_FLAC: FMOD_SOUND_TYPE = 4;
+pub const FMOD_SOUND_TYPE_FSB: FMOD_SOUND_TYPE = 5;
+pub const FMOD_SOUND_TYPE_IT: FMOD_SOUND_TYPE = 6;
+pub const FMOD_SOUND_TYPE_MIDI: FMOD_SOUND_TYPE = 7;
+pub const FMOD_SOUND_TYPE_MOD: FMOD_SOUND_TYPE = 8;
+pub const FMOD_SOUND_TYPE_MPEG: FMOD_SOUND_TYPE = 9;
+pub const FMOD_SOUND_TYPE_OGGVORBIS: FMOD_SOUND_TYPE = 10;
+pub const FMOD_SOUND_TYPE_PLAYLIST: FMOD_SOUND_TYPE = 11;
+pub const FMOD_SOUND_TYPE_RAW: FMOD_SOUND_TYPE = 12;
+pub const FMOD_SOUND_TYPE_S3M: FMOD_SOUND_TYPE = 13;
+pub const FMOD_SOUND_TYPE_USER: FMOD_SOUND_TYPE = 14;
+pub const FMOD_SOUND_TYPE_WAV: FMOD_SOUND_TYPE = 15;
+pub const FMOD_SOUND_TYPE_XM: FMOD_SOUND_TYPE = 16;
+pub const FMOD_SOUND_TYPE_XMA: FMOD_SOUND_TYPE = 17;
+pub const FMOD_SOUND_TYPE_AUDIOQUEUE: FMOD_SOUND_TYPE = 18;
+pub const FMOD_SOUND_TYPE_AT9: FMOD_SOUND_TYPE = 19;
+pub const FMOD_SOUND_TYPE_VORBIS: FMOD_SOUND_TYPE = 20;
+pub const FMOD_SOUND_TYPE_MEDIA_FOUNDATION: FMOD_SOUND_TYPE = 21;
+pub const FMOD_SOUND_TYPE_MEDIACODEC: FMOD_SOUND_TYPE = 22;
+pub const FMOD_SOUND_TYPE_FADPCM: FMOD_SOUND_TYPE = 23;
+pub const FMOD_SOUND_TYPE_OPUS: FMOD_SOUND_TYPE = 24;
+pub const FMOD_SOUND_TYPE_MAX: FMOD_SOUND_TYPE = 25;
+pub const FMOD_SOUND_TYPE_FORCEINT: FMOD_SOUND_TYPE = 65536;
+pub type FMOD_SOUND_TYPE = ::std::os::raw::c_uint;
+pub const FMOD_SOUND_FORMAT_NONE: FMOD_SOUND_FORMAT = 0;
+pub const FMOD_SOUND_FORMAT_PCM8: FMOD_SOUND_FORMAT = 1;
+pub const FMOD_SOUND_FORMAT_PCM16: FMOD_SOUND_FORMAT = 2;
+pub const FMOD_SOUND_FORMAT_PCM24: FMOD_SOUND_FORMAT = 3;
+pub const FMOD_SOUND_FORMAT_PCM32: FMOD_SOUND_FORMAT = 4;
+pub const FMOD_SOUND_FORMAT_PCMFLOAT: FMOD_SOUND_FORMAT = 5;
+pub const FMOD_SOUND_FORMAT_BITSTREAM: FMOD_SOUND_FORMAT = 6;
+pub const FMOD_SOUND_FORMAT_MAX: FMOD_SOUND_FORMAT = 7;
+pub const FMOD_SOUND_FORMAT_FORCEINT: FMOD_SOUND_FORMAT = 65536;
+pub type FMOD_SOUND_FORMAT = ::std::os::raw::c_uint;
+pub const FMOD_OPENSTATE_READY: FMOD_OPENSTATE = 0;
+pub const FMOD_OPENSTATE_LOADING: FMOD_OPENSTATE = 1;
+pub const FMOD_OPENSTATE_ERROR: FMOD_OPENSTATE = 2;
+pub const FMOD_OPENSTATE_CONNECTING: FMOD_OPENSTATE = 3;
+pub const FMOD_OPENSTATE_BUFFERING: FMOD_OPENSTATE = 4;
+pub const FMOD_OPENSTATE_SEEKING: FMOD_OPENSTATE = 5;
+pub const FMOD_OPENSTATE_PLAYING: FMOD_OPENSTATE = 6;
+pub const FMOD_OPENSTATE_SETPOSITION: FMOD_OPENSTATE = 7;
+pub const FMOD_OPENSTATE_MAX: FMOD_OPENSTATE = 8;
+pub const FMOD_OPENSTATE_FORCEINT: FMOD_OPENSTATE = 65536;
+pub type FMOD_OPENSTATE = ::std::os::raw::c_uint;
+pub const FMOD_SOUNDGROUP_BEHAVIOR_FAIL: FMOD_SOUNDGROUP_BEHAVIOR = 0;
+pub const FMOD_SOUNDGROUP_BEHAVIOR_MUTE: FMOD_SOUNDGROUP_BEHAVIOR = 1;
+pub const FMOD_SOUNDGROUP_BEHAVIOR_STEALLOWEST: FMOD_SOUNDGROUP_BEHAVIOR = 2;
+pub const FMOD_SOUNDGROUP_BEHAVIOR_MAX: FMOD_SOUNDGROUP_BEHAVIOR = 3;
+pub const FMOD_SOUNDGROUP_BEHAVIOR_FORCEINT: FMOD_SOUNDGROUP_BEHAVIOR = 65536;
+pub type FMOD_SOUNDGROUP_BEHAVIOR = ::std::os::raw::c_uint;
+pub const FMOD_CHANNELCONTROL_CALLBACK_END: FMOD_CHANNELCONTROL_CALLBACK_TYPE = 0;
+pub const FMOD_CHANNELCONTROL_CALLBACK_VIRTUALVOICE: FMOD_CHANNELCONTROL_CALLBACK_TYPE = 1;
+pub const FMOD_CHANNELCONTROL_CALLBACK_SYNCPOINT: FMOD_CHANNELCONTROL_CALLBACK_TYPE = 2;
+pub const FMOD_CHANNELCONTROL_CALLBACK_OCCLUSION: FMOD_CHANNELCONTROL_CALLBACK_TYPE = 3;
+pub const FMOD_CHANNELCONTROL_CALLBACK_MAX: FMOD_CHANNELCONTROL_CALLBACK_TYPE = 4;
+pub const FMOD_CHANNELCONTROL_CALLBACK_FORCEINT: FMOD_CHANNELCONTROL_CALLBACK_TYPE = 65536;
+pub type FMOD_CHANNELCONTROL_CALLBACK_TYPE = ::std::os::raw::c_uint;
+pub const FMOD_CHANNELCONTROL_DSP_HEAD: FMOD_CHANNELCONTROL_DSP_INDEX = -1;
+pub const FMOD_CHANNELCONTROL_DSP_FADER: FMOD_CHANNELCONTROL_DSP_INDEX = -2;
+pub const FMOD_CHANNELCONTROL_DSP_TAIL: FMOD_CHANNELCONTROL_DSP_INDEX = -3;
+pub const FMOD_CHANNELCONTROL_DSP_FORCEINT: FMOD_CHANNELCONTROL_DSP_INDEX = 65536;
+pub type FMOD_CHANNELCONTROL_DSP_INDEX = ::std::os::raw::c_int;
+pub const FMOD_ERRORCALLBACK_INSTANCETYPE_NONE: FMOD_ERRORCALLBACK_INSTANCETYPE = 0;
+pub const FMOD_ERRORCALLBACK_INSTANCETYPE_SYSTEM: FMOD_ERRORCALLBACK_INSTANCETYPE = 1;
+pub const FMOD_ERRORCALLBACK_INSTANCETYPE_CHANNEL: FMOD_ERRORCALLBACK_INSTANCETYPE = 2;
+pub const FMOD_ERRORCALLBACK_INSTANCETYPE_CHANNELGROUP: FMOD_ERRORCALLBACK_INSTANCETYPE = 3;
+pub const FMOD_ERRORCALLBACK_INSTANCETYPE_CHANNELCONTROL: FMOD_ERRORCALLBACK_INSTANCETYPE = 4;
+pub const FMOD_ERRORCALLBACK_INSTANCETYPE_SOUND: FMOD_ERRORCALLBACK_INSTANCETYPE = 5;
+pub const FMOD_ERRORCALLBACK_INSTANCETYPE_SOUNDGROUP: FMOD_ERRORCALLBACK_INSTANCETYPE = 6;
+pub const FMOD_ERRORCALLBACK_INSTANCETYPE_DSP: FMOD_ERRORCALLBACK_INSTANCETYPE = 7;
+pub const FMOD_ERRORCALLBACK_INSTANCETYPE_DSPCONNECTION: FMOD_ERRORCALLBACK_INSTANCETYPE = 8;
+pub const FMOD_ERRORCALLBACK_INSTANCETYPE_GEOMETRY: FMOD_ERRORCALLBACK_INSTANCETYPE = 9;
+pub const FMOD_ERRORCALLBACK_INSTANCETYPE_REVERB3D: FMOD_ERRORCALLBACK_INSTANCETYPE = 10;
+pub const FMOD_ERRORCALLBACK_INSTANCETYPE_STUDIO_SYSTEM: FMOD_ERRORCALLBACK_INSTANCETYPE = 11;
+pub const FMOD_ERRORCALLBACK_INSTANCETYPE_STUDIO_EVENTDESCRIPTION: FMOD_ERRORCALLBACK_INSTANCETYPE = 12;
+pub const FMOD_ERRORCALLBACK_INSTANCETYPE_STUDIO_EVENTINSTANCE: FMOD_ERRORCALLBACK_INSTANCETYPE = 13;
+pub const FMOD_ERRORCALLBACK_INSTANCETYPE_STUDIO_PARAMETERINSTANCE: FMOD_ERRORCALLBACK_INSTANCETYPE = 14;
+pub const FMOD_ERRORCALLBACK_INSTANCETYPE_STUDIO_BUS: FMOD_ERRORCALLBACK_INSTANCETYPE = 15;
+pub const FMOD_ERRORCALLBACK_INSTANCETYPE_STUDIO_VCA: FMOD_ERRORCALLBACK_INSTANCETYPE = 16;
+pub const FMOD_ERRORCALLBACK_INSTANCETYPE_STUDIO_BANK: FMOD_ERRORCALLBACK_INSTANCETYPE = 17;
+pub const FMOD_ERRORCALLBACK_INSTANCETYPE_STUDIO_COMMANDREPLAY: FMOD_ERRORCALLBACK_INSTANCETYPE = 18;
+pub const FMOD_ERRORCALLBACK_INSTANCETYPE_FORCEINT: FMOD_ERRORCALLBACK_INSTANCETYPE = 65536;
+pub type FMOD_ERRORCALLBACK_INSTANCETYPE = ::std::os::raw::c_uint;
+pub const FMOD_DSP_RESAMPLER_DEFAULT: FMOD_DSP_RESAMPLER = 0;
+pub const FMOD_DSP_RESAMPLER_NOINTERP: FMOD_DSP_RESAMPLER = 1;
+pub const FMOD_DSP_RESAMPLER_LINEAR: FMOD_DSP_RESAMPLER = 2;
+pub const FMOD_DSP_RESAMPLER_CUBIC: FMOD_DSP_RESAMPLER = 3;
+pub const FMOD_DSP_RESAMPLER_SPLINE: FMOD_DSP_RESAMPLER = 4;
+pub const FMOD_DSP_RESAMPLER_MAX: FMOD_DSP_RESAMPLER = 5;
+pub const FMOD_DSP_RESAMPLER_FORCEINT: FMOD_DSP_RESAMPLER = 65536;
+pub type FMOD_DSP_RESAMPLER = ::std::os::raw::c_uint;
+pub const FMOD_DSP_CALLBACK_DATAPARAMETERRELEASE: FMOD_DSP_CALLBACK_TYPE = 0;
+pub const FMOD_DSP_CALLBACK_MAX: FMOD_DSP_CALLBACK_TYPE = 1;
+pub const FMOD_DSP_CALLBACK_FORCEINT: FMOD_DSP_CALLBACK_TYPE = 65536;
+pub type FMOD_DSP_CALLBACK_TYPE = ::std::os::raw::c_uint;
+pub const FMOD_DSPCONNECTION_TYPE_STANDARD: FMOD_DSPCONNECTION_TYPE = 0;
+pub const FMOD_DSPCONNECTION_TYPE_SIDECHAIN: FMOD_DSPCONNECTION_TYPE = 1;
+pub const FMOD_DSPCONNECTION_TYPE_SEND: FMOD_DSPCONNECTION_TYPE = 2;
+pub const FMOD_DSPCONNECTION_TYPE_SEND_SIDECHAIN: FMOD_DSPCONNECTION_TYPE = 3;
+pub const FMOD_DSPCONNECTION_TYPE_MAX: FMOD_DSPCONNECTION_TYPE = 4;
+pub const FMOD_DSPCONNECTION_TYPE_FORCEINT: FMOD_DSPCONNECTION_TYPE = 65536;
+pub type FMOD_DSPCONNECTION_TYPE = ::std::os::raw::c_uint;
+pub const FMOD_TAGTYPE_UNKNOWN: FMOD_TAGTYPE = 0;
+pub const FMOD_TAGTYPE_ID3V1: FMOD_TAGTYPE = 1;
+pub const FMOD_TAGTYPE_ID3V2: FMOD_TAGTYPE = 2;
+pub const FMOD_TAGTYPE_VORBISCOMMENT: FMOD_TAGTYPE = 3;
+pub const FMOD_TAGTYPE_SHOUTCAST: FMOD_TAGTYPE = 4;
+pub const FMOD_TAGTYPE_ICECAST: FMOD_TAGTYPE = 5;
+pub const FMOD_TAGTYPE_ASF: FMOD_TAGTYPE = 6;
+pub const FMOD_TAGTYPE_MIDI: FMOD_TAGTYPE = 7;
+pub const FMOD_TAGTYPE_PLAYLIST: FMOD_TAGTYPE = 8;
+pub const FMOD_TAGTYPE_FMOD: FMOD_TAGTYPE = 9;
+pub const FMOD_TAGTYPE_USER: FMOD_TAGTYPE = 10;
+pub const FMOD_TAGTYPE_MAX: FMOD_TAGTYPE = 11;
+pub const FMOD_TAGTYPE_FORCEINT: FMOD_TAGTYPE = 65536;
+pub type FMOD_TAGTYPE = ::std::os::raw::c_uint;
+pub const FMOD_TAGDATATYPE_BINARY: FMOD_TAGDATATYPE = 0;
+pub const FMOD_TAGDATATYPE_INT: FMOD_TAGDATATYPE = 1;
+pub const FMOD_TAGDATATYPE_FLOAT: FMOD_TAGDATATYPE = 2;
+pub const FMOD_TAGDATATYPE_STRING: FMOD_TAGDATATYPE = 3;
+pub const FMOD_TAGDATATYPE_STRING_UTF16: FMOD_TAGDATATYPE = 4;
+pub const FMOD_TAGDATATYPE_STRING_UTF16BE: FMOD_TAGDATATYPE = 5;
+pub const FMOD_TAGDATATYPE_STRING_UTF8: FMOD_TAGDATATYPE = 6;
+pub const FMOD_TAGDATATYPE_MAX: FMOD_TAGDATATYPE = 7;
+pub const FMOD_TAGDATATYPE_FORCEINT: FMOD_TAGDATATYPE = 65536;
+pub type FMOD_TAGDATATYPE = ::std::os::raw::c_uint;
+pub const FMOD_PORT_TYPE_MUSIC: FMOD_PORT_TYPE = 0;
+pub const FMOD_PORT_TYPE_COPYRIGHT_MUSIC: FMOD_PORT_TYPE = 1;
+pub const FMOD_PORT_TYPE_VOICE: FMOD_PORT_TYPE = 2;
+pub const FMOD_PORT_TYPE_CONTROLLER: FMOD_PORT_TYPE = 3;
+pub const FMOD_PORT_TYPE_PERSONAL: FMOD_PORT_TYPE = 4;
+pub const FMOD_PORT_TYPE_VIBRATION: FMOD_PORT_TYPE = 5;
+pub const FMOD_PORT_TYPE_AUX: FMOD_PORT_TYPE = 6;
+pub const FMOD_PORT_TYPE_MAX: FMOD_PORT_TYPE = 7;
+pub const FMOD_PORT_TYPE_FORCEINT: FMOD_PORT_TYPE = 65536;
+pub type FMOD_PORT_TYPE = ::std::os::raw::c_uint;
+pub const FMOD_DSP_TYPE_UNKNOWN: FMOD_DSP_TYPE = 0;
+pub const FMOD_DSP_TYPE_MIXER: FMOD_DSP_TYPE = 1;
+pub const FMOD_DSP_TYPE_OSCILLATOR: FMOD_DSP_TYPE = 2;
+pub const FMOD_DSP_TYPE_LOWPASS: FMOD_DSP_TYPE = 3;
+pub const FMOD_DSP_TYPE_ITLOWPASS: FMOD_DSP_TYPE = 4;
+pub const FMOD_DSP_TYPE_HIGHPASS: FMOD_DSP_TYPE = 5;
+pub const FMOD_DSP_TYPE_ECHO: FMOD_DSP_TYPE = 6;
+pub const FMOD_DSP_TYPE_FADER: FMOD_DSP_TYPE = 7;
+pub const FMOD_DSP_TYPE_FLANGE: FMOD_DSP_TYPE = 8;
+pub const FMOD_DSP_TYPE_DISTORTION: FMOD_DSP_TYPE = 9;
+pub const FMOD_DSP_TYPE_NORMALIZE: FMOD_DSP_TYPE = 10;
+pub const FMOD_DSP_TYPE_LIMITER: FMOD_DSP_TYPE = 11;
+pub const FMOD_DSP_TYPE_PARAMEQ: FMOD_DSP_TYPE = 12;
+pub const FMOD_DSP_TYPE_PITCHSHIFT: FMOD_DSP_TYPE = 13;
+pub const FMOD_DSP_TYPE_CHORUS: FMOD_DSP_TYPE = 14;
+pub const FMOD_DSP_TYPE_VSTPLUGIN: FMOD_DSP_TYPE = 15;
+pub const FMOD_DSP_TYPE_WINAMPPLUGIN: FMOD_DSP_TYPE = 16;
+pub const FMOD_DSP_TYPE_ITECHO: FMOD_DSP_TYPE = 17;
+pub const FMOD_DSP_TYPE_COMPRESSOR: FMOD_DSP_TYPE = 18;
+pub const FMOD_DSP_TYPE_SFXREVERB: FMOD_DSP_TYPE = 19;
+pub const FMOD_DSP_TYPE_LOWPASS_SIMPLE: FMOD_DSP_TYPE = 20;
+pub const FMOD_DSP_TYPE_DELAY: FMOD_DSP_TYPE = 21;
+pub const FMOD_DSP_TYPE_TREMOLO: FMOD_DSP_TYPE = 22;
+pub const FMOD_DSP_TYPE_LADSPAPLUGIN: FMOD_DSP_TYPE = 23;
+pub const FMOD_DSP_TYPE_SEND: FMOD_DSP_TYPE = 24;
+pub const FMOD_DSP_TYPE_RETURN: FMOD_DSP_TYPE = 25;
+pub const FMOD_DSP_TYPE_HIGHPASS_SIMPLE: FMOD_DSP_TYPE = 26;
+pub const FMOD_DSP_TYPE_PAN: FMOD_DSP_TYPE = 27;
+pub const FMOD_DSP_TYPE_THREE_EQ: FMOD_DSP_TYPE = 28;
+pub const FMOD_DSP_TYPE_FFT: FMOD_DSP_TYPE = 29;
+pub const FMOD_DSP_TYPE_LOUDNESS_METER: FMOD_DSP_TYPE = 30;
+pub const FMOD_DSP_TYPE_ENVELOPEFOLLOWER: FMOD_DSP_TYPE = 31;
+pub const FMOD_DSP_TYPE_CONVOLUTIONREVERB: FMOD_DSP_TYPE = 32;
+pub const FMOD_DSP_TYPE_CHANNELMIX: FMOD_DSP_TYPE = 33;
+pub const FMOD_DSP_TYPE_TRANSCEIVER: FMOD_DSP_TYPE = 34;
+pub const FMOD_DSP_TYPE_OBJECTPAN: FMOD_DSP_TYPE = 35;
+pub const FMOD_DSP_TYPE_MULTIBAND_EQ: FMOD_DSP_TYPE = 36;
+pub const FMOD_DSP_TYPE_MAX: FMOD_DSP_TYPE = 37;
+pub const FMOD_DSP_TYPE_FORCEINT: FMOD_DSP_TYPE = 65536;
+pub type FMOD_DSP_TYPE = ::std::os::raw::c_uint;
+pub const FMOD_DSP_PARAMETER_TYPE_FLOAT: FMOD_DSP_PARAMETER_TYPE = 0;
+pub const FMOD_DSP_PARAMETER_TYPE_INT: FMOD_DSP_PARAMETER_TYPE = 1;
+pub const FMOD_DSP_PARAMETER_TYPE_BOOL: FMOD_DSP_PARAMETER_TYPE = 2;
+pub const FMOD_DSP_PARAMETER_TYPE_DATA: FMOD_DSP_PARAMETER_TYPE = 3;
+pub const FMOD_DSP_PARAMETER_TYPE_MAX: FMOD_DSP_PARAMETER_TYPE = 4;
+pub const FMOD_DSP_PARAMETER_TYPE_FORCEINT: FMOD_DSP_PARAMETER_TYPE = 65536;
+pub type FMOD_DSP_PARAMETER_TYPE = ::std::os::raw::c_uint;
+pub const FMOD_DSP_PARAMETER_FLOAT_MAPPING_TYPE_LINEAR: FMOD_DSP_PARAMETER_FLOAT_MAPPING_TYPE = 0;
+pub const FMOD_DSP_PARAMETER_FLOAT_MAPPING_TYPE_AUTO: FMOD_DSP_PARAMETER_FLOAT_MAPPING_TYPE = 1;
+pub const FMOD_DSP_PARAMETER_FLOAT_MAPPING_TYPE_PIECEWISE_LINEAR: FMOD_DSP_PARAMETER_FLOAT_MAPPING_TYPE = 2;
+pub const FMOD_DSP_PARAMETER_FLOAT_MAPPING_TYPE_FORCEINT: FMOD_DSP_PARAMETER_FLOAT_MAPPING_TYPE = 65536;
+pub type FMOD_DSP_PARAMETER_FLOAT_MAPPING_TYPE = ::std::os::raw::c_uint;
+pub const FMOD_DSP_PARAMETER_DATA_TYPE_USER: FMOD_DSP_PARAMETER_DATA_TYPE = 0;
+pub const FMOD_DSP_PARAMETER_DATA_TYPE_OVERALLGAIN: FMOD_DSP_PARAMETER_DATA_TYPE = -1;
+pub const FMOD_DSP_PARAMETER_DATA_TYPE_3DATTRIBUTES: FMOD_DSP_PARAMETER_DATA_TYPE = -2;
+pub const FMOD_DSP_PARAMETER_DATA_TYPE_SIDECHAIN: FMOD_DSP_PARAMETER_DATA_TYPE = -3;
+pub const FMOD_DSP_PARAMETER_DATA_TYPE_FFT: FMOD_DSP_PARAMETER_DATA_TYPE = -4;
+pub const FMOD_DSP_PARAMETER_DATA_TYPE_3DATTRIBUTES_MULTI: FMOD_DSP_PARAMETER_DATA_TYPE = -5;
+pub const FMOD_DSP_PARAMETER_DATA_TYPE_ATTENUATION_RANGE: FMOD_DSP_PARAMETER_DATA_TYPE = -6;
+pub const FMOD_DSP_PARAMETER_DATA_TYPE_FORCEINT: FMOD_DSP_PARAMETER_DATA_TYPE = 65536;
+pub type FMOD_DSP_PARAMETER_DATA_TYPE = ::std::os::raw::c_int;
+#[repr(transparent)]
+#[must_use]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct FMOD_RESULT(pub ::std::os::raw::c_uint);
+impl FMOD_RESULT {
+    pub const FMOD_OK: FMOD_RESULT = FMOD_RESULT(0);
+    pub const FMOD_ERR_BADCOMMAND: FMOD_RESULT = FMOD_RESULT(1);
+    pub const FMOD_ERR_CHANNEL_ALLOC: FMOD_RESULT = FMOD_RESULT(2);
+    pub const FMOD_ERR_CHANNEL_STOLEN: FMOD_RESULT = FMOD_RESULT(3);
+    pub const FMOD_ERR_DMA: FMOD_RESULT = FMOD_RESULT(4);
+    pub const FMOD_ERR_DSP_CONNECTION: FMOD_RESULT = FMOD_RESULT(5);
+    pub const FMOD_ERR_DSP_DONTPROCESS: FMOD_RESULT = FMOD_RESULT(6);
+    pub const FMOD_ERR_DSP_FORMAT: FMOD_RESULT = FMOD_RESULT(7);
+    pub const FMOD_ERR_DSP_INUSE: FMOD_RESULT = FMOD_RESULT(8);
+    pub const FMOD_ERR_DSP_NOTFOUND: FMOD_RESULT = FMOD_RESULT(9);
+    pub const FMOD_ERR_DSP_RESERVED: FMOD_RESULT = FMOD_RESULT(10);
+    pub const FMOD_ERR_DSP_SILENCE: FMOD_RESULT = FMOD_RESULT(11);
+    pub const FMOD_ERR_DSP_TYPE: FMOD_RESULT = FMOD_RESULT(12);
+    pub const FMOD_ERR_FILE_BAD: FMOD_RESULT = FMOD_RESULT(13);
+    pub const FMOD_ERR_FILE_COULDNOTSEEK: FMOD_RESULT = FMOD_RESULT(14);
+    pub const FMOD_ERR_FILE_DISKEJECTED: FMOD_RESULT = FMOD_RESULT(15);
+    pub const FMOD_ERR_FILE_EOF: FMOD_RESULT = FMOD_RESULT(16);
+    pub const FMOD_ERR_FILE_ENDOFDATA: FMOD_RESULT = FMOD_RESULT(17);
+    pub const FMOD_ERR_FILE_NOTFOUND: FMOD_RESULT = FMOD_RESULT(18);
+    pub const FMOD_ERR_FORMAT: FMOD_RESULT = FMOD_RESULT(19);
+    pub const FMOD_ERR_HEADER_MISMATCH: FMOD_RESULT = FMOD_RESULT(20);
+    pub const FMOD_ERR_HTTP: FMOD_RESULT = FMOD_RESULT(21);
+    pub const FMOD_ERR_HTTP_ACCESS: FMOD_RESULT = FMOD_RESULT(22);
+    pub const FMOD_ERR_HTTP_PROXY_AUTH: FMOD_RESULT = FMOD_RESULT(23);
+    pub const FMOD_ERR_HTTP_SERVER_ERROR: FMOD_RESULT = FMOD_RESULT(24);
+    pub const FMOD_ERR_HTTP_TIMEOUT: FMOD_RESULT = FMOD_RESULT(25);
+    pub const FMOD_ERR_INITIALIZATION: FMOD_RESULT = FMOD_RESULT(26);
+    pub const FMOD_ERR_INITIALIZED: FMOD_RESULT = FMOD_RESULT(27);
+    pub const FMOD_ERR_INTERNAL: FMOD_RESULT = FMOD_RESULT(28);
+    pub const FMOD_ERR_INVALID_FLOAT: FMOD_RESULT = FMOD_RESULT(29);
+    pub const FMOD_ERR_INVALID_HANDLE: FMOD_RESULT = FMOD_RESULT(30);
+    pub const FMOD_ERR_INVALID_PARAM: FMOD_RESULT = FMOD_RESULT(31);
+    pub const FMOD_ERR_INVALID_POSITION: FMOD_RESULT = FMOD_RESULT(32);
+    pub const FMOD_ERR_INVALID_SPEAKER: FMOD_RESULT = FMOD_RESULT(33);
+    pub const FMOD_ERR_INVALID_SYNCPOINT: FMOD_RESULT = FMOD_RESULT(34);
+    pub const FMOD_ERR_INVALID_THREAD: FMOD_RESULT = FMOD_RESULT(35);
+    pub const FMOD_ERR_INVALID_VECTOR: FMOD_RESULT = FMOD_RESULT(36);
+    pub const FMOD_ERR_MAXAUDIBLE: FMOD_RESULT = FMOD_RESULT(37);
+    pub const FMOD_ERR_MEMORY: FMOD_RESULT = FMOD_RESULT(38);
+    pub const FMOD_ERR_MEMORY_CANTPOINT: FMOD_RESULT = FMOD_RESULT(39);
+    pub const FMOD_ERR_NEEDS3D: FMOD_RESULT = FMOD_RESULT(40);
+    pub const FMOD_ERR_NEEDSHARDWARE: FMOD_RESULT = FMOD_RESULT(41);
+    pub const FMOD_ERR_NET_CONNECT: FMOD_RESULT = FMOD_RESULT(42);
+    pub const FMOD_ERR_NET_SOCKET_ERROR: FMOD_RESULT = FMOD_RESULT(43);
+    pub const FMOD_ERR_NET_URL: FMOD_RESULT = FMOD_RESULT(44);
+    pub const FMOD_ERR_NET_WOULD_BLOCK: FMOD_RESULT = FMOD_RESULT(45);
+    pub const FMOD_ERR_NOTREADY: FMOD_RESULT = FMOD_RESULT(46);
+    pub const FMOD_ERR_OUTPUT_ALLOCATED: FMOD_RESULT = FMOD_RESULT(47);
+    pub const FMOD_ERR_OUTPUT_CREATEBUFFER: FMOD_RESULT = FMOD_RESULT(48);
+    pub const FMOD_ERR_OUTPUT_DRIVERCALL: FMOD_RESULT = FMOD_RESULT(49);
+    pub const FMOD_ERR_OUTPUT_FORMAT: FMOD_RESULT = FMOD_RESULT(50);
+    pub const FMOD_ERR_OUTPUT_INIT: FMOD_RESULT = FMOD_RESULT(51);
+    pub const FMOD_ERR_OUTPUT_NODRIVERS: FMOD_RESULT = FMOD_RESULT(52);
+    pub const FMOD_ERR_PLUGIN: FMOD_RESULT = FMOD_RESULT(53);
+    pub const FMOD_ERR_PLUGIN_MISSING: FMOD_RESULT = FMOD_RESULT(54);
+    pub const FMOD_ERR_PLUGIN_RESOURCE: FMOD_RESULT = FMOD_RESULT(55);
+    pub const FMOD_ERR_PLUGIN_VERSION: FMOD_RESULT = FMOD_RESULT(56);
+    pub const FMOD_ERR_RECORD: FMOD_RESULT = FMOD_RESULT(57);
+    pub const FMOD_ERR_REVERB_CHANNELGROUP: FMOD_RESULT = FMOD_RESULT(58);
+    pub const FMOD_ERR_REVERB_INSTANCE: FMOD_RESULT = FMOD_RESULT(59);
+    pub const FMOD_ERR_SUBSOUNDS: FMOD_RESULT = FMOD_RESULT(60);
+    pub const FMOD_ERR_SUBSOUND_ALLOCATED: FMOD_RESULT = FMOD_RESULT(61);
+    pub const FMOD_ERR_SUBSOUND_CANTMOVE: FMOD_RESULT = FMOD_RESULT(62);
+    pub const FMOD_ERR_TAGNOTFOUND: FMOD_RESULT = FMOD_RESULT(63);
+    pub const FMOD_ERR_TOOMANYCHANNELS: FMOD_RESULT = FMOD_RESULT(64);
+    pub const FMOD_ERR_TRUNCATED: FMOD_RESULT = FMOD_RESULT(65);
+    pub const FMOD_ERR_UNIMPLEMENTED: FMOD_RESULT = FMOD_RESULT(66);
+    pub const FMOD_ERR_UNINITIALIZED: FMOD_RESULT = FMOD_RESULT(67);
+    pub const FMOD_ERR_UNSUPPORTED: FMOD_RESULT = FMOD_RESULT(68);
+    pub const FMOD_ERR_VERSION: FMOD_RESULT = FMOD_RESULT(69);
+    pub const FMOD_ERR_EVENT_ALREADY_LOADED: FMOD_RESULT = FMOD_RESULT(70);
+    pub const FMOD_ERR_EVENT_LIVEUPDATE_BUSY: FMOD_RESULT = FMOD_RESULT(71);
+    pub const FMOD_ERR_EVENT_LIVEUPDATE_MISMATCH: FMOD_RESULT = FMOD_RESULT(72);
+    pub const FMOD_ERR_EVENT_LIVEUPDATE_TIMEOUT: FMOD_RESULT = FMOD_RESULT(73);
+    pub const FMOD_ERR_EVENT_NOTFOUND: FMOD_RESULT = FMOD_RESULT(74);
+    pub const FMOD_ERR_STUDIO_UNINITIALIZED: FMOD_RESULT = FMOD_RESULT(75);
+    pub const FMOD_ERR_STUDIO_NOT_LOADED: FMOD_RESULT = FMOD_RESULT(76);
+    pub const FMOD_ERR_INVALID_STRING: FMOD_RESULT = FMOD_RESULT(77);
+    pub const FMOD_ERR_ALREADY_LOCKED: FMOD_RESULT = FMOD_RESULT(78);
+    pub const FMOD_ERR_NOT_LOCKED: FMOD_RESULT = FMOD_RESULT(79);
+    pub const FMOD_ERR_RECORD_DISCONNECTED: FMOD_RESULT = FMOD_RESULT(80);
+    pub const FMOD_ERR_TOOMANYSAMPLES: FMOD_RESULT = FMOD_RESULT(81);
+    pub const FMOD_RESULT_FORCEINT: FMOD_RESULT = FMOD_RESULT(65536);
+}
+
+#[repr(C)]
+#[allow(unknown_lints, unpredictable_function_pointer_comparisons)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct FMOD_ASYNCREADINFO {
+    pub handle: *mut ::std::os::raw::c_void,
+    pub offset: ::std::os::raw::c_uint,
+    pub sizebytes: ::std::os::raw::c_uint,
+    pub priority: ::std::os::raw::c_int,
+    pub userdata: *mut ::std::os::raw::c_void,
+    pub buffer: *mut ::std::os::raw::c_void,
+    pub bytesread: ::std::os::raw::c_uint,
+    pub done: FMOD_FILE_ASYNCDONE_FUNC,
+}
+impl Default for FMOD_ASYNCREADINFO {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct FMOD_VECTOR {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct FMOD_3D_ATTRIBUTES {
+    pub position: FMOD_VECTOR,
+    pub velocity: FMOD_VECTOR,
+    pub forward: FMOD_VECTOR,
+    pub up: FMOD_VECTOR,
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct FMOD_GUID {
+    pub Data1: ::std::os::raw::c_uint,
+    pub Data2: ::std::os::raw::c_ushort,
+    pub Data3: ::std::os::raw::c_ushort,
+    pub Data4: [::std::os::raw::c_uchar; 8usize],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct FMOD_PLUGINLIST {
+    pub type_: FMOD_PLUGINTYPE,
+    pub description: *mut ::std::os::raw::c_void,
+}
+impl Default for FMOD_PLUGINLIST {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct FMOD_ADVANCEDSETTINGS {
+    pub cbSize: ::std::os::raw::c_int,
+    pub maxMPEGCodecs: ::std::os::raw::c_int,
+    pub maxADPCMCodecs: ::std::os::raw::c_int,
+    pub maxXMACodecs: ::std::os::raw::c_int,
+    pub maxVorbisCodecs: ::std::os::raw::c_int,
+    pub maxAT9Codecs: ::std::os::raw::c_int,
+    pub maxFADPCMCodecs: ::std::os::raw::c_int,
+    pub maxOpusCodecs: ::std::os::raw::c_int,
+    pub ASIONumChannels: ::std::os::raw::c_int,
+    pub ASIOChannelList: *mut *mut ::std::os::raw::c_char,
+    pub ASIOSpeakerList: *mut FMOD_SPEAKER,
+    pub vol0virtualvol: f32,
+    pub defaultDecodeBufferSize: ::std::os::raw::c_uint,
+    pub profilePort: ::std::os::raw::c_ushort,
+    pub geometryMaxFadeTime: ::std::os::raw::c_uint,
+    pub distanceFilterCenterFreq: f32,
+    pub reverb3Dinstance: ::std::os::raw::c_int,
+    pub DSPBufferPoolSize: ::std::os::raw::c_int,
+    pub resamplerMethod: FMOD_DSP_RESAMPLER,
+    pub randomSeed: ::std::os::raw::c_uint,
+    pub maxConvolutionThreads: ::std::os::raw::c_int,
+    pub maxSpatialObjects: ::std::os::raw::c_int,
+}
+impl Default for FMOD_ADVANCEDSETTINGS {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct FMOD_TAG {
+    pub type_: FMOD_TAGTYPE,
+    pub datatype: FMOD_TAGDATATYPE,
+    pub name: *mut ::std::os::raw::c_char,
+    pub data: *mut ::std::os::raw::c_void,
+    pub datalen: ::std::os::raw::c_uint,
+    pub updated: FMOD_BOOL,
+}
+impl Default for FMOD_TAG {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[allow(unknown_lints, unpredictable_function_pointer_comparisons)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct FMOD_CREATESOUNDEXINFO {
+    pub cbsize: ::std::os::raw::c_int,
+    pub length: ::std::os::raw::c_uint,
+    pub fileoffset: ::std::os::raw::c_uint,
+    pub numchannels: ::std::os::raw::c_int,
+    pub defaultfrequency: ::std::os::raw::c_int,
+    pub format: FMOD_SOUND_FORMAT,
+    pub decodebuffersize: ::std::os::raw::c_uint,
+    pub initialsubsound: ::std::os::raw::c_int,
+    pub numsubsounds: ::std::os::raw::c_int,
+    pub inclusionlist: *mut ::std::os::raw::c_int,
+    pub inclusionlistnum: ::std::os::raw::c_int,
+    pub pcmreadcallback: FMOD_SOUND_PCMREAD_CALLBACK,
+    pub pcmsetposcallback: FMOD_SOUND_PCMSETPOS_CALLBACK,
+    pub nonblockcallback: FMOD_SOUND_NONBLOCK_CALLBACK,
+    pub dlsname: *const ::std::os::raw::c_char,
+    pub encryptionkey: *const ::std::os::raw::c_char,
+    pub maxpolyphony: ::std::os::raw::c_int,
+    pub userdata: *mut ::std::os::raw::c_void,
+    pub suggestedsoundtype: FMOD_SOUND_TYPE,
+    pub fileuseropen: FMOD_FILE_OPEN_CALLBACK,
+    pub fileuserclose: FMOD_FILE_CLOSE_CALLBACK,
+    pub fileuserread: FMOD_FILE_READ_CALLBACK,
+    pub fileuserseek: FMOD_FILE_SEEK_CALLBACK,
+    pub fileuserasyncread: FMOD_FILE_ASYNCREAD_CALLBACK,
+    pub fileuserasynccancel: FMOD_FILE_ASYNCCANCEL_CALLBACK,
+    pub fileuserdata: *mut ::std::os::raw::c_void,
+    pub filebuffersize: ::std::os::raw::c_int,
+    pub channelorder: FMOD_CHANNELORDER,
+    pub initialsoundgroup: *mut FMOD_SOUNDGROUP,
+    pub initialseekposition: ::std::os::raw::c_uint,
+    pub initialseekpostype: FMOD_TIMEUNIT,
+    pub ignoresetfilesystem: ::std::os::raw::c_int,
+    pub audioqueuepolicy: ::std::os::raw::c_uint,
+    pub minmidigranularity: ::std::os::raw::c_uint,
+    pub nonblockthreadid: ::std::os::raw::c_int,
+    pub fsbguid: *mut FMOD_GUID,
+}
+impl Default for FMOD_CREATESOUNDEXINFO {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct FMOD_REVERB_PROPERTIES {
+    pub DecayTime: f32,
+    pub EarlyDelay: f32,
+    pub LateDelay: f32,
+    pub HFReference: f32,
+    pub HFDecayRatio: f32,
+    pub Diffusion: f32,
+    pub Density: f32,
+    pub LowShelfFrequency: f32,
+    pub LowShelfGain: f32,
+    pub HighCut: f32,
+    pub EarlyLateMix: f32,
+    pub WetLevel: f32,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct FMOD_ERRORCALLBACK_INFO {
+    pub result: FMOD_RESULT,
+    pub instancetype: FMOD_ERRORCALLBACK_INSTANCETYPE,
+    pub instance: *mut ::std::os::raw::c_void,
+    pub functionname: *const ::std::os::raw::c_char,
+    pub functionparams: *const ::std::os::raw::c_char,
+}
+impl Default for FMOD_ERRORCALLBACK_INFO {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct FMOD_CPU_USAGE {
+    pub dsp: f32,
+    pub stream: f32,
+    pub geometry: f32,
+    pub update: f32,
+    pub convolution1: f32,
+    pub convolution2: f32,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct FMOD_DSP_DATA_PARAMETER_INFO {
+    pub data: *mut ::std::os::raw::c_void,
+    pub length: ::std::os::raw::c_uint,
+    pub index: ::std::os::raw::c_int,
+}
+impl Default for FMOD_DSP_DATA_PARAMETER_INFO {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct FMOD_DSP_PARAMETER_FLOAT_MAPPING_PIECEWISE_LINEAR {
+    pub numpoints: ::std::os::raw::c_int,
+    pub pointparamvalues: *mut f32,
+    pub pointpositions: *mut f32,
+}
+impl Default for FMOD_DSP_PARAMETER_FLOAT_MAPPING_PIECEWISE_LINEAR {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct FMOD_DSP_PARAMETER_FLOAT_MAPPING {
+    pub type_: FMOD_DSP_PARAMETER_FLOAT_MAPPING_TYPE,
+    pub piecewiselinearmapping: FMOD_DSP_PARAMETER_FLOAT_MAPPING_PIECEWISE_LINEAR,
+}
+impl Default for FMOD_DSP_PARAMETER_FLOAT_MAPPING {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct FMOD_DSP_PARAMETER_DESC_FLOAT {
+    pub min: f32,
+    pub max: f32,
+    pub defaultval: f32,
+    pub mapping: FMOD_DSP_PARAMETER_FLOAT_MAPPING,
+}
+impl Default for FMOD_DSP_PARAMETER_DESC_FLOAT {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct FMOD_DSP_PARAMETER_DESC_INT {
+    pub min: ::std::os::raw::c_int,
+    pub max: ::std::os::raw::c_int,
+    pub defaultval: ::std::os::raw::c_int,
+    pub goestoinf: FMOD_BOOL,
+    pub valuenames: *const *const ::std::os::raw::c_char,
+}
+impl Default for FMOD_DSP_PARAMETER_DESC_INT {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct FMOD_DSP_PARAMETER_DESC_BOOL {
+    pub defaultval: FMOD_BOOL,
+    pub valuenames: *const *const ::std::os::raw::c_char,
+}
+impl Default for FMOD_DSP_PARAMETER_DESC_BOOL {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct FMOD_DSP_PARAMETER_DESC_DATA {
+    pub datatype: ::std::os::raw::c_int,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct FMOD_DSP_PARAMETER_DESC {
+    pub type_: FMOD_DSP_PARAMETER_TYPE,
+    pub name: [::std::os::raw::c_char; 16usize],
+    pub label: [::std::os::raw::c_char; 16usize],
+    pub description: *const ::std::os::raw::c_char,
+    pub __bindgen_anon_1: FMOD_DSP_PARAMETER_DESC__bindgen_ty_1,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union FMOD_DSP_PARAMETER_DESC__bindgen_ty_1 {
+    pub floatdesc: FMOD_DSP_PARAMETER_DESC_FLOAT,
+    pub intdesc: FMOD_DSP_PARAMETER_DESC_INT,
+    pub booldesc: FMOD_DSP_PARAMETER_DESC_BOOL,
+    pub datadesc: FMOD_DSP_PARAMETER_DESC_DATA,
+}
+impl Default for FMOD_DSP_PARAMETER_DESC__bindgen_ty_1 {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+impl Default for FMOD_DSP_PARAMETER_DESC {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct FMOD_DSP_METERING_INFO {
+    pub numsamples: ::std::os::raw::c_int,
+    pub peaklevel: [f32; 32usize],
+    pub rmslevel: [f32; 32usize],
+    pub numchannels: ::std::os::raw::c_short,
+}
+impl Default for FMOD_DSP_METERING_INFO {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+
+pub type FMOD_DEBUG_CALLBACK = ::std::option::Option<
+    unsafe extern "C" fn(
+        flags: FMOD_DEBUG_FLAGS,
+        file: *const ::std::os::raw::c_char,
+        line: ::std::os::raw::c_int,
+        func: *const ::std::os::raw::c_char,
+        message: *const ::std::os::raw::c_char,
+    ) -> FMOD_RESULT,
+>;
+pub type FMOD_SYSTEM_CALLBACK = ::std::option::Option<
+    unsafe extern "C" fn(
+        system: *mut FMOD_SYSTEM,
+        type_: FMOD_SYSTEM_CALLBACK_TYPE,
+        commanddata1: *mut ::std::os::raw::c_void,
+        commanddata2: *mut ::std::os::raw::c_void,
+        userdata: *mut ::std::os::raw::c_void,
+    ) -> FMOD_RESULT,
+>;
+pub type FMOD_CHANNELCONTROL_CALLBACK = ::std::option::Option<
+    unsafe extern "C" fn(
+        channelcontrol: *mut FMOD_CHANNELCONTROL,
+        controltype: FMOD_CHANNELCONTROL_TYPE,
+        callbacktype: FMOD_CHANNELCONTROL_CALLBACK_TYPE,
+        commanddata1: *mut ::std::os::raw::c_void,
+        commanddata2: *mut ::std::os::raw::c_void,
+    ) -> FMOD_RESULT,
+>;
+pub type FMOD_DSP_CALLBACK = ::std::option::Option<
+    unsafe extern "C" fn(
+        dsp: *mut FMOD_DSP,
+        type_: FMOD_DSP_CALLBACK_TYPE,
+        data: *mut ::std::os::raw::c_void,
+    ) -> FMOD_RESULT,
+>;
+pub type FMOD_SOUND_NONBLOCK_CALLBACK = ::std::option::Option<
+    unsafe extern "C" fn(sound: *mut FMOD_SOUND, result: FMOD_RESULT) -> FMOD_RESULT,
+>;
+pub type FMOD_SOUND_PCMREAD_CALLBACK = ::std::option::Option<
+    unsafe extern "C" fn(
+        sound: *mut FMOD_SOUND,
+        data: *mut ::std::os::raw::c_void,
+        datalen: ::std::os::raw::c_uint,
+    ) -> FMOD_RESULT,
+>;
+pub type FMOD_SOUND_PCMSETPOS_CALLBACK = ::std::option::Option<
+    unsafe extern "C" fn(
+        sound: *mut FMOD_SOUND,
+        subsound: ::std::os::raw::c_int,
+        position: ::std::os::raw::c_uint,
+        postype: FMOD_TIMEUNIT,
+    ) -> FMOD_RESULT,
+>;
+pub type FMOD_FILE_OPEN_CALLBACK = ::std::option::Option<
+    unsafe extern "C" fn(
+        name: *const ::std::os::raw::c_char,
+        filesize: *mut ::std::os::raw::c_uint,
+        handle: *mut *mut ::std::os::raw::c_void,
+        userdata: *mut ::std::os::raw::c_void,
+    ) -> FMOD_RESULT,
+>;
+pub type FMOD_FILE_CLOSE_CALLBACK = ::std::option::Option<
+    unsafe extern "C" fn(
+        handle: *mut ::std::os::raw::c_void,
+        userdata: *mut ::std::os::raw::c_void,
+    ) -> FMOD_RESULT,
+>;
+pub type FMOD_FILE_READ_CALLBACK = ::std::option::Option<
+    unsafe extern "C" fn(
+        handle: *mut ::std::os::raw::c_void,
+        buffer: *mut ::std::os::raw::c_void,
+        sizebytes: ::std::os::raw::c_uint,
+        bytesread: *mut ::std::os::raw::c_uint,
+        userdata: *mut ::std::os::raw::c_void,
+    ) -> FMOD_RESULT,
+>;
+pub type FMOD_FILE_SEEK_CALLBACK = ::std::option::Option<
+    unsafe extern "C" fn(
+        handle: *mut ::std::os::raw::c_void,
+        pos: ::std::os::raw::c_uint,
+        userdata: *mut ::std::os::raw::c_void,
+    ) -> FMOD_RESULT,
+>;
+pub type FMOD_FILE_ASYNCREAD_CALLBACK = ::std::option::Option<
+    unsafe extern "C" fn(
+        info: *mut FMOD_ASYNCREADINFO,
+        userdata: *mut ::std::os::raw::c_void,
+    ) -> FMOD_RESULT,
+>;
+pub type FMOD_FILE_ASYNCCANCEL_CALLBACK = ::std::option::Option<
+    unsafe extern "C" fn(
+        info: *mut FMOD_ASYNCREADINFO,
+        userdata: *mut ::std::os::raw::c_void,
+    ) -> FMOD_RESULT,
+>;
+pub type FMOD_FILE_ASYNCDONE_FUNC =
+    ::std::option::Option<unsafe extern "C" fn(info: *mut FMOD_ASYNCREADINFO, result: FMOD_RESULT)>;
+pub type FMOD_MEMORY_ALLOC_CALLBACK = ::std::option::Option<
+    unsafe extern "C" fn(
+        size: ::std::os::raw::c_uint,
+        type_: FMOD_MEMORY_TYPE,
+        sourcestr: *const ::std::os::raw::c_char,
+    ) -> *mut ::std::os::raw::c_void,
+>;
+pub type FMOD_MEMORY_REALLOC_CALLBACK = ::std::option::Option<
+    unsafe extern "C" fn(
+        ptr: *mut ::std::os::raw::c_void,
+        size: ::std::os::raw::c_uint,
+        type_: FMOD_MEMORY_TYPE,
+        sourcestr: *const ::std::os::raw::c_char,
+    ) -> *mut ::std::os::raw::c_void,
+>;
+pub type FMOD_MEMORY_FREE_CALLBACK = ::std::option::Option<
+    unsafe extern "C" fn(
+        ptr: *mut ::std::os::raw::c_void,
+        type_: FMOD_MEMORY_TYPE,
+        sourcestr: *const ::std::os::raw::c_char,
+    ),
+>;
+pub type FMOD_3D_ROLLOFF_CALLBACK = ::std::option::Option<
+    unsafe extern "C" fn(channelcontrol: *mut FMOD_CHANNELCONTROL, distance: f32) -> f32,
+>;
+pub const FMOD_STUDIO_LOADING_STATE_UNLOADING: FMOD_STUDIO_LOADING_STATE = 0;
+pub const FMOD_STUDIO_LOADING_STATE_UNLOADED: FMOD_STUDIO_LOADING_STATE = 1;
+pub const FMOD_STUDIO_LOADING_STATE_LOADING: FMOD_STUDIO_LOADING_STATE = 2;
+pub const FMOD_STUDIO_LOADING_STATE_LOADED: FMOD_STUDIO_LOADING_STATE = 3;
+pub const FMOD_STUDIO_LOADING_STATE_ERROR: FMOD_STUDIO_LOADING_STATE = 4;
+pub const FMOD_STUDIO_LOADING_STATE_FORCEINT: FMOD_STUDIO_LOADING_STATE = 65536;
+pub type FMOD_STUDIO_LOADING_STATE = ::std::os::raw::c_uint;
+pub const FMOD_STUDIO_LOAD_MEMORY: FMOD_STUDIO_LOAD_MEMORY_MODE = 0;
+pub const FMOD_STUDIO_LOAD_MEMORY_POINT: FMOD_STUDIO_LOAD_MEMORY_MODE = 1;
+pub const FMOD_STUDIO_LOAD_MEMORY_FORCEINT: FMOD_STUDIO_LOAD_MEMORY_MODE = 65536;
+pub type FMOD_STUDIO_LOAD_MEMORY_MODE = ::std::os::raw::c_uint;
+pub const FMOD_STUDIO_PARAMETER_GAME_CONTROLLED: FMOD_STUDIO_PARAMETER_TYPE = 0;
+pub const FMOD_STUDIO_PARAMETER_AUTOMATIC_DISTANCE: FMOD_STUDIO_PARAMETER_TYPE = 1;
+pub const FMOD_STUDIO_PARAMETER_AUTOMATIC_EVENT_CONE_ANGLE: FMOD_STUDIO_PARAMETER_TYPE = 2;
+pub const FMOD_STUDIO_PARAMETER_AUTOMATIC_EVENT_ORIENTATION: FMOD_STUDIO_PARAMETER_TYPE = 3;
+pub const FMOD_STUDIO_PARAMETER_AUTOMATIC_DIRECTION: FMOD_STUDIO_PARAMETER_TYPE = 4;
+pub const FMOD_STUDIO_PARAMETER_AUTOMATIC_ELEVATION: FMOD_STUDIO_PARAMETER_TYPE = 5;
+pub const FMOD_STUDIO_PARAMETER_AUTOMATIC_LISTENER_ORIENTATION: FMOD_STUDIO_PARAMETER_TYPE = 6;
+pub const FMOD_STUDIO_PARAMETER_AUTOMATIC_SPEED: FMOD_STUDIO_PARAMETER_TYPE = 7;
+pub const FMOD_STUDIO_PARAMETER_AUTOMATIC_SPEED_ABSOLUTE: FMOD_STUDIO_PARAMETER_TYPE = 8;
+pub const FMOD_STUDIO_PARAMETER_AUTOMATIC_DISTANCE_NORMALIZED: FMOD_STUDIO_PARAMETER_TYPE = 9;
+pub const FMOD_STUDIO_PARAMETER_MAX: FMOD_STUDIO_PARAMETER_TYPE = 10;
+pub const FMOD_STUDIO_PARAMETER_FORCEINT: FMOD_STUDIO_PARAMETER_TYPE = 65536;
+pub type FMOD_STUDIO_PARAMETER_TYPE = ::std::os::raw::c_uint;
+pub const FMOD_STUDIO_USER_PROPERTY_TYPE_INTEGER: FMOD_STUDIO_USER_PROPERTY_TYPE = 0;
+pub const FMOD_STUDIO_USER_PROPERTY_TYPE_BOOLEAN: FMOD_STUDIO_USER_PROPERTY_TYPE = 1;
+pub const FMOD_STUDIO_USER_PROPERTY_TYPE_FLOAT: FMOD_STUDIO_USER_PROPERTY_TYPE = 2;
+pub const FMOD_STUDIO_USER_PROPERTY_TYPE_STRING: FMOD_STUDIO_USER_PROPERTY_TYPE = 3;
+pub const FMOD_STUDIO_USER_PROPERTY_TYPE_FORCEINT: FMOD_STUDIO_USER_PROPERTY_TYPE = 65536;
+pub type FMOD_STUDIO_USER_PROPERTY_TYPE = ::std::os::raw::c_uint;
+pub const FMOD_STUDIO_EVENT_PROPERTY_CHANNELPRIORITY: FMOD_STUDIO_EVENT_PROPERTY = 0;
+pub const FMOD_STUDIO_EVENT_PROPERTY_SCHEDULE_DELAY: FMOD_STUDIO_EVENT_PROPERTY = 1;
+pub const FMOD_STUDIO_EVENT_PROPERTY_SCHEDULE_LOOKAHEAD: FMOD_STUDIO_EVENT_PROPERTY = 2;
+pub const FMOD_STUDIO_EVENT_PROPERTY_MINIMUM_DISTANCE: FMOD_STUDIO_EVENT_PROPERTY = 3;
+pub const FMOD_STUDIO_EVENT_PROPERTY_MAXIMUM_DISTANCE: FMOD_STUDIO_EVENT_PROPERTY = 4;
+pub const FMOD_STUDIO_EVENT_PROPERTY_COOLDOWN: FMOD_STUDIO_EVENT_PROPERTY = 5;
+pub const FMOD_STUDIO_EVENT_PROPERTY_MAX: FMOD_STUDIO_EVENT_PROPERTY = 6;
+pub const FMOD_STUDIO_EVENT_PROPERTY_FORCEINT: FMOD_STUDIO_EVENT_PROPERTY = 65536;
+pub type FMOD_STUDIO_EVENT_PROPERTY = ::std::os::raw::c_uint;
+pub const FMOD_STUDIO_PLAYBACK_PLAYING: FMOD_STUDIO_PLAYBACK_STATE = 0;
+pub const FMOD_STUDIO_PLAYBACK_SUSTAINING: FMOD_STUDIO_PLAYBACK_STATE = 1;
+pub const FMOD_STUDIO_PLAYBACK_STOPPED: FMOD_STUDIO_PLAYBACK_STATE = 2;
+pub const FMOD_STUDIO_PLAYBACK_STARTING: FMOD_STUDIO_PLAYBACK_STATE = 3;
+pub const FMOD_STUDIO_PLAYBACK_STOPPING: FMOD_STUDIO_PLAYBACK_STATE = 4;
+pub const FMOD_STUDIO_PLAYBACK_FORCEINT: FMOD_STUDIO_PLAYBACK_STATE = 65536;
+pub type FMOD_STUDIO_PLAYBACK_STATE = ::std::os::raw::c_uint;
+pub const FMOD_STUDIO_STOP_ALLOWFADEOUT: FMOD_STUDIO_STOP_MODE = 0;
+pub const FMOD_STUDIO_STOP_IMMEDIATE: FMOD_STUDIO_STOP_MODE = 1;
+pub const FMOD_STUDIO_STOP_FORCEINT: FMOD_STUDIO_STOP_MODE = 65536;
+pub type FMOD_STUDIO_STOP_MODE = ::std::os::raw::c_uint;
+pub const FMOD_STUDIO_INSTANCETYPE_NONE: FMOD_STUDIO_INSTANCETYPE = 0;
+pub const FMOD_STUDIO_INSTANCETYPE_SYSTEM: FMOD_STUDIO_INSTANCETYPE = 1;
+pub const FMOD_STUDIO_INSTANCETYPE_EVENTDESCRIPTION: FMOD_STUDIO_INSTANCETYPE = 2;
+pub const FMOD_STUDIO_INSTANCETYPE_EVENTINSTANCE: FMOD_STUDIO_INSTANCETYPE = 3;
+pub const FMOD_STUDIO_INSTANCETYPE_PARAMETERINSTANCE: FMOD_STUDIO_INSTANCETYPE = 4;
+pub const FMOD_STUDIO_INSTANCETYPE_BUS: FMOD_STUDIO_INSTANCETYPE = 5;
+pub const FMOD_STUDIO_INSTANCETYPE_VCA: FMOD_STUDIO_INSTANCETYPE = 6;
+pub const FMOD_STUDIO_INSTANCETYPE_BANK: FMOD_STUDIO_INSTANCETYPE = 7;
+pub const FMOD_STUDIO_INSTANCETYPE_COMMANDREPLAY: FMOD_STUDIO_INSTANCETYPE = 8;
+pub const FMOD_STUDIO_INSTANCETYPE_FORCEINT: FMOD_STUDIO_INSTANCETYPE = 65536;
+pub type FMOD_STUDIO_INSTANCETYPE = ::std::os::raw::c_uint;
+pub const FMOD_STUDIO_LOAD_MEMORY_ALIGNMENT: u32 = 32;
+pub const FMOD_STUDIO_INIT_NORMAL: u32 = 0;
+pub const FMOD_STUDIO_INIT_LIVEUPDATE: u32 = 1;
+pub const FMOD_STUDIO_INIT_ALLOW_MISSING_PLUGINS: u32 = 2;
+pub const FMOD_STUDIO_INIT_SYNCHRONOUS_UPDATE: u32 = 4;
+pub const FMOD_STUDIO_INIT_DEFERRED_CALLBACKS: u32 = 8;
+pub const FMOD_STUDIO_INIT_LOAD_FROM_UPDATE: u32 = 16;
+pub const FMOD_STUDIO_INIT_MEMORY_TRACKING: u32 = 32;
+pub const FMOD_STUDIO_PARAMETER_READONLY: u32 = 1;
+pub const FMOD_STUDIO_PARAMETER_AUTOMATIC: u32 = 2;
+pub const FMOD_STUDIO_PARAMETER_GLOBAL: u32 = 4;
+pub const FMOD_STUDIO_PARAMETER_DISCRETE: u32 = 8;
+pub const FMOD_STUDIO_PARAMETER_LABELED: u32 = 16;
+pub const FMOD_STUDIO_SYSTEM_CALLBACK_PREUPDATE: u32 = 1;
+pub const FMOD_STUDIO_SYSTEM_CALLBACK_POSTUPDATE: u32 = 2;
+pub const FMOD_STUDIO_SYSTEM_CALLBACK_BANK_UNLOAD: u32 = 4;
+pub const FMOD_STUDIO_SYSTEM_CALLBACK_LIVEUPDATE_CONNECTED: u32 = 8;
+pub const FMOD_STUDIO_SYSTEM_CALLBACK_LIVEUPDATE_DISCONNECTED: u32 = 16;
+pub const FMOD_STUDIO_SYSTEM_CALLBACK_ALL: u32 = 4294967295;
+pub const FMOD_STUDIO_EVENT_CALLBACK_CREATED: u32 = 1;
+pub const FMOD_STUDIO_EVENT_CALLBACK_DESTROYED: u32 = 2;
+pub const FMOD_STUDIO_EVENT_CALLBACK_STARTING: u32 = 4;
+pub const FMOD_STUDIO_EVENT_CALLBACK_STARTED: u32 = 8;
+pub const FMOD_STUDIO_EVENT_CALLBACK_RESTARTED: u32 = 16;
+pub const FMOD_STUDIO_EVENT_CALLBACK_STOPPED: u32 = 32;
+pub const FMOD_STUDIO_EVENT_CALLBACK_START_FAILED: u32 = 64;
+pub const FMOD_STUDIO_EVENT_CALLBACK_CREATE_PROGRAMMER_SOUND: u32 = 128;
+pub const FMOD_STUDIO_EVENT_CALLBACK_DESTROY_PROGRAMMER_SOUND: u32 = 256;
+pub const FMOD_STUDIO_EVENT_CALLBACK_PLUGIN_CREATED: u32 = 512;
+pub const FMOD_STUDIO_EVENT_CALLBACK_PLUGIN_DESTROYED: u32 = 1024;
+pub const FMOD_STUDIO_EVENT_CALLBACK_TIMELINE_MARKER: u32 = 2048;
+pub const FMOD_STUDIO_EVENT_CALLBACK_TIMELINE_BEAT: u32 = 4096;
+pub const FMOD_STUDIO_EVENT_CALLBACK_SOUND_PLAYED: u32 = 8192;
+pub const FMOD_STUDIO_EVENT_CALLBACK_SOUND_STOPPED: u32 = 16384;
+pub const FMOD_STUDIO_EVENT_CALLBACK_REAL_TO_VIRTUAL: u32 = 32768;
+pub const FMOD_STUDIO_EVENT_CALLBACK_VIRTUAL_TO_REAL: u32 = 65536;
+pub const FMOD_STUDIO_EVENT_CALLBACK_START_EVENT_COMMAND: u32 = 131072;
+pub const FMOD_STUDIO_EVENT_CALLBACK_NESTED_TIMELINE_BEAT: u32 = 262144;
+pub const FMOD_STUDIO_EVENT_CALLBACK_ALL: u32 = 4294967295;
+pub const FMOD_STUDIO_LOAD_BANK_NORMAL: u32 = 0;
+pub const FMOD_STUDIO_LOAD_BANK_NONBLOCKING: u32 = 1;
+pub const FMOD_STUDIO_LOAD_BANK_DECOMPRESS_SAMPLES: u32 = 2;
+pub const FMOD_STUDIO_LOAD_BANK_UNENCRYPTED: u32 = 4;
+pub const FMOD_STUDIO_COMMANDCAPTURE_NORMAL: u32 = 0;
+pub const FMOD_STUDIO_COMMANDCAPTURE_FILEFLUSH: u32 = 1;
+pub const FMOD_STUDIO_COMMANDCAPTURE_SKIP_INITIAL_STATE: u32 = 2;
+pub const FMOD_STUDIO_COMMANDREPLAY_NORMAL: u32 = 0;
+pub const FMOD_STUDIO_COMMANDREPLAY_SKIP_CLEANUP: u32 = 1;
+pub const FMOD_STUDIO_COMMANDREPLAY_FAST_FORWARD: u32 = 2;
+pub const FMOD_STUDIO_COMMANDREPLAY_SKIP_BANK_LOAD: u32 = 4;
+pub type FMOD_STUDIO_INITFLAGS = ::std::os::raw::c_uint;
+pub type FMOD_STUDIO_PARAMETER_FLAGS = ::std::os::raw::c_uint;
+pub type FMOD_STUDIO_SYSTEM_CALLBACK_TYPE = ::std::os::raw::c_uint;
+pub type FMOD_STUDIO_EVENT_CALLBACK_TYPE = ::std::os::raw::c_uint;
+pub type FMOD_STUDIO_LOAD_BANK_FLAGS = ::std::os::raw::c_uint;
+pub type FMOD_STUDIO_COMMANDCAPTURE_FLAGS = ::std::os::raw::c_uint;
+pub type FMOD_STUDIO_COMMANDREPLAY_FLAGS = ::std::os::raw::c_uint;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct FMOD_STUDIO_SYSTEM {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct FMOD_STUDIO_EVENTDESCRIPTION {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct FMOD_STUDIO_EVENTINSTANCE {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct FMOD_STUDIO_BUS {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct FMOD_STUDIO_VCA {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct FMOD_STUDIO_BANK {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct FMOD_STUDIO_COMMANDREPLAY {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct FMOD_STUDIO_PARAMETER_ID {
+    pub data1: ::std::os::raw::c_uint,
+    pub data2: ::std::os::raw::c_uint,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct FMOD_STUDIO_PARAMETER_DESCRIPTION {
+    pub name: *const ::std::os::raw::c_char,
+    pub id: FMOD_STUDIO_PARAMETER_ID,
+    pub minimum: f32,
+    pub maximum: f32,
+    pub defaultvalue: f32,
+    pub type_: FMOD_STUDIO_PARAMETER_TYPE,
+    pub flags: FMOD_STUDIO_PARAMETER_FLAGS,
+    pub guid: FMOD_GUID,
+}
+impl Default for FMOD_STUDIO_PARAMETER_DESCRIPTION {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct FMOD_STUDIO_USER_PROPERTY {
+    pub name: *const ::std::os::raw::c_char,
+    pub type_: FMOD_STUDIO_USER_PROPERTY_TYPE,
+    pub __bindgen_anon_1: FMOD_STUDIO_USER_PROPERTY__bindgen_ty_1,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union FMOD_STUDIO_USER_PROPERTY__bindgen_ty_1 {
+    pub intvalue: ::std::os::raw::c_int,
+    pub boolvalue: FMOD_BOOL,
+    pub floatvalue: f32,
+    pub stringvalue: *const ::std::os::raw::c_char,
+}
+impl Default for FMOD_STUDIO_USER_PROPERTY__bindgen_ty_1 {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+impl Default for FMOD_STUDIO_USER_PROPERTY {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct FMOD_STUDIO_PROGRAMMER_SOUND_PROPERTIES {
+    pub name: *const ::std::os::raw::c_char,
+    pub sound: *mut FMOD_SOUND,
+    pub subsoundIndex: ::std::os::raw::c_int,
+}
+impl Default for FMOD_STUDIO_PROGRAMMER_SOUND_PROPERTIES {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct FMOD_STUDIO_PLUGIN_INSTANCE_PROPERTIES {
+    pub name: *const ::std::os::raw::c_char,
+    pub dsp: *mut FMOD_DSP,
+}
+impl Default for FMOD_STUDIO_PLUGIN_INSTANCE_PROPERTIES {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct FMOD_STUDIO_TIMELINE_MARKER_PROPERTIES {
+    pub name: *const ::std::os::raw::c_char,
+    pub position: ::std::os::raw::c_int,
+}
+impl Default for FMOD_STUDIO_TIMELINE_MARKER_PROPERTIES {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct FMOD_STUDIO_TIMELINE_BEAT_PROPERTIES {
+    pub bar: ::std::os::raw::c_int,
+    pub beat: ::std::os::raw::c_int,
+    pub position: ::std::os::raw::c_int,
+    pub tempo: f32,
+    pub timesignatureupper: ::std::os::raw::c_int,
+    pub timesignaturelower: ::std::os::raw::c_int,
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct FMOD_STUDIO_TIMELINE_NESTED_BEAT_PROPERTIES {
+    pub eventid: FMOD_GUID,
+    pub properties: FMOD_STUDIO_TIMELINE_BEAT_PROPERTIES,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct FMOD_STUDIO_ADVANCEDSETTINGS {
+    pub cbsize: ::std::os::raw::c_int,
+    pub commandqueuesize: ::std::os::raw::c_uint,
+    pub handleinitialsize: ::std::os::raw::c_uint,
+    pub studioupdateperiod: ::std::os::raw::c_int,
+    pub idlesampledatapoolsize: ::std::os::raw::c_int,
+    pub streamingscheduledelay: ::std::os::raw::c_uint,
+    pub encryptionkey: *const ::std::os::raw::c_char,
+}
+impl Default for FMOD_STUDIO_ADVANCEDSETTINGS {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct FMOD_STUDIO_CPU_USAGE {
+    pub update: f32,
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct FMOD_STUDIO_BUFFER_INFO {
+    pub currentusage: ::std::os::raw::c_int,
+    pub peakusage: ::std::os::raw::c_int,
+    pub capacity: ::std::os::raw::c_int,
+    pub stallcount: ::std::os::raw::c_int,
+    pub stalltime: f32,
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct FMOD_STUDIO_BUFFER_USAGE {
+    pub studiocommandqueue: FMOD_STUDIO_BUFFER_INFO,
+    pub studiohandle: FMOD_STUDIO_BUFFER_INFO,
+}
+#[repr(C)]
+#[allow(unknown_lints, unpredictable_function_pointer_comparisons)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct FMOD_STUDIO_SOUND_INFO {
+    pub name_or_data: *const ::std::os::raw::c_char,
+    pub mode: FMOD_MODE,
+    pub exinfo: FMOD_CREATESOUNDEXINFO,
+    pub subsoundindex: ::std::os::raw::c_int,
+}
+impl Default for FMOD_STUDIO_SOUND_INFO {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct FMOD_STUDIO_COMMAND_INFO {
+    pub commandname: *const ::std::os::raw::c_char,
+    pub parentcommandindex: ::std::os::raw::c_int,
+    pub framenumber: ::std::os::raw::c_int,
+    pub frametime: f32,
+    pub instancetype: FMOD_STUDIO_INSTANCETYPE,
+    pub outputtype: FMOD_STUDIO_INSTANCETYPE,
+    pub instancehandle: ::std::os::raw::c_uint,
+    pub outputhandle: ::std::os::raw::c_uint,
+}
+impl Default for FMOD_STUDIO_COMMAND_INFO {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct FMOD_STUDIO_MEMORY_USAGE {
+    pub exclusive: ::std::os::raw::c_int,
+    pub inclusive: ::std::os::raw::c_int,
+    pub sampledata: ::std::os::raw::c_int,
+}
+pub type FMOD_STUDIO_SYSTEM_CALLBACK = ::std::option::Option<
+    unsafe extern "C" fn(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        type_: FMOD_STUDIO_SYSTEM_CALLBACK_TYPE,
+        commanddata: *mut ::std::os::raw::c_void,
+        userdata: *mut ::std::os::raw::c_void,
+    ) -> FMOD_RESULT,
+>;
+pub type FMOD_STUDIO_EVENT_CALLBACK = ::std::option::Option<
+    unsafe extern "C" fn(
+        type_: FMOD_STUDIO_EVENT_CALLBACK_TYPE,
+        event: *mut FMOD_STUDIO_EVENTINSTANCE,
+        parameters: *mut ::std::os::raw::c_void,
+    ) -> FMOD_RESULT,
+>;
+pub type FMOD_STUDIO_COMMANDREPLAY_FRAME_CALLBACK = ::std::option::Option<
+    unsafe extern "C" fn(
+        replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+        commandindex: ::std::os::raw::c_int,
+        currenttime: f32,
+        userdata: *mut ::std::os::raw::c_void,
+    ) -> FMOD_RESULT,
+>;
+pub type FMOD_STUDIO_COMMANDREPLAY_LOAD_BANK_CALLBACK = ::std::option::Option<
+    unsafe extern "C" fn(
+        replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+        commandindex: ::std::os::raw::c_int,
+        bankguid: *const FMOD_GUID,
+        bankfilename: *const ::std::os::raw::c_char,
+        flags: FMOD_STUDIO_LOAD_BANK_FLAGS,
+        bank: *mut *mut FMOD_STUDIO_BANK,
+        userdata: *mut ::std::os::raw::c_void,
+    ) -> FMOD_RESULT,
+>;
+pub type FMOD_STUDIO_COMMANDREPLAY_CREATE_INSTANCE_CALLBACK = ::std::option::Option<
+    unsafe extern "C" fn(
+        replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+        commandindex: ::std::os::raw::c_int,
+        eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+        instance: *mut *mut FMOD_STUDIO_EVENTINSTANCE,
+        userdata: *mut ::std::os::raw::c_void,
+    ) -> FMOD_RESULT,
+>;
+extern "C" {
+    pub fn FMOD_Memory_Initialize(
+        poolmem: *mut ::std::os::raw::c_void,
+        poollen: ::std::os::raw::c_int,
+        useralloc: FMOD_MEMORY_ALLOC_CALLBACK,
+        userrealloc: FMOD_MEMORY_REALLOC_CALLBACK,
+        userfree: FMOD_MEMORY_FREE_CALLBACK,
+        memtypeflags: FMOD_MEMORY_TYPE,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Memory_GetStats(
+        currentalloced: *mut ::std::os::raw::c_int,
+        maxalloced: *mut ::std::os::raw::c_int,
+        blocking: FMOD_BOOL,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Debug_Initialize(
+        flags: FMOD_DEBUG_FLAGS,
+        mode: FMOD_DEBUG_MODE,
+        callback: FMOD_DEBUG_CALLBACK,
+        filename: *const ::std::os::raw::c_char,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_File_SetDiskBusy(busy: ::std::os::raw::c_int) -> FMOD_RESULT;
+    pub fn FMOD_File_GetDiskBusy(busy: *mut ::std::os::raw::c_int) -> FMOD_RESULT;
+    pub fn FMOD_Thread_SetAttributes(
+        type_: FMOD_THREAD_TYPE,
+        affinity: FMOD_THREAD_AFFINITY,
+        priority: FMOD_THREAD_PRIORITY,
+        stacksize: FMOD_THREAD_STACK_SIZE,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_Create(
+        system: *mut *mut FMOD_SYSTEM,
+        headerversion: ::std::os::raw::c_uint,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_Release(system: *mut FMOD_SYSTEM) -> FMOD_RESULT;
+    pub fn FMOD_System_SetOutput(system: *mut FMOD_SYSTEM, output: FMOD_OUTPUTTYPE) -> FMOD_RESULT;
+    pub fn FMOD_System_GetOutput(
+        system: *mut FMOD_SYSTEM,
+        output: *mut FMOD_OUTPUTTYPE,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_GetNumDrivers(
+        system: *mut FMOD_SYSTEM,
+        numdrivers: *mut ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_GetDriverInfo(
+        system: *mut FMOD_SYSTEM,
+        id: ::std::os::raw::c_int,
+        name: *mut ::std::os::raw::c_char,
+        namelen: ::std::os::raw::c_int,
+        guid: *mut FMOD_GUID,
+        systemrate: *mut ::std::os::raw::c_int,
+        speakermode: *mut FMOD_SPEAKERMODE,
+        speakermodechannels: *mut ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_SetDriver(
+        system: *mut FMOD_SYSTEM,
+        driver: ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_GetDriver(
+        system: *mut FMOD_SYSTEM,
+        driver: *mut ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_SetSoftwareChannels(
+        system: *mut FMOD_SYSTEM,
+        numsoftwarechannels: ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_GetSoftwareChannels(
+        system: *mut FMOD_SYSTEM,
+        numsoftwarechannels: *mut ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_SetSoftwareFormat(
+        system: *mut FMOD_SYSTEM,
+        samplerate: ::std::os::raw::c_int,
+        speakermode: FMOD_SPEAKERMODE,
+        numrawspeakers: ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_GetSoftwareFormat(
+        system: *mut FMOD_SYSTEM,
+        samplerate: *mut ::std::os::raw::c_int,
+        speakermode: *mut FMOD_SPEAKERMODE,
+        numrawspeakers: *mut ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_SetDSPBufferSize(
+        system: *mut FMOD_SYSTEM,
+        bufferlength: ::std::os::raw::c_uint,
+        numbuffers: ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_GetDSPBufferSize(
+        system: *mut FMOD_SYSTEM,
+        bufferlength: *mut ::std::os::raw::c_uint,
+        numbuffers: *mut ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_SetFileSystem(
+        system: *mut FMOD_SYSTEM,
+        useropen: FMOD_FILE_OPEN_CALLBACK,
+        userclose: FMOD_FILE_CLOSE_CALLBACK,
+        userread: FMOD_FILE_READ_CALLBACK,
+        userseek: FMOD_FILE_SEEK_CALLBACK,
+        userasyncread: FMOD_FILE_ASYNCREAD_CALLBACK,
+        userasynccancel: FMOD_FILE_ASYNCCANCEL_CALLBACK,
+        blockalign: ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_AttachFileSystem(
+        system: *mut FMOD_SYSTEM,
+        useropen: FMOD_FILE_OPEN_CALLBACK,
+        userclose: FMOD_FILE_CLOSE_CALLBACK,
+        userread: FMOD_FILE_READ_CALLBACK,
+        userseek: FMOD_FILE_SEEK_CALLBACK,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_SetAdvancedSettings(
+        system: *mut FMOD_SYSTEM,
+        settings: *mut FMOD_ADVANCEDSETTINGS,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_GetAdvancedSettings(
+        system: *mut FMOD_SYSTEM,
+        settings: *mut FMOD_ADVANCEDSETTINGS,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_SetCallback(
+        system: *mut FMOD_SYSTEM,
+        callback: FMOD_SYSTEM_CALLBACK,
+        callbackmask: FMOD_SYSTEM_CALLBACK_TYPE,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_SetPluginPath(
+        system: *mut FMOD_SYSTEM,
+        path: *const ::std::os::raw::c_char,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_LoadPlugin(
+        system: *mut FMOD_SYSTEM,
+        filename: *const ::std::os::raw::c_char,
+        handle: *mut ::std::os::raw::c_uint,
+        priority: ::std::os::raw::c_uint,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_UnloadPlugin(
+        system: *mut FMOD_SYSTEM,
+        handle: ::std::os::raw::c_uint,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_GetNumNestedPlugins(
+        system: *mut FMOD_SYSTEM,
+        handle: ::std::os::raw::c_uint,
+        count: *mut ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_GetNestedPlugin(
+        system: *mut FMOD_SYSTEM,
+        handle: ::std::os::raw::c_uint,
+        index: ::std::os::raw::c_int,
+        nestedhandle: *mut ::std::os::raw::c_uint,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_GetNumPlugins(
+        system: *mut FMOD_SYSTEM,
+        plugintype: FMOD_PLUGINTYPE,
+        numplugins: *mut ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_GetPluginHandle(
+        system: *mut FMOD_SYSTEM,
+        plugintype: FMOD_PLUGINTYPE,
+        index: ::std::os::raw::c_int,
+        handle: *mut ::std::os::raw::c_uint,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_GetPluginInfo(
+        system: *mut FMOD_SYSTEM,
+        handle: ::std::os::raw::c_uint,
+        plugintype: *mut FMOD_PLUGINTYPE,
+        name: *mut ::std::os::raw::c_char,
+        namelen: ::std::os::raw::c_int,
+        version: *mut ::std::os::raw::c_uint,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_SetOutputByPlugin(
+        system: *mut FMOD_SYSTEM,
+        handle: ::std::os::raw::c_uint,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_GetOutputByPlugin(
+        system: *mut FMOD_SYSTEM,
+        handle: *mut ::std::os::raw::c_uint,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_CreateDSPByPlugin(
+        system: *mut FMOD_SYSTEM,
+        handle: ::std::os::raw::c_uint,
+        dsp: *mut *mut FMOD_DSP,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_GetDSPInfoByPlugin(
+        system: *mut FMOD_SYSTEM,
+        handle: ::std::os::raw::c_uint,
+        description: *mut *const FMOD_DSP_DESCRIPTION,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_RegisterCodec(
+        system: *mut FMOD_SYSTEM,
+        description: *mut FMOD_CODEC_DESCRIPTION,
+        handle: *mut ::std::os::raw::c_uint,
+        priority: ::std::os::raw::c_uint,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_RegisterDSP(
+        system: *mut FMOD_SYSTEM,
+        description: *const FMOD_DSP_DESCRIPTION,
+        handle: *mut ::std::os::raw::c_uint,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_RegisterOutput(
+        system: *mut FMOD_SYSTEM,
+        description: *const FMOD_OUTPUT_DESCRIPTION,
+        handle: *mut ::std::os::raw::c_uint,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_Init(
+        system: *mut FMOD_SYSTEM,
+        maxchannels: ::std::os::raw::c_int,
+        flags: FMOD_INITFLAGS,
+        extradriverdata: *mut ::std::os::raw::c_void,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_Close(system: *mut FMOD_SYSTEM) -> FMOD_RESULT;
+    pub fn FMOD_System_Update(system: *mut FMOD_SYSTEM) -> FMOD_RESULT;
+    pub fn FMOD_System_SetSpeakerPosition(
+        system: *mut FMOD_SYSTEM,
+        speaker: FMOD_SPEAKER,
+        x: f32,
+        y: f32,
+        active: FMOD_BOOL,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_GetSpeakerPosition(
+        system: *mut FMOD_SYSTEM,
+        speaker: FMOD_SPEAKER,
+        x: *mut f32,
+        y: *mut f32,
+        active: *mut FMOD_BOOL,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_SetStreamBufferSize(
+        system: *mut FMOD_SYSTEM,
+        filebuffersize: ::std::os::raw::c_uint,
+        filebuffersizetype: FMOD_TIMEUNIT,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_GetStreamBufferSize(
+        system: *mut FMOD_SYSTEM,
+        filebuffersize: *mut ::std::os::raw::c_uint,
+        filebuffersizetype: *mut FMOD_TIMEUNIT,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_Set3DSettings(
+        system: *mut FMOD_SYSTEM,
+        dopplerscale: f32,
+        distancefactor: f32,
+        rolloffscale: f32,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_Get3DSettings(
+        system: *mut FMOD_SYSTEM,
+        dopplerscale: *mut f32,
+        distancefactor: *mut f32,
+        rolloffscale: *mut f32,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_Set3DNumListeners(
+        system: *mut FMOD_SYSTEM,
+        numlisteners: ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_Get3DNumListeners(
+        system: *mut FMOD_SYSTEM,
+        numlisteners: *mut ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_Set3DListenerAttributes(
+        system: *mut FMOD_SYSTEM,
+        listener: ::std::os::raw::c_int,
+        pos: *const FMOD_VECTOR,
+        vel: *const FMOD_VECTOR,
+        forward: *const FMOD_VECTOR,
+        up: *const FMOD_VECTOR,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_Get3DListenerAttributes(
+        system: *mut FMOD_SYSTEM,
+        listener: ::std::os::raw::c_int,
+        pos: *mut FMOD_VECTOR,
+        vel: *mut FMOD_VECTOR,
+        forward: *mut FMOD_VECTOR,
+        up: *mut FMOD_VECTOR,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_Set3DRolloffCallback(
+        system: *mut FMOD_SYSTEM,
+        callback: FMOD_3D_ROLLOFF_CALLBACK,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_MixerSuspend(system: *mut FMOD_SYSTEM) -> FMOD_RESULT;
+    pub fn FMOD_System_MixerResume(system: *mut FMOD_SYSTEM) -> FMOD_RESULT;
+    pub fn FMOD_System_GetDefaultMixMatrix(
+        system: *mut FMOD_SYSTEM,
+        sourcespeakermode: FMOD_SPEAKERMODE,
+        targetspeakermode: FMOD_SPEAKERMODE,
+        matrix: *mut f32,
+        matrixhop: ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_GetSpeakerModeChannels(
+        system: *mut FMOD_SYSTEM,
+        mode: FMOD_SPEAKERMODE,
+        channels: *mut ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_GetVersion(
+        system: *mut FMOD_SYSTEM,
+        version: *mut ::std::os::raw::c_uint,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_GetOutputHandle(
+        system: *mut FMOD_SYSTEM,
+        handle: *mut *mut ::std::os::raw::c_void,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_GetChannelsPlaying(
+        system: *mut FMOD_SYSTEM,
+        channels: *mut ::std::os::raw::c_int,
+        realchannels: *mut ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_GetCPUUsage(
+        system: *mut FMOD_SYSTEM,
+        usage: *mut FMOD_CPU_USAGE,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_GetFileUsage(
+        system: *mut FMOD_SYSTEM,
+        sampleBytesRead: *mut ::std::os::raw::c_longlong,
+        streamBytesRead: *mut ::std::os::raw::c_longlong,
+        otherBytesRead: *mut ::std::os::raw::c_longlong,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_CreateSound(
+        system: *mut FMOD_SYSTEM,
+        name_or_data: *const ::std::os::raw::c_char,
+        mode: FMOD_MODE,
+        exinfo: *mut FMOD_CREATESOUNDEXINFO,
+        sound: *mut *mut FMOD_SOUND,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_CreateStream(
+        system: *mut FMOD_SYSTEM,
+        name_or_data: *const ::std::os::raw::c_char,
+        mode: FMOD_MODE,
+        exinfo: *mut FMOD_CREATESOUNDEXINFO,
+        sound: *mut *mut FMOD_SOUND,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_CreateDSP(
+        system: *mut FMOD_SYSTEM,
+        description: *const FMOD_DSP_DESCRIPTION,
+        dsp: *mut *mut FMOD_DSP,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_CreateDSPByType(
+        system: *mut FMOD_SYSTEM,
+        type_: FMOD_DSP_TYPE,
+        dsp: *mut *mut FMOD_DSP,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_CreateChannelGroup(
+        system: *mut FMOD_SYSTEM,
+        name: *const ::std::os::raw::c_char,
+        channelgroup: *mut *mut FMOD_CHANNELGROUP,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_CreateSoundGroup(
+        system: *mut FMOD_SYSTEM,
+        name: *const ::std::os::raw::c_char,
+        soundgroup: *mut *mut FMOD_SOUNDGROUP,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_CreateReverb3D(
+        system: *mut FMOD_SYSTEM,
+        reverb: *mut *mut FMOD_REVERB3D,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_PlaySound(
+        system: *mut FMOD_SYSTEM,
+        sound: *mut FMOD_SOUND,
+        channelgroup: *mut FMOD_CHANNELGROUP,
+        paused: FMOD_BOOL,
+        channel: *mut *mut FMOD_CHANNEL,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_PlayDSP(
+        system: *mut FMOD_SYSTEM,
+        dsp: *mut FMOD_DSP,
+        channelgroup: *mut FMOD_CHANNELGROUP,
+        paused: FMOD_BOOL,
+        channel: *mut *mut FMOD_CHANNEL,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_GetChannel(
+        system: *mut FMOD_SYSTEM,
+        channelid: ::std::os::raw::c_int,
+        channel: *mut *mut FMOD_CHANNEL,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_GetDSPInfoByType(
+        system: *mut FMOD_SYSTEM,
+        type_: FMOD_DSP_TYPE,
+        description: *mut *const FMOD_DSP_DESCRIPTION,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_GetMasterChannelGroup(
+        system: *mut FMOD_SYSTEM,
+        channelgroup: *mut *mut FMOD_CHANNELGROUP,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_GetMasterSoundGroup(
+        system: *mut FMOD_SYSTEM,
+        soundgroup: *mut *mut FMOD_SOUNDGROUP,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_AttachChannelGroupToPort(
+        system: *mut FMOD_SYSTEM,
+        portType: FMOD_PORT_TYPE,
+        portIndex: FMOD_PORT_INDEX,
+        channelgroup: *mut FMOD_CHANNELGROUP,
+        passThru: FMOD_BOOL,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_DetachChannelGroupFromPort(
+        system: *mut FMOD_SYSTEM,
+        channelgroup: *mut FMOD_CHANNELGROUP,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_SetReverbProperties(
+        system: *mut FMOD_SYSTEM,
+        instance: ::std::os::raw::c_int,
+        prop: *const FMOD_REVERB_PROPERTIES,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_GetReverbProperties(
+        system: *mut FMOD_SYSTEM,
+        instance: ::std::os::raw::c_int,
+        prop: *mut FMOD_REVERB_PROPERTIES,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_LockDSP(system: *mut FMOD_SYSTEM) -> FMOD_RESULT;
+    pub fn FMOD_System_UnlockDSP(system: *mut FMOD_SYSTEM) -> FMOD_RESULT;
+    pub fn FMOD_System_GetRecordNumDrivers(
+        system: *mut FMOD_SYSTEM,
+        numdrivers: *mut ::std::os::raw::c_int,
+        numconnected: *mut ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_GetRecordDriverInfo(
+        system: *mut FMOD_SYSTEM,
+        id: ::std::os::raw::c_int,
+        name: *mut ::std::os::raw::c_char,
+        namelen: ::std::os::raw::c_int,
+        guid: *mut FMOD_GUID,
+        systemrate: *mut ::std::os::raw::c_int,
+        speakermode: *mut FMOD_SPEAKERMODE,
+        speakermodechannels: *mut ::std::os::raw::c_int,
+        state: *mut FMOD_DRIVER_STATE,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_GetRecordPosition(
+        system: *mut FMOD_SYSTEM,
+        id: ::std::os::raw::c_int,
+        position: *mut ::std::os::raw::c_uint,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_RecordStart(
+        system: *mut FMOD_SYSTEM,
+        id: ::std::os::raw::c_int,
+        sound: *mut FMOD_SOUND,
+        loop_: FMOD_BOOL,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_RecordStop(
+        system: *mut FMOD_SYSTEM,
+        id: ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_IsRecording(
+        system: *mut FMOD_SYSTEM,
+        id: ::std::os::raw::c_int,
+        recording: *mut FMOD_BOOL,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_CreateGeometry(
+        system: *mut FMOD_SYSTEM,
+        maxpolygons: ::std::os::raw::c_int,
+        maxvertices: ::std::os::raw::c_int,
+        geometry: *mut *mut FMOD_GEOMETRY,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_SetGeometrySettings(
+        system: *mut FMOD_SYSTEM,
+        maxworldsize: f32,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_GetGeometrySettings(
+        system: *mut FMOD_SYSTEM,
+        maxworldsize: *mut f32,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_LoadGeometry(
+        system: *mut FMOD_SYSTEM,
+        data: *const ::std::os::raw::c_void,
+        datasize: ::std::os::raw::c_int,
+        geometry: *mut *mut FMOD_GEOMETRY,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_GetGeometryOcclusion(
+        system: *mut FMOD_SYSTEM,
+        listener: *const FMOD_VECTOR,
+        source: *const FMOD_VECTOR,
+        direct: *mut f32,
+        reverb: *mut f32,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_SetNetworkProxy(
+        system: *mut FMOD_SYSTEM,
+        proxy: *const ::std::os::raw::c_char,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_GetNetworkProxy(
+        system: *mut FMOD_SYSTEM,
+        proxy: *mut ::std::os::raw::c_char,
+        proxylen: ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_SetNetworkTimeout(
+        system: *mut FMOD_SYSTEM,
+        timeout: ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_GetNetworkTimeout(
+        system: *mut FMOD_SYSTEM,
+        timeout: *mut ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_SetUserData(
+        system: *mut FMOD_SYSTEM,
+        userdata: *mut ::std::os::raw::c_void,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_System_GetUserData(
+        system: *mut FMOD_SYSTEM,
+        userdata: *mut *mut ::std::os::raw::c_void,
+    ) -> FMOD_RESULT;
+
+    pub fn FMOD_Sound_Release(sound: *mut FMOD_SOUND) -> FMOD_RESULT;
+    pub fn FMOD_Sound_GetSystemObject(
+        sound: *mut FMOD_SOUND,
+        system: *mut *mut FMOD_SYSTEM,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Sound_Lock(
+        sound: *mut FMOD_SOUND,
+        offset: ::std::os::raw::c_uint,
+        length: ::std::os::raw::c_uint,
+        ptr1: *mut *mut ::std::os::raw::c_void,
+        ptr2: *mut *mut ::std::os::raw::c_void,
+        len1: *mut ::std::os::raw::c_uint,
+        len2: *mut ::std::os::raw::c_uint,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Sound_Unlock(
+        sound: *mut FMOD_SOUND,
+        ptr1: *mut ::std::os::raw::c_void,
+        ptr2: *mut ::std::os::raw::c_void,
+        len1: ::std::os::raw::c_uint,
+        len2: ::std::os::raw::c_uint,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Sound_SetDefaults(
+        sound: *mut FMOD_SOUND,
+        frequency: f32,
+        priority: ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Sound_GetDefaults(
+        sound: *mut FMOD_SOUND,
+        frequency: *mut f32,
+        priority: *mut ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Sound_Set3DMinMaxDistance(
+        sound: *mut FMOD_SOUND,
+        min: f32,
+        max: f32,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Sound_Get3DMinMaxDistance(
+        sound: *mut FMOD_SOUND,
+        min: *mut f32,
+        max: *mut f32,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Sound_Set3DConeSettings(
+        sound: *mut FMOD_SOUND,
+        insideconeangle: f32,
+        outsideconeangle: f32,
+        outsidevolume: f32,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Sound_Get3DConeSettings(
+        sound: *mut FMOD_SOUND,
+        insideconeangle: *mut f32,
+        outsideconeangle: *mut f32,
+        outsidevolume: *mut f32,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Sound_Set3DCustomRolloff(
+        sound: *mut FMOD_SOUND,
+        points: *mut FMOD_VECTOR,
+        numpoints: ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Sound_Get3DCustomRolloff(
+        sound: *mut FMOD_SOUND,
+        points: *mut *mut FMOD_VECTOR,
+        numpoints: *mut ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Sound_GetSubSound(
+        sound: *mut FMOD_SOUND,
+        index: ::std::os::raw::c_int,
+        subsound: *mut *mut FMOD_SOUND,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Sound_GetSubSoundParent(
+        sound: *mut FMOD_SOUND,
+        parentsound: *mut *mut FMOD_SOUND,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Sound_GetName(
+        sound: *mut FMOD_SOUND,
+        name: *mut ::std::os::raw::c_char,
+        namelen: ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Sound_GetLength(
+        sound: *mut FMOD_SOUND,
+        length: *mut ::std::os::raw::c_uint,
+        lengthtype: FMOD_TIMEUNIT,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Sound_GetFormat(
+        sound: *mut FMOD_SOUND,
+        type_: *mut FMOD_SOUND_TYPE,
+        format: *mut FMOD_SOUND_FORMAT,
+        channels: *mut ::std::os::raw::c_int,
+        bits: *mut ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Sound_GetNumSubSounds(
+        sound: *mut FMOD_SOUND,
+        numsubsounds: *mut ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Sound_GetNumTags(
+        sound: *mut FMOD_SOUND,
+        numtags: *mut ::std::os::raw::c_int,
+        numtagsupdated: *mut ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Sound_GetTag(
+        sound: *mut FMOD_SOUND,
+        name: *const ::std::os::raw::c_char,
+        index: ::std::os::raw::c_int,
+        tag: *mut FMOD_TAG,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Sound_GetOpenState(
+        sound: *mut FMOD_SOUND,
+        openstate: *mut FMOD_OPENSTATE,
+        percentbuffered: *mut ::std::os::raw::c_uint,
+        starving: *mut FMOD_BOOL,
+        diskbusy: *mut FMOD_BOOL,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Sound_ReadData(
+        sound: *mut FMOD_SOUND,
+        buffer: *mut ::std::os::raw::c_void,
+        length: ::std::os::raw::c_uint,
+        read: *mut ::std::os::raw::c_uint,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Sound_SeekData(sound: *mut FMOD_SOUND, pcm: ::std::os::raw::c_uint) -> FMOD_RESULT;
+    pub fn FMOD_Sound_SetSoundGroup(
+        sound: *mut FMOD_SOUND,
+        soundgroup: *mut FMOD_SOUNDGROUP,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Sound_GetSoundGroup(
+        sound: *mut FMOD_SOUND,
+        soundgroup: *mut *mut FMOD_SOUNDGROUP,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Sound_GetNumSyncPoints(
+        sound: *mut FMOD_SOUND,
+        numsyncpoints: *mut ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Sound_GetSyncPoint(
+        sound: *mut FMOD_SOUND,
+        index: ::std::os::raw::c_int,
+        point: *mut *mut FMOD_SYNCPOINT,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Sound_GetSyncPointInfo(
+        sound: *mut FMOD_SOUND,
+        point: *mut FMOD_SYNCPOINT,
+        name: *mut ::std::os::raw::c_char,
+        namelen: ::std::os::raw::c_int,
+        offset: *mut ::std::os::raw::c_uint,
+        offsettype: FMOD_TIMEUNIT,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Sound_AddSyncPoint(
+        sound: *mut FMOD_SOUND,
+        offset: ::std::os::raw::c_uint,
+        offsettype: FMOD_TIMEUNIT,
+        name: *const ::std::os::raw::c_char,
+        point: *mut *mut FMOD_SYNCPOINT,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Sound_DeleteSyncPoint(
+        sound: *mut FMOD_SOUND,
+        point: *mut FMOD_SYNCPOINT,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Sound_SetMode(sound: *mut FMOD_SOUND, mode: FMOD_MODE) -> FMOD_RESULT;
+    pub fn FMOD_Sound_GetMode(sound: *mut FMOD_SOUND, mode: *mut FMOD_MODE) -> FMOD_RESULT;
+    pub fn FMOD_Sound_SetLoopCount(
+        sound: *mut FMOD_SOUND,
+        loopcount: ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Sound_GetLoopCount(
+        sound: *mut FMOD_SOUND,
+        loopcount: *mut ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Sound_SetLoopPoints(
+        sound: *mut FMOD_SOUND,
+        loopstart: ::std::os::raw::c_uint,
+        loopstarttype: FMOD_TIMEUNIT,
+        loopend: ::std::os::raw::c_uint,
+        loopendtype: FMOD_TIMEUNIT,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Sound_GetLoopPoints(
+        sound: *mut FMOD_SOUND,
+        loopstart: *mut ::std::os::raw::c_uint,
+        loopstarttype: FMOD_TIMEUNIT,
+        loopend: *mut ::std::os::raw::c_uint,
+        loopendtype: FMOD_TIMEUNIT,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Sound_GetMusicNumChannels(
+        sound: *mut FMOD_SOUND,
+        numchannels: *mut ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Sound_SetMusicChannelVolume(
+        sound: *mut FMOD_SOUND,
+        channel: ::std::os::raw::c_int,
+        volume: f32,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Sound_GetMusicChannelVolume(
+        sound: *mut FMOD_SOUND,
+        channel: ::std::os::raw::c_int,
+        volume: *mut f32,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Sound_SetMusicSpeed(sound: *mut FMOD_SOUND, speed: f32) -> FMOD_RESULT;
+    pub fn FMOD_Sound_GetMusicSpeed(sound: *mut FMOD_SOUND, speed: *mut f32) -> FMOD_RESULT;
+    pub fn FMOD_Sound_SetUserData(
+        sound: *mut FMOD_SOUND,
+        userdata: *mut ::std::os::raw::c_void,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Sound_GetUserData(
+        sound: *mut FMOD_SOUND,
+        userdata: *mut *mut ::std::os::raw::c_void,
+    ) -> FMOD_RESULT;
+
+    pub fn FMOD_Channel_SetFrequency(channel: *mut FMOD_CHANNEL, frequency: f32) -> FMOD_RESULT;
+    pub fn FMOD_Channel_GetFrequency(
+        channel: *mut FMOD_CHANNEL,
+        frequency: *mut f32,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Channel_SetPriority(
+        channel: *mut FMOD_CHANNEL,
+        priority: ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Channel_GetPriority(
+        channel: *mut FMOD_CHANNEL,
+        priority: *mut ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Channel_SetPosition(
+        channel: *mut FMOD_CHANNEL,
+        position: ::std::os::raw::c_uint,
+        postype: FMOD_TIMEUNIT,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Channel_GetPosition(
+        channel: *mut FMOD_CHANNEL,
+        position: *mut ::std::os::raw::c_uint,
+        postype: FMOD_TIMEUNIT,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Channel_SetChannelGroup(
+        channel: *mut FMOD_CHANNEL,
+        channelgroup: *mut FMOD_CHANNELGROUP,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Channel_GetChannelGroup(
+        channel: *mut FMOD_CHANNEL,
+        channelgroup: *mut *mut FMOD_CHANNELGROUP,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Channel_SetLoopCount(
+        channel: *mut FMOD_CHANNEL,
+        loopcount: ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Channel_GetLoopCount(
+        channel: *mut FMOD_CHANNEL,
+        loopcount: *mut ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Channel_SetLoopPoints(
+        channel: *mut FMOD_CHANNEL,
+        loopstart: ::std::os::raw::c_uint,
+        loopstarttype: FMOD_TIMEUNIT,
+        loopend: ::std::os::raw::c_uint,
+        loopendtype: FMOD_TIMEUNIT,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Channel_GetLoopPoints(
+        channel: *mut FMOD_CHANNEL,
+        loopstart: *mut ::std::os::raw::c_uint,
+        loopstarttype: FMOD_TIMEUNIT,
+        loopend: *mut ::std::os::raw::c_uint,
+        loopendtype: FMOD_TIMEUNIT,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Channel_IsVirtual(
+        channel: *mut FMOD_CHANNEL,
+        isvirtual: *mut FMOD_BOOL,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Channel_GetCurrentSound(
+        channel: *mut FMOD_CHANNEL,
+        sound: *mut *mut FMOD_SOUND,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Channel_GetIndex(
+        channel: *mut FMOD_CHANNEL,
+        index: *mut ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+
+    pub fn FMOD_ChannelGroup_Release(channelgroup: *mut FMOD_CHANNELGROUP) -> FMOD_RESULT;
+    pub fn FMOD_ChannelGroup_AddGroup(
+        channelgroup: *mut FMOD_CHANNELGROUP,
+        group: *mut FMOD_CHANNELGROUP,
+        propagatedspclock: FMOD_BOOL,
+        connection: *mut *mut FMOD_DSPCONNECTION,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_ChannelGroup_GetNumGroups(
+        channelgroup: *mut FMOD_CHANNELGROUP,
+        numgroups: *mut ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_ChannelGroup_GetGroup(
+        channelgroup: *mut FMOD_CHANNELGROUP,
+        index: ::std::os::raw::c_int,
+        group: *mut *mut FMOD_CHANNELGROUP,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_ChannelGroup_GetParentGroup(
+        channelgroup: *mut FMOD_CHANNELGROUP,
+        group: *mut *mut FMOD_CHANNELGROUP,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_ChannelGroup_GetName(
+        channelgroup: *mut FMOD_CHANNELGROUP,
+        name: *mut ::std::os::raw::c_char,
+        namelen: ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_ChannelGroup_GetNumChannels(
+        channelgroup: *mut FMOD_CHANNELGROUP,
+        numchannels: *mut ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_ChannelGroup_GetChannel(
+        channelgroup: *mut FMOD_CHANNELGROUP,
+        index: ::std::os::raw::c_int,
+        channel: *mut *mut FMOD_CHANNEL,
+    ) -> FMOD_RESULT;
+
+    pub fn FMOD_SoundGroup_Release(soundgroup: *mut FMOD_SOUNDGROUP) -> FMOD_RESULT;
+    pub fn FMOD_SoundGroup_GetSystemObject(
+        soundgroup: *mut FMOD_SOUNDGROUP,
+        system: *mut *mut FMOD_SYSTEM,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_SoundGroup_SetMaxAudible(
+        soundgroup: *mut FMOD_SOUNDGROUP,
+        maxaudible: ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_SoundGroup_GetMaxAudible(
+        soundgroup: *mut FMOD_SOUNDGROUP,
+        maxaudible: *mut ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_SoundGroup_SetMaxAudibleBehavior(
+        soundgroup: *mut FMOD_SOUNDGROUP,
+        behavior: FMOD_SOUNDGROUP_BEHAVIOR,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_SoundGroup_GetMaxAudibleBehavior(
+        soundgroup: *mut FMOD_SOUNDGROUP,
+        behavior: *mut FMOD_SOUNDGROUP_BEHAVIOR,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_SoundGroup_SetMuteFadeSpeed(
+        soundgroup: *mut FMOD_SOUNDGROUP,
+        speed: f32,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_SoundGroup_GetMuteFadeSpeed(
+        soundgroup: *mut FMOD_SOUNDGROUP,
+        speed: *mut f32,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_SoundGroup_SetVolume(soundgroup: *mut FMOD_SOUNDGROUP, volume: f32) -> FMOD_RESULT;
+    pub fn FMOD_SoundGroup_GetVolume(
+        soundgroup: *mut FMOD_SOUNDGROUP,
+        volume: *mut f32,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_SoundGroup_Stop(soundgroup: *mut FMOD_SOUNDGROUP) -> FMOD_RESULT;
+    pub fn FMOD_SoundGroup_GetName(
+        soundgroup: *mut FMOD_SOUNDGROUP,
+        name: *mut ::std::os::raw::c_char,
+        namelen: ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_SoundGroup_GetNumSounds(
+        soundgroup: *mut FMOD_SOUNDGROUP,
+        numsounds: *mut ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_SoundGroup_GetSound(
+        soundgroup: *mut FMOD_SOUNDGROUP,
+        index: ::std::os::raw::c_int,
+        sound: *mut *mut FMOD_SOUND,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_SoundGroup_GetNumPlaying(
+        soundgroup: *mut FMOD_SOUNDGROUP,
+        numplaying: *mut ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_SoundGroup_SetUserData(
+        soundgroup: *mut FMOD_SOUNDGROUP,
+        userdata: *mut ::std::os::raw::c_void,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_SoundGroup_GetUserData(
+        soundgroup: *mut FMOD_SOUNDGROUP,
+        userdata: *mut *mut ::std::os::raw::c_void,
+    ) -> FMOD_RESULT;
+
+    pub fn FMOD_DSP_Release(dsp: *mut FMOD_DSP) -> FMOD_RESULT;
+    pub fn FMOD_DSP_GetSystemObject(
+        dsp: *mut FMOD_DSP,
+        system: *mut *mut FMOD_SYSTEM,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_DSP_AddInput(
+        dsp: *mut FMOD_DSP,
+        input: *mut FMOD_DSP,
+        connection: *mut *mut FMOD_DSPCONNECTION,
+        type_: FMOD_DSPCONNECTION_TYPE,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_DSP_DisconnectFrom(
+        dsp: *mut FMOD_DSP,
+        target: *mut FMOD_DSP,
+        connection: *mut FMOD_DSPCONNECTION,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_DSP_DisconnectAll(
+        dsp: *mut FMOD_DSP,
+        inputs: FMOD_BOOL,
+        outputs: FMOD_BOOL,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_DSP_GetNumInputs(
+        dsp: *mut FMOD_DSP,
+        numinputs: *mut ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_DSP_GetNumOutputs(
+        dsp: *mut FMOD_DSP,
+        numoutputs: *mut ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_DSP_GetInput(
+        dsp: *mut FMOD_DSP,
+        index: ::std::os::raw::c_int,
+        input: *mut *mut FMOD_DSP,
+        inputconnection: *mut *mut FMOD_DSPCONNECTION,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_DSP_GetOutput(
+        dsp: *mut FMOD_DSP,
+        index: ::std::os::raw::c_int,
+        output: *mut *mut FMOD_DSP,
+        outputconnection: *mut *mut FMOD_DSPCONNECTION,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_DSP_SetActive(dsp: *mut FMOD_DSP, active: FMOD_BOOL) -> FMOD_RESULT;
+    pub fn FMOD_DSP_GetActive(dsp: *mut FMOD_DSP, active: *mut FMOD_BOOL) -> FMOD_RESULT;
+    pub fn FMOD_DSP_SetBypass(dsp: *mut FMOD_DSP, bypass: FMOD_BOOL) -> FMOD_RESULT;
+    pub fn FMOD_DSP_GetBypass(dsp: *mut FMOD_DSP, bypass: *mut FMOD_BOOL) -> FMOD_RESULT;
+    pub fn FMOD_DSP_SetWetDryMix(
+        dsp: *mut FMOD_DSP,
+        prewet: f32,
+        postwet: f32,
+        dry: f32,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_DSP_GetWetDryMix(
+        dsp: *mut FMOD_DSP,
+        prewet: *mut f32,
+        postwet: *mut f32,
+        dry: *mut f32,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_DSP_SetChannelFormat(
+        dsp: *mut FMOD_DSP,
+        channelmask: FMOD_CHANNELMASK,
+        numchannels: ::std::os::raw::c_int,
+        source_speakermode: FMOD_SPEAKERMODE,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_DSP_GetChannelFormat(
+        dsp: *mut FMOD_DSP,
+        channelmask: *mut FMOD_CHANNELMASK,
+        numchannels: *mut ::std::os::raw::c_int,
+        source_speakermode: *mut FMOD_SPEAKERMODE,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_DSP_GetOutputChannelFormat(
+        dsp: *mut FMOD_DSP,
+        inmask: FMOD_CHANNELMASK,
+        inchannels: ::std::os::raw::c_int,
+        inspeakermode: FMOD_SPEAKERMODE,
+        outmask: *mut FMOD_CHANNELMASK,
+        outchannels: *mut ::std::os::raw::c_int,
+        outspeakermode: *mut FMOD_SPEAKERMODE,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_DSP_Reset(dsp: *mut FMOD_DSP) -> FMOD_RESULT;
+    pub fn FMOD_DSP_SetParameterFloat(
+        dsp: *mut FMOD_DSP,
+        index: ::std::os::raw::c_int,
+        value: f32,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_DSP_SetParameterInt(
+        dsp: *mut FMOD_DSP,
+        index: ::std::os::raw::c_int,
+        value: ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_DSP_SetParameterBool(
+        dsp: *mut FMOD_DSP,
+        index: ::std::os::raw::c_int,
+        value: FMOD_BOOL,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_DSP_SetParameterData(
+        dsp: *mut FMOD_DSP,
+        index: ::std::os::raw::c_int,
+        data: *mut ::std::os::raw::c_void,
+        length: ::std::os::raw::c_uint,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_DSP_GetParameterFloat(
+        dsp: *mut FMOD_DSP,
+        index: ::std::os::raw::c_int,
+        value: *mut f32,
+        valuestr: *mut ::std::os::raw::c_char,
+        valuestrlen: ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_DSP_GetParameterInt(
+        dsp: *mut FMOD_DSP,
+        index: ::std::os::raw::c_int,
+        value: *mut ::std::os::raw::c_int,
+        valuestr: *mut ::std::os::raw::c_char,
+        valuestrlen: ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_DSP_GetParameterBool(
+        dsp: *mut FMOD_DSP,
+        index: ::std::os::raw::c_int,
+        value: *mut FMOD_BOOL,
+        valuestr: *mut ::std::os::raw::c_char,
+        valuestrlen: ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_DSP_GetParameterData(
+        dsp: *mut FMOD_DSP,
+        index: ::std::os::raw::c_int,
+        data: *mut *mut ::std::os::raw::c_void,
+        length: *mut ::std::os::raw::c_uint,
+        valuestr: *mut ::std::os::raw::c_char,
+        valuestrlen: ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_DSP_GetNumParameters(
+        dsp: *mut FMOD_DSP,
+        numparams: *mut ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_DSP_GetParameterInfo(
+        dsp: *mut FMOD_DSP,
+        index: ::std::os::raw::c_int,
+        desc: *mut *mut FMOD_DSP_PARAMETER_DESC,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_DSP_GetDataParameterIndex(
+        dsp: *mut FMOD_DSP,
+        datatype: ::std::os::raw::c_int,
+        index: *mut ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_DSP_GetType(dsp: *mut FMOD_DSP, type_: *mut FMOD_DSP_TYPE) -> FMOD_RESULT;
+    pub fn FMOD_DSP_GetIdle(dsp: *mut FMOD_DSP, idle: *mut FMOD_BOOL) -> FMOD_RESULT;
+    pub fn FMOD_DSP_SetUserData(
+        dsp: *mut FMOD_DSP,
+        userdata: *mut ::std::os::raw::c_void,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_DSP_GetUserData(
+        dsp: *mut FMOD_DSP,
+        userdata: *mut *mut ::std::os::raw::c_void,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_DSP_SetMeteringEnabled(
+        dsp: *mut FMOD_DSP,
+        inputEnabled: FMOD_BOOL,
+        outputEnabled: FMOD_BOOL,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_DSP_GetMeteringEnabled(
+        dsp: *mut FMOD_DSP,
+        inputEnabled: *mut FMOD_BOOL,
+        outputEnabled: *mut FMOD_BOOL,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_DSP_GetMeteringInfo(
+        dsp: *mut FMOD_DSP,
+        inputInfo: *mut FMOD_DSP_METERING_INFO,
+        outputInfo: *mut FMOD_DSP_METERING_INFO,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_DSP_GetCPUUsage(
+        dsp: *mut FMOD_DSP,
+        exclusive: *mut ::std::os::raw::c_uint,
+        inclusive: *mut ::std::os::raw::c_uint,
+    ) -> FMOD_RESULT;
+
+    pub fn FMOD_DSPConnection_GetInput(
+        dspconnection: *mut FMOD_DSPCONNECTION,
+        input: *mut *mut FMOD_DSP,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_DSPConnection_GetOutput(
+        dspconnection: *mut FMOD_DSPCONNECTION,
+        output: *mut *mut FMOD_DSP,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_DSPConnection_SetMix(
+        dspconnection: *mut FMOD_DSPCONNECTION,
+        volume: f32,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_DSPConnection_GetMix(
+        dspconnection: *mut FMOD_DSPCONNECTION,
+        volume: *mut f32,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_DSPConnection_GetType(
+        dspconnection: *mut FMOD_DSPCONNECTION,
+        type_: *mut FMOD_DSPCONNECTION_TYPE,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_DSPConnection_SetUserData(
+        dspconnection: *mut FMOD_DSPCONNECTION,
+        userdata: *mut ::std::os::raw::c_void,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_DSPConnection_GetUserData(
+        dspconnection: *mut FMOD_DSPCONNECTION,
+        userdata: *mut *mut ::std::os::raw::c_void,
+    ) -> FMOD_RESULT;
+
+    pub fn FMOD_Geometry_Release(geometry: *mut FMOD_GEOMETRY) -> FMOD_RESULT;
+    pub fn FMOD_Geometry_AddPolygon(
+        geometry: *mut FMOD_GEOMETRY,
+        directocclusion: f32,
+        reverbocclusion: f32,
+        doublesided: FMOD_BOOL,
+        numvertices: ::std::os::raw::c_int,
+        vertices: *const FMOD_VECTOR,
+        polygonindex: *mut ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Geometry_GetNumPolygons(
+        geometry: *mut FMOD_GEOMETRY,
+        numpolygons: *mut ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Geometry_GetMaxPolygons(
+        geometry: *mut FMOD_GEOMETRY,
+        maxpolygons: *mut ::std::os::raw::c_int,
+        maxvertices: *mut ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Geometry_GetPolygonNumVertices(
+        geometry: *mut FMOD_GEOMETRY,
+        index: ::std::os::raw::c_int,
+        numvertices: *mut ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Geometry_SetPolygonVertex(
+        geometry: *mut FMOD_GEOMETRY,
+        index: ::std::os::raw::c_int,
+        vertexindex: ::std::os::raw::c_int,
+        vertex: *const FMOD_VECTOR,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Geometry_GetPolygonVertex(
+        geometry: *mut FMOD_GEOMETRY,
+        index: ::std::os::raw::c_int,
+        vertexindex: ::std::os::raw::c_int,
+        vertex: *mut FMOD_VECTOR,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Geometry_SetPolygonAttributes(
+        geometry: *mut FMOD_GEOMETRY,
+        index: ::std::os::raw::c_int,
+        directocclusion: f32,
+        reverbocclusion: f32,
+        doublesided: FMOD_BOOL,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Geometry_GetPolygonAttributes(
+        geometry: *mut FMOD_GEOMETRY,
+        index: ::std::os::raw::c_int,
+        directocclusion: *mut f32,
+        reverbocclusion: *mut f32,
+        doublesided: *mut FMOD_BOOL,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Geometry_SetActive(geometry: *mut FMOD_GEOMETRY, active: FMOD_BOOL) -> FMOD_RESULT;
+    pub fn FMOD_Geometry_GetActive(
+        geometry: *mut FMOD_GEOMETRY,
+        active: *mut FMOD_BOOL,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Geometry_SetRotation(
+        geometry: *mut FMOD_GEOMETRY,
+        forward: *const FMOD_VECTOR,
+        up: *const FMOD_VECTOR,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Geometry_GetRotation(
+        geometry: *mut FMOD_GEOMETRY,
+        forward: *mut FMOD_VECTOR,
+        up: *mut FMOD_VECTOR,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Geometry_SetPosition(
+        geometry: *mut FMOD_GEOMETRY,
+        position: *const FMOD_VECTOR,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Geometry_GetPosition(
+        geometry: *mut FMOD_GEOMETRY,
+        position: *mut FMOD_VECTOR,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Geometry_SetScale(
+        geometry: *mut FMOD_GEOMETRY,
+        scale: *const FMOD_VECTOR,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Geometry_GetScale(
+        geometry: *mut FMOD_GEOMETRY,
+        scale: *mut FMOD_VECTOR,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Geometry_Save(
+        geometry: *mut FMOD_GEOMETRY,
+        data: *mut ::std::os::raw::c_void,
+        datasize: *mut ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Geometry_SetUserData(
+        geometry: *mut FMOD_GEOMETRY,
+        userdata: *mut ::std::os::raw::c_void,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Geometry_GetUserData(
+        geometry: *mut FMOD_GEOMETRY,
+        userdata: *mut *mut ::std::os::raw::c_void,
+    ) -> FMOD_RESULT;
+
+    pub fn FMOD_Reverb3D_Release(reverb3d: *mut FMOD_REVERB3D) -> FMOD_RESULT;
+    pub fn FMOD_Reverb3D_Set3DAttributes(
+        reverb3d: *mut FMOD_REVERB3D,
+        position: *const FMOD_VECTOR,
+        mindistance: f32,
+        maxdistance: f32,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Reverb3D_Get3DAttributes(
+        reverb3d: *mut FMOD_REVERB3D,
+        position: *mut FMOD_VECTOR,
+        mindistance: *mut f32,
+        maxdistance: *mut f32,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Reverb3D_SetProperties(
+        reverb3d: *mut FMOD_REVERB3D,
+        properties: *const FMOD_REVERB_PROPERTIES,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Reverb3D_GetProperties(
+        reverb3d: *mut FMOD_REVERB3D,
+        properties: *mut FMOD_REVERB_PROPERTIES,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Reverb3D_SetActive(reverb3d: *mut FMOD_REVERB3D, active: FMOD_BOOL) -> FMOD_RESULT;
+    pub fn FMOD_Reverb3D_GetActive(
+        reverb3d: *mut FMOD_REVERB3D,
+        active: *mut FMOD_BOOL,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Reverb3D_SetUserData(
+        reverb3d: *mut FMOD_REVERB3D,
+        userdata: *mut ::std::os::raw::c_void,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Reverb3D_GetUserData(
+        reverb3d: *mut FMOD_REVERB3D,
+        userdata: *mut *mut ::std::os::raw::c_void,
+    ) -> FMOD_RESULT;
+}
+extern "C" {
+
+    pub fn FMOD_Channel_CastToControl(channel: *mut FMOD_CHANNEL) -> *mut FMOD_CHANNELCONTROL;
+    pub fn FMOD_ChannelGroup_CastToControl(
+        group: *mut FMOD_CHANNELGROUP,
+    ) -> *mut FMOD_CHANNELCONTROL;
+
+    pub fn FMOD_ChannelControl_GetSystemObject(
+        channelcontrol: *mut FMOD_CHANNELCONTROL,
+        system: *mut *mut FMOD_SYSTEM,
+    ) -> FMOD_RESULT;
+
+    pub fn FMOD_ChannelControl_Stop(channelcontrol: *mut FMOD_CHANNELCONTROL) -> FMOD_RESULT;
+    pub fn FMOD_ChannelControl_SetPaused(
+        channelcontrol: *mut FMOD_CHANNELCONTROL,
+        paused: bool,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_ChannelControl_GetPaused(
+        channelcontrol: *mut FMOD_CHANNELCONTROL,
+        paused: *mut bool,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_ChannelControl_SetVolume(
+        channelcontrol: *mut FMOD_CHANNELCONTROL,
+        volume: f32,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_ChannelControl_GetVolume(
+        channelcontrol: *mut FMOD_CHANNELCONTROL,
+        volume: *mut f32,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_ChannelControl_SetVolumeRamp(
+        channelcontrol: *mut FMOD_CHANNELCONTROL,
+        ramp: bool,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_ChannelControl_GetVolumeRamp(
+        channelcontrol: *mut FMOD_CHANNELCONTROL,
+        ramp: *mut bool,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_ChannelControl_GetAudibility(
+        channelcontrol: *mut FMOD_CHANNELCONTROL,
+        audibility: *mut f32,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_ChannelControl_SetPitch(
+        channelcontrol: *mut FMOD_CHANNELCONTROL,
+        pitch: f32,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_ChannelControl_GetPitch(
+        channelcontrol: *mut FMOD_CHANNELCONTROL,
+        pitch: *mut f32,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_ChannelControl_SetMute(
+        channelcontrol: *mut FMOD_CHANNELCONTROL,
+        mute: bool,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_ChannelControl_GetMute(
+        channelcontrol: *mut FMOD_CHANNELCONTROL,
+        mute: *mut bool,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_ChannelControl_SetReverbProperties(
+        channelcontrol: *mut FMOD_CHANNELCONTROL,
+        instance: ::std::os::raw::c_int,
+        wet: f32,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_ChannelControl_GetReverbProperties(
+        channelcontrol: *mut FMOD_CHANNELCONTROL,
+        instance: ::std::os::raw::c_int,
+        wet: *mut f32,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_ChannelControl_SetLowPassGain(
+        channelcontrol: *mut FMOD_CHANNELCONTROL,
+        gain: f32,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_ChannelControl_GetLowPassGain(
+        channelcontrol: *mut FMOD_CHANNELCONTROL,
+        gain: *mut f32,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_ChannelControl_SetMode(
+        channelcontrol: *mut FMOD_CHANNELCONTROL,
+        mode: FMOD_MODE,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_ChannelControl_GetMode(
+        channelcontrol: *mut FMOD_CHANNELCONTROL,
+        mode: *mut FMOD_MODE,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_ChannelControl_SetCallback(
+        channelcontrol: *mut FMOD_CHANNELCONTROL,
+        callback: FMOD_CHANNELCONTROL_CALLBACK,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_ChannelControl_IsPlaying(
+        channelcontrol: *mut FMOD_CHANNELCONTROL,
+        isplaying: *mut bool,
+    ) -> FMOD_RESULT;
+
+    pub fn FMOD_ChannelControl_SetPan(
+        channelcontrol: *mut FMOD_CHANNELCONTROL,
+        pan: f32,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_ChannelControl_SetMixLevelsOutput(
+        channelcontrol: *mut FMOD_CHANNELCONTROL,
+        frontleft: f32,
+        frontright: f32,
+        center: f32,
+        lfe: f32,
+        surroundleft: f32,
+        surroundright: f32,
+        backleft: f32,
+        backright: f32,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_ChannelControl_SetMixLevelsInput(
+        channelcontrol: *mut FMOD_CHANNELCONTROL,
+        levels: *mut f32,
+        numlevels: ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_ChannelControl_SetMixMatrix(
+        channelcontrol: *mut FMOD_CHANNELCONTROL,
+        matrix: *mut f32,
+        outchannels: ::std::os::raw::c_int,
+        inchannels: ::std::os::raw::c_int,
+        inchannel_hop: ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_ChannelControl_GetMixMatrix(
+        channelcontrol: *mut FMOD_CHANNELCONTROL,
+        matrix: *mut f32,
+        outchannels: *mut ::std::os::raw::c_int,
+        inchannels: *mut ::std::os::raw::c_int,
+        inchannel_hop: ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+
+    pub fn FMOD_ChannelControl_GetDSPClock(
+        channelcontrol: *mut FMOD_CHANNELCONTROL,
+        dspclock: *mut ::std::os::raw::c_ulonglong,
+        parentclock: *mut ::std::os::raw::c_ulonglong,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_ChannelControl_SetDelay(
+        channelcontrol: *mut FMOD_CHANNELCONTROL,
+        dspclock_start: ::std::os::raw::c_ulonglong,
+        dspclock_end: ::std::os::raw::c_ulonglong,
+        stopchannels: bool,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_ChannelControl_GetDelay(
+        channelcontrol: *mut FMOD_CHANNELCONTROL,
+        dspclock_start: *mut ::std::os::raw::c_ulonglong,
+        dspclock_end: *mut ::std::os::raw::c_ulonglong,
+        stopchannels: *mut bool,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_ChannelControl_AddFadePoint(
+        channelcontrol: *mut FMOD_CHANNELCONTROL,
+        dspclock: ::std::os::raw::c_ulonglong,
+        volume: f32,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_ChannelControl_SetFadePointRamp(
+        channelcontrol: *mut FMOD_CHANNELCONTROL,
+        dspclock: ::std::os::raw::c_ulonglong,
+        volume: f32,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_ChannelControl_RemoveFadePoints(
+        channelcontrol: *mut FMOD_CHANNELCONTROL,
+        dspclock_start: ::std::os::raw::c_ulonglong,
+        dspclock_end: ::std::os::raw::c_ulonglong,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_ChannelControl_GetFadePoints(
+        channelcontrol: *mut FMOD_CHANNELCONTROL,
+        numpoints: *mut ::std::os::raw::c_uint,
+        point_dspclock: *mut ::std::os::raw::c_ulonglong,
+        point_volume: *mut f32,
+    ) -> FMOD_RESULT;
+
+    pub fn FMOD_ChannelControl_GetDSP(
+        channelcontrol: *mut FMOD_CHANNELCONTROL,
+        index: ::std::os::raw::c_int,
+        dsp: *mut *mut FMOD_DSP,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_ChannelControl_AddDSP(
+        channelcontrol: *mut FMOD_CHANNELCONTROL,
+        index: ::std::os::raw::c_int,
+        dsp: *mut FMOD_DSP,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_ChannelControl_RemoveDSP(
+        channelcontrol: *mut FMOD_CHANNELCONTROL,
+        dsp: *mut FMOD_DSP,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_ChannelControl_GetNumDSPs(
+        channelcontrol: *mut FMOD_CHANNELCONTROL,
+        numdsps: *mut ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_ChannelControl_SetDSPIndex(
+        channelcontrol: *mut FMOD_CHANNELCONTROL,
+        dsp: *mut FMOD_DSP,
+        index: ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_ChannelControl_GetDSPIndex(
+        channelcontrol: *mut FMOD_CHANNELCONTROL,
+        dsp: *mut FMOD_DSP,
+        index: *mut ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+
+    pub fn FMOD_ChannelControl_Set3DAttributes(
+        channelcontrol: *mut FMOD_CHANNELCONTROL,
+        pos: *const FMOD_VECTOR,
+        vel: *const FMOD_VECTOR,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_ChannelControl_Get3DAttributes(
+        channelcontrol: *mut FMOD_CHANNELCONTROL,
+        pos: *mut FMOD_VECTOR,
+        vel: *mut FMOD_VECTOR,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_ChannelControl_Set3DMinMaxDistance(
+        channelcontrol: *mut FMOD_CHANNELCONTROL,
+        mindistance: f32,
+        maxdistance: f32,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_ChannelControl_Get3DMinMaxDistance(
+        channelcontrol: *mut FMOD_CHANNELCONTROL,
+        mindistance: *mut f32,
+        maxdistance: *mut f32,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_ChannelControl_Set3DConeSettings(
+        channelcontrol: *mut FMOD_CHANNELCONTROL,
+        insideconeangle: f32,
+        outsideconeangle: f32,
+        outsidevolume: f32,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_ChannelControl_Get3DConeSettings(
+        channelcontrol: *mut FMOD_CHANNELCONTROL,
+        insideconeangle: *mut f32,
+        outsideconeangle: *mut f32,
+        outsidevolume: *mut f32,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_ChannelControl_Set3DConeOrientation(
+        channelcontrol: *mut FMOD_CHANNELCONTROL,
+        orientation: *mut FMOD_VECTOR,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_ChannelControl_Get3DConeOrientation(
+        channelcontrol: *mut FMOD_CHANNELCONTROL,
+        orientation: *mut FMOD_VECTOR,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_ChannelControl_Set3DCustomRolloff(
+        channelcontrol: *mut FMOD_CHANNELCONTROL,
+        points: *mut FMOD_VECTOR,
+        numpoints: ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_ChannelControl_Get3DCustomRolloff(
+        channelcontrol: *mut FMOD_CHANNELCONTROL,
+        points: *mut *mut FMOD_VECTOR,
+        numpoints: *mut ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_ChannelControl_Set3DOcclusion(
+        channelcontrol: *mut FMOD_CHANNELCONTROL,
+        directocclusion: f32,
+        reverbocclusion: f32,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_ChannelControl_Get3DOcclusion(
+        channelcontrol: *mut FMOD_CHANNELCONTROL,
+        directocclusion: *mut f32,
+        reverbocclusion: *mut f32,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_ChannelControl_Set3DSpread(
+        channelcontrol: *mut FMOD_CHANNELCONTROL,
+        angle: f32,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_ChannelControl_Get3DSpread(
+        channelcontrol: *mut FMOD_CHANNELCONTROL,
+        angle: *mut f32,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_ChannelControl_Set3DLevel(
+        channelcontrol: *mut FMOD_CHANNELCONTROL,
+        level: f32,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_ChannelControl_Get3DLevel(
+        channelcontrol: *mut FMOD_CHANNELCONTROL,
+        level: *mut f32,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_ChannelControl_Set3DDopplerLevel(
+        channelcontrol: *mut FMOD_CHANNELCONTROL,
+        level: f32,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_ChannelControl_Get3DDopplerLevel(
+        channelcontrol: *mut FMOD_CHANNELCONTROL,
+        level: *mut f32,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_ChannelControl_Set3DDistanceFilter(
+        channelcontrol: *mut FMOD_CHANNELCONTROL,
+        custom: bool,
+        customLevel: f32,
+        centerFreq: f32,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_ChannelControl_Get3DDistanceFilter(
+        channelcontrol: *mut FMOD_CHANNELCONTROL,
+        custom: *mut bool,
+        customLevel: *mut f32,
+        centerFreq: *mut f32,
+    ) -> FMOD_RESULT;
+
+    pub fn FMOD_ChannelControl_SetUserData(
+        channelcontrol: *mut FMOD_CHANNELCONTROL,
+        userdata: *mut ::std::os::raw::c_void,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_ChannelControl_GetUserData(
+        channelcontrol: *mut FMOD_CHANNELCONTROL,
+        userdata: *mut *mut ::std::os::raw::c_void,
+    ) -> FMOD_RESULT;
+
+}
+extern "C" {
+    pub fn FMOD_Studio_ParseID(
+        idstring: *const ::std::os::raw::c_char,
+        id: *mut FMOD_GUID,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_System_Create(
+        system: *mut *mut FMOD_STUDIO_SYSTEM,
+        headerversion: ::std::os::raw::c_uint,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_System_IsValid(system: *mut FMOD_STUDIO_SYSTEM) -> FMOD_BOOL;
+    pub fn FMOD_Studio_System_SetAdvancedSettings(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        settings: *mut FMOD_STUDIO_ADVANCEDSETTINGS,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_System_GetAdvancedSettings(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        settings: *mut FMOD_STUDIO_ADVANCEDSETTINGS,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_System_Initialize(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        maxchannels: ::std::os::raw::c_int,
+        studioflags: FMOD_STUDIO_INITFLAGS,
+        flags: FMOD_INITFLAGS,
+        extradriverdata: *mut ::std::os::raw::c_void,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_System_Release(system: *mut FMOD_STUDIO_SYSTEM) -> FMOD_RESULT;
+    pub fn FMOD_Studio_System_Update(system: *mut FMOD_STUDIO_SYSTEM) -> FMOD_RESULT;
+    pub fn FMOD_Studio_System_GetCoreSystem(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        coresystem: *mut *mut FMOD_SYSTEM,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_System_GetEvent(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        pathOrID: *const ::std::os::raw::c_char,
+        event: *mut *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_System_GetBus(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        pathOrID: *const ::std::os::raw::c_char,
+        bus: *mut *mut FMOD_STUDIO_BUS,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_System_GetVCA(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        pathOrID: *const ::std::os::raw::c_char,
+        vca: *mut *mut FMOD_STUDIO_VCA,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_System_GetBank(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        pathOrID: *const ::std::os::raw::c_char,
+        bank: *mut *mut FMOD_STUDIO_BANK,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_System_GetEventByID(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        id: *const FMOD_GUID,
+        event: *mut *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_System_GetBusByID(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        id: *const FMOD_GUID,
+        bus: *mut *mut FMOD_STUDIO_BUS,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_System_GetVCAByID(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        id: *const FMOD_GUID,
+        vca: *mut *mut FMOD_STUDIO_VCA,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_System_GetBankByID(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        id: *const FMOD_GUID,
+        bank: *mut *mut FMOD_STUDIO_BANK,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_System_GetSoundInfo(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        key: *const ::std::os::raw::c_char,
+        info: *mut FMOD_STUDIO_SOUND_INFO,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_System_GetParameterDescriptionByName(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        name: *const ::std::os::raw::c_char,
+        parameter: *mut FMOD_STUDIO_PARAMETER_DESCRIPTION,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_System_GetParameterDescriptionByID(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        id: FMOD_STUDIO_PARAMETER_ID,
+        parameter: *mut FMOD_STUDIO_PARAMETER_DESCRIPTION,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_System_GetParameterLabelByName(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        name: *const ::std::os::raw::c_char,
+        labelindex: ::std::os::raw::c_int,
+        label: *mut ::std::os::raw::c_char,
+        size: ::std::os::raw::c_int,
+        retrieved: *mut ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_System_GetParameterLabelByID(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        id: FMOD_STUDIO_PARAMETER_ID,
+        labelindex: ::std::os::raw::c_int,
+        label: *mut ::std::os::raw::c_char,
+        size: ::std::os::raw::c_int,
+        retrieved: *mut ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_System_GetParameterByID(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        id: FMOD_STUDIO_PARAMETER_ID,
+        value: *mut f32,
+        finalvalue: *mut f32,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_System_SetParameterByID(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        id: FMOD_STUDIO_PARAMETER_ID,
+        value: f32,
+        ignoreseekspeed: FMOD_BOOL,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_System_SetParameterByIDWithLabel(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        id: FMOD_STUDIO_PARAMETER_ID,
+        label: *const ::std::os::raw::c_char,
+        ignoreseekspeed: FMOD_BOOL,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_System_SetParametersByIDs(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        ids: *const FMOD_STUDIO_PARAMETER_ID,
+        values: *mut f32,
+        count: ::std::os::raw::c_int,
+        ignoreseekspeed: FMOD_BOOL,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_System_GetParameterByName(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        name: *const ::std::os::raw::c_char,
+        value: *mut f32,
+        finalvalue: *mut f32,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_System_SetParameterByName(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        name: *const ::std::os::raw::c_char,
+        value: f32,
+        ignoreseekspeed: FMOD_BOOL,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_System_SetParameterByNameWithLabel(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        name: *const ::std::os::raw::c_char,
+        label: *const ::std::os::raw::c_char,
+        ignoreseekspeed: FMOD_BOOL,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_System_LookupID(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        path: *const ::std::os::raw::c_char,
+        id: *mut FMOD_GUID,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_System_LookupPath(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        id: *const FMOD_GUID,
+        path: *mut ::std::os::raw::c_char,
+        size: ::std::os::raw::c_int,
+        retrieved: *mut ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_System_GetNumListeners(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        numlisteners: *mut ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_System_SetNumListeners(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        numlisteners: ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_System_GetListenerAttributes(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        index: ::std::os::raw::c_int,
+        attributes: *mut FMOD_3D_ATTRIBUTES,
+        attenuationposition: *mut FMOD_VECTOR,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_System_SetListenerAttributes(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        index: ::std::os::raw::c_int,
+        attributes: *const FMOD_3D_ATTRIBUTES,
+        attenuationposition: *const FMOD_VECTOR,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_System_GetListenerWeight(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        index: ::std::os::raw::c_int,
+        weight: *mut f32,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_System_SetListenerWeight(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        index: ::std::os::raw::c_int,
+        weight: f32,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_System_LoadBankFile(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        filename: *const ::std::os::raw::c_char,
+        flags: FMOD_STUDIO_LOAD_BANK_FLAGS,
+        bank: *mut *mut FMOD_STUDIO_BANK,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_System_LoadBankMemory(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        buffer: *const ::std::os::raw::c_char,
+        length: ::std::os::raw::c_int,
+        mode: FMOD_STUDIO_LOAD_MEMORY_MODE,
+        flags: FMOD_STUDIO_LOAD_BANK_FLAGS,
+        bank: *mut *mut FMOD_STUDIO_BANK,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_System_UnloadAll(system: *mut FMOD_STUDIO_SYSTEM) -> FMOD_RESULT;
+    pub fn FMOD_Studio_System_FlushCommands(system: *mut FMOD_STUDIO_SYSTEM) -> FMOD_RESULT;
+    pub fn FMOD_Studio_System_FlushSampleLoading(system: *mut FMOD_STUDIO_SYSTEM) -> FMOD_RESULT;
+    pub fn FMOD_Studio_System_StartCommandCapture(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        filename: *const ::std::os::raw::c_char,
+        flags: FMOD_STUDIO_COMMANDCAPTURE_FLAGS,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_System_StopCommandCapture(system: *mut FMOD_STUDIO_SYSTEM) -> FMOD_RESULT;
+    pub fn FMOD_Studio_System_LoadCommandReplay(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        filename: *const ::std::os::raw::c_char,
+        flags: FMOD_STUDIO_COMMANDREPLAY_FLAGS,
+        replay: *mut *mut FMOD_STUDIO_COMMANDREPLAY,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_System_GetBankCount(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        count: *mut ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_System_GetBankList(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        array: *mut *mut FMOD_STUDIO_BANK,
+        capacity: ::std::os::raw::c_int,
+        count: *mut ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_System_GetParameterDescriptionCount(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        count: *mut ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_System_GetParameterDescriptionList(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        array: *mut FMOD_STUDIO_PARAMETER_DESCRIPTION,
+        capacity: ::std::os::raw::c_int,
+        count: *mut ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_System_GetCPUUsage(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        usage: *mut FMOD_STUDIO_CPU_USAGE,
+        usage_core: *mut FMOD_CPU_USAGE,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_System_GetBufferUsage(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        usage: *mut FMOD_STUDIO_BUFFER_USAGE,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_System_ResetBufferUsage(system: *mut FMOD_STUDIO_SYSTEM) -> FMOD_RESULT;
+    pub fn FMOD_Studio_System_SetCallback(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        callback: FMOD_STUDIO_SYSTEM_CALLBACK,
+        callbackmask: FMOD_STUDIO_SYSTEM_CALLBACK_TYPE,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_System_SetUserData(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        userdata: *mut ::std::os::raw::c_void,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_System_GetUserData(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        userdata: *mut *mut ::std::os::raw::c_void,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_System_GetMemoryUsage(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        memoryusage: *mut FMOD_STUDIO_MEMORY_USAGE,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_System_RegisterPlugin(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        description: *const FMOD_DSP_DESCRIPTION,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_System_UnregisterPlugin(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        name: *const ::std::os::raw::c_char,
+    ) -> FMOD_RESULT;
+
+    pub fn FMOD_Studio_EventDescription_IsValid(
+        eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    ) -> FMOD_BOOL;
+    pub fn FMOD_Studio_EventDescription_GetID(
+        eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+        id: *mut FMOD_GUID,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_EventDescription_GetPath(
+        eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+        path: *mut ::std::os::raw::c_char,
+        size: ::std::os::raw::c_int,
+        retrieved: *mut ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_EventDescription_GetParameterDescriptionCount(
+        eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+        count: *mut ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_EventDescription_GetParameterDescriptionByIndex(
+        eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+        index: ::std::os::raw::c_int,
+        parameter: *mut FMOD_STUDIO_PARAMETER_DESCRIPTION,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_EventDescription_GetParameterDescriptionByName(
+        eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+        name: *const ::std::os::raw::c_char,
+        parameter: *mut FMOD_STUDIO_PARAMETER_DESCRIPTION,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_EventDescription_GetParameterDescriptionByID(
+        eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+        id: FMOD_STUDIO_PARAMETER_ID,
+        parameter: *mut FMOD_STUDIO_PARAMETER_DESCRIPTION,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_EventDescription_GetParameterLabelByIndex(
+        eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+        index: ::std::os::raw::c_int,
+        labelindex: ::std::os::raw::c_int,
+        label: *mut ::std::os::raw::c_char,
+        size: ::std::os::raw::c_int,
+        retrieved: *mut ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_EventDescription_GetParameterLabelByName(
+        eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+        name: *const ::std::os::raw::c_char,
+        labelindex: ::std::os::raw::c_int,
+        label: *mut ::std::os::raw::c_char,
+        size: ::std::os::raw::c_int,
+        retrieved: *mut ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_EventDescription_GetParameterLabelByID(
+        eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+        id: FMOD_STUDIO_PARAMETER_ID,
+        labelindex: ::std::os::raw::c_int,
+        label: *mut ::std::os::raw::c_char,
+        size: ::std::os::raw::c_int,
+        retrieved: *mut ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_EventDescription_GetUserPropertyCount(
+        eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+        count: *mut ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_EventDescription_GetUserPropertyByIndex(
+        eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+        index: ::std::os::raw::c_int,
+        property: *mut FMOD_STUDIO_USER_PROPERTY,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_EventDescription_GetUserProperty(
+        eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+        name: *const ::std::os::raw::c_char,
+        property: *mut FMOD_STUDIO_USER_PROPERTY,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_EventDescription_GetLength(
+        eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+        length: *mut ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_EventDescription_GetMinMaxDistance(
+        eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+        min: *mut f32,
+        max: *mut f32,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_EventDescription_GetSoundSize(
+        eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+        size: *mut f32,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_EventDescription_IsSnapshot(
+        eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+        snapshot: *mut FMOD_BOOL,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_EventDescription_IsOneshot(
+        eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+        oneshot: *mut FMOD_BOOL,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_EventDescription_IsStream(
+        eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+        isStream: *mut FMOD_BOOL,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_EventDescription_Is3D(
+        eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+        is3D: *mut FMOD_BOOL,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_EventDescription_IsDopplerEnabled(
+        eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+        doppler: *mut FMOD_BOOL,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_EventDescription_HasSustainPoint(
+        eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+        sustainPoint: *mut FMOD_BOOL,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_EventDescription_CreateInstance(
+        eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+        instance: *mut *mut FMOD_STUDIO_EVENTINSTANCE,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_EventDescription_GetInstanceCount(
+        eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+        count: *mut ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_EventDescription_GetInstanceList(
+        eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+        array: *mut *mut FMOD_STUDIO_EVENTINSTANCE,
+        capacity: ::std::os::raw::c_int,
+        count: *mut ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_EventDescription_LoadSampleData(
+        eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_EventDescription_UnloadSampleData(
+        eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_EventDescription_GetSampleLoadingState(
+        eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+        state: *mut FMOD_STUDIO_LOADING_STATE,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_EventDescription_ReleaseAllInstances(
+        eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_EventDescription_SetCallback(
+        eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+        callback: FMOD_STUDIO_EVENT_CALLBACK,
+        callbackmask: FMOD_STUDIO_EVENT_CALLBACK_TYPE,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_EventDescription_GetUserData(
+        eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+        userdata: *mut *mut ::std::os::raw::c_void,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_EventDescription_SetUserData(
+        eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+        userdata: *mut ::std::os::raw::c_void,
+    ) -> FMOD_RESULT;
+
+    pub fn FMOD_Studio_EventInstance_IsValid(
+        eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    ) -> FMOD_BOOL;
+    pub fn FMOD_Studio_EventInstance_GetDescription(
+        eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+        description: *mut *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_EventInstance_GetVolume(
+        eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+        volume: *mut f32,
+        finalvolume: *mut f32,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_EventInstance_SetVolume(
+        eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+        volume: f32,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_EventInstance_GetPitch(
+        eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+        pitch: *mut f32,
+        finalpitch: *mut f32,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_EventInstance_SetPitch(
+        eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+        pitch: f32,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_EventInstance_Get3DAttributes(
+        eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+        attributes: *mut FMOD_3D_ATTRIBUTES,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_EventInstance_Set3DAttributes(
+        eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+        attributes: *mut FMOD_3D_ATTRIBUTES,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_EventInstance_GetListenerMask(
+        eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+        mask: *mut ::std::os::raw::c_uint,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_EventInstance_SetListenerMask(
+        eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+        mask: ::std::os::raw::c_uint,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_EventInstance_GetProperty(
+        eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+        index: FMOD_STUDIO_EVENT_PROPERTY,
+        value: *mut f32,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_EventInstance_SetProperty(
+        eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+        index: FMOD_STUDIO_EVENT_PROPERTY,
+        value: f32,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_EventInstance_GetReverbLevel(
+        eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+        index: ::std::os::raw::c_int,
+        level: *mut f32,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_EventInstance_SetReverbLevel(
+        eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+        index: ::std::os::raw::c_int,
+        level: f32,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_EventInstance_GetPaused(
+        eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+        paused: *mut FMOD_BOOL,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_EventInstance_SetPaused(
+        eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+        paused: FMOD_BOOL,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_EventInstance_Start(
+        eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_EventInstance_Stop(
+        eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+        mode: FMOD_STUDIO_STOP_MODE,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_EventInstance_GetTimelinePosition(
+        eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+        position: *mut ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_EventInstance_SetTimelinePosition(
+        eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+        position: ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_EventInstance_GetPlaybackState(
+        eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+        state: *mut FMOD_STUDIO_PLAYBACK_STATE,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_EventInstance_GetChannelGroup(
+        eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+        group: *mut *mut FMOD_CHANNELGROUP,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_EventInstance_GetMinMaxDistance(
+        eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+        min: *mut f32,
+        max: *mut f32,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_EventInstance_Release(
+        eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_EventInstance_IsVirtual(
+        eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+        virtualstate: *mut FMOD_BOOL,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_EventInstance_GetParameterByName(
+        eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+        name: *const ::std::os::raw::c_char,
+        value: *mut f32,
+        finalvalue: *mut f32,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_EventInstance_SetParameterByName(
+        eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+        name: *const ::std::os::raw::c_char,
+        value: f32,
+        ignoreseekspeed: FMOD_BOOL,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_EventInstance_SetParameterByNameWithLabel(
+        eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+        name: *const ::std::os::raw::c_char,
+        label: *const ::std::os::raw::c_char,
+        ignoreseekspeed: FMOD_BOOL,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_EventInstance_GetParameterByID(
+        eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+        id: FMOD_STUDIO_PARAMETER_ID,
+        value: *mut f32,
+        finalvalue: *mut f32,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_EventInstance_SetParameterByID(
+        eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+        id: FMOD_STUDIO_PARAMETER_ID,
+        value: f32,
+        ignoreseekspeed: FMOD_BOOL,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_EventInstance_SetParameterByIDWithLabel(
+        eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+        id: FMOD_STUDIO_PARAMETER_ID,
+        label: *const ::std::os::raw::c_char,
+        ignoreseekspeed: FMOD_BOOL,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_EventInstance_SetParametersByIDs(
+        eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+        ids: *const FMOD_STUDIO_PARAMETER_ID,
+        values: *mut f32,
+        count: ::std::os::raw::c_int,
+        ignoreseekspeed: FMOD_BOOL,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_EventInstance_KeyOff(
+        eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_EventInstance_SetCallback(
+        eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+        callback: FMOD_STUDIO_EVENT_CALLBACK,
+        callbackmask: FMOD_STUDIO_EVENT_CALLBACK_TYPE,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_EventInstance_GetUserData(
+        eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+        userdata: *mut *mut ::std::os::raw::c_void,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_EventInstance_SetUserData(
+        eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+        userdata: *mut ::std::os::raw::c_void,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_EventInstance_GetCPUUsage(
+        eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+        exclusive: *mut ::std::os::raw::c_uint,
+        inclusive: *mut ::std::os::raw::c_uint,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_EventInstance_GetMemoryUsage(
+        eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+        memoryusage: *mut FMOD_STUDIO_MEMORY_USAGE,
+    ) -> FMOD_RESULT;
+
+    pub fn FMOD_Studio_Bus_IsValid(bus: *mut FMOD_STUDIO_BUS) -> FMOD_BOOL;
+    pub fn FMOD_Studio_Bus_GetID(bus: *mut FMOD_STUDIO_BUS, id: *mut FMOD_GUID) -> FMOD_RESULT;
+    pub fn FMOD_Studio_Bus_GetPath(
+        bus: *mut FMOD_STUDIO_BUS,
+        path: *mut ::std::os::raw::c_char,
+        size: ::std::os::raw::c_int,
+        retrieved: *mut ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_Bus_GetVolume(
+        bus: *mut FMOD_STUDIO_BUS,
+        volume: *mut f32,
+        finalvolume: *mut f32,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_Bus_SetVolume(bus: *mut FMOD_STUDIO_BUS, volume: f32) -> FMOD_RESULT;
+    pub fn FMOD_Studio_Bus_GetPaused(
+        bus: *mut FMOD_STUDIO_BUS,
+        paused: *mut FMOD_BOOL,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_Bus_SetPaused(bus: *mut FMOD_STUDIO_BUS, paused: FMOD_BOOL) -> FMOD_RESULT;
+    pub fn FMOD_Studio_Bus_GetMute(bus: *mut FMOD_STUDIO_BUS, mute: *mut FMOD_BOOL) -> FMOD_RESULT;
+    pub fn FMOD_Studio_Bus_SetMute(bus: *mut FMOD_STUDIO_BUS, mute: FMOD_BOOL) -> FMOD_RESULT;
+    pub fn FMOD_Studio_Bus_StopAllEvents(
+        bus: *mut FMOD_STUDIO_BUS,
+        mode: FMOD_STUDIO_STOP_MODE,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_Bus_GetPortIndex(
+        bus: *mut FMOD_STUDIO_BUS,
+        index: *mut FMOD_PORT_INDEX,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_Bus_SetPortIndex(
+        bus: *mut FMOD_STUDIO_BUS,
+        index: FMOD_PORT_INDEX,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_Bus_LockChannelGroup(bus: *mut FMOD_STUDIO_BUS) -> FMOD_RESULT;
+    pub fn FMOD_Studio_Bus_UnlockChannelGroup(bus: *mut FMOD_STUDIO_BUS) -> FMOD_RESULT;
+    pub fn FMOD_Studio_Bus_GetChannelGroup(
+        bus: *mut FMOD_STUDIO_BUS,
+        group: *mut *mut FMOD_CHANNELGROUP,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_Bus_GetCPUUsage(
+        bus: *mut FMOD_STUDIO_BUS,
+        exclusive: *mut ::std::os::raw::c_uint,
+        inclusive: *mut ::std::os::raw::c_uint,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_Bus_GetMemoryUsage(
+        bus: *mut FMOD_STUDIO_BUS,
+        memoryusage: *mut FMOD_STUDIO_MEMORY_USAGE,
+    ) -> FMOD_RESULT;
+
+    pub fn FMOD_Studio_VCA_IsValid(vca: *mut FMOD_STUDIO_VCA) -> FMOD_BOOL;
+    pub fn FMOD_Studio_VCA_GetID(vca: *mut FMOD_STUDIO_VCA, id: *mut FMOD_GUID) -> FMOD_RESULT;
+    pub fn FMOD_Studio_VCA_GetPath(
+        vca: *mut FMOD_STUDIO_VCA,
+        path: *mut ::std::os::raw::c_char,
+        size: ::std::os::raw::c_int,
+        retrieved: *mut ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_VCA_GetVolume(
+        vca: *mut FMOD_STUDIO_VCA,
+        volume: *mut f32,
+        finalvolume: *mut f32,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_VCA_SetVolume(vca: *mut FMOD_STUDIO_VCA, volume: f32) -> FMOD_RESULT;
+
+    pub fn FMOD_Studio_Bank_IsValid(bank: *mut FMOD_STUDIO_BANK) -> FMOD_BOOL;
+    pub fn FMOD_Studio_Bank_GetID(bank: *mut FMOD_STUDIO_BANK, id: *mut FMOD_GUID) -> FMOD_RESULT;
+    pub fn FMOD_Studio_Bank_GetPath(
+        bank: *mut FMOD_STUDIO_BANK,
+        path: *mut ::std::os::raw::c_char,
+        size: ::std::os::raw::c_int,
+        retrieved: *mut ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_Bank_Unload(bank: *mut FMOD_STUDIO_BANK) -> FMOD_RESULT;
+    pub fn FMOD_Studio_Bank_LoadSampleData(bank: *mut FMOD_STUDIO_BANK) -> FMOD_RESULT;
+    pub fn FMOD_Studio_Bank_UnloadSampleData(bank: *mut FMOD_STUDIO_BANK) -> FMOD_RESULT;
+    pub fn FMOD_Studio_Bank_GetLoadingState(
+        bank: *mut FMOD_STUDIO_BANK,
+        state: *mut FMOD_STUDIO_LOADING_STATE,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_Bank_GetSampleLoadingState(
+        bank: *mut FMOD_STUDIO_BANK,
+        state: *mut FMOD_STUDIO_LOADING_STATE,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_Bank_GetStringCount(
+        bank: *mut FMOD_STUDIO_BANK,
+        count: *mut ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_Bank_GetStringInfo(
+        bank: *mut FMOD_STUDIO_BANK,
+        index: ::std::os::raw::c_int,
+        id: *mut FMOD_GUID,
+        path: *mut ::std::os::raw::c_char,
+        size: ::std::os::raw::c_int,
+        retrieved: *mut ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_Bank_GetEventCount(
+        bank: *mut FMOD_STUDIO_BANK,
+        count: *mut ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_Bank_GetEventList(
+        bank: *mut FMOD_STUDIO_BANK,
+        array: *mut *mut FMOD_STUDIO_EVENTDESCRIPTION,
+        capacity: ::std::os::raw::c_int,
+        count: *mut ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_Bank_GetBusCount(
+        bank: *mut FMOD_STUDIO_BANK,
+        count: *mut ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_Bank_GetBusList(
+        bank: *mut FMOD_STUDIO_BANK,
+        array: *mut *mut FMOD_STUDIO_BUS,
+        capacity: ::std::os::raw::c_int,
+        count: *mut ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_Bank_GetVCACount(
+        bank: *mut FMOD_STUDIO_BANK,
+        count: *mut ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_Bank_GetVCAList(
+        bank: *mut FMOD_STUDIO_BANK,
+        array: *mut *mut FMOD_STUDIO_VCA,
+        capacity: ::std::os::raw::c_int,
+        count: *mut ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_Bank_GetUserData(
+        bank: *mut FMOD_STUDIO_BANK,
+        userdata: *mut *mut ::std::os::raw::c_void,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_Bank_SetUserData(
+        bank: *mut FMOD_STUDIO_BANK,
+        userdata: *mut ::std::os::raw::c_void,
+    ) -> FMOD_RESULT;
+
+    pub fn FMOD_Studio_CommandReplay_IsValid(replay: *mut FMOD_STUDIO_COMMANDREPLAY) -> FMOD_BOOL;
+    pub fn FMOD_Studio_CommandReplay_GetSystem(
+        replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+        system: *mut *mut FMOD_STUDIO_SYSTEM,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_CommandReplay_GetLength(
+        replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+        length: *mut f32,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_CommandReplay_GetCommandCount(
+        replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+        count: *mut ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_CommandReplay_GetCommandInfo(
+        replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+        commandindex: ::std::os::raw::c_int,
+        info: *mut FMOD_STUDIO_COMMAND_INFO,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_CommandReplay_GetCommandString(
+        replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+        commandindex: ::std::os::raw::c_int,
+        buffer: *mut ::std::os::raw::c_char,
+        length: ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_CommandReplay_GetCommandAtTime(
+        replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+        time: f32,
+        commandindex: *mut ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_CommandReplay_SetBankPath(
+        replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+        bankPath: *const ::std::os::raw::c_char,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_CommandReplay_Start(replay: *mut FMOD_STUDIO_COMMANDREPLAY) -> FMOD_RESULT;
+    pub fn FMOD_Studio_CommandReplay_Stop(replay: *mut FMOD_STUDIO_COMMANDREPLAY) -> FMOD_RESULT;
+    pub fn FMOD_Studio_CommandReplay_SeekToTime(
+        replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+        time: f32,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_CommandReplay_SeekToCommand(
+        replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+        commandindex: ::std::os::raw::c_int,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_CommandReplay_GetPaused(
+        replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+        paused: *mut FMOD_BOOL,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_CommandReplay_SetPaused(
+        replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+        paused: FMOD_BOOL,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_CommandReplay_GetPlaybackState(
+        replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+        state: *mut FMOD_STUDIO_PLAYBACK_STATE,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_CommandReplay_GetCurrentCommand(
+        replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+        commandindex: *mut ::std::os::raw::c_int,
+        currenttime: *mut f32,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_CommandReplay_Release(replay: *mut FMOD_STUDIO_COMMANDREPLAY) -> FMOD_RESULT;
+    pub fn FMOD_Studio_CommandReplay_SetFrameCallback(
+        replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+        callback: FMOD_STUDIO_COMMANDREPLAY_FRAME_CALLBACK,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_CommandReplay_SetLoadBankCallback(
+        replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+        callback: FMOD_STUDIO_COMMANDREPLAY_LOAD_BANK_CALLBACK,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_CommandReplay_SetCreateInstanceCallback(
+        replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+        callback: FMOD_STUDIO_COMMANDREPLAY_CREATE_INSTANCE_CALLBACK,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_CommandReplay_GetUserData(
+        replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+        userdata: *mut *mut ::std::os::raw::c_void,
+    ) -> FMOD_RESULT;
+    pub fn FMOD_Studio_CommandReplay_SetUserData(
+        replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+        userdata: *mut ::std::os::raw::c_void,
+    ) -> FMOD_RESULT;
+}
@@ -0,0 +1,26 @@
+// Copyright (c) 2024 Lily Lyons
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+// stand-ins for src/channel_control.cpp, which isn't compiled when mocking
+
+use super::record;
+use crate::*;
+
+#[no_mangle]
+pub unsafe extern "C" fn FMOD_Channel_CastToControl(
+    channel: *mut FMOD_CHANNEL,
+) -> *mut FMOD_CHANNELCONTROL {
+    record("FMOD_Channel_CastToControl");
+    channel.cast()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn FMOD_ChannelGroup_CastToControl(
+    group: *mut FMOD_CHANNELGROUP,
+) -> *mut FMOD_CHANNELCONTROL {
+    record("FMOD_ChannelGroup_CastToControl");
+    group.cast()
+}
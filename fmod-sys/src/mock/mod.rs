@@ -0,0 +1,461 @@
+// Copyright (c) 2024 Lily Lyons
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! A Rust stand-in for the FMOD libraries, enabled with the `mock` feature.
+//!
+//! Instead of linking against FMOD, every FMOD function is defined by this crate.
+//! A subset of the Studio API is simulated against a [`Fixture`], which describes the banks and events that exist:
+//! - creating, initializing, updating and releasing a Studio system
+//! - loading and unloading banks, and looking up their events
+//! - bank IDs and the string table of the strings bank
+//! - event and global parameter descriptions, labels and values
+//! - creating, starting, stopping, pausing and releasing event instances
+//! - event instance volumes and parameters
+//! - listener and event instance 3D attributes
+//! - event description and event instance userdata
+//!
+//! Everything else returns `FMOD_ERR_UNSUPPORTED` (or a zeroed value, for functions that don't return an `FMOD_RESULT`).
+//! Every call is recorded, so tests can assert on what was called with [`calls`].
+//!
+//! Event instances follow FMOD's playback states: starting an instance moves it to `FMOD_STUDIO_PLAYBACK_STARTING`, and the next `FMOD_Studio_System_Update` moves it to `FMOD_STUDIO_PLAYBACK_PLAYING`.
+//! Stopping with `FMOD_STUDIO_STOP_ALLOWFADEOUT` works the same way, through `FMOD_STUDIO_PLAYBACK_STOPPING`.
+//! Events in a fixture never finish on their own, and are 2D unless [`EventFixture::is_3d`] says otherwise.
+//! Banks have no buses or VCAs. Global parameters are taken from the fixture when a Studio system is created.
+//!
+//! The mock declares its own bindings in `src/mock/bindings`, which cover what this workspace uses, so it builds without FMOD installed.
+//! They're kept in the same shape as the generated bindings.
+//!
+//! State is global, like FMOD's, so tests that use the mock should not run concurrently.
+
+use std::{
+    collections::HashMap,
+    ffi::{c_char, c_int, CStr, CString},
+    sync::{Mutex, MutexGuard, PoisonError},
+};
+
+use crate::*;
+
+mod channel_control;
+mod studio;
+
+#[allow(unused_variables, clippy::all, clippy::pedantic)]
+mod fallbacks {
+    use crate::*;
+
+    include!(concat!(env!("OUT_DIR"), "/mock_fallbacks.rs"));
+}
+
+/// The banks, events and global parameters the mock pretends exist.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Fixture {
+    pub banks: Vec<BankFixture>,
+    pub parameters: Vec<ParameterFixture>,
+}
+
+/// A bank that can be loaded with `FMOD_Studio_System_LoadBankFile`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BankFixture {
+    /// The path of the bank, like `bank:/Master`.
+    pub path: String,
+    /// The file name the bank is loaded from, like `Master.bank`.
+    ///
+    /// Only the file name of the path passed to `FMOD_Studio_System_LoadBankFile` is compared, so banks can be loaded from any directory.
+    pub file: String,
+    pub id: FMOD_GUID,
+    pub events: Vec<EventFixture>,
+    /// Whether the string table of this bank has the path of every bank and event in the fixture, like a strings bank.
+    pub strings: bool,
+}
+
+/// An event in a [`BankFixture`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct EventFixture {
+    /// The path of the event, like `event:/Music/Level 01`.
+    pub path: String,
+    pub id: FMOD_GUID,
+    pub parameters: Vec<ParameterFixture>,
+    /// 3D attributes of instances of 2D events can't be set or retrieved.
+    pub is_3d: bool,
+}
+
+/// A parameter of an [`EventFixture`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParameterFixture {
+    pub name: String,
+    pub id: FMOD_STUDIO_PARAMETER_ID,
+    pub minimum: f32,
+    pub maximum: f32,
+    pub default: f32,
+    /// The labels of a labeled parameter, one for each integer value from `minimum` to `maximum`.
+    pub labels: Vec<String>,
+}
+
+impl Fixture {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn bank(mut self, bank: BankFixture) -> Self {
+        self.banks.push(bank);
+        self
+    }
+
+    /// Adds a global parameter ranging from `minimum` to `maximum`.
+    ///
+    /// The ID of the parameter is derived from its name.
+    pub fn parameter(
+        mut self,
+        name: impl Into<String>,
+        minimum: f32,
+        maximum: f32,
+        default: f32,
+    ) -> Self {
+        let parameter = ParameterFixture::new("parameter:", name.into(), minimum, maximum, default);
+        self.parameters.push(parameter);
+        self
+    }
+
+    /// Adds a labeled global parameter, ranging from 0 to the number of labels minus one.
+    pub fn labeled_parameter(
+        mut self,
+        name: impl Into<String>,
+        labels: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        let parameter = ParameterFixture::labeled("parameter:", name.into(), labels);
+        self.parameters.push(parameter);
+        self
+    }
+}
+
+impl BankFixture {
+    /// Creates a bank with no events, loaded from `<name>.bank` where `path` is `bank:/<name>`.
+    ///
+    /// The ID of the bank is derived from its path, so it is the same every time.
+    pub fn new(path: impl Into<String>) -> Self {
+        let path = path.into();
+        let name = path.strip_prefix("bank:/").unwrap_or(&path);
+        BankFixture {
+            file: format!("{name}.bank"),
+            id: guid_from_path(&path),
+            path,
+            events: vec![],
+            strings: false,
+        }
+    }
+
+    pub fn file(mut self, file: impl Into<String>) -> Self {
+        self.file = file.into();
+        self
+    }
+
+    pub fn strings(mut self, strings: bool) -> Self {
+        self.strings = strings;
+        self
+    }
+
+    pub fn event(mut self, event: EventFixture) -> Self {
+        self.events.push(event);
+        self
+    }
+}
+
+impl EventFixture {
+    /// Creates an event with no parameters.
+    ///
+    /// The ID of the event is derived from its path, so it is the same every time.
+    pub fn new(path: impl Into<String>) -> Self {
+        let path = path.into();
+        EventFixture {
+            id: guid_from_path(&path),
+            path,
+            parameters: vec![],
+            is_3d: false,
+        }
+    }
+
+    pub fn id(mut self, id: FMOD_GUID) -> Self {
+        self.id = id;
+        self
+    }
+
+    pub fn is_3d(mut self, is_3d: bool) -> Self {
+        self.is_3d = is_3d;
+        self
+    }
+
+    /// Adds a parameter ranging from `minimum` to `maximum`.
+    ///
+    /// The ID of the parameter is derived from the event path and parameter name.
+    pub fn parameter(
+        mut self,
+        name: impl Into<String>,
+        minimum: f32,
+        maximum: f32,
+        default: f32,
+    ) -> Self {
+        let parameter = ParameterFixture::new(&self.path, name.into(), minimum, maximum, default);
+        self.parameters.push(parameter);
+        self
+    }
+
+    /// Adds a labeled parameter, ranging from 0 to the number of labels minus one.
+    pub fn labeled_parameter(
+        mut self,
+        name: impl Into<String>,
+        labels: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        let parameter = ParameterFixture::labeled(&self.path, name.into(), labels);
+        self.parameters.push(parameter);
+        self
+    }
+}
+
+impl ParameterFixture {
+    // `scope` is the path of the event, or `parameter:` for global parameters
+    fn new(scope: &str, name: String, minimum: f32, maximum: f32, default: f32) -> Self {
+        let hash = fnv1a(format!("{scope}/{name}").as_bytes());
+        ParameterFixture {
+            name,
+            id: FMOD_STUDIO_PARAMETER_ID {
+                data1: hash as _,
+                data2: (hash >> 32) as _,
+            },
+            minimum,
+            maximum,
+            default,
+            labels: vec![],
+        }
+    }
+
+    fn labeled(
+        scope: &str,
+        name: String,
+        labels: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        let labels: Vec<String> = labels.into_iter().map(Into::into).collect();
+        let maximum = labels.len().saturating_sub(1) as f32;
+        ParameterFixture {
+            labels,
+            ..ParameterFixture::new(scope, name, 0.0, maximum, 0.0)
+        }
+    }
+}
+
+/// A snapshot of an event instance, as returned by [`instances`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct InstanceSnapshot {
+    pub handle: *mut FMOD_STUDIO_EVENTINSTANCE,
+    /// The path of the event this is an instance of.
+    pub event: String,
+    pub playback_state: FMOD_STUDIO_PLAYBACK_STATE,
+    pub paused: bool,
+    pub volume: f32,
+    /// The value of every parameter, in the order they were declared in the [`EventFixture`].
+    pub parameters: Vec<(String, f32)>,
+    /// Whether `FMOD_Studio_EventInstance_Release` was called.
+    /// The instance is destroyed once it has stopped.
+    pub released: bool,
+}
+
+/// Sets the banks and events the mock pretends exist.
+///
+/// Banks that are already loaded are not affected.
+pub fn set_fixture(fixture: Fixture) {
+    state().fixture = fixture;
+}
+
+/// Every FMOD function called since the last call to [`reset`] or [`clear_calls`], in order.
+pub fn calls() -> Vec<&'static str> {
+    state().calls.clone()
+}
+
+pub fn clear_calls() {
+    state().calls.clear();
+}
+
+/// A snapshot of every event instance that exists, in the order they were created.
+pub fn instances() -> Vec<InstanceSnapshot> {
+    let state = state();
+    let mut instances: Vec<_> = state.instances.iter().collect();
+    instances.sort_by_key(|(handle, _)| **handle);
+    instances
+        .into_iter()
+        .map(|(&handle, instance)| InstanceSnapshot {
+            handle: handle as _,
+            event: state.events[&instance.event].fixture.path.clone(),
+            playback_state: instance.playback_state,
+            paused: instance.paused,
+            volume: instance.volume,
+            parameters: instance.parameters.clone(),
+            released: instance.released,
+        })
+        .collect()
+}
+
+/// Destroys every object and forgets the fixture and recorded calls.
+pub fn reset() {
+    *state() = State::default();
+}
+
+#[derive(Default)]
+struct State {
+    fixture: Fixture,
+    calls: Vec<&'static str>,
+    // handles are never reused, so stale handles are always invalid
+    next_handle: usize,
+    systems: HashMap<usize, SystemState>,
+    banks: HashMap<usize, BankState>,
+    events: HashMap<usize, EventState>,
+    instances: HashMap<usize, InstanceState>,
+}
+
+struct SystemState {
+    core: usize,
+    initialized: bool,
+    // the global parameters, with their current values
+    parameters: Vec<(ParameterFixture, f32)>,
+    // parameter descriptions point at these, so they live as long as the system
+    parameter_names: Vec<CString>,
+    // the attributes and attenuation position of each listener
+    listeners: Vec<(FMOD_3D_ATTRIBUTES, FMOD_VECTOR)>,
+}
+
+struct BankState {
+    system: usize,
+    path: String,
+    id: FMOD_GUID,
+    events: Vec<usize>,
+    // the id and path of every string table entry
+    strings: Vec<(FMOD_GUID, String)>,
+}
+
+struct EventState {
+    bank: usize,
+    fixture: EventFixture,
+    userdata: *mut std::ffi::c_void,
+    // parameter descriptions point at these, so they live as long as the event
+    parameter_names: Vec<CString>,
+}
+
+struct InstanceState {
+    event: usize,
+    playback_state: FMOD_STUDIO_PLAYBACK_STATE,
+    paused: bool,
+    volume: f32,
+    parameters: Vec<(String, f32)>,
+    released: bool,
+    userdata: *mut std::ffi::c_void,
+    attributes: FMOD_3D_ATTRIBUTES,
+}
+
+// the only pointer in the state is userdata, which is never dereferenced
+unsafe impl Send for State {}
+
+static STATE: Mutex<Option<State>> = Mutex::new(None);
+
+struct StateGuard(MutexGuard<'static, Option<State>>);
+
+impl std::ops::Deref for StateGuard {
+    type Target = State;
+
+    fn deref(&self) -> &State {
+        self.0.as_ref().unwrap()
+    }
+}
+
+impl std::ops::DerefMut for StateGuard {
+    fn deref_mut(&mut self) -> &mut State {
+        self.0.as_mut().unwrap()
+    }
+}
+
+fn state() -> StateGuard {
+    // a panicking test shouldn't poison every other test
+    let mut guard = STATE.lock().unwrap_or_else(PoisonError::into_inner);
+    guard.get_or_insert_with(State::default);
+    StateGuard(guard)
+}
+
+impl State {
+    fn allocate_handle(&mut self) -> usize {
+        // keep handles aligned and non-null
+        self.next_handle += 1;
+        self.next_handle * 16
+    }
+}
+
+// records a call and runs `f` with the state locked
+fn call(name: &'static str, f: impl FnOnce(&mut State) -> FMOD_RESULT) -> FMOD_RESULT {
+    let mut state = state();
+    state.calls.push(name);
+    f(&mut state)
+}
+
+pub(crate) fn record(name: &'static str) {
+    state().calls.push(name);
+}
+
+pub(crate) fn unsupported(name: &'static str) -> FMOD_RESULT {
+    record(name);
+    FMOD_RESULT::FMOD_ERR_UNSUPPORTED
+}
+
+// writes through an out pointer, ignoring null pointers like FMOD does
+unsafe fn write<T>(ptr: *mut T, value: T) {
+    if !ptr.is_null() {
+        unsafe { ptr.write(value) };
+    }
+}
+
+unsafe fn read_str<'a>(ptr: *const c_char) -> Option<&'a str> {
+    if ptr.is_null() {
+        return None;
+    }
+    unsafe { CStr::from_ptr(ptr) }.to_str().ok()
+}
+
+// copies a string into a caller provided buffer, the same way FMOD does
+unsafe fn write_str(
+    string: &str,
+    buffer: *mut c_char,
+    size: c_int,
+    retrieved: *mut c_int,
+) -> FMOD_RESULT {
+    let needed = string.len() + 1;
+    unsafe { write(retrieved, needed as c_int) };
+    if buffer.is_null() || size <= 0 {
+        return FMOD_RESULT::FMOD_OK;
+    }
+
+    let size = size as usize;
+    let copied = string.len().min(size - 1);
+    unsafe {
+        std::ptr::copy_nonoverlapping(string.as_ptr().cast(), buffer, copied);
+        buffer.add(copied).write(0);
+    }
+    if needed > size {
+        FMOD_RESULT::FMOD_ERR_TRUNCATED
+    } else {
+        FMOD_RESULT::FMOD_OK
+    }
+}
+
+fn guid_from_path(path: &str) -> FMOD_GUID {
+    let hash = fnv1a(path.as_bytes());
+    FMOD_GUID {
+        Data1: hash as _,
+        Data2: (hash >> 32) as _,
+        Data3: (hash >> 48) as _,
+        Data4: hash.to_le_bytes(),
+    }
+}
+
+// stable across runs and platforms, unlike the std hasher
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
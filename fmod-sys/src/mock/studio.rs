@@ -0,0 +1,1348 @@
+// Copyright (c) 2024 Lily Lyons
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::ffi::{c_char, c_float, c_int, c_uint, c_void, CString};
+use std::path::Path;
+
+use super::{
+    call, read_str, state, write, write_str, BankState, EventState, InstanceState,
+    ParameterFixture, State, SystemState,
+};
+use crate::*;
+
+// looks up a handle, returning FMOD_ERR_INVALID_HANDLE from the calling function if it doesn't exist
+macro_rules! get {
+    ($map:expr, $handle:expr) => {
+        match $map.get_mut(&($handle as usize)) {
+            Some(object) => object,
+            None => return FMOD_RESULT::FMOD_ERR_INVALID_HANDLE,
+        }
+    };
+}
+
+fn is_valid(name: &'static str, f: impl FnOnce(&State) -> bool) -> FMOD_BOOL {
+    let mut state = state();
+    state.calls.push(name);
+    f(&state).into()
+}
+
+impl State {
+    fn destroy_bank(&mut self, bank: usize) {
+        let Some(bank) = self.banks.remove(&bank) else {
+            return;
+        };
+        for event in bank.events {
+            self.events.remove(&event);
+            self.instances.retain(|_, instance| instance.event != event);
+        }
+    }
+
+    fn find_event(&self, system: usize, path_or_id: &str) -> Option<usize> {
+        self.events.iter().find_map(|(&handle, event)| {
+            let matches =
+                event.fixture.path == path_or_id || format_guid(&event.fixture.id) == path_or_id;
+            (matches && self.banks[&event.bank].system == system).then_some(handle)
+        })
+    }
+}
+
+fn format_guid(guid: &FMOD_GUID) -> String {
+    let d4 = guid.Data4;
+    format!(
+        "{{{:08x}-{:04x}-{:04x}-{:02x}{:02x}-{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}}}",
+        guid.Data1, guid.Data2, guid.Data3, d4[0], d4[1], d4[2], d4[3], d4[4], d4[5], d4[6], d4[7]
+    )
+}
+
+// System
+
+#[no_mangle]
+pub unsafe extern "C" fn FMOD_Studio_System_Create(
+    system: *mut *mut FMOD_STUDIO_SYSTEM,
+    headerversion: c_uint,
+) -> FMOD_RESULT {
+    call("FMOD_Studio_System_Create", |state| {
+        if headerversion != FMOD_VERSION {
+            return FMOD_RESULT::FMOD_ERR_HEADER_MISMATCH;
+        }
+        let handle = state.allocate_handle();
+        let core = state.allocate_handle();
+        let parameters: Vec<_> = state
+            .fixture
+            .parameters
+            .iter()
+            .map(|parameter| (parameter.clone(), parameter.default))
+            .collect();
+        let parameter_names = parameters
+            .iter()
+            .map(|(parameter, _)| CString::new(parameter.name.as_str()).unwrap())
+            .collect();
+        state.systems.insert(
+            handle,
+            SystemState {
+                core,
+                initialized: false,
+                parameters,
+                parameter_names,
+                // like FMOD, there's one listener at the origin
+                listeners: vec![Default::default()],
+            },
+        );
+        unsafe { write(system, handle as _) };
+        FMOD_RESULT::FMOD_OK
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn FMOD_Studio_System_IsValid(system: *mut FMOD_STUDIO_SYSTEM) -> FMOD_BOOL {
+    is_valid("FMOD_Studio_System_IsValid", |state| {
+        state.systems.contains_key(&(system as usize))
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn FMOD_Studio_System_GetCoreSystem(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    coresystem: *mut *mut FMOD_SYSTEM,
+) -> FMOD_RESULT {
+    call("FMOD_Studio_System_GetCoreSystem", |state| {
+        let system = get!(state.systems, system);
+        unsafe { write(coresystem, system.core as _) };
+        FMOD_RESULT::FMOD_OK
+    })
+}
+
+// the core system of a Studio system can be configured before the Studio system is initialized
+#[no_mangle]
+pub unsafe extern "C" fn FMOD_System_SetOutput(
+    system: *mut FMOD_SYSTEM,
+    _output: FMOD_OUTPUTTYPE,
+) -> FMOD_RESULT {
+    call("FMOD_System_SetOutput", |state| {
+        // there's no output to switch, so all we can do is validate the handle
+        match state
+            .systems
+            .values()
+            .find(|studio| studio.core == system as usize)
+        {
+            Some(studio) if studio.initialized => FMOD_RESULT::FMOD_ERR_INITIALIZED,
+            Some(_) => FMOD_RESULT::FMOD_OK,
+            None => FMOD_RESULT::FMOD_ERR_INVALID_HANDLE,
+        }
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn FMOD_Studio_System_Initialize(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    _maxchannels: c_int,
+    _studioflags: FMOD_STUDIO_INITFLAGS,
+    _flags: FMOD_INITFLAGS,
+    _extradriverdata: *mut c_void,
+) -> FMOD_RESULT {
+    call("FMOD_Studio_System_Initialize", |state| {
+        let system = get!(state.systems, system);
+        if system.initialized {
+            return FMOD_RESULT::FMOD_ERR_INITIALIZED;
+        }
+        system.initialized = true;
+        FMOD_RESULT::FMOD_OK
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn FMOD_Studio_System_Release(
+    system: *mut FMOD_STUDIO_SYSTEM,
+) -> FMOD_RESULT {
+    call("FMOD_Studio_System_Release", |state| {
+        if state.systems.remove(&(system as usize)).is_none() {
+            return FMOD_RESULT::FMOD_ERR_INVALID_HANDLE;
+        }
+        let banks: Vec<usize> = state
+            .banks
+            .iter()
+            .filter(|(_, bank)| bank.system == system as usize)
+            .map(|(&handle, _)| handle)
+            .collect();
+        for bank in banks {
+            state.destroy_bank(bank);
+        }
+        FMOD_RESULT::FMOD_OK
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn FMOD_Studio_System_Update(system: *mut FMOD_STUDIO_SYSTEM) -> FMOD_RESULT {
+    call("FMOD_Studio_System_Update", |state| {
+        if !get!(state.systems, system).initialized {
+            return FMOD_RESULT::FMOD_ERR_STUDIO_UNINITIALIZED;
+        }
+
+        let State {
+            banks,
+            events,
+            instances,
+            ..
+        } = state;
+        instances.retain(|_, instance| {
+            if banks[&events[&instance.event].bank].system != system as usize {
+                return true;
+            }
+            instance.playback_state = match instance.playback_state {
+                FMOD_STUDIO_PLAYBACK_STARTING => FMOD_STUDIO_PLAYBACK_PLAYING,
+                FMOD_STUDIO_PLAYBACK_STOPPING => FMOD_STUDIO_PLAYBACK_STOPPED,
+                state => state,
+            };
+            // released instances are destroyed once they stop
+            !(instance.released && instance.playback_state == FMOD_STUDIO_PLAYBACK_STOPPED)
+        });
+        FMOD_RESULT::FMOD_OK
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn FMOD_Studio_System_FlushCommands(
+    system: *mut FMOD_STUDIO_SYSTEM,
+) -> FMOD_RESULT {
+    call("FMOD_Studio_System_FlushCommands", |state| {
+        get!(state.systems, system);
+        FMOD_RESULT::FMOD_OK
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn FMOD_Studio_System_GetNumListeners(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    numlisteners: *mut c_int,
+) -> FMOD_RESULT {
+    call("FMOD_Studio_System_GetNumListeners", |state| {
+        let system = get!(state.systems, system);
+        unsafe { write(numlisteners, system.listeners.len() as c_int) };
+        FMOD_RESULT::FMOD_OK
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn FMOD_Studio_System_SetNumListeners(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    numlisteners: c_int,
+) -> FMOD_RESULT {
+    call("FMOD_Studio_System_SetNumListeners", |state| {
+        let system = get!(state.systems, system);
+        if !(1..=FMOD_MAX_LISTENERS as c_int).contains(&numlisteners) {
+            return FMOD_RESULT::FMOD_ERR_INVALID_PARAM;
+        }
+        system
+            .listeners
+            .resize(numlisteners as usize, Default::default());
+        FMOD_RESULT::FMOD_OK
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn FMOD_Studio_System_GetListenerAttributes(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    index: c_int,
+    attributes: *mut FMOD_3D_ATTRIBUTES,
+    attenuationposition: *mut FMOD_VECTOR,
+) -> FMOD_RESULT {
+    call("FMOD_Studio_System_GetListenerAttributes", |state| {
+        let system = get!(state.systems, system);
+        let Some(&(listener, attenuation)) = system.listeners.get(index as usize) else {
+            return FMOD_RESULT::FMOD_ERR_INVALID_PARAM;
+        };
+        unsafe {
+            write(attributes, listener);
+            write(attenuationposition, attenuation);
+        }
+        FMOD_RESULT::FMOD_OK
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn FMOD_Studio_System_SetListenerAttributes(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    index: c_int,
+    attributes: *const FMOD_3D_ATTRIBUTES,
+    attenuationposition: *const FMOD_VECTOR,
+) -> FMOD_RESULT {
+    call("FMOD_Studio_System_SetListenerAttributes", |state| {
+        let system = get!(state.systems, system);
+        if attributes.is_null() {
+            return FMOD_RESULT::FMOD_ERR_INVALID_PARAM;
+        }
+        let Some(listener) = system.listeners.get_mut(index as usize) else {
+            return FMOD_RESULT::FMOD_ERR_INVALID_PARAM;
+        };
+        let attributes = unsafe { attributes.read() };
+        // without an attenuation position, the listener's position is used
+        let attenuation = if attenuationposition.is_null() {
+            attributes.position
+        } else {
+            unsafe { attenuationposition.read() }
+        };
+        *listener = (attributes, attenuation);
+        FMOD_RESULT::FMOD_OK
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn FMOD_Studio_System_GetParameterDescriptionCount(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    count: *mut c_int,
+) -> FMOD_RESULT {
+    call("FMOD_Studio_System_GetParameterDescriptionCount", |state| {
+        let system = get!(state.systems, system);
+        unsafe { write(count, system.parameters.len() as c_int) };
+        FMOD_RESULT::FMOD_OK
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn FMOD_Studio_System_GetParameterDescriptionList(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    array: *mut FMOD_STUDIO_PARAMETER_DESCRIPTION,
+    capacity: c_int,
+    count: *mut c_int,
+) -> FMOD_RESULT {
+    call("FMOD_Studio_System_GetParameterDescriptionList", |state| {
+        let system = get!(state.systems, system);
+        if array.is_null() || capacity < 0 {
+            return FMOD_RESULT::FMOD_ERR_INVALID_PARAM;
+        }
+        let written = system.parameters.len().min(capacity as usize);
+        for (index, (parameter, _)) in system.parameters.iter().take(written).enumerate() {
+            let description =
+                parameter_description(parameter, &system.parameter_names[index], true);
+            unsafe { array.add(index).write(description) };
+        }
+        unsafe { write(count, written as c_int) };
+        FMOD_RESULT::FMOD_OK
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn FMOD_Studio_System_GetParameterDescriptionByName(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    name: *const c_char,
+    parameter: *mut FMOD_STUDIO_PARAMETER_DESCRIPTION,
+) -> FMOD_RESULT {
+    call(
+        "FMOD_Studio_System_GetParameterDescriptionByName",
+        |state| {
+            let system = get!(state.systems, system);
+            let Some(name) = (unsafe { read_str(name) }) else {
+                return FMOD_RESULT::FMOD_ERR_INVALID_PARAM;
+            };
+            let Some(index) = system
+                .parameters
+                .iter()
+                .position(|(parameter, _)| parameter.name.eq_ignore_ascii_case(name))
+            else {
+                return FMOD_RESULT::FMOD_ERR_EVENT_NOTFOUND;
+            };
+            let description = parameter_description(
+                &system.parameters[index].0,
+                &system.parameter_names[index],
+                true,
+            );
+            unsafe { write(parameter, description) };
+            FMOD_RESULT::FMOD_OK
+        },
+    )
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn FMOD_Studio_System_GetParameterLabelByID(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    id: FMOD_STUDIO_PARAMETER_ID,
+    labelindex: c_int,
+    label: *mut c_char,
+    size: c_int,
+    retrieved: *mut c_int,
+) -> FMOD_RESULT {
+    call("FMOD_Studio_System_GetParameterLabelByID", |state| {
+        let system = get!(state.systems, system);
+        let Some((parameter, _)) = system
+            .parameters
+            .iter()
+            .find(|(parameter, _)| parameter.id == id)
+        else {
+            return FMOD_RESULT::FMOD_ERR_EVENT_NOTFOUND;
+        };
+        let Some(string) = parameter.labels.get(labelindex as usize) else {
+            return FMOD_RESULT::FMOD_ERR_INVALID_PARAM;
+        };
+        unsafe { write_str(string, label, size, retrieved) }
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn FMOD_Studio_System_SetParameterByID(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    id: FMOD_STUDIO_PARAMETER_ID,
+    value: c_float,
+    _ignoreseekspeed: FMOD_BOOL,
+) -> FMOD_RESULT {
+    call("FMOD_Studio_System_SetParameterByID", |state| {
+        let system = get!(state.systems, system);
+        if !value.is_finite() {
+            return FMOD_RESULT::FMOD_ERR_INVALID_FLOAT;
+        }
+        let Some((parameter, current)) = system
+            .parameters
+            .iter_mut()
+            .find(|(parameter, _)| parameter.id == id)
+        else {
+            return FMOD_RESULT::FMOD_ERR_EVENT_NOTFOUND;
+        };
+        // clamped to the parameter's range like FMOD does
+        *current = value.clamp(parameter.minimum, parameter.maximum);
+        FMOD_RESULT::FMOD_OK
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn FMOD_Studio_System_GetParameterByID(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    id: FMOD_STUDIO_PARAMETER_ID,
+    value: *mut c_float,
+    finalvalue: *mut c_float,
+) -> FMOD_RESULT {
+    call("FMOD_Studio_System_GetParameterByID", |state| {
+        let system = get!(state.systems, system);
+        let Some(&(_, current)) = system
+            .parameters
+            .iter()
+            .find(|(parameter, _)| parameter.id == id)
+        else {
+            return FMOD_RESULT::FMOD_ERR_EVENT_NOTFOUND;
+        };
+        unsafe {
+            write(value, current);
+            write(finalvalue, current);
+        }
+        FMOD_RESULT::FMOD_OK
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn FMOD_Studio_System_LoadBankFile(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    filename: *const c_char,
+    _flags: FMOD_STUDIO_LOAD_BANK_FLAGS,
+    bank: *mut *mut FMOD_STUDIO_BANK,
+) -> FMOD_RESULT {
+    call("FMOD_Studio_System_LoadBankFile", |state| {
+        if !get!(state.systems, system).initialized {
+            return FMOD_RESULT::FMOD_ERR_STUDIO_UNINITIALIZED;
+        }
+        let Some(filename) = (unsafe { read_str(filename) }) else {
+            return FMOD_RESULT::FMOD_ERR_INVALID_PARAM;
+        };
+        let file_name = Path::new(filename)
+            .file_name()
+            .and_then(|name| name.to_str());
+        let Some(fixture) = state
+            .fixture
+            .banks
+            .iter()
+            .find(|bank| Some(bank.file.as_str()) == file_name)
+            .cloned()
+        else {
+            return FMOD_RESULT::FMOD_ERR_FILE_NOTFOUND;
+        };
+        if state
+            .banks
+            .values()
+            .any(|bank| bank.system == system as usize && bank.path == fixture.path)
+        {
+            return FMOD_RESULT::FMOD_ERR_EVENT_ALREADY_LOADED;
+        }
+
+        let mut strings = vec![];
+        if fixture.strings {
+            for bank in &state.fixture.banks {
+                strings.push((bank.id, bank.path.clone()));
+                for event in &bank.events {
+                    strings.push((event.id, event.path.clone()));
+                }
+            }
+        }
+
+        let handle = state.allocate_handle();
+        let mut events = vec![];
+        for event in fixture.events {
+            let event_handle = state.allocate_handle();
+            let parameter_names = event
+                .parameters
+                .iter()
+                .map(|parameter| CString::new(parameter.name.as_str()).unwrap())
+                .collect();
+            state.events.insert(
+                event_handle,
+                EventState {
+                    bank: handle,
+                    fixture: event,
+                    userdata: std::ptr::null_mut(),
+                    parameter_names,
+                },
+            );
+            events.push(event_handle);
+        }
+        state.banks.insert(
+            handle,
+            BankState {
+                system: system as usize,
+                path: fixture.path,
+                id: fixture.id,
+                events,
+                strings,
+            },
+        );
+        unsafe { write(bank, handle as _) };
+        FMOD_RESULT::FMOD_OK
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn FMOD_Studio_System_GetBank(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    path_or_id: *const c_char,
+    bank: *mut *mut FMOD_STUDIO_BANK,
+) -> FMOD_RESULT {
+    call("FMOD_Studio_System_GetBank", |state| {
+        get!(state.systems, system);
+        let Some(path) = (unsafe { read_str(path_or_id) }) else {
+            return FMOD_RESULT::FMOD_ERR_INVALID_PARAM;
+        };
+        match state
+            .banks
+            .iter()
+            .find(|(_, b)| b.system == system as usize && b.path == path)
+        {
+            Some((&handle, _)) => {
+                unsafe { write(bank, handle as _) };
+                FMOD_RESULT::FMOD_OK
+            }
+            None => FMOD_RESULT::FMOD_ERR_EVENT_NOTFOUND,
+        }
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn FMOD_Studio_System_GetBankCount(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    count: *mut c_int,
+) -> FMOD_RESULT {
+    call("FMOD_Studio_System_GetBankCount", |state| {
+        get!(state.systems, system);
+        let banks = state
+            .banks
+            .values()
+            .filter(|bank| bank.system == system as usize)
+            .count();
+        unsafe { write(count, banks as c_int) };
+        FMOD_RESULT::FMOD_OK
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn FMOD_Studio_System_GetEvent(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    path_or_id: *const c_char,
+    event: *mut *mut FMOD_STUDIO_EVENTDESCRIPTION,
+) -> FMOD_RESULT {
+    call("FMOD_Studio_System_GetEvent", |state| {
+        get!(state.systems, system);
+        let Some(path_or_id) = (unsafe { read_str(path_or_id) }) else {
+            return FMOD_RESULT::FMOD_ERR_INVALID_PARAM;
+        };
+        match state.find_event(system as usize, path_or_id) {
+            Some(handle) => {
+                unsafe { write(event, handle as _) };
+                FMOD_RESULT::FMOD_OK
+            }
+            None => FMOD_RESULT::FMOD_ERR_EVENT_NOTFOUND,
+        }
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn FMOD_Studio_System_GetEventByID(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    id: *const FMOD_GUID,
+    event: *mut *mut FMOD_STUDIO_EVENTDESCRIPTION,
+) -> FMOD_RESULT {
+    call("FMOD_Studio_System_GetEventByID", |state| {
+        get!(state.systems, system);
+        if id.is_null() {
+            return FMOD_RESULT::FMOD_ERR_INVALID_PARAM;
+        }
+        let id = format_guid(unsafe { &*id });
+        match state.find_event(system as usize, &id) {
+            Some(handle) => {
+                unsafe { write(event, handle as _) };
+                FMOD_RESULT::FMOD_OK
+            }
+            None => FMOD_RESULT::FMOD_ERR_EVENT_NOTFOUND,
+        }
+    })
+}
+
+// Bank
+
+#[no_mangle]
+pub unsafe extern "C" fn FMOD_Studio_Bank_IsValid(bank: *mut FMOD_STUDIO_BANK) -> FMOD_BOOL {
+    is_valid("FMOD_Studio_Bank_IsValid", |state| {
+        state.banks.contains_key(&(bank as usize))
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn FMOD_Studio_Bank_Unload(bank: *mut FMOD_STUDIO_BANK) -> FMOD_RESULT {
+    call("FMOD_Studio_Bank_Unload", |state| {
+        get!(state.banks, bank);
+        state.destroy_bank(bank as usize);
+        FMOD_RESULT::FMOD_OK
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn FMOD_Studio_Bank_GetID(
+    bank: *mut FMOD_STUDIO_BANK,
+    id: *mut FMOD_GUID,
+) -> FMOD_RESULT {
+    call("FMOD_Studio_Bank_GetID", |state| {
+        let bank = get!(state.banks, bank);
+        unsafe { write(id, bank.id) };
+        FMOD_RESULT::FMOD_OK
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn FMOD_Studio_Bank_GetPath(
+    bank: *mut FMOD_STUDIO_BANK,
+    path: *mut c_char,
+    size: c_int,
+    retrieved: *mut c_int,
+) -> FMOD_RESULT {
+    call("FMOD_Studio_Bank_GetPath", |state| {
+        let bank = get!(state.banks, bank);
+        unsafe { write_str(&bank.path, path, size, retrieved) }
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn FMOD_Studio_Bank_GetEventCount(
+    bank: *mut FMOD_STUDIO_BANK,
+    count: *mut c_int,
+) -> FMOD_RESULT {
+    call("FMOD_Studio_Bank_GetEventCount", |state| {
+        let bank = get!(state.banks, bank);
+        unsafe { write(count, bank.events.len() as c_int) };
+        FMOD_RESULT::FMOD_OK
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn FMOD_Studio_Bank_GetEventList(
+    bank: *mut FMOD_STUDIO_BANK,
+    array: *mut *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    capacity: c_int,
+    count: *mut c_int,
+) -> FMOD_RESULT {
+    call("FMOD_Studio_Bank_GetEventList", |state| {
+        let bank = get!(state.banks, bank);
+        let written = bank.events.len().min(capacity.max(0) as usize);
+        for (i, &event) in bank.events[..written].iter().enumerate() {
+            unsafe { array.add(i).write(event as _) };
+        }
+        unsafe { write(count, written as c_int) };
+        FMOD_RESULT::FMOD_OK
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn FMOD_Studio_Bank_GetBusCount(
+    bank: *mut FMOD_STUDIO_BANK,
+    count: *mut c_int,
+) -> FMOD_RESULT {
+    call("FMOD_Studio_Bank_GetBusCount", |state| {
+        get!(state.banks, bank);
+        unsafe { write(count, 0) };
+        FMOD_RESULT::FMOD_OK
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn FMOD_Studio_Bank_GetBusList(
+    bank: *mut FMOD_STUDIO_BANK,
+    _array: *mut *mut FMOD_STUDIO_BUS,
+    _capacity: c_int,
+    count: *mut c_int,
+) -> FMOD_RESULT {
+    call("FMOD_Studio_Bank_GetBusList", |state| {
+        get!(state.banks, bank);
+        unsafe { write(count, 0) };
+        FMOD_RESULT::FMOD_OK
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn FMOD_Studio_Bank_GetVCACount(
+    bank: *mut FMOD_STUDIO_BANK,
+    count: *mut c_int,
+) -> FMOD_RESULT {
+    call("FMOD_Studio_Bank_GetVCACount", |state| {
+        get!(state.banks, bank);
+        unsafe { write(count, 0) };
+        FMOD_RESULT::FMOD_OK
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn FMOD_Studio_Bank_GetVCAList(
+    bank: *mut FMOD_STUDIO_BANK,
+    _array: *mut *mut FMOD_STUDIO_VCA,
+    _capacity: c_int,
+    count: *mut c_int,
+) -> FMOD_RESULT {
+    call("FMOD_Studio_Bank_GetVCAList", |state| {
+        get!(state.banks, bank);
+        unsafe { write(count, 0) };
+        FMOD_RESULT::FMOD_OK
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn FMOD_Studio_Bank_GetStringCount(
+    bank: *mut FMOD_STUDIO_BANK,
+    count: *mut c_int,
+) -> FMOD_RESULT {
+    call("FMOD_Studio_Bank_GetStringCount", |state| {
+        let bank = get!(state.banks, bank);
+        unsafe { write(count, bank.strings.len() as c_int) };
+        FMOD_RESULT::FMOD_OK
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn FMOD_Studio_Bank_GetStringInfo(
+    bank: *mut FMOD_STUDIO_BANK,
+    index: c_int,
+    id: *mut FMOD_GUID,
+    path: *mut c_char,
+    size: c_int,
+    retrieved: *mut c_int,
+) -> FMOD_RESULT {
+    call("FMOD_Studio_Bank_GetStringInfo", |state| {
+        let bank = get!(state.banks, bank);
+        let Some((string_id, string)) = bank.strings.get(index as usize) else {
+            return FMOD_RESULT::FMOD_ERR_INVALID_PARAM;
+        };
+        unsafe {
+            write(id, *string_id);
+            write_str(string, path, size, retrieved)
+        }
+    })
+}
+
+// Event description
+
+#[no_mangle]
+pub unsafe extern "C" fn FMOD_Studio_EventDescription_IsValid(
+    eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+) -> FMOD_BOOL {
+    is_valid("FMOD_Studio_EventDescription_IsValid", |state| {
+        state.events.contains_key(&(eventdescription as usize))
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn FMOD_Studio_EventDescription_GetID(
+    eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    id: *mut FMOD_GUID,
+) -> FMOD_RESULT {
+    call("FMOD_Studio_EventDescription_GetID", |state| {
+        let event = get!(state.events, eventdescription);
+        unsafe { write(id, event.fixture.id) };
+        FMOD_RESULT::FMOD_OK
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn FMOD_Studio_EventDescription_GetPath(
+    eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    path: *mut c_char,
+    size: c_int,
+    retrieved: *mut c_int,
+) -> FMOD_RESULT {
+    call("FMOD_Studio_EventDescription_GetPath", |state| {
+        let event = get!(state.events, eventdescription);
+        unsafe { write_str(&event.fixture.path, path, size, retrieved) }
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn FMOD_Studio_EventDescription_GetParameterDescriptionCount(
+    eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    count: *mut c_int,
+) -> FMOD_RESULT {
+    call(
+        "FMOD_Studio_EventDescription_GetParameterDescriptionCount",
+        |state| {
+            let event = get!(state.events, eventdescription);
+            unsafe { write(count, event.fixture.parameters.len() as c_int) };
+            FMOD_RESULT::FMOD_OK
+        },
+    )
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn FMOD_Studio_EventDescription_GetParameterDescriptionByIndex(
+    eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    index: c_int,
+    parameter: *mut FMOD_STUDIO_PARAMETER_DESCRIPTION,
+) -> FMOD_RESULT {
+    call(
+        "FMOD_Studio_EventDescription_GetParameterDescriptionByIndex",
+        |state| {
+            let event = get!(state.events, eventdescription);
+            let Some(fixture) = event.fixture.parameters.get(index as usize) else {
+                return FMOD_RESULT::FMOD_ERR_INVALID_PARAM;
+            };
+            let description =
+                parameter_description(fixture, &event.parameter_names[index as usize], false);
+            unsafe { write(parameter, description) };
+            FMOD_RESULT::FMOD_OK
+        },
+    )
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn FMOD_Studio_EventDescription_GetParameterDescriptionByName(
+    eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    name: *const c_char,
+    parameter: *mut FMOD_STUDIO_PARAMETER_DESCRIPTION,
+) -> FMOD_RESULT {
+    call(
+        "FMOD_Studio_EventDescription_GetParameterDescriptionByName",
+        |state| {
+            let event = get!(state.events, eventdescription);
+            let Some(name) = (unsafe { read_str(name) }) else {
+                return FMOD_RESULT::FMOD_ERR_INVALID_PARAM;
+            };
+            let Some(index) = event
+                .fixture
+                .parameters
+                .iter()
+                .position(|parameter| parameter.name.eq_ignore_ascii_case(name))
+            else {
+                return FMOD_RESULT::FMOD_ERR_EVENT_NOTFOUND;
+            };
+            let description = parameter_description(
+                &event.fixture.parameters[index],
+                &event.parameter_names[index],
+                false,
+            );
+            unsafe { write(parameter, description) };
+            FMOD_RESULT::FMOD_OK
+        },
+    )
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn FMOD_Studio_EventDescription_GetParameterLabelByIndex(
+    eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    index: c_int,
+    labelindex: c_int,
+    label: *mut c_char,
+    size: c_int,
+    retrieved: *mut c_int,
+) -> FMOD_RESULT {
+    call(
+        "FMOD_Studio_EventDescription_GetParameterLabelByIndex",
+        |state| {
+            let event = get!(state.events, eventdescription);
+            let Some(string) = event
+                .fixture
+                .parameters
+                .get(index as usize)
+                .and_then(|parameter| parameter.labels.get(labelindex as usize))
+            else {
+                return FMOD_RESULT::FMOD_ERR_INVALID_PARAM;
+            };
+            unsafe { write_str(string, label, size, retrieved) }
+        },
+    )
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn FMOD_Studio_EventDescription_GetParameterLabelByID(
+    eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    id: FMOD_STUDIO_PARAMETER_ID,
+    labelindex: c_int,
+    label: *mut c_char,
+    size: c_int,
+    retrieved: *mut c_int,
+) -> FMOD_RESULT {
+    call(
+        "FMOD_Studio_EventDescription_GetParameterLabelByID",
+        |state| {
+            let event = get!(state.events, eventdescription);
+            let Some(parameter) = event
+                .fixture
+                .parameters
+                .iter()
+                .find(|parameter| parameter.id == id)
+            else {
+                return FMOD_RESULT::FMOD_ERR_EVENT_NOTFOUND;
+            };
+            let Some(string) = parameter.labels.get(labelindex as usize) else {
+                return FMOD_RESULT::FMOD_ERR_INVALID_PARAM;
+            };
+            unsafe { write_str(string, label, size, retrieved) }
+        },
+    )
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn FMOD_Studio_EventDescription_CreateInstance(
+    eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    instance: *mut *mut FMOD_STUDIO_EVENTINSTANCE,
+) -> FMOD_RESULT {
+    call("FMOD_Studio_EventDescription_CreateInstance", |state| {
+        let event = get!(state.events, eventdescription);
+        let parameters = event
+            .fixture
+            .parameters
+            .iter()
+            .map(|parameter| (parameter.name.clone(), parameter.default))
+            .collect();
+        // like FMOD, instances start out with the userdata of their description
+        let userdata = event.userdata;
+
+        let handle = state.allocate_handle();
+        state.instances.insert(
+            handle,
+            InstanceState {
+                event: eventdescription as usize,
+                playback_state: FMOD_STUDIO_PLAYBACK_STOPPED,
+                paused: false,
+                volume: 1.0,
+                parameters,
+                released: false,
+                userdata,
+                attributes: FMOD_3D_ATTRIBUTES::default(),
+            },
+        );
+        unsafe { write(instance, handle as _) };
+        FMOD_RESULT::FMOD_OK
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn FMOD_Studio_EventDescription_GetInstanceCount(
+    eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    count: *mut c_int,
+) -> FMOD_RESULT {
+    call("FMOD_Studio_EventDescription_GetInstanceCount", |state| {
+        get!(state.events, eventdescription);
+        let instances = state
+            .instances
+            .values()
+            .filter(|instance| instance.event == eventdescription as usize)
+            .count();
+        unsafe { write(count, instances as c_int) };
+        FMOD_RESULT::FMOD_OK
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn FMOD_Studio_EventDescription_SetUserData(
+    eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    userdata: *mut c_void,
+) -> FMOD_RESULT {
+    call("FMOD_Studio_EventDescription_SetUserData", |state| {
+        get!(state.events, eventdescription).userdata = userdata;
+        FMOD_RESULT::FMOD_OK
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn FMOD_Studio_EventDescription_GetUserData(
+    eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    userdata: *mut *mut c_void,
+) -> FMOD_RESULT {
+    call("FMOD_Studio_EventDescription_GetUserData", |state| {
+        let event = get!(state.events, eventdescription);
+        unsafe { write(userdata, event.userdata) };
+        FMOD_RESULT::FMOD_OK
+    })
+}
+
+// Event instance
+
+#[no_mangle]
+pub unsafe extern "C" fn FMOD_Studio_EventInstance_IsValid(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+) -> FMOD_BOOL {
+    is_valid("FMOD_Studio_EventInstance_IsValid", |state| {
+        state.instances.contains_key(&(eventinstance as usize))
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn FMOD_Studio_EventInstance_GetDescription(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    description: *mut *mut FMOD_STUDIO_EVENTDESCRIPTION,
+) -> FMOD_RESULT {
+    call("FMOD_Studio_EventInstance_GetDescription", |state| {
+        let instance = get!(state.instances, eventinstance);
+        unsafe { write(description, instance.event as _) };
+        FMOD_RESULT::FMOD_OK
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn FMOD_Studio_EventInstance_Start(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+) -> FMOD_RESULT {
+    call("FMOD_Studio_EventInstance_Start", |state| {
+        let instance = get!(state.instances, eventinstance);
+        // starting an instance that is already playing restarts it
+        instance.playback_state = FMOD_STUDIO_PLAYBACK_STARTING;
+        FMOD_RESULT::FMOD_OK
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn FMOD_Studio_EventInstance_Stop(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    mode: FMOD_STUDIO_STOP_MODE,
+) -> FMOD_RESULT {
+    call("FMOD_Studio_EventInstance_Stop", |state| {
+        let instance = get!(state.instances, eventinstance);
+        instance.playback_state = match (instance.playback_state, mode) {
+            (FMOD_STUDIO_PLAYBACK_STOPPED, _) => FMOD_STUDIO_PLAYBACK_STOPPED,
+            (_, FMOD_STUDIO_STOP_ALLOWFADEOUT) => FMOD_STUDIO_PLAYBACK_STOPPING,
+            _ => FMOD_STUDIO_PLAYBACK_STOPPED,
+        };
+        FMOD_RESULT::FMOD_OK
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn FMOD_Studio_EventInstance_GetPlaybackState(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    state: *mut FMOD_STUDIO_PLAYBACK_STATE,
+) -> FMOD_RESULT {
+    let out = state;
+    call("FMOD_Studio_EventInstance_GetPlaybackState", |state| {
+        let instance = get!(state.instances, eventinstance);
+        unsafe { write(out, instance.playback_state) };
+        FMOD_RESULT::FMOD_OK
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn FMOD_Studio_EventInstance_SetPaused(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    paused: FMOD_BOOL,
+) -> FMOD_RESULT {
+    call("FMOD_Studio_EventInstance_SetPaused", |state| {
+        get!(state.instances, eventinstance).paused = paused.into();
+        FMOD_RESULT::FMOD_OK
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn FMOD_Studio_EventInstance_GetPaused(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    paused: *mut FMOD_BOOL,
+) -> FMOD_RESULT {
+    call("FMOD_Studio_EventInstance_GetPaused", |state| {
+        let instance = get!(state.instances, eventinstance);
+        unsafe { write(paused, instance.paused.into()) };
+        FMOD_RESULT::FMOD_OK
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn FMOD_Studio_EventInstance_Get3DAttributes(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    attributes: *mut FMOD_3D_ATTRIBUTES,
+) -> FMOD_RESULT {
+    call("FMOD_Studio_EventInstance_Get3DAttributes", |state| {
+        let instance = get!(state.instances, eventinstance);
+        if !state.events[&instance.event].fixture.is_3d {
+            return FMOD_RESULT::FMOD_ERR_NEEDS3D;
+        }
+        unsafe { write(attributes, instance.attributes) };
+        FMOD_RESULT::FMOD_OK
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn FMOD_Studio_EventInstance_Set3DAttributes(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    attributes: *mut FMOD_3D_ATTRIBUTES,
+) -> FMOD_RESULT {
+    call("FMOD_Studio_EventInstance_Set3DAttributes", |state| {
+        let instance = get!(state.instances, eventinstance);
+        if attributes.is_null() {
+            return FMOD_RESULT::FMOD_ERR_INVALID_PARAM;
+        }
+        if !state.events[&instance.event].fixture.is_3d {
+            return FMOD_RESULT::FMOD_ERR_NEEDS3D;
+        }
+        instance.attributes = unsafe { attributes.read() };
+        FMOD_RESULT::FMOD_OK
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn FMOD_Studio_EventInstance_IsVirtual(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    virtualstate: *mut FMOD_BOOL,
+) -> FMOD_RESULT {
+    call("FMOD_Studio_EventInstance_IsVirtual", |state| {
+        get!(state.instances, eventinstance);
+        // there's no voice limit, so nothing ever goes virtual
+        unsafe { write(virtualstate, false.into()) };
+        FMOD_RESULT::FMOD_OK
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn FMOD_Studio_EventInstance_SetVolume(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    volume: c_float,
+) -> FMOD_RESULT {
+    call("FMOD_Studio_EventInstance_SetVolume", |state| {
+        if !volume.is_finite() {
+            return FMOD_RESULT::FMOD_ERR_INVALID_FLOAT;
+        }
+        get!(state.instances, eventinstance).volume = volume.max(0.0);
+        FMOD_RESULT::FMOD_OK
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn FMOD_Studio_EventInstance_GetVolume(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    volume: *mut c_float,
+    finalvolume: *mut c_float,
+) -> FMOD_RESULT {
+    call("FMOD_Studio_EventInstance_GetVolume", |state| {
+        let instance = get!(state.instances, eventinstance);
+        // there's no mixer, so nothing else affects the final volume
+        unsafe {
+            write(volume, instance.volume);
+            write(finalvolume, instance.volume);
+        }
+        FMOD_RESULT::FMOD_OK
+    })
+}
+
+// `name` has to outlive the description, so it's stored alongside the fixture
+fn parameter_description(
+    fixture: &ParameterFixture,
+    name: &CString,
+    global: bool,
+) -> FMOD_STUDIO_PARAMETER_DESCRIPTION {
+    let mut flags = 0;
+    if !fixture.labels.is_empty() {
+        flags |= FMOD_STUDIO_PARAMETER_DISCRETE | FMOD_STUDIO_PARAMETER_LABELED;
+    }
+    if global {
+        flags |= FMOD_STUDIO_PARAMETER_GLOBAL;
+    }
+    FMOD_STUDIO_PARAMETER_DESCRIPTION {
+        name: name.as_ptr(),
+        id: fixture.id,
+        minimum: fixture.minimum,
+        maximum: fixture.maximum,
+        defaultvalue: fixture.default,
+        type_: FMOD_STUDIO_PARAMETER_GAME_CONTROLLED,
+        flags,
+        guid: FMOD_GUID::default(),
+    }
+}
+
+// sets a parameter by its index in the event fixture, clamping it to the parameter's range like FMOD does
+fn set_parameter(
+    state: &mut State,
+    eventinstance: usize,
+    index: Option<usize>,
+    value: c_float,
+) -> FMOD_RESULT {
+    if !value.is_finite() {
+        return FMOD_RESULT::FMOD_ERR_INVALID_FLOAT;
+    }
+    let instance = get!(state.instances, eventinstance);
+    let Some(index) = index else {
+        return FMOD_RESULT::FMOD_ERR_EVENT_NOTFOUND;
+    };
+    let parameter = &state.events[&instance.event].fixture.parameters[index];
+    instance.parameters[index].1 = value.clamp(parameter.minimum, parameter.maximum);
+    FMOD_RESULT::FMOD_OK
+}
+
+fn get_parameter(
+    state: &mut State,
+    eventinstance: usize,
+    index: Option<usize>,
+    value: *mut c_float,
+    finalvalue: *mut c_float,
+) -> FMOD_RESULT {
+    let instance = get!(state.instances, eventinstance);
+    let Some(index) = index else {
+        return FMOD_RESULT::FMOD_ERR_EVENT_NOTFOUND;
+    };
+    let current = instance.parameters[index].1;
+    unsafe {
+        write(value, current);
+        write(finalvalue, current);
+    }
+    FMOD_RESULT::FMOD_OK
+}
+
+fn parameter_by_name(state: &State, eventinstance: usize, name: Option<&str>) -> Option<usize> {
+    let instance = state.instances.get(&eventinstance)?;
+    let name = name?;
+    state.events[&instance.event]
+        .fixture
+        .parameters
+        .iter()
+        .position(|parameter| parameter.name.eq_ignore_ascii_case(name))
+}
+
+fn parameter_by_id(
+    state: &State,
+    eventinstance: usize,
+    id: FMOD_STUDIO_PARAMETER_ID,
+) -> Option<usize> {
+    let instance = state.instances.get(&eventinstance)?;
+    state.events[&instance.event]
+        .fixture
+        .parameters
+        .iter()
+        .position(|parameter| parameter.id == id)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn FMOD_Studio_EventInstance_SetParameterByName(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    name: *const c_char,
+    value: c_float,
+    _ignoreseekspeed: FMOD_BOOL,
+) -> FMOD_RESULT {
+    call("FMOD_Studio_EventInstance_SetParameterByName", |state| {
+        let name = unsafe { read_str(name) };
+        let index = parameter_by_name(state, eventinstance as usize, name);
+        set_parameter(state, eventinstance as usize, index, value)
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn FMOD_Studio_EventInstance_GetParameterByName(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    name: *const c_char,
+    value: *mut c_float,
+    finalvalue: *mut c_float,
+) -> FMOD_RESULT {
+    call("FMOD_Studio_EventInstance_GetParameterByName", |state| {
+        let name = unsafe { read_str(name) };
+        let index = parameter_by_name(state, eventinstance as usize, name);
+        get_parameter(state, eventinstance as usize, index, value, finalvalue)
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn FMOD_Studio_EventInstance_SetParameterByID(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    id: FMOD_STUDIO_PARAMETER_ID,
+    value: c_float,
+    _ignoreseekspeed: FMOD_BOOL,
+) -> FMOD_RESULT {
+    call("FMOD_Studio_EventInstance_SetParameterByID", |state| {
+        let index = parameter_by_id(state, eventinstance as usize, id);
+        set_parameter(state, eventinstance as usize, index, value)
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn FMOD_Studio_EventInstance_GetParameterByID(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    id: FMOD_STUDIO_PARAMETER_ID,
+    value: *mut c_float,
+    finalvalue: *mut c_float,
+) -> FMOD_RESULT {
+    call("FMOD_Studio_EventInstance_GetParameterByID", |state| {
+        let index = parameter_by_id(state, eventinstance as usize, id);
+        get_parameter(state, eventinstance as usize, index, value, finalvalue)
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn FMOD_Studio_EventInstance_SetParametersByIDs(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    ids: *const FMOD_STUDIO_PARAMETER_ID,
+    values: *mut c_float,
+    count: c_int,
+    _ignoreseekspeed: FMOD_BOOL,
+) -> FMOD_RESULT {
+    call("FMOD_Studio_EventInstance_SetParametersByIDs", |state| {
+        get!(state.instances, eventinstance);
+        if !(1..=32).contains(&count) || ids.is_null() || values.is_null() {
+            return FMOD_RESULT::FMOD_ERR_INVALID_PARAM;
+        }
+        for i in 0..count as usize {
+            let (id, value) = unsafe { (ids.add(i).read(), values.add(i).read()) };
+            let index = parameter_by_id(state, eventinstance as usize, id);
+            let result = set_parameter(state, eventinstance as usize, index, value);
+            if result != FMOD_RESULT::FMOD_OK {
+                return result;
+            }
+        }
+        FMOD_RESULT::FMOD_OK
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn FMOD_Studio_EventInstance_Release(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+) -> FMOD_RESULT {
+    call("FMOD_Studio_EventInstance_Release", |state| {
+        get!(state.instances, eventinstance).released = true;
+        FMOD_RESULT::FMOD_OK
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn FMOD_Studio_EventInstance_SetUserData(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    userdata: *mut c_void,
+) -> FMOD_RESULT {
+    call("FMOD_Studio_EventInstance_SetUserData", |state| {
+        get!(state.instances, eventinstance).userdata = userdata;
+        FMOD_RESULT::FMOD_OK
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn FMOD_Studio_EventInstance_GetUserData(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    userdata: *mut *mut c_void,
+) -> FMOD_RESULT {
+    call("FMOD_Studio_EventInstance_GetUserData", |state| {
+        let instance = get!(state.instances, eventinstance);
+        unsafe { write(userdata, instance.userdata) };
+        FMOD_RESULT::FMOD_OK
+    })
+}
@@ -21,3 +21,8 @@
 // Studio
 #include <fmod_studio.h>
 #include <fmod_studio_common.h>
+
+// FSBank
+#ifdef FMOD_SYS_FSBANK
+#include <fsbank.h>
+#endif
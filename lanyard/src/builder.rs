@@ -0,0 +1,272 @@
+// Copyright (c) 2024 Lily Lyons
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+use core::{fmt::Write, ops::Deref};
+
+use crate::{AsUtf8CStr, InlineUtf8CStr, Utf8CStr, Utf8CString};
+use alloc::{
+    ffi::{CString, NulError},
+    string::String,
+    vec::Vec,
+};
+
+/// A growable UTF-8 C string, which is always nul-terminated.
+///
+/// This is to [`Utf8CString`] what [`String`] is to <code>[Box]<[str]></code>: it can be appended to in place,
+/// and cleared with [`Utf8CStringBuilder::clear`] to build another string in the same allocation.
+/// That makes it a good fit for building event paths or parameter names in a loop:
+///
+/// ```rust
+/// use lanyard::Utf8CStringBuilder;
+/// use std::fmt::Write;
+///
+/// let mut path = Utf8CStringBuilder::new();
+/// for surface in ["grass", "gravel", "wood"] {
+///     path.clear();
+///     write!(path, "event:/Footsteps/{surface}").unwrap();
+///     assert_eq!(path.as_str_with_nul(), format!("event:/Footsteps/{surface}\0"));
+/// }
+/// ```
+///
+/// Nul bytes can't be pushed, as they would end the C string early.
+/// [`Utf8CStringBuilder::push_str`] returns an error instead, and the [`core::fmt::Write`] implementation returns [`core::fmt::Error`].
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Utf8CStringBuilder {
+    // always ends with a nul byte, and has no others
+    buffer: String,
+}
+
+impl Utf8CStringBuilder {
+    /// Creates an empty builder.
+    ///
+    /// This allocates, as the builder always has room for the nul terminator.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    /// Creates an empty builder with room for at least `capacity` bytes, not counting the nul terminator.
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        let mut buffer = String::with_capacity(capacity + 1);
+        buffer.push('\0');
+        Self { buffer }
+    }
+
+    /// Appends a string.
+    ///
+    /// Returns an error if `string` contains a nul byte, in which case nothing is appended.
+    /// The position in the error is relative to `string`.
+    pub fn push_str(&mut self, string: &str) -> Result<(), NulError> {
+        if string.as_bytes().contains(&0) {
+            return Err(nul_error(string));
+        }
+        self.buffer.pop();
+        self.buffer.push_str(string);
+        self.buffer.push('\0');
+        Ok(())
+    }
+
+    /// Appends a character.
+    ///
+    /// Returns an error if `ch` is `'\0'`, in which case nothing is appended.
+    pub fn push(&mut self, ch: char) -> Result<(), NulError> {
+        self.push_str(ch.encode_utf8(&mut [0; 4]))
+    }
+
+    /// Appends formatted text, usually from [`format_args!`].
+    ///
+    /// Returns an error if the formatted text contains a nul byte, or if a formatting trait implementation returns an error.
+    /// Either way nothing is appended.
+    /// The position in a [`FormatError::Nul`] is relative to the formatted text.
+    pub fn push_fmt(&mut self, args: core::fmt::Arguments<'_>) -> Result<(), FormatError> {
+        let len = self.buffer.len();
+        let mut writer = FormatWriter {
+            builder: self,
+            start: len - 1,
+            nul: None,
+        };
+        let result = writer.write_fmt(args);
+        let nul = writer.nul;
+        if result.is_err() || nul.is_some() {
+            // undo whatever was written before the error
+            self.buffer.truncate(len - 1);
+            self.buffer.push('\0');
+            return Err(nul.map_or(FormatError::Fmt(core::fmt::Error), FormatError::Nul));
+        }
+        Ok(())
+    }
+
+    /// Truncates the string to be empty, keeping its allocation.
+    pub fn clear(&mut self) {
+        self.buffer.clear();
+        self.buffer.push('\0');
+    }
+
+    /// Reserves room for at least `additional` more bytes.
+    pub fn reserve(&mut self, additional: usize) {
+        self.buffer.reserve(additional);
+    }
+
+    /// Shrinks the allocation to fit the string built so far.
+    pub fn shrink_to_fit(&mut self) {
+        self.buffer.shrink_to_fit();
+    }
+
+    /// Returns how many bytes can be held without reallocating, not counting the nul terminator.
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.buffer.capacity() - 1
+    }
+
+    /// Borrows the string built so far.
+    #[must_use]
+    pub fn as_utf8_cstr(&self) -> &Utf8CStr {
+        // SAFETY: the buffer is a String, which is UTF-8, and always has exactly one nul byte at the end.
+        unsafe { Utf8CStr::from_utf8_with_nul_unchecked(self.buffer.as_bytes()) }
+    }
+
+    /// Returns the underlying bytes, including the nul terminator.
+    ///
+    /// This is meant for C functions that write a string into a caller-provided buffer,
+    /// so the builder's allocation can be reused for them.
+    ///
+    /// # Safety
+    ///
+    /// When the borrow ends, the bytes must be valid UTF-8 that ends with a nul byte and contains no other nul bytes.
+    pub unsafe fn as_mut_vec(&mut self) -> &mut Vec<u8> {
+        // SAFETY: the caller promises to leave valid UTF-8 behind.
+        unsafe { self.buffer.as_mut_vec() }
+    }
+
+    /// Converts the builder into a [`Utf8CString`], without reallocating.
+    #[must_use]
+    pub fn into_cstring(self) -> Utf8CString {
+        // SAFETY: the buffer is a String, which is UTF-8, and always has exactly one nul byte at the end.
+        unsafe { Utf8CString::from_utf8_with_nul_unchecked(self.buffer.into_bytes()) }
+    }
+}
+
+// only called once a nul byte has been found, so CString::new always fails
+fn nul_error(string: &str) -> NulError {
+    CString::new(string).expect_err("string should contain a nul byte")
+}
+
+// remembers whether writing failed because of a nul byte, so push_fmt can tell that apart from a formatting trait failing
+struct FormatWriter<'a> {
+    builder: &'a mut Utf8CStringBuilder,
+    // where the formatted text starts in the builder
+    start: usize,
+    nul: Option<NulError>,
+}
+
+impl Write for FormatWriter<'_> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        if self.nul.is_none() && !s.as_bytes().contains(&0) {
+            return self.builder.write_str(s);
+        }
+        if self.nul.is_none() {
+            let mut text = String::from(&self.builder.as_str()[self.start..]);
+            text.push_str(s);
+            self.nul = Some(nul_error(&text));
+        }
+        Err(core::fmt::Error)
+    }
+}
+
+/// An error returned by [`Utf8CStringBuilder::push_fmt`] and [`cformat!`](crate::cformat).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FormatError {
+    /// The formatted text contained a nul byte.
+    Nul(NulError),
+    /// A formatting trait implementation returned an error.
+    Fmt(core::fmt::Error),
+}
+
+impl core::fmt::Display for FormatError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            FormatError::Nul(e) => e.fmt(f),
+            FormatError::Fmt(e) => e.fmt(f),
+        }
+    }
+}
+#[cfg(feature = "std")]
+impl std::error::Error for FormatError {}
+
+impl From<NulError> for FormatError {
+    fn from(value: NulError) -> Self {
+        Self::Nul(value)
+    }
+}
+
+impl Default for Utf8CStringBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Deref for Utf8CStringBuilder {
+    type Target = Utf8CStr;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_utf8_cstr()
+    }
+}
+
+impl AsRef<Utf8CStr> for Utf8CStringBuilder {
+    fn as_ref(&self) -> &Utf8CStr {
+        self.as_utf8_cstr()
+    }
+}
+
+impl AsUtf8CStr for Utf8CStringBuilder {
+    fn to_utf8_cstr(&self) -> Result<InlineUtf8CStr<'_>, NulError> {
+        self.as_utf8_cstr().to_utf8_cstr()
+    }
+}
+
+impl Write for Utf8CStringBuilder {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        self.push_str(s).map_err(|_| core::fmt::Error)
+    }
+
+    fn write_char(&mut self, c: char) -> core::fmt::Result {
+        self.push(c).map_err(|_| core::fmt::Error)
+    }
+}
+
+impl From<Utf8CString> for Utf8CStringBuilder {
+    fn from(value: Utf8CString) -> Self {
+        Self {
+            buffer: value.into_string_with_nul(),
+        }
+    }
+}
+
+impl From<Utf8CStringBuilder> for Utf8CString {
+    fn from(value: Utf8CStringBuilder) -> Self {
+        value.into_cstring()
+    }
+}
+
+impl core::fmt::Debug for Utf8CStringBuilder {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.as_utf8_cstr().fmt(f)
+    }
+}
+
+impl core::fmt::Display for Utf8CStringBuilder {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.as_utf8_cstr().fmt(f)
+    }
+}
+
+#[doc(hidden)]
+pub fn __format(args: core::fmt::Arguments<'_>) -> Result<Utf8CString, FormatError> {
+    let mut builder = Utf8CStringBuilder::new();
+    builder.push_fmt(args)?;
+    Ok(builder.into_cstring())
+}
@@ -0,0 +1,225 @@
+// Copyright (c) 2024 Lily Lyons
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+use core::{borrow::Borrow, ops::Deref};
+
+use crate::{Utf8CStr, Utf8CString};
+use alloc::{borrow::Cow, boxed::Box, ffi::NulError, string::String};
+
+/// The longest string (including the nul terminator) that [`InlineUtf8CStr`] can hold without allocating.
+pub const INLINE_CAPACITY: usize = 128;
+
+/// A <code>&[`Utf8CStr`]</code> that may have needed a nul terminator added to it.
+///
+/// Strings that are already nul-terminated are borrowed.
+/// Other strings are copied into an inline buffer of [`INLINE_CAPACITY`] bytes to add the nul terminator,
+/// or onto the heap if they are too long for it, so short strings never allocate.
+///
+/// This is returned by [`AsUtf8CStr::to_utf8_cstr`] and [`IntoUtf8CStr::into_utf8_cstr`], and derefs to [`Utf8CStr`].
+pub struct InlineUtf8CStr<'a>(Repr<'a>);
+
+enum Repr<'a> {
+    Borrowed(&'a Utf8CStr),
+    Inline {
+        buffer: [u8; INLINE_CAPACITY],
+        // including the nul terminator
+        len: usize,
+    },
+    Owned(Utf8CString),
+}
+
+impl<'a> InlineUtf8CStr<'a> {
+    /// Converts a string into a C string, adding the nul terminator if `string` doesn't already end with one.
+    ///
+    /// Returns an error if `string` contains a nul byte anywhere but the end.
+    pub fn new(string: &'a str) -> Result<Self, NulError> {
+        if let Ok(cstr) = Utf8CStr::from_str_with_nul(string) {
+            return Ok(Self(Repr::Borrowed(cstr)));
+        }
+
+        if string.len() < INLINE_CAPACITY && !string.as_bytes().contains(&0) {
+            let mut buffer = [0; INLINE_CAPACITY];
+            buffer[..string.len()].copy_from_slice(string.as_bytes());
+            return Ok(Self(Repr::Inline {
+                buffer,
+                len: string.len() + 1,
+            }));
+        }
+
+        // too long for the buffer, or has an interior nul (which is the only way to create a NulError)
+        Utf8CString::new(string).map(|cstring| Self(Repr::Owned(cstring)))
+    }
+
+    /// Returns `true` if the string was copied onto the heap.
+    #[must_use]
+    pub fn spilled(&self) -> bool {
+        matches!(self.0, Repr::Owned(_))
+    }
+
+    /// Converts this into an owned C string, which only allocates if the string isn't already on the heap.
+    #[must_use]
+    pub fn into_cstring(self) -> Utf8CString {
+        match self.0 {
+            Repr::Owned(cstring) => cstring,
+            _ => self.to_owned(),
+        }
+    }
+}
+
+impl Deref for InlineUtf8CStr<'_> {
+    type Target = Utf8CStr;
+
+    fn deref(&self) -> &Self::Target {
+        match &self.0 {
+            Repr::Borrowed(cstr) => cstr,
+            // SAFETY: the buffer was copied from a str without interior nul bytes, and is followed by a nul terminator.
+            Repr::Inline { buffer, len } => unsafe {
+                Utf8CStr::from_utf8_with_nul_unchecked(&buffer[..*len])
+            },
+            Repr::Owned(cstring) => cstring,
+        }
+    }
+}
+
+impl AsRef<Utf8CStr> for InlineUtf8CStr<'_> {
+    fn as_ref(&self) -> &Utf8CStr {
+        self
+    }
+}
+
+impl Borrow<Utf8CStr> for InlineUtf8CStr<'_> {
+    fn borrow(&self) -> &Utf8CStr {
+        self
+    }
+}
+
+impl core::fmt::Debug for InlineUtf8CStr<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        (**self).fmt(f)
+    }
+}
+
+impl core::fmt::Display for InlineUtf8CStr<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        (**self).fmt(f)
+    }
+}
+
+/// Types that can be borrowed as a [`Utf8CStr`], adding a nul terminator without allocating where possible.
+///
+/// This is meant for function arguments, so that both <code>&[`Utf8CStr`]</code> and plain strings can be passed in:
+///
+/// ```rust
+/// use lanyard::{c, AsUtf8CStr};
+/// use std::ffi::NulError;
+///
+/// fn print(string: impl AsUtf8CStr) -> Result<(), NulError> {
+///     let string = string.to_utf8_cstr()?;
+///     println!("{string}");
+///     Ok(())
+/// }
+///
+/// # fn main() -> Result<(), NulError> {
+/// print(c!("Hello"))?;
+/// print("Hello")?;
+/// print(&format!("Hello, {}!", "world"))?;
+/// # Ok(())
+/// # }
+/// ```
+pub trait AsUtf8CStr {
+    /// Borrows `self` as a C string, copying it into an [`InlineUtf8CStr`] if it needs a nul terminator.
+    ///
+    /// Returns an error if `self` contains an interior nul byte.
+    fn to_utf8_cstr(&self) -> Result<InlineUtf8CStr<'_>, NulError>;
+}
+
+impl AsUtf8CStr for Utf8CStr {
+    fn to_utf8_cstr(&self) -> Result<InlineUtf8CStr<'_>, NulError> {
+        Ok(InlineUtf8CStr(Repr::Borrowed(self)))
+    }
+}
+
+impl AsUtf8CStr for Utf8CString {
+    fn to_utf8_cstr(&self) -> Result<InlineUtf8CStr<'_>, NulError> {
+        Ok(InlineUtf8CStr(Repr::Borrowed(self)))
+    }
+}
+
+impl AsUtf8CStr for InlineUtf8CStr<'_> {
+    fn to_utf8_cstr(&self) -> Result<InlineUtf8CStr<'_>, NulError> {
+        Ok(InlineUtf8CStr(Repr::Borrowed(self)))
+    }
+}
+
+impl AsUtf8CStr for str {
+    fn to_utf8_cstr(&self) -> Result<InlineUtf8CStr<'_>, NulError> {
+        InlineUtf8CStr::new(self)
+    }
+}
+
+impl AsUtf8CStr for String {
+    fn to_utf8_cstr(&self) -> Result<InlineUtf8CStr<'_>, NulError> {
+        InlineUtf8CStr::new(self)
+    }
+}
+
+impl AsUtf8CStr for Box<str> {
+    fn to_utf8_cstr(&self) -> Result<InlineUtf8CStr<'_>, NulError> {
+        InlineUtf8CStr::new(self)
+    }
+}
+
+impl AsUtf8CStr for Cow<'_, str> {
+    fn to_utf8_cstr(&self) -> Result<InlineUtf8CStr<'_>, NulError> {
+        InlineUtf8CStr::new(self)
+    }
+}
+
+impl<T: AsUtf8CStr + ?Sized> AsUtf8CStr for &T {
+    fn to_utf8_cstr(&self) -> Result<InlineUtf8CStr<'_>, NulError> {
+        (**self).to_utf8_cstr()
+    }
+}
+
+impl<T: AsUtf8CStr + ?Sized> AsUtf8CStr for &mut T {
+    fn to_utf8_cstr(&self) -> Result<InlineUtf8CStr<'_>, NulError> {
+        (**self).to_utf8_cstr()
+    }
+}
+
+/// Types that can be converted into an [`InlineUtf8CStr`], like [`AsUtf8CStr`] but by value.
+///
+/// Borrowed strings are converted like [`AsUtf8CStr::to_utf8_cstr`],
+/// while owned strings keep their allocation instead of being copied.
+pub trait IntoUtf8CStr<'a> {
+    /// Converts `self` into a C string, adding a nul terminator if needed.
+    ///
+    /// Returns an error if `self` contains an interior nul byte.
+    fn into_utf8_cstr(self) -> Result<InlineUtf8CStr<'a>, NulError>;
+}
+
+impl<'a, T: AsUtf8CStr + ?Sized> IntoUtf8CStr<'a> for &'a T {
+    fn into_utf8_cstr(self) -> Result<InlineUtf8CStr<'a>, NulError> {
+        self.to_utf8_cstr()
+    }
+}
+
+impl<'a> IntoUtf8CStr<'a> for InlineUtf8CStr<'a> {
+    fn into_utf8_cstr(self) -> Result<InlineUtf8CStr<'a>, NulError> {
+        Ok(self)
+    }
+}
+
+impl IntoUtf8CStr<'static> for Utf8CString {
+    fn into_utf8_cstr(self) -> Result<InlineUtf8CStr<'static>, NulError> {
+        Ok(InlineUtf8CStr(Repr::Owned(self)))
+    }
+}
+
+impl IntoUtf8CStr<'static> for String {
+    fn into_utf8_cstr(self) -> Result<InlineUtf8CStr<'static>, NulError> {
+        Utf8CString::new(self).map(|cstring| InlineUtf8CStr(Repr::Owned(cstring)))
+    }
+}
@@ -18,13 +18,21 @@
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
+#[cfg(feature = "alloc")]
+mod builder;
 mod cstr;
 #[cfg(feature = "alloc")]
 mod cstring;
+#[cfg(feature = "alloc")]
+mod inline;
 
+#[cfg(feature = "alloc")]
+pub use builder::*;
 pub use cstr::*;
 #[cfg(feature = "alloc")]
 pub use cstring::*;
+#[cfg(feature = "alloc")]
+pub use inline::*;
 
 /// Create a const <code>&'static [`Utf8CStr`]</code> from a string literal.
 ///
@@ -58,6 +66,32 @@ macro_rules! c {
     }};
 }
 
+/// Create a [`Utf8CString`] from formatted text, like [`format!`].
+///
+/// Returns a <code>[Result]<[Utf8CString], [FormatError]></code>,
+/// as the formatted text might contain a nul byte, or a formatting trait implementation might return an error.
+///
+/// # Example
+///
+/// ```rust
+/// use lanyard::cformat;
+///
+/// let surface = "grass";
+/// let path = cformat!("event:/Footsteps/{surface}").unwrap();
+/// assert_eq!(path, "event:/Footsteps/grass");
+///
+/// cformat!("Hello{}, world!", '\0').expect_err("string had interior nul");
+/// ```
+///
+/// To build many strings without reallocating each time, use [`Utf8CStringBuilder`].
+#[cfg(feature = "alloc")]
+#[macro_export]
+macro_rules! cformat {
+    ($($arg:tt)*) => {
+        $crate::__format(core::format_args!($($arg)*))
+    };
+}
+
 macro_rules! cmp_impls {
     (impl $impl_for:ty {
       $(
@@ -100,7 +134,9 @@ pub(crate) use cmp_impls;
 
 #[cfg(test)]
 mod tests {
-    use crate::{Utf8CStr, Utf8CString};
+    use crate::{
+        AsUtf8CStr, FormatError, Utf8CStr, Utf8CString, Utf8CStringBuilder, INLINE_CAPACITY,
+    };
 
     const TEST_STR: &str = "Hello, world!";
     const INTERIOR_NUL: &str = "Hello\0, world!";
@@ -134,4 +170,97 @@ mod tests {
         let str = Utf8CString::new(TEST_STR).unwrap();
         assert_eq!(str, TEST_STR);
     }
+
+    #[test]
+    fn builder_reuse() {
+        let mut builder = Utf8CStringBuilder::with_capacity(32);
+        builder.push_str("Hello").unwrap();
+        builder.push(',').unwrap();
+        let capacity = builder.capacity();
+        builder.clear();
+        builder.push_fmt(format_args!("{TEST_STR}")).unwrap();
+        assert_eq!(builder.as_str_with_nul(), TRAILING_NUL);
+        assert_eq!(builder.capacity(), capacity);
+    }
+
+    #[test]
+    fn builder_shrink() {
+        let mut builder = Utf8CStringBuilder::with_capacity(255);
+        builder.push_str(TEST_STR).unwrap();
+        builder.shrink_to_fit();
+        assert_eq!(builder.capacity(), TEST_STR.len());
+        assert_eq!(builder.into_cstring(), TEST_STR);
+    }
+
+    #[test]
+    fn builder_interior() {
+        let mut builder = Utf8CStringBuilder::new();
+        builder.push_str("Hello").unwrap();
+        builder
+            .push_str(INTERIOR_NUL)
+            .expect_err("string had interior nul");
+        builder
+            .push_fmt(format_args!(", {INTERIOR_NUL}"))
+            .expect_err("string had interior nul");
+        assert_eq!(builder.as_str_with_nul(), "Hello\0");
+    }
+
+    #[test]
+    fn builder_fmt_error() {
+        struct Failing;
+
+        impl core::fmt::Display for Failing {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str("partial")?;
+                Err(core::fmt::Error)
+            }
+        }
+
+        let mut builder = Utf8CStringBuilder::new();
+        builder.push_str("Hello").unwrap();
+        let error = builder.push_fmt(format_args!(", {Failing}")).unwrap_err();
+        assert_eq!(error, FormatError::Fmt(core::fmt::Error));
+        assert_eq!(builder.as_str_with_nul(), "Hello\0");
+        assert_eq!(
+            cformat!("{Failing}"),
+            Err(FormatError::Fmt(core::fmt::Error))
+        );
+
+        let Err(FormatError::Nul(error)) = cformat!("Hello{}, world!", '\0') else {
+            panic!("string had interior nul");
+        };
+        assert_eq!(error.nul_position(), 5);
+    }
+
+    #[test]
+    fn builder_mut_vec() {
+        let mut builder = Utf8CStringBuilder::with_capacity(16);
+        let bytes = unsafe { builder.as_mut_vec() };
+        bytes.clear();
+        bytes.extend_from_slice(b"Hello\0");
+        assert_eq!(builder.as_str_with_nul(), "Hello\0");
+        assert!(builder.capacity() >= 16);
+    }
+
+    #[test]
+    fn inline_short() {
+        let str = TEST_STR.to_utf8_cstr().unwrap();
+        assert!(!str.spilled());
+        assert_eq!(str.as_str_with_nul(), TRAILING_NUL);
+    }
+
+    #[test]
+    fn inline_long() {
+        let long = "a".repeat(INLINE_CAPACITY);
+        let str = long.to_utf8_cstr().unwrap();
+        assert!(str.spilled());
+        assert_eq!(&*str, long.as_str());
+    }
+
+    #[test]
+    fn inline_interior() {
+        INTERIOR_NUL
+            .to_utf8_cstr()
+            .expect_err("string had interior nul");
+    }
 }